@@ -3,6 +3,7 @@
 extern crate test;
 
 use algoritmer::search::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use test::Bencher;
 
 #[bench]
@@ -20,7 +21,25 @@ fn jump_search_bench(b: &mut Bencher) {
 #[bench]
 fn linear_search_bench(b: &mut Bencher) {
     let arr = [1, 3, 5, 7, 9, 11, 13, 15, 2, 4, 6, 8, 10, 12, 14, 16];
-    b.iter(|| linear(&arr, 9).unwrap());
+    b.iter(|| linear(&arr, &9).unwrap());
+}
+
+#[bench]
+fn linear_search_table_bench(b: &mut Bencher) {
+    let table: Vec<u32> = (0..4096).rev().collect();
+    b.iter(|| linear(&table, &17).unwrap());
+}
+
+#[bench]
+fn linear_sentinel_search_table_bench(b: &mut Bencher) {
+    let mut table: Vec<u32> = (0..4096).rev().collect();
+    b.iter(|| linear_sentinel(&mut table, &17).unwrap());
+}
+
+#[bench]
+fn linear_simd_search_table_bench(b: &mut Bencher) {
+    let table: Vec<u32> = (0..4096).rev().collect();
+    b.iter(|| linear_simd(&table, &17).unwrap());
 }
 
 #[bench]
@@ -34,3 +53,31 @@ fn ternary_search_bench(b: &mut Bencher) {
     let arr = [1, 3, 5, 7, 9, 11, 13, 15, 2, 4, 6, 8, 10, 12, 14, 16];
     b.iter(|| ternary(&arr, &9).unwrap());
 }
+
+/// A sorted table much larger than the cache, and pseudo-random keys to look up in it.
+fn large_table() -> (Vec<u32>, Vec<u32>) {
+    let table: Vec<u32> = (0..1 << 22).map(|i| 3 * i).collect();
+    let mut rng = StdRng::seed_from_u64(0x2545_f491);
+    let queries = (0..1024).map(|_| rng.gen_range(0..3 << 22)).collect();
+    (table, queries)
+}
+
+#[bench]
+fn binary_search_large_bench(b: &mut Bencher) {
+    let (table, queries) = large_table();
+    b.iter(|| queries.iter().filter_map(|key| binary(&table, key)).count());
+}
+
+#[bench]
+fn eytzinger_search_large_bench(b: &mut Bencher) {
+    let (table, queries) = large_table();
+    let index = StaticSearchIndex::new(&table, Layout::Eytzinger);
+    b.iter(|| queries.iter().filter_map(|key| index.find(key)).count());
+}
+
+#[bench]
+fn btree_search_large_bench(b: &mut Bencher) {
+    let (table, queries) = large_table();
+    let index = StaticSearchIndex::new(&table, Layout::BTree);
+    b.iter(|| queries.iter().filter_map(|key| index.find(key)).count());
+}
@@ -0,0 +1,206 @@
+#![feature(test)]
+
+extern crate test;
+
+use algoritmer::strings::search::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use test::Bencher;
+
+/// A worst case for quadratic searches: `n` copies of `a` followed by `b`,
+/// searched for `a` repeated `n / 8` times followed by `b`.
+///
+/// Doubling `n` should double the time taken by a linear search.
+fn periodic(n: usize) -> (String, String) {
+    let text = format!("{}b", "a".repeat(n));
+    let pattern = format!("{}b", "a".repeat(n / 8));
+    (text, pattern)
+}
+
+/// Non-ASCII text of about `n` bytes ending in the pattern.
+fn accented(n: usize) -> (String, String) {
+    let text = format!("{}s\u{f8}", "\u{e6}\u{f8}\u{e5} ".repeat(n / 7));
+    (text, String::from("\u{e5} s\u{f8}"))
+}
+
+#[bench]
+fn kmp_check_periodic_4k_bench(b: &mut Bencher) {
+    let (text, pattern) = periodic(1 << 12);
+    b.iter(|| kmp_check(&text, &pattern));
+}
+
+#[bench]
+fn kmp_check_periodic_8k_bench(b: &mut Bencher) {
+    let (text, pattern) = periodic(1 << 13);
+    b.iter(|| kmp_check(&text, &pattern));
+}
+
+#[bench]
+fn kmp_check_periodic_16k_bench(b: &mut Bencher) {
+    let (text, pattern) = periodic(1 << 14);
+    b.iter(|| kmp_check(&text, &pattern));
+}
+
+#[bench]
+fn kmp_check_accented_4k_bench(b: &mut Bencher) {
+    let (text, pattern) = accented(1 << 12);
+    b.iter(|| kmp_check(&text, &pattern));
+}
+
+#[bench]
+fn kmp_check_accented_16k_bench(b: &mut Bencher) {
+    let (text, pattern) = accented(1 << 14);
+    b.iter(|| kmp_check(&text, &pattern));
+}
+
+#[bench]
+fn kmp_search_chars_16k_bench(b: &mut Bencher) {
+    let (text, pattern) = periodic(1 << 14);
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    b.iter(|| kmp_search(&text, &pattern));
+}
+
+#[bench]
+fn z_function_periodic_4k_bench(b: &mut Bencher) {
+    let (text, _) = periodic(1 << 12);
+    b.iter(|| z_function(&text));
+}
+
+#[bench]
+fn z_function_periodic_8k_bench(b: &mut Bencher) {
+    let (text, _) = periodic(1 << 13);
+    b.iter(|| z_function(&text));
+}
+
+#[bench]
+fn z_function_periodic_16k_bench(b: &mut Bencher) {
+    let (text, _) = periodic(1 << 14);
+    b.iter(|| z_function(&text));
+}
+
+#[bench]
+fn z_search_periodic_4k_bench(b: &mut Bencher) {
+    let (text, pattern) = periodic(1 << 12);
+    b.iter(|| z_search(text.as_bytes(), pattern.as_bytes()));
+}
+
+#[bench]
+fn z_search_periodic_16k_bench(b: &mut Bencher) {
+    let (text, pattern) = periodic(1 << 14);
+    b.iter(|| z_search(text.as_bytes(), pattern.as_bytes()));
+}
+
+#[bench]
+fn prefix_function_periodic_16k_bench(b: &mut Bencher) {
+    let (text, _) = periodic(1 << 14);
+    b.iter(|| prefix_function(text.as_bytes()));
+}
+
+/// Pseudorandom text of about `n` bytes drawn from `words`.
+fn generate(n: usize, words: &[&str]) -> String {
+    let mut rng = StdRng::seed_from_u64(0x2545_f491);
+    let mut text = String::with_capacity(n + 16);
+    while text.len() < n {
+        text.push_str(words[rng.gen_range(0..words.len())]);
+    }
+    text
+}
+
+/// About 256 KiB of English-like prose, searched for a phrase that only occurs at the end.
+fn prose() -> (String, &'static str) {
+    let words: Vec<String> = "the of and to in a is that for it as was with be by on not he this \
+        are or his from at which but have an had they you were their one all we can her has there \
+        been if more when will would who so no river morning quietly."
+        .split_whitespace()
+        .map(|word| format!("{word} "))
+        .collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    let needle = "the quick brown fox jumps over the lazy dog";
+    (generate(1 << 18, &words) + needle, needle)
+}
+
+/// About 256 KiB of DNA, searched for a sequence that only occurs at the end.
+fn dna() -> (String, &'static str) {
+    let needle = "GATTACAGATTACATTTAGGCCAT";
+    (generate(1 << 18, &["A", "C", "G", "T"]) + needle, needle)
+}
+
+#[bench]
+fn brute_force_prose_bench(b: &mut Bencher) {
+    let (text, pattern) = prose();
+    b.iter(|| brute_force(&text, pattern));
+}
+
+#[bench]
+fn kmp_check_prose_bench(b: &mut Bencher) {
+    let (text, pattern) = prose();
+    b.iter(|| kmp_check(&text, pattern));
+}
+
+#[bench]
+fn boyer_moore_prose_bench(b: &mut Bencher) {
+    let (text, pattern) = prose();
+    let matcher = BoyerMooreMatcher::new(pattern);
+    b.iter(|| matcher.find(&text));
+}
+
+#[bench]
+fn horspool_prose_bench(b: &mut Bencher) {
+    let (text, pattern) = prose();
+    let matcher = HorspoolMatcher::new(pattern);
+    b.iter(|| matcher.find(&text));
+}
+
+#[bench]
+fn sunday_prose_bench(b: &mut Bencher) {
+    let (text, pattern) = prose();
+    let matcher = SundayMatcher::new(pattern);
+    b.iter(|| matcher.find(&text));
+}
+
+#[bench]
+fn two_way_prose_bench(b: &mut Bencher) {
+    let (text, pattern) = prose();
+    let matcher = TwoWayMatcher::new(pattern);
+    b.iter(|| matcher.find(&text));
+}
+
+#[bench]
+fn brute_force_dna_bench(b: &mut Bencher) {
+    let (text, pattern) = dna();
+    b.iter(|| brute_force(&text, pattern));
+}
+
+#[bench]
+fn kmp_check_dna_bench(b: &mut Bencher) {
+    let (text, pattern) = dna();
+    b.iter(|| kmp_check(&text, pattern));
+}
+
+#[bench]
+fn boyer_moore_dna_bench(b: &mut Bencher) {
+    let (text, pattern) = dna();
+    let matcher = BoyerMooreMatcher::new(pattern);
+    b.iter(|| matcher.find(&text));
+}
+
+#[bench]
+fn horspool_dna_bench(b: &mut Bencher) {
+    let (text, pattern) = dna();
+    let matcher = HorspoolMatcher::new(pattern);
+    b.iter(|| matcher.find(&text));
+}
+
+#[bench]
+fn sunday_dna_bench(b: &mut Bencher) {
+    let (text, pattern) = dna();
+    let matcher = SundayMatcher::new(pattern);
+    b.iter(|| matcher.find(&text));
+}
+
+#[bench]
+fn two_way_dna_bench(b: &mut Bencher) {
+    let (text, pattern) = dna();
+    let matcher = TwoWayMatcher::new(pattern);
+    b.iter(|| matcher.find(&text));
+}
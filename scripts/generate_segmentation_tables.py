@@ -0,0 +1,127 @@
+#!/usr/bin/env python3
+"""Generates the property tables of src/strings/segmentation from the Unicode
+Character Database.
+
+Usage:
+
+    scripts/generate_segmentation_tables.py <ucd> > src/strings/segmentation/tables.rs
+
+where <ucd> is the unzipped UCD.zip of a version of Unicode, from
+https://www.unicode.org/Public/<version>/ucd/UCD.zip. It reads
+auxiliary/GraphemeBreakProperty.txt, auxiliary/WordBreakProperty.txt,
+emoji/emoji-data.txt and DerivedCoreProperties.txt.
+
+The conformance tests run auxiliary/GraphemeBreakTest.txt and
+auxiliary/WordBreakTest.txt of the same UCD:
+
+    UCD=<ucd> cargo test conformance -- --ignored
+
+Hangul syllables are left out of the grapheme table, as their property
+follows from their code point.
+"""
+
+import os
+import re
+import sys
+
+HANGUL_SYLLABLES = range(0xAC00, 0xD7A4)
+
+
+def read(ucd, path, select):
+    """Yields the code point ranges and values of the lines of a property file
+    that `select` keeps, given their fields, and the version of the file."""
+    with open(os.path.join(ucd, path), encoding="utf-8") as file:
+        lines = file.read().splitlines()
+    version = re.search(r"-(\d+)\.(\d+)\.(\d+)\.txt", lines[0]).groups()
+    ranges = []
+    for line in lines:
+        fields = [field.strip() for field in line.split("#")[0].split(";")]
+        if len(fields) < 2:
+            continue
+        value = select(fields[1:])
+        if value is None:
+            continue
+        first, _, last = fields[0].partition("..")
+        ranges.append((int(first, 16), int(last or first, 16), value))
+    return ranges, tuple(int(part) for part in version)
+
+
+def merge(ranges):
+    """Sorts ranges, checks they do not overlap and joins adjacent ones with
+    the same value."""
+    merged = []
+    for first, last, value in sorted(ranges):
+        if merged and first <= merged[-1][1]:
+            raise ValueError(f"{first:04X} has both {merged[-1][2]} and {value}")
+        if merged and first == merged[-1][1] + 1 and value == merged[-1][2]:
+            merged[-1] = (merged[-1][0], last, value)
+        else:
+            merged.append((first, last, value))
+    return merged
+
+
+def rust_name(value):
+    """The name of a Rust variant for a property value, as `Lvt` for `LVT`
+    and `RegionalIndicator` for `Regional_Indicator`."""
+    return "".join(
+        part.capitalize() if part.isupper() and len(part) > 1 else part
+        for part in value.split("_")
+    )
+
+
+def table(name, alias, ranges):
+    rows = "".join(
+        f"    ('\\u{{{first:X}}}', '\\u{{{last:X}}}', {alias}::{rust_name(value)}),\n"
+        for first, last, value in ranges
+    )
+    return f"pub(super) const {name}: &[(char, char, {alias})] = &[\n{rows}];\n"
+
+
+def main(ucd):
+    grapheme, version = read(ucd, "auxiliary/GraphemeBreakProperty.txt", lambda fields: fields[0])
+    grapheme = [
+        (first, last, value)
+        for first, last, value in grapheme
+        if not (value in ("LV", "LVT") and first in HANGUL_SYLLABLES and last in HANGUL_SYLLABLES)
+    ]
+    pictographic, _ = read(
+        ucd,
+        "emoji/emoji-data.txt",
+        lambda fields: "Extended_Pictographic" if fields[0] == "Extended_Pictographic" else None,
+    )
+    word, _ = read(ucd, "auxiliary/WordBreakProperty.txt", lambda fields: fields[0])
+    conjunct, _ = read(
+        ucd,
+        "DerivedCoreProperties.txt",
+        lambda fields: fields[1] if fields[0] == "InCB" else None,
+    )
+
+    print(
+        "//! Unicode character properties for text segmentation.\n"
+        "//!\n"
+        "//! Generated by `scripts/generate_segmentation_tables.py` from the Unicode\n"
+        "//! Character Database. Do not edit by hand.\n"
+        "\n"
+        "use super::properties::{GraphemeBreak as G, IndicConjunctBreak as I, WordBreak as W};\n"
+        "\n"
+        "/// The version of Unicode that segmentation follows.\n"
+        f"pub const UNICODE_VERSION: (u8, u8, u8) = {version};\n"
+        "\n"
+        "/// `Grapheme_Cluster_Break` values other than `Other`, with\n"
+        "/// `Extended_Pictographic`, which only `Other` characters have. Hangul\n"
+        "/// syllables are left out.\n"
+        + table("GRAPHEME_BREAK", "G", merge(grapheme + pictographic))
+        + "\n"
+        "/// `Indic_Conjunct_Break` values other than `None`.\n"
+        + table("INDIC_CONJUNCT_BREAK", "I", merge(conjunct))
+        + "\n"
+        "/// `Word_Break` values other than `Other`.\n"
+        + table("WORD_BREAK", "W", merge(word)),
+        end="",
+    )
+
+
+if __name__ == "__main__":
+    if len(sys.argv) != 2:
+        sys.exit(__doc__)
+    main(sys.argv[1])
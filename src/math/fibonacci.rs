@@ -77,7 +77,7 @@ impl Fibonacci for usize {
     )]
     fn analytic(self) -> usize {
         let sqrt_5 = f64::sqrt(5.0);
-        let phi = (1. + sqrt_5) / 2.;
+        let phi = f64::midpoint(1., sqrt_5);
         let q = 1. / phi;
 
         ((phi.powi(self as i32) + q.powi(self as i32)) / sqrt_5 + 0.5) as usize
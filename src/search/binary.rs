@@ -51,13 +51,13 @@ where
     T: Ord,
 {
     let mut lo = 0; // set the lowest point of the array.
-    let mut hi = arr.len() - 1; // set the highest point of the array.
+    let mut hi = arr.len(); // set one past the highest point of the array.
 
-    while lo < hi + 1 {
+    while lo < hi {
         let m = lo + (hi - lo) / 2; // set the middle point of the array.
 
         match key.cmp(&arr[m]) {
-            Ordering::Less => hi = m - 1,
+            Ordering::Less => hi = m,
             Ordering::Greater => lo = m + 1,
             Ordering::Equal => return Some(m),
         }
@@ -83,5 +83,7 @@ mod tests {
         assert_eq!(binary(&arr, &8), Some(7));
         assert_eq!(binary(&arr, &9), Some(8));
         assert_eq!(binary(&arr, &10), None);
+        assert_eq!(binary(&arr, &0), None);
+        assert_eq!(binary(&[], &0), None);
     }
 }
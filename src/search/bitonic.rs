@@ -0,0 +1,151 @@
+use core::cmp::Ordering;
+
+/// Finds the peak of a bitonic array.
+///
+/// A bitonic array first strictly increases and then strictly decreases, like
+/// the halves [`bitonic`](crate::sorts::bitonic) sort merges. Comparing the
+/// middle element with its right neighbour tells which side of the peak it
+/// is on.
+///
+/// # Arguments
+///
+/// * `arr` - A strictly increasing run followed by a strictly decreasing run.
+///   Either run may be empty.
+///
+/// # Returns
+///
+/// The index of the largest element, or `None` if the array is empty.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::bitonic_peak;
+///
+/// assert_eq!(bitonic_peak(&[1, 3, 8, 12, 4, 2]), Some(3));
+/// assert_eq!(bitonic_peak(&[5, 4, 3]), Some(0));
+/// assert_eq!(bitonic_peak::<i32>(&[]), None);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(log n)
+///
+/// ## Space Complexity
+///
+/// O(1)
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Bitonic_sorter)
+pub fn bitonic_peak<T>(arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if arr.is_empty() {
+        return None;
+    }
+
+    let (mut lo, mut hi) = (0, arr.len() - 1);
+    while lo < hi {
+        let m = lo + (hi - lo) / 2;
+        if arr[m] < arr[m + 1] {
+            lo = m + 1;
+        } else {
+            hi = m;
+        }
+    }
+
+    Some(lo)
+}
+
+/// Searches a bitonic array for a key.
+///
+/// The peak is found first, then the increasing run before it and the
+/// decreasing run after it are each binary searched.
+///
+/// # Arguments
+///
+/// * `arr` - A strictly increasing run followed by a strictly decreasing run.
+/// * `key` - The target value being searched for.
+///
+/// # Returns
+///
+/// The index of the target value if found, or `None` if not found.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::bitonic_search;
+///
+/// let arr = [1, 3, 8, 12, 4, 2];
+/// assert_eq!(bitonic_search(&arr, &4), Some(4));
+/// assert_eq!(bitonic_search(&arr, &3), Some(1));
+/// assert_eq!(bitonic_search(&arr, &5), None);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(log n)
+///
+/// ## Space Complexity
+///
+/// O(1)
+pub fn bitonic_search<T>(arr: &[T], key: &T) -> Option<usize>
+where
+    T: Ord,
+{
+    let peak = bitonic_peak(arr)?;
+    let (increasing, decreasing) = arr.split_at(peak + 1);
+
+    search_run(increasing, |x| x.cmp(key))
+        .or_else(|| search_run(decreasing, |x| key.cmp(x)).map(|i| peak + 1 + i))
+}
+
+/// Binary searches a run that `cmp` orders as ascending.
+fn search_run<T, F>(run: &[T], cmp: F) -> Option<usize>
+where
+    F: Fn(&T) -> Ordering,
+{
+    let (mut lo, mut hi) = (0, run.len());
+    while lo < hi {
+        let m = lo + (hi - lo) / 2;
+        match cmp(&run[m]) {
+            Ordering::Less => lo = m + 1,
+            Ordering::Greater => hi = m,
+            Ordering::Equal => return Some(m),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(&[1] => Some(0))]
+    #[test_case(&[1, 2] => Some(1))]
+    #[test_case(&[2, 1] => Some(0))]
+    #[test_case(&[1, 2, 3, 4, 5] => Some(4))]
+    #[test_case(&[5, 4, 3, 2, 1] => Some(0))]
+    #[test_case(&[1, 4, 9, 7, 3, 0] => Some(2))]
+    #[test_case(&[-3, 10, 9, 8, 7, 6, 5, 4] => Some(1))]
+    fn test_bitonic_peak(arr: &[i32]) -> Option<usize> {
+        bitonic_peak(arr)
+    }
+
+    #[test]
+    fn test_bitonic_search() {
+        let arr = [1, 3, 5, 7, 9, 11, 13, 15, 16, 14, 12, 10, 8, 6, 4, 2];
+        for (i, value) in arr.iter().enumerate() {
+            assert_eq!(bitonic_search(&arr, value), Some(i));
+        }
+        assert_eq!(bitonic_search(&arr, &0), None);
+        assert_eq!(bitonic_search(&arr, &17), None);
+    }
+}
@@ -0,0 +1,198 @@
+use alloc::vec::Vec;
+
+/// One augmented list of a [`FractionalCascade`].
+///
+/// The keys are the original list merged with every other key of the next
+/// augmented list. Each key, and one sentinel past the end, records where
+/// its lower bound falls in the original list and in the next augmented list.
+#[derive(Debug, Clone)]
+struct Level<T> {
+    keys: Vec<T>,
+    /// The number of elements of the original list that are less than each key.
+    own: Vec<usize>,
+    /// The position of the lower bound of each key in the next level.
+    bridge: Vec<usize>,
+}
+
+/// Finds the position of one key in many sorted lists at once.
+///
+/// Searching `k` lists of total length `n` separately costs `k` binary
+/// searches. Fractional cascading links each list to the next by merging in
+/// every other element of it, so that once the key has been located in the
+/// first list its position in every following list is at most a couple of
+/// steps away from a precomputed pointer.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::FractionalCascade;
+///
+/// let catalogues = [vec![2, 5, 9, 12], vec![1, 5, 6], vec![], vec![3, 10, 11, 20]];
+/// let cascade = FractionalCascade::new(&catalogues);
+///
+/// let positions: Vec<usize> = cascade.lower_bounds(&5).collect();
+/// assert_eq!(positions, [1, 1, 0, 1]);
+///
+/// let positions: Vec<usize> = cascade.lower_bounds(&13).collect();
+/// assert_eq!(positions, [4, 3, 0, 3]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Build: O(n)
+/// - Query: O(log n + k)
+///
+/// ## Space Complexity
+///
+/// O(n) - each augmented list is at most twice the size of the lists from it onwards.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Fractional_cascading)
+/// - [Chazelle and Guibas, Fractional cascading: I. A data structuring technique](https://doi.org/10.1007/BF01840440)
+#[derive(Debug, Clone)]
+pub struct FractionalCascade<T> {
+    levels: Vec<Level<T>>,
+}
+
+impl<T> FractionalCascade<T>
+where
+    T: Ord + Clone,
+{
+    /// Links `lists` together for searching.
+    ///
+    /// # Arguments
+    ///
+    /// * `lists` - The lists to search, each sorted in ascending order.
+    #[must_use]
+    pub fn new<L>(lists: &[L]) -> Self
+    where
+        L: AsRef<[T]>,
+    {
+        let mut levels: Vec<Level<T>> = Vec::with_capacity(lists.len());
+
+        for list in lists.iter().rev() {
+            let list = list.as_ref();
+            let next = levels.last().map_or(&[][..], |level| &level.keys);
+            let sample: Vec<&T> = next.iter().skip(1).step_by(2).collect();
+
+            let mut level = Level {
+                keys: Vec::with_capacity(list.len() + sample.len()),
+                own: Vec::with_capacity(list.len() + sample.len() + 1),
+                bridge: Vec::with_capacity(list.len() + sample.len() + 1),
+            };
+
+            // Sampled keys go before equal keys of the list, so that the
+            // first of a run of equal keys always carries the lower bound.
+            let (mut i, mut j, mut b) = (0, 0, 0);
+            let mut run_start = 0;
+            while i < list.len() || j < sample.len() {
+                let from_list = j == sample.len() || (i < list.len() && list[i] < *sample[j]);
+                let (key, own) = if from_list {
+                    if i == 0 || list[i - 1] < list[i] {
+                        run_start = i;
+                    }
+                    i += 1;
+                    (&list[i - 1], run_start)
+                } else {
+                    // Every element of the list less than the sampled key has been merged.
+                    j += 1;
+                    (sample[j - 1], i)
+                };
+
+                while b < next.len() && next[b] < *key {
+                    b += 1;
+                }
+                level.keys.push(key.clone());
+                level.own.push(own);
+                level.bridge.push(b);
+            }
+            level.own.push(list.len());
+            level.bridge.push(next.len());
+
+            levels.push(level);
+        }
+
+        levels.reverse();
+        Self { levels }
+    }
+
+    /// The number of lists.
+    #[must_use]
+    pub fn lists(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Finds the lower bound of `key` in every list.
+    ///
+    /// # Returns
+    ///
+    /// A lazy iterator yielding, for each list in order, the number of its
+    /// elements that are less than `key`. This is the position of the first
+    /// element not less than `key`, or the length of the list if there is none.
+    pub fn lower_bounds<'a>(&'a self, key: &'a T) -> impl Iterator<Item = usize> + 'a {
+        let mut position = self
+            .levels
+            .first()
+            .map_or(0, |level| level.keys.partition_point(|x| x < key));
+
+        self.levels.iter().enumerate().map(move |(depth, level)| {
+            if depth > 0 {
+                // Only keys in `[key, bridge)` can precede the bridged
+                // position, and sampling leaves at most one of them.
+                let previous = &self.levels[depth - 1];
+                position = previous.bridge[position];
+                while position > 0 && level.keys[position - 1] >= *key {
+                    position -= 1;
+                }
+            }
+            level.own[position]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{vec, vec::Vec};
+
+    fn check(lists: &[Vec<i32>]) {
+        let cascade = FractionalCascade::new(lists);
+        assert_eq!(cascade.lists(), lists.len());
+
+        let max = lists.iter().flatten().max().copied().unwrap_or(0);
+        for key in -1..=max + 1 {
+            let expected: Vec<usize> = lists
+                .iter()
+                .map(|list| list.partition_point(|x| *x < key))
+                .collect();
+            let actual: Vec<usize> = cascade.lower_bounds(&key).collect();
+            assert_eq!(actual, expected, "key {key}");
+        }
+    }
+
+    #[test]
+    fn agrees_with_binary_search() {
+        let lists: Vec<Vec<i32>> = (1..12)
+            .map(|k| (0..40 * k).filter(|x| x % (k + 1) == k % 3).collect())
+            .collect();
+        check(&lists);
+    }
+
+    #[test]
+    fn handles_duplicates_and_empty_lists() {
+        check(&[
+            vec![1, 1, 1, 2, 2, 5],
+            vec![],
+            vec![1, 2, 2, 2, 2, 2, 2, 3],
+            vec![2, 2, 2, 2],
+            vec![0, 0, 7, 7, 7],
+            vec![],
+        ]);
+        check(&[]);
+        check(&[vec![], vec![]]);
+    }
+}
@@ -0,0 +1,365 @@
+use alloc::vec::Vec;
+use core::ops::{Bound, Range, RangeBounds};
+
+use super::binary;
+
+/// A line that predicts the position of the keys starting at `key`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Segment {
+    key: u64,
+    position: usize,
+    slope: f64,
+}
+
+impl Segment {
+    fn predict(&self, key: u64) -> f64 {
+        self.position as f64 + self.slope * (key - self.key) as f64
+    }
+}
+
+/// A learned index over a sorted set of `u64` keys.
+///
+/// The positions of the keys are approximated by a piecewise linear function
+/// whose error is bounded by `epsilon`, in the style of the PGM-index. A
+/// lookup finds the segment covering the key, predicts its position, and
+/// searches only a window of `2 * max_error + 3` keys around the prediction,
+/// where [`max_error`](Self::max_error) is the largest error measured over
+/// the keys and is at most `epsilon + 1`.
+///
+/// The model is usually orders of magnitude smaller than the keys, since one
+/// segment covers every run of keys that are spread out almost evenly.
+///
+/// The segments are fitted greedily with a shrinking cone, which bounds the
+/// error but can produce more segments than the optimal fit of the
+/// PGM-index.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::LearnedIndex;
+///
+/// let keys: Vec<u64> = (0..1000).map(|i| i * i).collect();
+/// let index = LearnedIndex::new(&keys, 8);
+///
+/// assert_eq!(index.find(400), Some(20));
+/// assert_eq!(index.find(401), None);
+/// assert_eq!(index.lower_bound(401), 21);
+/// assert_eq!(index.range(100..=900), 10..31);
+/// assert!(index.max_error() <= 8);
+/// assert!(index.segments() < 100);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Build: O(n)
+/// - Query: O(log m + log ε), where `m` is the number of segments.
+///
+/// ## Space Complexity
+///
+/// O(m) - the keys themselves are borrowed.
+///
+/// # References
+///
+/// - [The PGM-index](https://pgm.di.unipi.it/)
+/// - [The Case for Learned Index Structures](https://arxiv.org/abs/1712.01208)
+#[derive(Debug, Clone)]
+pub struct LearnedIndex<'a> {
+    keys: &'a [u64],
+    segments: Vec<Segment>,
+    epsilon: usize,
+    max_error: usize,
+}
+
+impl<'a> LearnedIndex<'a> {
+    /// Fits a learned index over `keys`.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys to index, in strictly ascending order.
+    /// * `epsilon` - The largest distance allowed between a predicted and an actual position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keys` are not in strictly ascending order.
+    #[must_use]
+    pub fn new(keys: &'a [u64], epsilon: usize) -> Self {
+        assert!(
+            keys.windows(2).all(|w| w[0] < w[1]),
+            "keys must be strictly ascending"
+        );
+
+        let mut index = Self {
+            keys,
+            segments: fit(keys, epsilon as f64),
+            epsilon,
+            max_error: 0,
+        };
+
+        // Rounding can push a prediction slightly past `epsilon`, so the
+        // window is sized by the error that is actually observed. The keys
+        // are ascending, so their segments are found in a single pass.
+        let mut segment = 0;
+        for (i, &key) in keys.iter().enumerate() {
+            while index.segments.get(segment).is_some_and(|s| s.key <= key) {
+                segment += 1;
+            }
+            index.max_error = index
+                .max_error
+                .max(index.predict_in(segment, key).abs_diff(i));
+        }
+
+        index
+    }
+
+    /// The keys the index was built over.
+    #[must_use]
+    pub fn keys(&self) -> &'a [u64] {
+        self.keys
+    }
+
+    /// The error bound the segments were fitted with.
+    #[must_use]
+    pub fn epsilon(&self) -> usize {
+        self.epsilon
+    }
+
+    /// The largest distance between the predicted and the actual position of any key.
+    #[must_use]
+    pub fn max_error(&self) -> usize {
+        self.max_error
+    }
+
+    /// The number of linear segments in the model.
+    #[must_use]
+    pub fn segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// The size of the model in bytes, not counting the keys.
+    #[must_use]
+    pub fn model_size(&self) -> usize {
+        size_of_val(self.segments.as_slice())
+    }
+
+    /// Finds `key` in the index.
+    ///
+    /// # Returns
+    ///
+    /// The position of `key` among the keys, or `None` if it is not present.
+    #[must_use]
+    pub fn find(&self, key: u64) -> Option<usize> {
+        let window = self.window(key);
+        binary(&self.keys[window.clone()], &key).map(|i| window.start + i)
+    }
+
+    /// Counts the keys that are less than `key`.
+    ///
+    /// This is also the position of the first key that is not less than `key`.
+    #[must_use]
+    pub fn lower_bound(&self, key: u64) -> usize {
+        let window = self.window(key);
+        window.start + self.keys[window].partition_point(|&k| k < key)
+    }
+
+    /// Finds the positions of the keys within `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::search::LearnedIndex;
+    ///
+    /// let keys = [2, 3, 5, 7, 11, 13, 17, 19];
+    /// let index = LearnedIndex::new(&keys, 1);
+    ///
+    /// let found = index.range(4..17);
+    /// assert_eq!(&keys[found], &[5, 7, 11, 13]);
+    /// ```
+    #[must_use]
+    pub fn range<R>(&self, range: R) -> Range<usize>
+    where
+        R: RangeBounds<u64>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&key) => self.lower_bound(key),
+            Bound::Excluded(&key) => self.upper_bound(key),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&key) => self.upper_bound(key),
+            Bound::Excluded(&key) => self.lower_bound(key),
+            Bound::Unbounded => self.keys.len(),
+        };
+        start..end.max(start)
+    }
+
+    /// Counts the keys that are not greater than `key`.
+    fn upper_bound(&self, key: u64) -> usize {
+        key.checked_add(1)
+            .map_or(self.keys.len(), |next| self.lower_bound(next))
+    }
+
+    /// Predicts the position of the first key that is not less than `key`.
+    fn predict(&self, key: u64) -> usize {
+        self.predict_in(self.segments.partition_point(|s| s.key <= key), key)
+    }
+
+    /// Predicts the position of `key` with the segment before `i`, the last
+    /// one that starts at or before it.
+    #[allow(clippy::cast_sign_loss)]
+    fn predict_in(&self, i: usize, key: u64) -> usize {
+        if i == 0 {
+            return 0;
+        }
+
+        // Keys past the end of a segment can only be ranked up to the start
+        // of the next one.
+        let next = self.segments.get(i).map_or(self.keys.len(), |s| s.position);
+        let segment = &self.segments[i - 1];
+        (segment.predict(key).round().max(0.0) as usize).clamp(segment.position, next)
+    }
+
+    /// The positions that can hold the lower bound of `key`.
+    fn window(&self, key: u64) -> Range<usize> {
+        let predicted = self.predict(key);
+        let start = predicted.saturating_sub(self.max_error + 1);
+        let end = (predicted + self.max_error + 2).min(self.keys.len());
+        start..end
+    }
+}
+
+/// Splits `keys` into segments whose predictions are at most `epsilon` off.
+///
+/// Each segment starts at a key and keeps the range of slopes that predict
+/// every key seen since within `epsilon`. When a key narrows the range to
+/// nothing, a new segment starts at that key.
+fn fit(keys: &[u64], epsilon: f64) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let Some(&first) = keys.first() else {
+        return segments;
+    };
+
+    let mut start = Segment {
+        key: first,
+        position: 0,
+        slope: 0.0,
+    };
+    let (mut lo, mut hi) = (0.0, f64::INFINITY);
+
+    for (position, &key) in keys.iter().enumerate().skip(1) {
+        let dx = (key - start.key) as f64;
+        let dy = (position - start.position) as f64;
+        let (min, max) = ((dy - epsilon) / dx, (dy + epsilon) / dx);
+
+        if min > hi || max < lo {
+            segments.push(Segment {
+                slope: slope(lo, hi),
+                ..start
+            });
+            start = Segment {
+                key,
+                position,
+                slope: 0.0,
+            };
+            (lo, hi) = (0.0, f64::INFINITY);
+        } else {
+            lo = f64::max(lo, min);
+            hi = f64::min(hi, max);
+        }
+    }
+
+    segments.push(Segment {
+        slope: slope(lo, hi),
+        ..start
+    });
+    segments
+}
+
+/// Picks a slope in the middle of the cone `[lo, hi]`.
+fn slope(lo: f64, hi: f64) -> f64 {
+    if hi.is_finite() {
+        f64::midpoint(lo, hi)
+    } else {
+        lo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use test_case::test_case;
+
+    /// Keys with uneven gaps, from a seeded generator.
+    fn irregular_keys(n: usize) -> Vec<u64> {
+        let mut rng = StdRng::seed_from_u64(0x9e37_79b9_7f4a_7c15);
+        let mut key = 0;
+        (0..n)
+            .map(|_| {
+                key += rng.gen_range(1..=1000);
+                key
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty_index() {
+        let index = LearnedIndex::new(&[], 4);
+        assert_eq!(index.segments(), 0);
+        assert_eq!(index.find(1), None);
+        assert_eq!(index.lower_bound(1), 0);
+        assert_eq!(index.range(..), 0..0);
+    }
+
+    #[test_case(0)]
+    #[test_case(1)]
+    #[test_case(4)]
+    #[test_case(64)]
+    fn agrees_with_binary_search(epsilon: usize) {
+        let keys = irregular_keys(2000);
+        let index = LearnedIndex::new(&keys, epsilon);
+        assert!(index.max_error() <= epsilon + 1);
+
+        for (i, &key) in keys.iter().enumerate() {
+            assert_eq!(index.find(key), Some(i));
+            assert_eq!(index.find(key + 1), keys.binary_search(&(key + 1)).ok());
+            assert_eq!(
+                index.lower_bound(key - 1),
+                keys.partition_point(|&k| k < key - 1)
+            );
+        }
+        assert_eq!(index.lower_bound(0), 0);
+        assert_eq!(index.lower_bound(u64::MAX), keys.len());
+    }
+
+    #[test]
+    fn evenly_spread_keys_need_one_segment() {
+        let keys: Vec<u64> = (0..10_000).map(|i| 7 * i + 3).collect();
+        let index = LearnedIndex::new(&keys, 0);
+        assert_eq!(index.segments(), 1);
+        assert_eq!(index.max_error(), 0);
+        assert_eq!(index.model_size(), size_of::<Segment>());
+    }
+
+    #[test]
+    fn larger_epsilon_gives_smaller_model() {
+        let keys = irregular_keys(5000);
+        let small = LearnedIndex::new(&keys, 2);
+        let large = LearnedIndex::new(&keys, 32);
+        assert!(large.segments() < small.segments());
+    }
+
+    #[test]
+    fn range_lookups() {
+        let keys = [1, 4, 9, 16, 25, 36, 49, 64, 81, u64::MAX];
+        let index = LearnedIndex::new(&keys, 1);
+        assert_eq!(index.range(9..49), 2..6);
+        assert_eq!(index.range(9..=49), 2..7);
+        assert_eq!(index.range((Bound::Excluded(9), Bound::Unbounded)), 3..10);
+        assert_eq!(index.range(..=u64::MAX), 0..10);
+        assert_eq!(index.range(50..50), 7..7);
+    }
+}
@@ -1,4 +1,6 @@
-/// A Rust implementation of a binary search algorithm.
+use core::simd::{cmp::SimdPartialEq, Simd};
+
+/// A Rust implementation of a linear search algorithm.
 ///
 /// # Arguments
 ///
@@ -9,15 +11,53 @@
 ///
 /// The index of the target value if found, or None if not found
 ///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::linear;
+///
+/// let words = ["red", "green", "blue"];
+/// assert_eq!(linear(&words, &"blue"), Some(2));
+/// assert_eq!(linear(&words, &"cyan"), None);
+/// ```
+///
 /// # References
 ///
 /// - [Wikipedia](https://en.wikipedia.org/wiki/Linear_search)
-pub fn linear<T>(arr: &[T], key: T) -> Option<usize>
+pub fn linear<T>(arr: &[T], key: &T) -> Option<usize>
+where
+    T: PartialEq,
+{
+    linear_by(arr, |v| v == key)
+}
+
+/// Finds the first element that satisfies a predicate.
+///
+/// # Arguments
+///
+/// * `arr` - The array being searched
+/// * `predicate` - Returns `true` for the element being searched for
+///
+/// # Returns
+///
+/// The index of the first element for which `predicate` returns `true`, or
+/// None if there is none
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::linear_by;
+///
+/// let arr = [3, 8, 5, 12, 7];
+/// assert_eq!(linear_by(&arr, |&x| x > 10), Some(3));
+/// assert_eq!(linear_by(&arr, |&x| x > 20), None);
+/// ```
+pub fn linear_by<T, P>(arr: &[T], mut predicate: P) -> Option<usize>
 where
-    T: PartialOrd + Copy,
+    P: FnMut(&T) -> bool,
 {
-    for (i, &v) in arr.iter().enumerate() {
-        if v == key {
+    for (i, v) in arr.iter().enumerate() {
+        if predicate(v) {
             return Some(i);
         }
     }
@@ -25,15 +65,258 @@ where
     None
 }
 
+/// Finds every occurrence of a value.
+///
+/// # Arguments
+///
+/// * `arr` - The array being searched
+/// * `key` - The target value being searched for
+///
+/// # Returns
+///
+/// A lazy iterator over the indices of every element equal to `key`, in
+/// ascending order
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::find_all;
+///
+/// let arr = [1, 2, 1, 3, 1];
+/// assert!(find_all(&arr, &1).eq([0, 2, 4]));
+/// assert_eq!(find_all(&arr, &4).next(), None);
+/// ```
+pub fn find_all<'a, T>(arr: &'a [T], key: &'a T) -> impl Iterator<Item = usize> + 'a
+where
+    T: PartialEq,
+{
+    arr.iter()
+        .enumerate()
+        .filter_map(move |(i, v)| (v == key).then_some(i))
+}
+
+/// A linear search that stores the key in the last slot as a sentinel.
+///
+/// With the key in the last slot, the loop is certain to stop there at the
+/// latest, so it has no end-of-array condition of its own. This needs `Eq`:
+/// a key that is not equal to itself, like a NaN, would run the loop off the
+/// end. Since `Eq` is a safe trait, each index is still bounds checked, so an
+/// implementation that breaks it makes the search panic rather than read
+/// past the array.
+///
+/// The last element is restored before returning, and also if a comparison
+/// panics.
+///
+/// # Arguments
+///
+/// * `arr` - The array being searched, borrowed mutably for the duration of the search
+/// * `key` - The target value being searched for
+///
+/// # Returns
+///
+/// The index of the target value if found, or None if not found
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::linear_sentinel;
+///
+/// let mut arr = [4, 8, 15, 16, 23, 42];
+/// assert_eq!(linear_sentinel(&mut arr, &16), Some(3));
+/// assert_eq!(linear_sentinel(&mut arr, &42), Some(5));
+/// assert_eq!(linear_sentinel(&mut arr, &7), None);
+/// assert_eq!(arr, [4, 8, 15, 16, 23, 42]);
+/// ```
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Linear_search#With_a_sentinel)
+pub fn linear_sentinel<T>(arr: &mut [T], key: &T) -> Option<usize>
+where
+    T: Eq + Clone,
+{
+    let last = arr.len().checked_sub(1)?;
+    let saved = core::mem::replace(&mut arr[last], key.clone());
+    let sentinel = Sentinel { arr, saved };
+
+    let mut i = 0;
+    while sentinel.arr[i] != sentinel.arr[last] {
+        i += 1;
+    }
+
+    (i < last || sentinel.saved == sentinel.arr[last]).then_some(i)
+}
+
+/// Puts the last element of an array back in place of the key when dropped.
+struct Sentinel<'a, T> {
+    arr: &'a mut [T],
+    saved: T,
+}
+
+impl<T> Drop for Sentinel<'_, T> {
+    fn drop(&mut self) {
+        let last = self.arr.len() - 1;
+        core::mem::swap(&mut self.arr[last], &mut self.saved);
+    }
+}
+
+/// Integer types that can be searched with [`linear_simd`].
+pub trait SimdSearchable: Copy + PartialEq + private::Sealed {
+    #[doc(hidden)]
+    fn find_simd(arr: &[Self], key: &Self) -> Option<usize>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_simd_searchable {
+    ($($t:ty),* $(,)?) => {$(
+        impl private::Sealed for $t {}
+
+        impl SimdSearchable for $t {
+            fn find_simd(arr: &[Self], key: &Self) -> Option<usize> {
+                // One 64-byte cache line per block, whatever the width of the
+                // type, including `usize` on targets of any pointer width.
+                const LANES: usize = 64 / core::mem::size_of::<$t>();
+
+                let (prefix, middle, suffix) = arr.as_simd::<LANES>();
+                if let Some(i) = linear(prefix, key) {
+                    return Some(i);
+                }
+
+                let keys = Simd::splat(*key);
+                for (i, chunk) in middle.iter().enumerate() {
+                    if let Some(j) = chunk.simd_eq(keys).first_set() {
+                        return Some(prefix.len() + i * LANES + j);
+                    }
+                }
+
+                linear(suffix, key).map(|i| arr.len() - suffix.len() + i)
+            }
+        }
+    )*};
+}
+
+impl_simd_searchable!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+
+/// A linear search over primitive integers that compares a whole block of
+/// elements at once with SIMD instructions.
+///
+/// Each block is 64 bytes, one cache line, and is checked with a single
+/// vector comparison. The unaligned elements at either end are checked one
+/// at a time.
+///
+/// # Arguments
+///
+/// * `arr` - The array being searched
+/// * `key` - The target value being searched for
+///
+/// # Returns
+///
+/// The index of the target value if found, or None if not found
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::linear_simd;
+///
+/// let arr: Vec<u32> = (0..1000).rev().collect();
+/// assert_eq!(linear_simd(&arr, &10), Some(989));
+/// assert_eq!(linear_simd(&arr, &1000), None);
+/// ```
+pub fn linear_simd<T>(arr: &[T], key: &T) -> Option<usize>
+where
+    T: SimdSearchable,
+{
+    T::find_simd(arr, key)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use alloc::vec::Vec;
+
     #[test]
     fn test_linear_search() {
         let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-        assert_eq!(linear(&arr, 1), Some(0));
-        assert_eq!(linear(&arr, 10), Some(9));
-        assert_eq!(linear(&arr, 11), None);
+        assert_eq!(linear(&arr, &1), Some(0));
+        assert_eq!(linear(&arr, &10), Some(9));
+        assert_eq!(linear(&arr, &11), None);
+    }
+
+    #[test]
+    fn test_linear_sentinel() {
+        let mut arr = [3, 1, 4, 1, 5, 9, 2, 6];
+        for key in 0..10 {
+            let expected = linear(&arr, &key);
+            assert_eq!(linear_sentinel(&mut arr, &key), expected);
+        }
+        assert_eq!(arr, [3, 1, 4, 1, 5, 9, 2, 6]);
+        assert_eq!(linear_sentinel(&mut [], &1), None);
+    }
+
+    /// Panics when compared with the value 9, and is otherwise compared by value.
+    #[derive(Debug, Clone)]
+    struct Fragile(u8);
+
+    impl PartialEq for Fragile {
+        fn eq(&self, other: &Self) -> bool {
+            assert!(self.0 != 9 && other.0 != 9, "compared with 9");
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Fragile {}
+
+    /// Is equal to nothing, not even itself, in breach of `Eq`.
+    #[derive(Debug, Clone)]
+    struct Unequal;
+
+    impl PartialEq for Unequal {
+        fn eq(&self, _: &Self) -> bool {
+            false
+        }
+    }
+
+    impl Eq for Unequal {}
+
+    #[test]
+    fn test_linear_sentinel_restores_after_panic() {
+        extern crate std;
+
+        let mut arr = [Fragile(1), Fragile(9), Fragile(3)];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            linear_sentinel(&mut arr, &Fragile(5))
+        }));
+        assert!(result.is_err());
+        assert_eq!(arr.map(|x| x.0), [1, 9, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_linear_sentinel_broken_eq() {
+        let _ = linear_sentinel(&mut [Unequal, Unequal], &Unequal);
+    }
+
+    #[test]
+    fn test_linear_simd() {
+        let arr: Vec<i16> = (0..300).map(|i| (i * 7) % 251 - 100).collect();
+        // Search every alignment of the array.
+        for start in 0..40 {
+            let arr = &arr[start..];
+            for key in -105..155 {
+                assert_eq!(linear_simd(arr, &key), linear(arr, &key), "key {key}");
+            }
+        }
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(linear_simd(&bytes, &200), Some(200));
+        assert_eq!(linear_simd::<u64>(&[], &1), None);
+
+        let indices: Vec<usize> = (0..100).collect();
+        assert_eq!(linear_simd(&indices, &77), Some(77));
+        assert_eq!(linear_simd(&indices[3..], &2), None);
     }
 }
@@ -1,15 +1,31 @@
 //! A collection of useful search algorithms
 
 mod binary;
+mod bitonic;
 mod exponential;
 mod fibonacci;
+mod fractional_cascade;
 mod jump;
+mod learned;
 mod linear;
+mod rotated;
+mod set_operations;
+mod sorted_matrix;
+mod static_index;
 mod ternary;
+mod unimodal;
 
 pub use binary::*;
+pub use bitonic::*;
 pub use exponential::*;
 pub use fibonacci::*;
+pub use fractional_cascade::*;
 pub use jump::*;
+pub use learned::*;
 pub use linear::*;
+pub use rotated::*;
+pub use set_operations::*;
+pub use sorted_matrix::*;
+pub use static_index::*;
 pub use ternary::*;
+pub use unimodal::*;
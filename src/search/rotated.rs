@@ -0,0 +1,180 @@
+use core::cmp::Ordering;
+
+/// Searches a sorted array that has been rotated by an unknown amount.
+///
+/// A rotated array such as `[5, 6, 7, 1, 2, 3, 4]` consists of two sorted
+/// runs. At least one half around the middle element is always sorted, so
+/// comparing the key with the ends of that half tells which half to keep.
+///
+/// # Arguments
+///
+/// * `arr` - A sorted array rotated by any number of positions.
+/// * `key` - The target value being searched for.
+///
+/// # Returns
+///
+/// The index of the target value if found, or `None` if not found.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::rotated;
+///
+/// let arr = [15, 18, 22, 3, 4, 7, 10, 12];
+/// assert_eq!(rotated(&arr, &4), Some(4));
+/// assert_eq!(rotated(&arr, &18), Some(1));
+/// assert_eq!(rotated(&arr, &5), None);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Best: O(1)
+/// - Worst: O(n) - when most of the elements are equal, neither half can be ruled out.
+/// - Average: O(log n)
+///
+/// ## Space Complexity
+///
+/// O(1)
+///
+/// # References
+///
+/// - [GeeksforGeeks](https://www.geeksforgeeks.org/search-an-element-in-a-sorted-and-pivoted-array/)
+pub fn rotated<T>(arr: &[T], key: &T) -> Option<usize>
+where
+    T: Ord,
+{
+    let (mut lo, mut hi) = (0, arr.len());
+
+    while lo < hi {
+        let m = lo + (hi - lo) / 2;
+        let last = hi - 1;
+
+        if arr[m] == *key {
+            return Some(m);
+        }
+
+        if arr[lo] == arr[m] && arr[m] == arr[last] {
+            // The rotation point could be on either side, but neither end is the key.
+            lo += 1;
+            hi = last;
+        } else if arr[lo] <= arr[m] {
+            // The left half is sorted.
+            if arr[lo] <= *key && *key < arr[m] {
+                hi = m;
+            } else {
+                lo = m + 1;
+            }
+        } else if arr[m] < *key && *key <= arr[last] {
+            // The right half is sorted.
+            lo = m + 1;
+        } else {
+            hi = m;
+        }
+    }
+
+    None
+}
+
+/// Finds the index of the smallest element of a sorted array that has been rotated.
+///
+/// This is the number of positions the array was rotated right by.
+///
+/// # Arguments
+///
+/// * `arr` - A sorted array rotated by any number of positions. The elements must be distinct.
+///
+/// # Returns
+///
+/// The index of the smallest element, or `None` if the array is empty.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::rotation_point;
+///
+/// assert_eq!(rotation_point(&[15, 18, 22, 3, 4, 7, 10, 12]), Some(3));
+/// assert_eq!(rotation_point(&[1, 2, 3]), Some(0));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(log n)
+///
+/// ## Space Complexity
+///
+/// O(1)
+pub fn rotation_point<T>(arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let last = arr.last()?;
+    let (mut lo, mut hi) = (0, arr.len() - 1);
+
+    // Every element before the rotation point is greater than the last one.
+    while lo < hi {
+        let m = lo + (hi - lo) / 2;
+        match arr[m].cmp(last) {
+            Ordering::Greater => lo = m + 1,
+            Ordering::Less | Ordering::Equal => hi = m,
+        }
+    }
+
+    Some(lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    #[test]
+    fn test_rotated_search() {
+        let sorted: Vec<i32> = (0..20).map(|i| 3 * i).collect();
+        for shift in 0..sorted.len() {
+            let mut arr = sorted.clone();
+            arr.rotate_right(shift);
+
+            assert_eq!(rotation_point(&arr), Some(shift));
+            for (i, value) in arr.iter().enumerate() {
+                assert_eq!(rotated(&arr, value), Some(i));
+                assert_eq!(rotated(&arr, &(value + 1)), None);
+            }
+            assert_eq!(rotated(&arr, &-1), None);
+        }
+    }
+
+    #[test]
+    fn test_rotated_search_every_small_array() {
+        // Every sorted array of up to 7 elements drawn from 0..3, in every rotation.
+        for len in 0..=7 {
+            for mask in 0..3_u32.pow(len) {
+                let mut sorted: Vec<u32> = (0..len).map(|i| mask / 3_u32.pow(i) % 3).collect();
+                sorted.sort_unstable();
+                for shift in 0..sorted.len().max(1) {
+                    let mut arr = sorted.clone();
+                    arr.rotate_right(shift);
+                    for key in 0..4 {
+                        let found = rotated(&arr, &key);
+                        assert_eq!(found.is_some(), arr.contains(&key), "{arr:?} {key}");
+                        assert!(found.is_none_or(|i| arr[i] == key));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test_case(&[2, 2, 2, 3, 2, 2, 2], 3 => Some(3))]
+    #[test_case(&[2, 2, 2, 2, 2, 3, 2], 3 => Some(5))]
+    #[test_case(&[1, 1, 1, 1, 1, 1, 1], 2 => None)]
+    #[test_case(&[4, 5, 1, 1, 1, 1, 2, 3], 3 => Some(7))]
+    #[test_case(&[], 1 => None)]
+    fn test_rotated_search_with_duplicates(arr: &[i32], key: i32) -> Option<usize> {
+        rotated(arr, &key)
+    }
+}
@@ -0,0 +1,771 @@
+use alloc::vec::Vec;
+use core::{cmp::Ordering, iter::FusedIterator};
+
+/// The number of elements stepped over one at a time before [`seek`] starts galloping.
+const MIN_GALLOP: usize = 8;
+
+/// Finds the first position at or after `from` whose element is not less than `target`.
+///
+/// Short skips are found with a linear scan, as in an ordinary merge. Once
+/// more than [`MIN_GALLOP`] elements have been skipped, the step doubles
+/// each time and the final range is binary searched, so skipping `d`
+/// elements costs O(log d) comparisons.
+fn seek<T, F>(slice: &[T], from: usize, target: &T, cmp: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut less = |i: usize| cmp(&slice[i], target) == Ordering::Less;
+
+    let linear_end = slice.len().min(from + MIN_GALLOP);
+    let mut lo = from;
+    while lo < linear_end {
+        if !less(lo) {
+            return lo;
+        }
+        lo += 1;
+    }
+
+    let mut step = 1;
+    let mut hi = lo;
+    while hi < slice.len() && less(hi) {
+        lo = hi + 1;
+        hi += step;
+        step *= 2;
+    }
+    hi = hi.min(slice.len());
+
+    while lo < hi {
+        let m = lo + (hi - lo) / 2;
+        if less(m) {
+            lo = m + 1;
+        } else {
+            hi = m;
+        }
+    }
+    lo
+}
+
+/// A lazy iterator over the union of two sorted sets, created by [`union`] and [`union_by`].
+#[derive(Debug, Clone)]
+pub struct Union<'a, T, F> {
+    a: &'a [T],
+    b: &'a [T],
+    cmp: F,
+}
+
+impl<'a, T, F> Iterator for Union<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let (Some(x), Some(y)) = (self.a.first(), self.b.first()) else {
+            let (x, rest) = self.a.split_first().or_else(|| self.b.split_first())?;
+            if self.a.is_empty() {
+                self.b = rest;
+            } else {
+                self.a = rest;
+            }
+            return Some(x);
+        };
+
+        match (self.cmp)(x, y) {
+            Ordering::Less => self.a = &self.a[1..],
+            Ordering::Greater => {
+                self.b = &self.b[1..];
+                return Some(y);
+            }
+            Ordering::Equal => {
+                self.a = &self.a[1..];
+                self.b = &self.b[1..];
+            }
+        }
+        Some(x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.a.len(), self.b.len());
+        (a.max(b), a.checked_add(b))
+    }
+}
+
+impl<T, F> FusedIterator for Union<'_, T, F> where F: FnMut(&T, &T) -> Ordering {}
+
+/// A lazy iterator over the intersection of two sorted sets, created by
+/// [`intersection`] and [`intersection_by`].
+#[derive(Debug, Clone)]
+pub struct Intersection<'a, T, F> {
+    a: &'a [T],
+    b: &'a [T],
+    cmp: F,
+}
+
+impl<'a, T, F> Iterator for Intersection<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let (x, y) = (self.a.first()?, self.b.first()?);
+            match (self.cmp)(x, y) {
+                Ordering::Less => self.a = &self.a[seek(self.a, 1, y, &mut self.cmp)..],
+                Ordering::Greater => self.b = &self.b[seek(self.b, 1, x, &mut self.cmp)..],
+                Ordering::Equal => {
+                    self.a = &self.a[1..];
+                    self.b = &self.b[1..];
+                    return Some(x);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.a.len().min(self.b.len())))
+    }
+}
+
+impl<T, F> FusedIterator for Intersection<'_, T, F> where F: FnMut(&T, &T) -> Ordering {}
+
+/// A lazy iterator over the elements of one sorted set that are not in
+/// another, created by [`difference`] and [`difference_by`].
+#[derive(Debug, Clone)]
+pub struct Difference<'a, T, F> {
+    a: &'a [T],
+    b: &'a [T],
+    cmp: F,
+}
+
+impl<'a, T, F> Iterator for Difference<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let (x, rest) = self.a.split_first()?;
+            let Some(y) = self.b.first() else {
+                self.a = rest;
+                return Some(x);
+            };
+
+            match (self.cmp)(x, y) {
+                Ordering::Less => {
+                    self.a = rest;
+                    return Some(x);
+                }
+                Ordering::Greater => self.b = &self.b[seek(self.b, 1, x, &mut self.cmp)..],
+                Ordering::Equal => {
+                    self.a = rest;
+                    self.b = &self.b[1..];
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.a.len().saturating_sub(self.b.len()),
+            Some(self.a.len()),
+        )
+    }
+}
+
+impl<T, F> FusedIterator for Difference<'_, T, F> where F: FnMut(&T, &T) -> Ordering {}
+
+/// A lazy iterator over the elements in exactly one of two sorted sets,
+/// created by [`symmetric_difference`] and [`symmetric_difference_by`].
+#[derive(Debug, Clone)]
+pub struct SymmetricDifference<'a, T, F> {
+    a: &'a [T],
+    b: &'a [T],
+    cmp: F,
+}
+
+impl<'a, T, F> Iterator for SymmetricDifference<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let (Some(x), Some(y)) = (self.a.first(), self.b.first()) else {
+                let (x, rest) = self.a.split_first().or_else(|| self.b.split_first())?;
+                if self.a.is_empty() {
+                    self.b = rest;
+                } else {
+                    self.a = rest;
+                }
+                return Some(x);
+            };
+
+            match (self.cmp)(x, y) {
+                Ordering::Less => {
+                    self.a = &self.a[1..];
+                    return Some(x);
+                }
+                Ordering::Greater => {
+                    self.b = &self.b[1..];
+                    return Some(y);
+                }
+                Ordering::Equal => {
+                    self.a = &self.a[1..];
+                    self.b = &self.b[1..];
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.a.len().checked_add(self.b.len()))
+    }
+}
+
+impl<T, F> FusedIterator for SymmetricDifference<'_, T, F> where F: FnMut(&T, &T) -> Ordering {}
+
+/// Iterates over the elements that are in `a`, in `b`, or in both.
+///
+/// # Arguments
+///
+/// * `a` - A set, sorted in ascending order without duplicates.
+/// * `b` - A set, sorted in ascending order without duplicates.
+///
+/// # Returns
+///
+/// A lazy iterator over the union, in ascending order. An element in both
+/// sets is yielded once, from `a`.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::union;
+///
+/// let found: Vec<_> = union(&[1, 3, 5], &[2, 3, 4]).copied().collect();
+/// assert_eq!(found, [1, 2, 3, 4, 5]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n + m)
+pub fn union<'a, T>(a: &'a [T], b: &'a [T]) -> Union<'a, T, fn(&T, &T) -> Ordering>
+where
+    T: Ord,
+{
+    union_by(a, b, T::cmp)
+}
+
+/// Iterates over the elements that are in `a`, in `b`, or in both, as ordered by `cmp`.
+///
+/// See [`union`].
+pub fn union_by<'a, T, F>(a: &'a [T], b: &'a [T], cmp: F) -> Union<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Union { a, b, cmp }
+}
+
+/// Iterates over the elements that are in both `a` and `b`.
+///
+/// When one set is much smaller than the other, the larger one is galloped
+/// through, skipping the elements between two matches with an exponential
+/// search instead of stepping over them one at a time.
+///
+/// # Arguments
+///
+/// * `a` - A set, sorted in ascending order without duplicates.
+/// * `b` - A set, sorted in ascending order without duplicates.
+///
+/// # Returns
+///
+/// A lazy iterator over the intersection, in ascending order, yielding the elements of `a`.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::intersection;
+///
+/// let postings: Vec<u32> = (0..1_000_000).step_by(3).collect();
+/// let found: Vec<_> = intersection(&[4, 9, 30, 31, 999_999], &postings).copied().collect();
+/// assert_eq!(found, [9, 30, 999_999]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(m log(n / m)) - where `m` is the size of the smaller set and `n` of the larger.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Exponential_search)
+/// - [Demaine, López-Ortiz and Munro, Adaptive set intersections, unions, and differences](https://dl.acm.org/doi/10.5555/338219.338634)
+pub fn intersection<'a, T>(a: &'a [T], b: &'a [T]) -> Intersection<'a, T, fn(&T, &T) -> Ordering>
+where
+    T: Ord,
+{
+    intersection_by(a, b, T::cmp)
+}
+
+/// Iterates over the elements that are in both `a` and `b`, as ordered by `cmp`.
+///
+/// See [`intersection`].
+pub fn intersection_by<'a, T, F>(a: &'a [T], b: &'a [T], cmp: F) -> Intersection<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Intersection { a, b, cmp }
+}
+
+/// Iterates over the elements of `a` that are not in `b`.
+///
+/// Runs of `b` that fall between two elements of `a` are galloped over.
+///
+/// # Arguments
+///
+/// * `a` - A set, sorted in ascending order without duplicates.
+/// * `b` - A set, sorted in ascending order without duplicates.
+///
+/// # Returns
+///
+/// A lazy iterator over the difference, in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::difference;
+///
+/// let found: Vec<_> = difference(&[1, 2, 3, 4, 5], &[2, 4, 6]).copied().collect();
+/// assert_eq!(found, [1, 3, 5]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n + m log(k / n)) - where `n` is the size of `a`, `k` of `b`, and `m = min(n, k)`.
+pub fn difference<'a, T>(a: &'a [T], b: &'a [T]) -> Difference<'a, T, fn(&T, &T) -> Ordering>
+where
+    T: Ord,
+{
+    difference_by(a, b, T::cmp)
+}
+
+/// Iterates over the elements of `a` that are not in `b`, as ordered by `cmp`.
+///
+/// See [`difference`].
+pub fn difference_by<'a, T, F>(a: &'a [T], b: &'a [T], cmp: F) -> Difference<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Difference { a, b, cmp }
+}
+
+/// Iterates over the elements that are in exactly one of `a` and `b`.
+///
+/// # Arguments
+///
+/// * `a` - A set, sorted in ascending order without duplicates.
+/// * `b` - A set, sorted in ascending order without duplicates.
+///
+/// # Returns
+///
+/// A lazy iterator over the symmetric difference, in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::symmetric_difference;
+///
+/// let found: Vec<_> = symmetric_difference(&[1, 2, 3], &[2, 3, 4]).copied().collect();
+/// assert_eq!(found, [1, 4]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n + m)
+pub fn symmetric_difference<'a, T>(
+    a: &'a [T],
+    b: &'a [T],
+) -> SymmetricDifference<'a, T, fn(&T, &T) -> Ordering>
+where
+    T: Ord,
+{
+    symmetric_difference_by(a, b, T::cmp)
+}
+
+/// Iterates over the elements that are in exactly one of `a` and `b`, as ordered by `cmp`.
+///
+/// See [`symmetric_difference`].
+pub fn symmetric_difference_by<'a, T, F>(
+    a: &'a [T],
+    b: &'a [T],
+    cmp: F,
+) -> SymmetricDifference<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SymmetricDifference { a, b, cmp }
+}
+
+/// Checks whether every element of `a` is also in `b`.
+///
+/// `b` is galloped through, so a small `a` is checked against a large `b`
+/// in time logarithmic in the size of `b`.
+///
+/// # Arguments
+///
+/// * `a` - A set, sorted in ascending order without duplicates.
+/// * `b` - A set, sorted in ascending order without duplicates.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::is_subset;
+///
+/// assert!(is_subset(&[2, 8], &[1, 2, 4, 8, 16]));
+/// assert!(!is_subset(&[2, 3], &[1, 2, 4, 8, 16]));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n log(k / n)) - where `n` is the size of `a` and `k` of `b`.
+#[must_use]
+pub fn is_subset<T>(a: &[T], b: &[T]) -> bool
+where
+    T: Ord,
+{
+    is_subset_by(a, b, T::cmp)
+}
+
+/// Checks whether every element of `a` is also in `b`, as ordered by `cmp`.
+///
+/// See [`is_subset`].
+#[must_use]
+pub fn is_subset_by<T, F>(a: &[T], b: &[T], mut cmp: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if a.len() > b.len() {
+        return false;
+    }
+
+    let mut j = 0;
+    for x in a {
+        j = seek(b, j, x, &mut cmp);
+        if j == b.len() || cmp(&b[j], x) != Ordering::Equal {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
+
+/// Appends the union of `a` and `b` to `out`.
+///
+/// Runs of one set that fall between two elements of the other are found by
+/// galloping and copied in bulk.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::union_into;
+///
+/// let mut out = vec![0];
+/// union_into(&[1, 3, 5], &[2, 3, 4], &mut out);
+/// assert_eq!(out, [0, 1, 2, 3, 4, 5]);
+/// ```
+pub fn union_into<T>(a: &[T], b: &[T], out: &mut Vec<T>)
+where
+    T: Ord + Clone,
+{
+    union_into_by(a, b, out, T::cmp);
+}
+
+/// Appends the union of `a` and `b`, as ordered by `cmp`, to `out`.
+///
+/// See [`union_into`].
+pub fn union_into_by<T, F>(mut a: &[T], mut b: &[T], out: &mut Vec<T>, mut cmp: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    out.reserve(a.len().max(b.len()));
+
+    while let (Some(x), Some(y)) = (a.first(), b.first()) {
+        match cmp(x, y) {
+            Ordering::Less => {
+                let run = seek(a, 1, y, &mut cmp);
+                out.extend_from_slice(&a[..run]);
+                a = &a[run..];
+            }
+            Ordering::Greater => {
+                let run = seek(b, 1, x, &mut cmp);
+                out.extend_from_slice(&b[..run]);
+                b = &b[run..];
+            }
+            Ordering::Equal => {
+                out.push(x.clone());
+                a = &a[1..];
+                b = &b[1..];
+            }
+        }
+    }
+
+    out.extend_from_slice(a);
+    out.extend_from_slice(b);
+}
+
+/// Appends the intersection of `a` and `b` to `out`.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::intersection_into;
+///
+/// let mut out = Vec::new();
+/// intersection_into(&[1, 3, 5], &[2, 3, 4, 5], &mut out);
+/// assert_eq!(out, [3, 5]);
+/// ```
+pub fn intersection_into<T>(a: &[T], b: &[T], out: &mut Vec<T>)
+where
+    T: Ord + Clone,
+{
+    intersection_into_by(a, b, out, T::cmp);
+}
+
+/// Appends the intersection of `a` and `b`, as ordered by `cmp`, to `out`.
+///
+/// See [`intersection_into`].
+pub fn intersection_into_by<T, F>(a: &[T], b: &[T], out: &mut Vec<T>, cmp: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    out.extend(intersection_by(a, b, cmp).cloned());
+}
+
+/// Appends the elements of `a` that are not in `b` to `out`.
+///
+/// Runs of `a` that fall between two elements of `b` are found by galloping
+/// and copied in bulk.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::difference_into;
+///
+/// let mut out = Vec::new();
+/// difference_into(&[1, 2, 3, 4, 5], &[2, 4, 6], &mut out);
+/// assert_eq!(out, [1, 3, 5]);
+/// ```
+pub fn difference_into<T>(a: &[T], b: &[T], out: &mut Vec<T>)
+where
+    T: Ord + Clone,
+{
+    difference_into_by(a, b, out, T::cmp);
+}
+
+/// Appends the elements of `a` that are not in `b`, as ordered by `cmp`, to `out`.
+///
+/// See [`difference_into`].
+pub fn difference_into_by<T, F>(mut a: &[T], mut b: &[T], out: &mut Vec<T>, mut cmp: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    while let (Some(x), Some(y)) = (a.first(), b.first()) {
+        match cmp(x, y) {
+            Ordering::Less => {
+                let run = seek(a, 1, y, &mut cmp);
+                out.extend_from_slice(&a[..run]);
+                a = &a[run..];
+            }
+            Ordering::Greater => b = &b[seek(b, 1, x, &mut cmp)..],
+            Ordering::Equal => {
+                a = &a[1..];
+                b = &b[1..];
+            }
+        }
+    }
+
+    out.extend_from_slice(a);
+}
+
+/// Appends the elements in exactly one of `a` and `b` to `out`.
+///
+/// Runs of one set that fall between two elements of the other are found by
+/// galloping and copied in bulk.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::symmetric_difference_into;
+///
+/// let mut out = Vec::new();
+/// symmetric_difference_into(&[1, 2, 3], &[2, 3, 4], &mut out);
+/// assert_eq!(out, [1, 4]);
+/// ```
+pub fn symmetric_difference_into<T>(a: &[T], b: &[T], out: &mut Vec<T>)
+where
+    T: Ord + Clone,
+{
+    symmetric_difference_into_by(a, b, out, T::cmp);
+}
+
+/// Appends the elements in exactly one of `a` and `b`, as ordered by `cmp`, to `out`.
+///
+/// See [`symmetric_difference_into`].
+pub fn symmetric_difference_into_by<T, F>(mut a: &[T], mut b: &[T], out: &mut Vec<T>, mut cmp: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    while let (Some(x), Some(y)) = (a.first(), b.first()) {
+        match cmp(x, y) {
+            Ordering::Less => {
+                let run = seek(a, 1, y, &mut cmp);
+                out.extend_from_slice(&a[..run]);
+                a = &a[run..];
+            }
+            Ordering::Greater => {
+                let run = seek(b, 1, x, &mut cmp);
+                out.extend_from_slice(&b[..run]);
+                b = &b[run..];
+            }
+            Ordering::Equal => {
+                a = &a[1..];
+                b = &b[1..];
+            }
+        }
+    }
+
+    out.extend_from_slice(a);
+    out.extend_from_slice(b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    /// Pairs of sets with small and large gaps, overlapping in different ways.
+    fn cases() -> Vec<(Vec<u32>, Vec<u32>)> {
+        let mut cases = Vec::new();
+        for (step_a, step_b) in [(1, 1), (2, 3), (1, 50), (97, 1), (7, 7)] {
+            for offset in [0, 1, 500] {
+                let a = (offset..2000).step_by(step_a).collect();
+                let b = (0..2000 - offset).step_by(step_b).collect();
+                cases.push((a, b));
+            }
+        }
+        cases.push((Vec::new(), (0..10).collect()));
+        cases.push((Vec::new(), Vec::new()));
+        cases
+    }
+
+    #[test]
+    fn agrees_with_btree_set() {
+        for (a, b) in cases() {
+            for (a, b) in [(&a, &b), (&b, &a)] {
+                let (sa, sb): (BTreeSet<u32>, BTreeSet<u32>) =
+                    (a.iter().copied().collect(), b.iter().copied().collect());
+
+                let expected: Vec<u32> = sa.union(&sb).copied().collect();
+                assert_eq!(union(a, b).copied().collect::<Vec<_>>(), expected);
+                let mut out = Vec::new();
+                union_into(a, b, &mut out);
+                assert_eq!(out, expected);
+
+                let expected: Vec<u32> = sa.intersection(&sb).copied().collect();
+                assert_eq!(intersection(a, b).copied().collect::<Vec<_>>(), expected);
+                let mut out = Vec::new();
+                intersection_into(a, b, &mut out);
+                assert_eq!(out, expected);
+
+                let expected: Vec<u32> = sa.difference(&sb).copied().collect();
+                assert_eq!(difference(a, b).copied().collect::<Vec<_>>(), expected);
+                let mut out = Vec::new();
+                difference_into(a, b, &mut out);
+                assert_eq!(out, expected);
+
+                let expected: Vec<u32> = sa.symmetric_difference(&sb).copied().collect();
+                assert_eq!(
+                    symmetric_difference(a, b).copied().collect::<Vec<_>>(),
+                    expected
+                );
+                let mut out = Vec::new();
+                symmetric_difference_into(a, b, &mut out);
+                assert_eq!(out, expected);
+
+                assert_eq!(is_subset(a, b), sa.is_subset(&sb));
+            }
+        }
+    }
+
+    #[test]
+    fn gallops_over_large_gaps() {
+        let large: Vec<u32> = (0..1_000_000).collect();
+        let mut comparisons = 0;
+        let found: Vec<_> = intersection_by(&[10, 500_000, 999_999], &large, |x, y| {
+            comparisons += 1;
+            x.cmp(y)
+        })
+        .copied()
+        .collect();
+
+        assert_eq!(found, [10, 500_000, 999_999]);
+        assert!(comparisons < 200, "{comparisons} comparisons");
+    }
+
+    #[test]
+    fn comparator_variants() {
+        let a = [9, 7, 5, 3, 1];
+        let b = [8, 7, 3, 2];
+        let desc = |x: &i32, y: &i32| y.cmp(x);
+
+        assert_eq!(
+            union_by(&a, &b, desc).copied().collect::<Vec<_>>(),
+            [9, 8, 7, 5, 3, 2, 1]
+        );
+        assert_eq!(
+            intersection_by(&a, &b, desc).copied().collect::<Vec<_>>(),
+            [7, 3]
+        );
+        assert_eq!(
+            difference_by(&a, &b, desc).copied().collect::<Vec<_>>(),
+            [9, 5, 1]
+        );
+        assert_eq!(
+            symmetric_difference_by(&a, &b, desc)
+                .copied()
+                .collect::<Vec<_>>(),
+            [9, 8, 5, 2, 1]
+        );
+        assert!(is_subset_by(&[7, 3], &a, desc));
+        assert!(!is_subset_by(&[3, 7], &a, desc));
+
+        let mut out = Vec::new();
+        union_into_by(&a, &b, &mut out, desc);
+        assert_eq!(out, [9, 8, 7, 5, 3, 2, 1]);
+        out.clear();
+        intersection_into_by(&a, &b, &mut out, desc);
+        assert_eq!(out, [7, 3]);
+        out.clear();
+        difference_into_by(&a, &b, &mut out, desc);
+        assert_eq!(out, [9, 5, 1]);
+        out.clear();
+        symmetric_difference_into_by(&a, &b, &mut out, desc);
+        assert_eq!(out, [9, 8, 5, 2, 1]);
+    }
+}
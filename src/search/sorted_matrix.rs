@@ -0,0 +1,180 @@
+use alloc::collections::BinaryHeap;
+use core::cmp::{Ordering, Reverse};
+
+/// Searches a matrix whose rows and columns are both sorted in ascending order.
+///
+/// The search starts in the top-right corner. Every element below is larger
+/// and every element to the left is smaller, so each comparison rules out a
+/// whole row or a whole column, tracing a staircase towards the bottom-left.
+///
+/// # Arguments
+///
+/// * `matrix` - A rectangular matrix, given as a slice of rows.
+/// * `key` - The target value being searched for.
+///
+/// # Returns
+///
+/// The row and column of the target value if found, or `None` if not found.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::staircase;
+///
+/// let matrix = [
+///     [1, 4, 7, 11],
+///     [2, 5, 8, 12],
+///     [3, 6, 9, 16],
+///     [10, 13, 14, 17],
+/// ];
+/// assert_eq!(staircase(&matrix, &9), Some((2, 2)));
+/// assert_eq!(staircase(&matrix, &15), None);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(m + n) - for a matrix with `m` rows and `n` columns.
+///
+/// ## Space Complexity
+///
+/// O(1)
+///
+/// # References
+///
+/// - [Saddleback search](https://www.cs.ox.ac.uk/people/richard.bird/online/BirdSaddleback.pdf)
+pub fn staircase<T, R>(matrix: &[R], key: &T) -> Option<(usize, usize)>
+where
+    T: Ord,
+    R: AsRef<[T]>,
+{
+    let mut row = 0;
+    let mut col = matrix.first()?.as_ref().len();
+
+    while row < matrix.len() && col > 0 {
+        match matrix[row].as_ref()[col - 1].cmp(key) {
+            Ordering::Equal => return Some((row, col - 1)),
+            Ordering::Greater => col -= 1,
+            Ordering::Less => row += 1,
+        }
+    }
+
+    None
+}
+
+/// Finds the `k`-th smallest element of a matrix whose rows are sorted in ascending order.
+///
+/// The rows are merged lazily through a min-heap holding the next element of
+/// each row, and the first `k` elements popped are discarded. Sorted columns
+/// are not required.
+///
+/// # Arguments
+///
+/// * `matrix` - A matrix given as a slice of rows. The rows may have different lengths.
+/// * `k` - The zero-based position of the element in sorted order.
+///
+/// # Returns
+///
+/// The element that would be at index `k` if all elements were sorted, or
+/// `None` if the matrix has at most `k` elements.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::kth_smallest;
+///
+/// let matrix = [[1, 5, 9], [10, 11, 13], [12, 13, 15]];
+/// assert_eq!(kth_smallest(&matrix, 0), Some(&1));
+/// assert_eq!(kth_smallest(&matrix, 7), Some(&13));
+/// assert_eq!(kth_smallest(&matrix, 9), None);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(m + k log m) - for a matrix with `m` rows.
+///
+/// ## Space Complexity
+///
+/// O(m)
+pub fn kth_smallest<T, R>(matrix: &[R], k: usize) -> Option<&T>
+where
+    T: Ord,
+    R: AsRef<[T]>,
+{
+    let mut heap: BinaryHeap<_> = matrix
+        .iter()
+        .enumerate()
+        .filter_map(|(row, r)| r.as_ref().first().map(|x| Reverse((x, row, 0))))
+        .collect();
+
+    for _ in 0..k {
+        let Reverse((_, row, col)) = heap.pop()?;
+        if let Some(next) = matrix[row].as_ref().get(col + 1) {
+            heap.push(Reverse((next, row, col + 1)));
+        }
+    }
+
+    heap.pop().map(|Reverse((x, _, _))| x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{vec, vec::Vec};
+
+    fn matrix() -> Vec<Vec<i32>> {
+        (0..5)
+            .map(|r| (0..7).map(|c| 2 * (r * 3 + c * 2)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_staircase() {
+        let matrix = matrix();
+        for (r, row) in matrix.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                let (fr, fc) = staircase(&matrix, value).unwrap();
+                assert_eq!(matrix[fr][fc], *value, "found at ({r}, {c})");
+            }
+        }
+        assert_eq!(staircase(&matrix, &1), None);
+        assert_eq!(staircase(&matrix, &-2), None);
+        assert_eq!(staircase(&matrix, &100), None);
+        assert_eq!(staircase::<i32, [i32; 0]>(&[], &1), None);
+        assert_eq!(staircase::<i32, [i32; 0]>(&[[]], &1), None);
+    }
+
+    #[test]
+    fn test_kth_smallest() {
+        let matrix = matrix();
+        let mut sorted: Vec<i32> = matrix.iter().flatten().copied().collect();
+        sorted.sort_unstable();
+        for (k, value) in sorted.iter().enumerate() {
+            assert_eq!(kth_smallest(&matrix, k), Some(value));
+        }
+        assert_eq!(kth_smallest(&matrix, sorted.len()), None);
+    }
+
+    #[test]
+    fn test_kth_smallest_ragged() {
+        let matrix = vec![vec![4], vec![], vec![1, 2, 3, 8], vec![5, 9]];
+        let found: Vec<_> = (0..8).map(|k| kth_smallest(&matrix, k)).collect();
+        assert_eq!(
+            found,
+            [
+                Some(&1),
+                Some(&2),
+                Some(&3),
+                Some(&4),
+                Some(&5),
+                Some(&8),
+                Some(&9),
+                None
+            ]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use alloc::vec::Vec;
+
+/// The number of keys in a node of the [`Layout::BTree`] layout.
+///
+/// Sixteen 32-bit keys fill a 64-byte cache line.
+const BLOCK: usize = 16;
+
+/// How a [`StaticSearchIndex`] arranges its elements in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// The elements of an implicit binary search tree, stored in breadth-first
+    /// order so that the children of node `k` are at `2k` and `2k + 1`.
+    ///
+    /// The first levels of the tree share a few cache lines, and the
+    /// grandchildren several levels down can be prefetched before they are
+    /// needed.
+    Eytzinger,
+    /// The nodes of an implicit B+ tree with `16` keys per node, stored layer
+    /// by layer. The leaf layer is a copy of the sorted slice, and every query
+    /// touches one cache line per layer.
+    BTree,
+}
+
+/// A read-only index over a sorted slice, rearranged so that searching it
+/// makes better use of the cache than [`binary`](crate::search::binary).
+///
+/// Binary search on a large sorted array touches one cache line per
+/// comparison and the hardware cannot guess the next one. Rebuilding the
+/// array in a [`Layout`] where the next probes sit close together or can be
+/// prefetched makes lookups several times faster once the array no longer
+/// fits in the cache. The search loops contain no data-dependent branches.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::{Layout, StaticSearchIndex};
+///
+/// let sorted = [1, 3, 5, 7, 9, 11, 13];
+///
+/// for layout in [Layout::Eytzinger, Layout::BTree] {
+///     let index = StaticSearchIndex::new(&sorted, layout);
+///     assert_eq!(index.find(&9), Some(4));
+///     assert_eq!(index.find(&8), None);
+///     assert_eq!(index.lower_bound(&8), Some(&9));
+///     assert_eq!(index.lower_bound(&14), None);
+///     assert_eq!(index.rank(&8), 4);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Build: O(n)
+/// - Query: O(log n)
+///
+/// ## Space Complexity
+///
+/// - Eytzinger: O(n) - a copy of the elements and their positions in the sorted slice.
+/// - B-tree: O(n) - a copy of the elements and about `n / 16` keys for the inner nodes.
+///
+/// # References
+///
+/// - [Algorithmica: Eytzinger Binary Search](https://en.algorithmica.org/hpc/data-structures/binary-search/)
+/// - [Algorithmica: Static B-Trees](https://en.algorithmica.org/hpc/data-structures/s-tree/)
+#[derive(Debug, Clone)]
+pub struct StaticSearchIndex<T> {
+    layout: Layout,
+    len: usize,
+    /// The elements in Eytzinger order, or the B+ tree layers from the root
+    /// down to the leaves.
+    data: Vec<T>,
+    /// For [`Layout::Eytzinger`], the position in the sorted slice of each
+    /// element of `data`. For [`Layout::BTree`], the offset of each layer in
+    /// `data`, from the root down to the leaves.
+    offsets: Vec<usize>,
+}
+
+impl<T> StaticSearchIndex<T>
+where
+    T: Ord + Clone,
+{
+    /// Builds an index over `sorted` in the given `layout`.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - The elements to index. They must be sorted in ascending order.
+    /// * `layout` - How the elements are arranged in memory.
+    #[must_use]
+    pub fn new(sorted: &[T], layout: Layout) -> Self {
+        match layout {
+            Layout::Eytzinger => Self::eytzinger(sorted),
+            Layout::BTree => Self::btree(sorted),
+        }
+    }
+
+    /// Builds an index over `sorted` in the [`Layout::Eytzinger`] layout.
+    #[must_use]
+    pub fn eytzinger(sorted: &[T]) -> Self {
+        fn fill(k: usize, n: usize, next: &mut usize, order: &mut [usize]) {
+            if k <= n {
+                fill(2 * k, n, next, order);
+                order[k - 1] = *next;
+                *next += 1;
+                fill(2 * k + 1, n, next, order);
+            }
+        }
+
+        let mut order = alloc::vec![0; sorted.len()];
+        fill(1, sorted.len(), &mut 0, &mut order);
+
+        Self {
+            layout: Layout::Eytzinger,
+            len: sorted.len(),
+            data: order.iter().map(|&i| sorted[i].clone()).collect(),
+            offsets: order,
+        }
+    }
+
+    /// Builds an index over `sorted` in the [`Layout::BTree`] layout.
+    #[must_use]
+    pub fn btree(sorted: &[T]) -> Self {
+        let Some(max) = sorted.last() else {
+            return Self {
+                layout: Layout::BTree,
+                len: 0,
+                data: Vec::new(),
+                offsets: Vec::new(),
+            };
+        };
+
+        // The number of nodes in each layer, from the leaves up to the root.
+        let mut layers = alloc::vec![sorted.len().div_ceil(BLOCK)];
+        while layers[layers.len() - 1] > 1 {
+            layers.push(layers[layers.len() - 1].div_ceil(BLOCK + 1));
+        }
+
+        let mut data = Vec::with_capacity(layers.iter().sum::<usize>() * BLOCK);
+        let mut offsets = Vec::with_capacity(layers.len());
+
+        // Every key of an inner node is the smallest element of the subtree to
+        // its right. Missing subtrees and the tail of the last leaf are padded
+        // with the largest element, which no query descends past.
+        let mut span = BLOCK;
+        let mut spans = Vec::with_capacity(layers.len());
+        for _ in 0..layers.len() {
+            spans.push(span);
+            span *= BLOCK + 1;
+        }
+
+        for height in (0..layers.len()).rev() {
+            offsets.push(data.len());
+            for node in 0..layers[height] * BLOCK {
+                let first = if height == 0 {
+                    node
+                } else {
+                    let (parent, key) = (node / BLOCK, node % BLOCK);
+                    (parent * (BLOCK + 1) + key + 1) * spans[height - 1]
+                };
+                data.push(sorted.get(first).unwrap_or(max).clone());
+            }
+        }
+
+        Self {
+            layout: Layout::BTree,
+            len: sorted.len(),
+            data,
+            offsets,
+        }
+    }
+
+    /// The layout the elements are arranged in.
+    #[must_use]
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// The number of indexed elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the index contains no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Finds `key` in the index.
+    ///
+    /// # Returns
+    ///
+    /// The position of `key` in the sorted slice the index was built from, or
+    /// `None` if it is not present. If `key` occurs more than once, the first
+    /// position is returned.
+    #[must_use]
+    pub fn find(&self, key: &T) -> Option<usize> {
+        let (rank, found) = self.search(key)?;
+        (found == key).then_some(rank)
+    }
+
+    /// Finds the smallest element that is not less than `key`.
+    ///
+    /// # Returns
+    ///
+    /// The element, or `None` if every element is less than `key`.
+    #[must_use]
+    pub fn lower_bound(&self, key: &T) -> Option<&T> {
+        self.search(key).map(|(_, found)| found)
+    }
+
+    /// Counts the elements that are less than `key`.
+    ///
+    /// This is also the position at which `key` would be inserted to keep
+    /// the sorted slice sorted.
+    #[must_use]
+    pub fn rank(&self, key: &T) -> usize {
+        self.search(key).map_or(self.len, |(rank, _)| rank)
+    }
+
+    /// Returns the position in the sorted slice and the value of the lower bound of `key`.
+    fn search(&self, key: &T) -> Option<(usize, &T)> {
+        match self.layout {
+            Layout::Eytzinger => self.search_eytzinger(key),
+            Layout::BTree => self.search_btree(key),
+        }
+    }
+
+    fn search_eytzinger(&self, key: &T) -> Option<(usize, &T)> {
+        let mut k = 1;
+        while k <= self.len {
+            // The 16 great-great-grandchildren of `k` are contiguous.
+            prefetch(self.data.as_ptr().wrapping_add(16 * k - 1));
+            k = 2 * k + usize::from(self.data[k - 1] < *key);
+        }
+        // Undo the right turns taken after the last left turn, which was at the lower bound.
+        k >>= k.trailing_ones() + 1;
+
+        (k > 0).then(|| (self.offsets[k - 1], &self.data[k - 1]))
+    }
+
+    fn search_btree(&self, key: &T) -> Option<(usize, &T)> {
+        let leaves = &self.data[*self.offsets.last()?..];
+        if leaves[self.len - 1] < *key {
+            return None;
+        }
+
+        // With `key` at most the largest element, no padding key is less than
+        // `key`, so every child taken below exists.
+        let mut node = 0;
+        for &offset in &self.offsets[..self.offsets.len() - 1] {
+            let keys = &self.data[offset + node * BLOCK..offset + (node + 1) * BLOCK];
+            node = node * (BLOCK + 1) + count_less(keys, key);
+        }
+
+        let keys = &leaves[node * BLOCK..(node + 1) * BLOCK];
+        let rank = node * BLOCK + count_less(keys, key);
+        Some((rank, &leaves[rank]))
+    }
+}
+
+/// Counts the elements of `keys` that are less than `key` without branching on the comparisons.
+fn count_less<T>(keys: &[T], key: &T) -> usize
+where
+    T: Ord,
+{
+    keys.iter().map(|k| usize::from(k < key)).sum()
+}
+
+/// Hints the processor to load the cache line at `ptr`.
+///
+/// The pointer may be out of bounds, prefetching never faults.
+#[inline]
+fn prefetch<T>(ptr: *const T) {
+    #[cfg(target_arch = "x86_64")]
+    // SAFETY: SSE is part of the x86-64 baseline, and prefetching does not dereference `ptr`.
+    unsafe {
+        core::arch::x86_64::_mm_prefetch::<{ core::arch::x86_64::_MM_HINT_T0 }>(ptr.cast());
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = ptr;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    #[test_case(Layout::Eytzinger ; "eytzinger")]
+    #[test_case(Layout::BTree ; "btree")]
+    fn empty_index(layout: Layout) {
+        let index = StaticSearchIndex::<i32>::new(&[], layout);
+        assert!(index.is_empty());
+        assert_eq!(index.find(&1), None);
+        assert_eq!(index.lower_bound(&1), None);
+        assert_eq!(index.rank(&1), 0);
+    }
+
+    #[test_case(Layout::Eytzinger ; "eytzinger")]
+    #[test_case(Layout::BTree ; "btree")]
+    fn agrees_with_binary_search(layout: Layout) {
+        for n in [1, 2, 3, 15, 16, 17, 100, 271, 272, 273, 1000, 5000] {
+            let sorted: Vec<i64> = (0..n).map(|i| 2 * i + 1).collect();
+            let index = StaticSearchIndex::new(&sorted, layout);
+            assert_eq!(index.len(), sorted.len());
+
+            for key in -1..=2 * n + 1 {
+                let expected = sorted.partition_point(|&x| x < key);
+                assert_eq!(index.rank(&key), expected, "n = {n}, key = {key}");
+                assert_eq!(index.lower_bound(&key), sorted.get(expected));
+                assert_eq!(index.find(&key), sorted.binary_search(&key).ok());
+            }
+        }
+    }
+
+    #[test_case(Layout::Eytzinger ; "eytzinger")]
+    #[test_case(Layout::BTree ; "btree")]
+    fn finds_first_of_duplicates(layout: Layout) {
+        let sorted = [
+            1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3,
+        ];
+        let index = StaticSearchIndex::new(&sorted, layout);
+        assert_eq!(index.find(&2), Some(1));
+        assert_eq!(index.find(&3), Some(19));
+        assert_eq!(index.rank(&3), 19);
+        assert_eq!(index.rank(&4), 22);
+    }
+}
@@ -0,0 +1,676 @@
+use crate::math::Fibonacci;
+
+/// The golden ratio φ, used by golden-section search to place its probes.
+const PHI: f64 = 1.618_033_988_749_895;
+
+/// The largest Fibonacci index used by [`fibonacci_optimize`], `F(90)` still fits in a `usize`.
+const MAX_FIBONACCI_INDEX: usize = 90;
+
+/// The Fibonacci numbers up to the first above `2^64 + 1`, the padded length
+/// of the whole range of `i64` in [`fibonacci_optimize_int`].
+///
+/// [`Fibonacci`] is only implemented for `usize`, whose largest Fibonacci
+/// number `F(93)` is below that length, so the bounds are kept in a `u128`
+/// table built at compile time instead.
+const FIBONACCI: [u128; 96] = {
+    let mut fibonacci = [0; 96];
+    fibonacci[1] = 1;
+    let mut i = 2;
+    while i < fibonacci.len() {
+        fibonacci[i] = fibonacci[i - 1] + fibonacci[i - 2];
+        i += 1;
+    }
+    fibonacci
+};
+
+/// Which extremum of a unimodal function is being searched for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extremum {
+    /// Search for the smallest value of a function that decreases and then increases.
+    Minimum,
+    /// Search for the largest value of a function that increases and then decreases.
+    Maximum,
+}
+
+impl Extremum {
+    /// Returns `true` if `a` is strictly closer to the extremum than `b`.
+    fn better<Y>(self, a: &Y, b: &Y) -> bool
+    where
+        Y: PartialOrd,
+    {
+        match self {
+            Self::Minimum => a < b,
+            Self::Maximum => a > b,
+        }
+    }
+}
+
+/// The point at which a unimodal function reaches its extremum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Optimum<X, Y> {
+    /// The argument at which the extremum is reached.
+    pub arg: X,
+    /// The value of the function at `arg`.
+    pub value: Y,
+}
+
+/// Finds the extremum of a unimodal function by ternary search.
+///
+/// The interval `[lo, hi]` is split into thirds and the third that cannot
+/// contain the extremum is discarded, until the interval is narrower than
+/// `tolerance`.
+///
+/// # Arguments
+///
+/// * `f` - A function that is unimodal on `[lo, hi]`.
+/// * `lo` - The lower end of the interval being searched.
+/// * `hi` - The upper end of the interval being searched.
+/// * `extremum` - Whether to search for the minimum or the maximum.
+/// * `tolerance` - The width of the interval at which the search stops.
+///
+/// # Returns
+///
+/// The midpoint of the final interval and the value of `f` at that point.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::{ternary_optimize, Extremum};
+///
+/// let optimum = ternary_optimize(|x| (x - 2.0) * (x - 2.0), -10.0, 10.0, Extremum::Minimum, 1e-9);
+/// assert!((optimum.arg - 2.0).abs() < 1e-6);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(log((hi - lo) / tolerance)) evaluations of `f`, two per iteration.
+///
+/// # Panics
+///
+/// Panics if `tolerance` is not positive.
+///
+/// # Note
+///
+/// The search also stops once the probes round onto the ends of the
+/// interval, so a `tolerance` below the spacing of `f64` values around
+/// `[lo, hi]` has no further effect.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Ternary_search)
+pub fn ternary_optimize<F>(
+    f: F,
+    mut lo: f64,
+    mut hi: f64,
+    extremum: Extremum,
+    tolerance: f64,
+) -> Optimum<f64, f64>
+where
+    F: Fn(f64) -> f64,
+{
+    assert!(tolerance > 0.0, "tolerance must be positive");
+
+    while hi - lo > tolerance {
+        let m1 = lo + (hi - lo) / 3.0;
+        let m2 = hi - (hi - lo) / 3.0;
+
+        // Below the spacing of floats around the interval the probes round
+        // onto its ends, and it cannot shrink any further.
+        if m1 <= lo || m2 >= hi {
+            break;
+        }
+
+        if extremum.better(&f(m1), &f(m2)) {
+            hi = m2;
+        } else {
+            lo = m1;
+        }
+    }
+
+    let arg = f64::midpoint(lo, hi);
+    Optimum { arg, value: f(arg) }
+}
+
+/// Finds the extremum of a unimodal function over the integers in `[lo, hi]` by ternary search.
+///
+/// # Arguments
+///
+/// * `f` - A function that is unimodal on `[lo, hi]`.
+/// * `lo` - The smallest argument in the domain.
+/// * `hi` - The largest argument in the domain.
+/// * `extremum` - Whether to search for the minimum or the maximum.
+///
+/// # Returns
+///
+/// The argument at which `f` reaches its extremum and the value there. If
+/// several arguments reach it, any one of them may be returned.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::{ternary_optimize_int, Extremum};
+///
+/// let optimum = ternary_optimize_int(|x| -(x - 7) * (x - 7), 0, 100, Extremum::Maximum);
+/// assert_eq!(optimum.arg, 7);
+/// assert_eq!(optimum.value, 0);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(log n) evaluations of `f`, where `n` is `hi - lo + 1`.
+///
+/// # Panics
+///
+/// Panics if `lo > hi`.
+pub fn ternary_optimize_int<F, Y>(
+    f: F,
+    mut lo: i64,
+    mut hi: i64,
+    extremum: Extremum,
+) -> Optimum<i64, Y>
+where
+    F: Fn(i64) -> Y,
+    Y: PartialOrd,
+{
+    assert!(lo <= hi, "the domain must not be empty");
+
+    // The width is unsigned so that it cannot overflow, and the probes lie
+    // within the domain, so adding it back to an end is exact.
+    while hi.abs_diff(lo) > 2 {
+        let third = hi.abs_diff(lo) / 3;
+        let m1 = lo.wrapping_add_unsigned(third);
+        let m2 = hi.wrapping_sub_unsigned(third);
+
+        if extremum.better(&f(m1), &f(m2)) {
+            hi = m2 - 1;
+        } else {
+            lo = m1 + 1;
+        }
+    }
+
+    scan(&f, lo, hi, extremum)
+}
+
+/// Finds the extremum of a unimodal function by golden-section search.
+///
+/// Like ternary search, but the probes are placed at the golden ratio so
+/// that one of them can be reused in the next iteration, needing a single
+/// evaluation of `f` per iteration.
+///
+/// # Arguments
+///
+/// * `f` - A function that is unimodal on `[lo, hi]`.
+/// * `lo` - The lower end of the interval being searched.
+/// * `hi` - The upper end of the interval being searched.
+/// * `extremum` - Whether to search for the minimum or the maximum.
+/// * `tolerance` - The width of the interval at which the search stops.
+///
+/// # Returns
+///
+/// The midpoint of the final interval and the value of `f` at that point.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::{golden_section, Extremum};
+///
+/// let optimum = golden_section(f64::sin, 0.0, 3.0, Extremum::Maximum, 1e-9);
+/// assert!((optimum.arg - core::f64::consts::FRAC_PI_2).abs() < 1e-6);
+/// assert!((optimum.value - 1.0).abs() < 1e-9);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(log((hi - lo) / tolerance)) evaluations of `f`, one per iteration.
+///
+/// # Panics
+///
+/// Panics if `tolerance` is not positive.
+///
+/// # Note
+///
+/// The search also stops once the probes round onto the ends of the
+/// interval, so a `tolerance` below the spacing of `f64` values around
+/// `[lo, hi]` has no further effect.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Golden-section_search)
+pub fn golden_section<F>(
+    f: F,
+    mut lo: f64,
+    mut hi: f64,
+    extremum: Extremum,
+    tolerance: f64,
+) -> Optimum<f64, f64>
+where
+    F: Fn(f64) -> f64,
+{
+    assert!(tolerance > 0.0, "tolerance must be positive");
+
+    let mut m1 = hi - (hi - lo) / PHI;
+    let mut m2 = lo + (hi - lo) / PHI;
+    let mut f1 = f(m1);
+    let mut f2 = f(m2);
+
+    while hi - lo > tolerance {
+        // Below the spacing of floats around the interval the probes round
+        // onto its ends, and it cannot shrink any further.
+        if m1 <= lo || m2 >= hi || m1 >= m2 {
+            break;
+        }
+
+        if extremum.better(&f1, &f2) {
+            hi = m2;
+            (m2, f2) = (m1, f1);
+            m1 = hi - (hi - lo) / PHI;
+            f1 = f(m1);
+        } else {
+            lo = m1;
+            (m1, f1) = (m2, f2);
+            m2 = lo + (hi - lo) / PHI;
+            f2 = f(m2);
+        }
+    }
+
+    let arg = f64::midpoint(lo, hi);
+    Optimum { arg, value: f(arg) }
+}
+
+/// Finds the extremum of a unimodal function over the integers in `[lo, hi]` by golden-section search.
+///
+/// The probes are rounded to the nearest integer, and a probe that lands on
+/// the point kept from the previous iteration is not evaluated again.
+///
+/// # Arguments
+///
+/// * `f` - A function that is unimodal on `[lo, hi]`.
+/// * `lo` - The smallest argument in the domain.
+/// * `hi` - The largest argument in the domain.
+/// * `extremum` - Whether to search for the minimum or the maximum.
+///
+/// # Returns
+///
+/// The argument at which `f` reaches its extremum and the value there.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::{golden_section_int, Extremum};
+///
+/// let optimum = golden_section_int(|x| (x - 42).abs(), -1000, 1000, Extremum::Minimum);
+/// assert_eq!(optimum.arg, 42);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `lo > hi`.
+#[allow(clippy::cast_sign_loss)]
+pub fn golden_section_int<F, Y>(
+    f: F,
+    mut lo: i64,
+    mut hi: i64,
+    extremum: Extremum,
+) -> Optimum<i64, Y>
+where
+    F: Fn(i64) -> Y,
+    Y: PartialOrd,
+{
+    assert!(lo <= hi, "the domain must not be empty");
+
+    let mut kept: Option<Optimum<i64, Y>> = None;
+    let probe = |x: i64, kept: &mut Option<Optimum<i64, Y>>| match kept.take() {
+        Some(optimum) if optimum.arg == x => optimum.value,
+        other => {
+            *kept = other;
+            f(x)
+        }
+    };
+
+    while hi.abs_diff(lo) > 2 {
+        // Keep the probes distinct, or the point between them would be lost.
+        let width = hi.abs_diff(lo);
+        let d = ((width as f64 / PHI).round() as u64).max(width / 2 + 1);
+        let (m1, m2) = (hi.wrapping_sub_unsigned(d), lo.wrapping_add_unsigned(d));
+        let f1 = probe(m1, &mut kept);
+        let f2 = probe(m2, &mut kept);
+
+        if extremum.better(&f1, &f2) {
+            hi = m2 - 1;
+            kept = Some(Optimum { arg: m1, value: f1 });
+        } else {
+            lo = m1 + 1;
+            kept = Some(Optimum { arg: m2, value: f2 });
+        }
+    }
+
+    scan(&f, lo, hi, extremum)
+}
+
+/// Finds the extremum of a unimodal function by Fibonacci search.
+///
+/// The interval is shrunk by ratios of consecutive Fibonacci numbers, which
+/// is the optimal strategy when the number of evaluations of `f` is fixed in
+/// advance.
+///
+/// # Arguments
+///
+/// * `f` - A function that is unimodal on `[lo, hi]`.
+/// * `lo` - The lower end of the interval being searched.
+/// * `hi` - The upper end of the interval being searched.
+/// * `extremum` - Whether to search for the minimum or the maximum.
+/// * `tolerance` - The width of the interval at which the search stops.
+///
+/// # Returns
+///
+/// The midpoint of the final interval and the value of `f` at that point.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::{fibonacci_optimize, Extremum};
+///
+/// let optimum = fibonacci_optimize(|x| x * x * x * x - 3.0 * x, 0.0, 2.0, Extremum::Minimum, 1e-9);
+/// assert!((optimum.arg - 0.75_f64.cbrt()).abs() < 1e-6);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(log((hi - lo) / tolerance)) evaluations of `f`, one per iteration.
+///
+/// # Panics
+///
+/// Panics if `tolerance` is not positive.
+///
+/// # Note
+///
+/// The interval is shrunk at most `F(90)` times, so a `tolerance` below
+/// `(hi - lo) / F(90)` has no further effect.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Golden-section_search#Fibonacci_search)
+pub fn fibonacci_optimize<F>(
+    f: F,
+    mut lo: f64,
+    mut hi: f64,
+    extremum: Extremum,
+    tolerance: f64,
+) -> Optimum<f64, f64>
+where
+    F: Fn(f64) -> f64,
+{
+    assert!(tolerance > 0.0, "tolerance must be positive");
+
+    let mut n = 2;
+    while n < MAX_FIBONACCI_INDEX && (n.iterative() as f64) * tolerance < hi - lo {
+        n += 1;
+    }
+
+    let ratio = |k: usize| (k - 2).iterative() as f64 / k.iterative() as f64;
+    let mut m1 = lo + ratio(n) * (hi - lo);
+    let mut m2 = lo + hi - m1;
+    let mut f1 = f(m1);
+    let mut f2 = f(m2);
+
+    for _ in 2..n {
+        if extremum.better(&f1, &f2) {
+            hi = m2;
+            (m2, f2) = (m1, f1);
+            m1 = lo + hi - m2;
+            f1 = f(m1);
+        } else {
+            lo = m1;
+            (m1, f1) = (m2, f2);
+            m2 = lo + hi - m1;
+            f2 = f(m2);
+        }
+
+        if m1 > m2 {
+            (m1, f1, m2, f2) = (m2, f2, m1, f1);
+        }
+    }
+
+    let arg = f64::midpoint(lo, hi);
+    Optimum { arg, value: f(arg) }
+}
+
+/// Finds the extremum of a unimodal function over the integers in `[lo, hi]` by Fibonacci search.
+///
+/// The domain is padded to a Fibonacci length, with the padding treated as
+/// worse than any value of `f`, so that every probe lands on an integer and
+/// one probe is reused in each iteration.
+///
+/// # Arguments
+///
+/// * `f` - A function that is unimodal on `[lo, hi]`.
+/// * `lo` - The smallest argument in the domain.
+/// * `hi` - The largest argument in the domain.
+/// * `extremum` - Whether to search for the minimum or the maximum.
+///
+/// # Returns
+///
+/// The argument at which `f` reaches its extremum and the value there.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::search::{fibonacci_optimize_int, Extremum};
+///
+/// let heights = [1, 3, 8, 12, 9, 4, 2];
+/// let optimum = fibonacci_optimize_int(|i| heights[i as usize], 0, 6, Extremum::Maximum);
+/// assert_eq!(optimum.arg, 3);
+/// assert_eq!(optimum.value, 12);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(log n) evaluations of `f`, where `n` is `hi - lo + 1`.
+///
+/// # Panics
+///
+/// Panics if `lo > hi`.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Fibonacci_search_technique)
+#[allow(clippy::cast_possible_truncation)]
+pub fn fibonacci_optimize_int<F, Y>(f: F, lo: i64, hi: i64, extremum: Extremum) -> Optimum<i64, Y>
+where
+    F: Fn(i64) -> Y,
+    Y: PartialOrd,
+{
+    assert!(lo <= hi, "the domain must not be empty");
+
+    // Offsets are 1-based so that the open interval (a, a + F(k)) starts at a = 0.
+    // They are wide enough for the whole range of `i64`, padding included.
+    let len = u128::from(hi.abs_diff(lo)) + 1;
+    let at = |offset: u128| lo.wrapping_add_unsigned((offset - 1) as u64);
+    let probe = |offset: u128| (offset <= len).then(|| f(at(offset)));
+    // `None` stands for the padding, which always lies to the right of the domain.
+    let left_wins = |a: &Option<Y>, b: &Option<Y>| match (a, b) {
+        (Some(a), Some(b)) => extremum.better(a, b),
+        (_, None) => true,
+        (None, Some(_)) => false,
+    };
+
+    let mut k = 4;
+    while FIBONACCI[k] < len + 1 {
+        k += 1;
+    }
+
+    let mut a = 0;
+    let mut x1 = a + FIBONACCI[k - 2];
+    let mut x2 = a + FIBONACCI[k - 1];
+    let mut f1 = probe(x1);
+    let mut f2 = probe(x2);
+
+    while k > 4 {
+        k -= 1;
+        if left_wins(&f1, &f2) {
+            (x2, f2) = (x1, f1);
+            x1 = a + FIBONACCI[k - 2];
+            f1 = probe(x1);
+        } else {
+            a = x1;
+            (x1, f1) = (x2, f2);
+            x2 = a + FIBONACCI[k - 1];
+            f2 = probe(x2);
+        }
+    }
+
+    let last = (a + FIBONACCI[k] - 1).min(len);
+    scan(&f, at(a + 1), at(last), extremum)
+}
+
+/// Evaluates every argument in `[lo, hi]` and returns the best one.
+fn scan<F, Y>(f: &F, lo: i64, hi: i64, extremum: Extremum) -> Optimum<i64, Y>
+where
+    F: Fn(i64) -> Y,
+    Y: PartialOrd,
+{
+    let mut best = Optimum {
+        arg: lo,
+        value: f(lo),
+    };
+    for x in (lo..=hi).skip(1) {
+        let value = f(x);
+        if extremum.better(&value, &best.value) {
+            best = Optimum { arg: x, value };
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    type RealSearch = fn(fn(f64) -> f64, f64, f64, Extremum, f64) -> Optimum<f64, f64>;
+    type IntegerSearch<Y = i64> = fn(fn(i64) -> Y, i64, i64, Extremum) -> Optimum<i64, Y>;
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn parabola(x: f64) -> f64 {
+        (x - 1.5) * (x - 1.5) + 4.0
+    }
+
+    fn valley(x: i64) -> i64 {
+        (x - 17).abs() * 3 + 5
+    }
+
+    #[test_case(ternary_optimize ; "ternary")]
+    #[test_case(golden_section ; "golden section")]
+    #[test_case(fibonacci_optimize ; "fibonacci")]
+    fn finds_real_minimum(search: RealSearch) {
+        let optimum = search(parabola, -100.0, 100.0, Extremum::Minimum, TOLERANCE);
+        assert!((optimum.arg - 1.5).abs() < 1e-6);
+        assert!((optimum.value - 4.0).abs() < 1e-9);
+    }
+
+    #[test_case(ternary_optimize ; "ternary")]
+    #[test_case(golden_section ; "golden section")]
+    #[test_case(fibonacci_optimize ; "fibonacci")]
+    fn finds_real_maximum(search: RealSearch) {
+        let optimum = search(|x| -parabola(x), -3.0, 2.0, Extremum::Maximum, TOLERANCE);
+        assert!((optimum.arg - 1.5).abs() < 1e-6);
+        assert!((optimum.value + 4.0).abs() < 1e-9);
+    }
+
+    #[test_case(ternary_optimize ; "ternary")]
+    #[test_case(golden_section ; "golden section")]
+    #[test_case(fibonacci_optimize ; "fibonacci")]
+    fn finds_real_extremum_at_boundary(search: RealSearch) {
+        let optimum = search(parabola, 3.0, 10.0, Extremum::Minimum, TOLERANCE);
+        assert!((optimum.arg - 3.0).abs() < 1e-6);
+    }
+
+    #[test_case(ternary_optimize ; "ternary")]
+    #[test_case(golden_section ; "golden section")]
+    #[test_case(fibonacci_optimize ; "fibonacci")]
+    fn stops_at_tolerance_below_float_spacing(search: RealSearch) {
+        const CENTER: f64 = 1e10;
+        let optimum = search(
+            |x| (x - CENTER).abs(),
+            CENTER - 1.0,
+            CENTER + 1.0,
+            Extremum::Minimum,
+            1e-9,
+        );
+        assert!((optimum.arg - CENTER).abs() < 1e-5);
+    }
+
+    #[test_case(ternary_optimize_int ; "ternary")]
+    #[test_case(golden_section_int ; "golden section")]
+    #[test_case(fibonacci_optimize_int ; "fibonacci")]
+    fn finds_integer_extremum(search: IntegerSearch) {
+        for (lo, hi) in [
+            (-1000, 1000),
+            (17, 17),
+            (0, 1),
+            (16, 18),
+            (17, 1000),
+            (-1000, 17),
+            (20, 30),
+        ] {
+            let expected = (lo..=hi).min_by_key(|&x| valley(x)).unwrap();
+            assert_eq!(
+                search(valley, lo, hi, Extremum::Minimum).arg,
+                expected,
+                "{lo}..={hi}"
+            );
+            assert_eq!(
+                search(|x| -valley(x), lo, hi, Extremum::Maximum).value,
+                -valley(expected),
+                "{lo}..={hi}"
+            );
+        }
+    }
+
+    #[test_case(ternary_optimize_int ; "ternary")]
+    #[test_case(golden_section_int ; "golden section")]
+    #[test_case(fibonacci_optimize_int ; "fibonacci")]
+    fn finds_integer_extremum_in_wide_domains(search: IntegerSearch<u64>) {
+        for (lo, hi, expected) in [
+            (i64::MIN, i64::MAX, 17),
+            (i64::MIN, 0, 0),
+            (100, i64::MAX, 100),
+            (i64::MIN, i64::MIN + 1, i64::MIN + 1),
+            (i64::MAX, i64::MAX, i64::MAX),
+        ] {
+            let optimum = search(|x| x.abs_diff(17), lo, hi, Extremum::Minimum);
+            assert_eq!(optimum.arg, expected, "{lo}..={hi}");
+            let optimum = search(|x| !x.abs_diff(17), lo, hi, Extremum::Maximum);
+            assert_eq!(optimum.arg, expected, "{lo}..={hi}");
+        }
+    }
+
+    #[test_case(ternary_optimize_int ; "ternary")]
+    #[test_case(golden_section_int ; "golden section")]
+    #[test_case(fibonacci_optimize_int ; "fibonacci")]
+    fn finds_integer_extremum_for_every_length(search: IntegerSearch) {
+        for len in 1..100 {
+            for shift in 0..len {
+                let optimum = search(
+                    |x| valley(x + 17),
+                    -shift,
+                    len - 1 - shift,
+                    Extremum::Minimum,
+                );
+                assert_eq!(optimum.arg, 0, "len {len}, shift {shift}");
+            }
+        }
+    }
+}
@@ -75,6 +75,6 @@ mod tests {
         let mut unsorted = [7, 3, 2, 1, 5, 4, 6];
         let sorted = [1, 2, 3, 4, 5, 6, 7];
         cycle(&mut unsorted);
-        assert!(unsorted == sorted);
+        assert_eq!(unsorted, sorted);
     }
 }
@@ -0,0 +1,174 @@
+use alloc::{string::String, vec::Vec};
+use core::{fmt::Write, ops::Range};
+
+/// A column of an [`Alignment`] of sequences `a` and `b`.
+///
+/// The positions are indices into `a` and `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Column {
+    /// `a[a]` is paired with `b[b]`.
+    Pair {
+        /// The position in `a`.
+        a: usize,
+        /// The position in `b`.
+        b: usize,
+    },
+    /// `a[a]` is paired with a gap in `b`.
+    Deletion {
+        /// The position in `a`.
+        a: usize,
+    },
+    /// `b[b]` is paired with a gap in `a`.
+    Insertion {
+        /// The position in `b`.
+        b: usize,
+    },
+}
+
+/// An alignment of two sequences, or of a substring of each.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Alignment {
+    /// The score of the alignment: the sum of the scores of its pairs, less
+    /// the penalties of its gaps.
+    pub score: i32,
+    /// The columns, in order.
+    pub columns: Vec<Column>,
+}
+
+impl Alignment {
+    /// The positions of the items of `a` the alignment covers.
+    #[must_use]
+    pub fn range_a(&self) -> Range<usize> {
+        self.range(|column| match column {
+            Column::Pair { a, .. } | Column::Deletion { a } => Some(a),
+            Column::Insertion { .. } => None,
+        })
+    }
+
+    /// The positions of the items of `b` the alignment covers.
+    #[must_use]
+    pub fn range_b(&self) -> Range<usize> {
+        self.range(|column| match column {
+            Column::Pair { b, .. } | Column::Insertion { b } => Some(b),
+            Column::Deletion { .. } => None,
+        })
+    }
+
+    /// The number of columns that pair equal items.
+    #[must_use]
+    pub fn identities<T: Eq>(&self, a: &[T], b: &[T]) -> usize {
+        self.columns
+            .iter()
+            .filter(|column| matches!(column, Column::Pair { a: i, b: j } if a[*i] == b[*j]))
+            .count()
+    }
+
+    /// Lays the alignment out as text, in blocks of at most `width` columns.
+    ///
+    /// Each block has a line for each sequence, with `-` for gaps, and a
+    /// line between them with `|` under equal items and `.` under
+    /// different ones. The lines of the sequences start with the position
+    /// of their first item in the block and end with that of their last,
+    /// counting from one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::alignment::{needleman_wunsch, GapPenalty, MatchMismatch};
+    ///
+    /// let scoring = MatchMismatch {
+    ///     matched: 1,
+    ///     mismatched: -1,
+    /// };
+    /// let (a, b) = (b"GATTACA", b"GATACA");
+    /// let alignment = needleman_wunsch(a, b, &scoring, GapPenalty::linear(1));
+    /// assert_eq!(
+    ///     alignment.pretty(a, b, 60),
+    ///     "1 GATTACA 7\n  || ||||\n1 GA-TACA 6\n"
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero or the alignment has positions out of
+    /// bounds of `a` or `b`.
+    #[must_use]
+    pub fn pretty(&self, a: &[u8], b: &[u8], width: usize) -> String {
+        assert!(width > 0, "width must be positive");
+        let (range_a, range_b) = (self.range_a(), self.range_b());
+        let digits = range_a.end.max(range_b.end).max(1).ilog10() as usize + 1;
+
+        let mut text = String::new();
+        // The number of items of each sequence laid out so far.
+        let (mut done_a, mut done_b) = (range_a.start, range_b.start);
+        for block in self.columns.chunks(width) {
+            let (mut top, mut middle, mut bottom) = (String::new(), String::new(), String::new());
+            let (start_a, start_b) = (done_a + 1, done_b + 1);
+            for column in block {
+                let (x, y) = match *column {
+                    Column::Pair { a: i, b: j } => (a[i], b[j]),
+                    Column::Deletion { a: i } => (a[i], b'-'),
+                    Column::Insertion { b: j } => (b'-', b[j]),
+                };
+                done_a += usize::from(x != b'-');
+                done_b += usize::from(y != b'-');
+                top.push(char::from(x));
+                bottom.push(char::from(y));
+                middle.push(match (x, y) {
+                    (b'-', _) | (_, b'-') => ' ',
+                    _ if x == y => '|',
+                    _ => '.',
+                });
+            }
+
+            let _ = writeln!(text, "{start_a:>digits$} {top} {done_a}");
+            let _ = writeln!(text, "{:digits$} {}", "", middle.trim_end());
+            let _ = writeln!(text, "{start_b:>digits$} {bottom} {done_b}");
+            if done_a < range_a.end || done_b < range_b.end {
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    fn range(&self, position: impl Fn(Column) -> Option<usize>) -> Range<usize> {
+        let mut positions = self.columns.iter().filter_map(|&column| position(column));
+        let Some(start) = positions.next() else {
+            return 0..0;
+        };
+        start..positions.last().unwrap_or(start) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pretty_wraps_blocks() {
+        let alignment = Alignment {
+            score: 0,
+            columns: Vec::from([
+                Column::Pair { a: 0, b: 0 },
+                Column::Pair { a: 1, b: 1 },
+                Column::Insertion { b: 2 },
+                Column::Pair { a: 2, b: 3 },
+                Column::Deletion { a: 3 },
+            ]),
+        };
+        assert_eq!(alignment.range_a(), 0..4);
+        assert_eq!(alignment.range_b(), 0..4);
+        assert_eq!(alignment.identities(b"ACGT", b"AGTG"), 2);
+        assert_eq!(
+            alignment.pretty(b"ACGT", b"AGTG", 3),
+            "1 AC- 2\n  |.\n1 AGT 3\n\n3 GT 4\n  |\n4 G- 4\n"
+        );
+    }
+
+    #[test]
+    fn empty_alignment() {
+        let alignment = Alignment::default();
+        assert_eq!(alignment.range_a(), 0..0);
+        assert_eq!(alignment.pretty(b"", b"", 10), "");
+    }
+}
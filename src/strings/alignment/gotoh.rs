@@ -0,0 +1,429 @@
+use alloc::{vec, vec::Vec};
+
+use super::{Alignment, Column, GapPenalty, Scoring};
+
+/// A score lower than any alignment can have, which stays negative when penalties are subtracted.
+pub(super) const NONE: i32 = i32::MIN / 4;
+
+/// The last column of the alignments a cell of the tables is the best score of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Pair,
+    Deletion,
+    Insertion,
+}
+
+/// The tables of Gotoh's algorithm: the best score of aligning each pair
+/// of prefixes, by the last column of the alignment.
+#[derive(Debug)]
+struct Tables {
+    columns: usize,
+    pair: Vec<i32>,
+    deletion: Vec<i32>,
+    insertion: Vec<i32>,
+}
+
+impl Tables {
+    fn get(&self, state: State, i: usize, j: usize) -> i32 {
+        let table = match state {
+            State::Pair => &self.pair,
+            State::Deletion => &self.deletion,
+            State::Insertion => &self.insertion,
+        };
+        table[i * self.columns + j]
+    }
+
+    /// The best of the states at a cell, preferring pairs to deletions to insertions.
+    fn best(&self, i: usize, j: usize) -> (State, i32) {
+        [State::Pair, State::Deletion, State::Insertion]
+            .into_iter()
+            .map(|state| (state, self.get(state, i, j)))
+            .fold((State::Pair, NONE), |best, next| {
+                if next.1 > best.1 {
+                    next
+                } else {
+                    best
+                }
+            })
+    }
+}
+
+/// Finds a best global alignment of two sequences with the
+/// Needleman–Wunsch algorithm, extended by Gotoh to affine gap penalties.
+///
+/// Every item of both sequences is in the alignment. Among equally good
+/// alignments, those ending in pairs are preferred.
+///
+/// # Arguments
+///
+/// * `a` - The first sequence.
+/// * `b` - The second sequence.
+/// * `scoring` - The scores of pairing items.
+/// * `gap` - The penalties for runs of gaps.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::alignment::{needleman_wunsch, GapPenalty, BLOSUM62};
+///
+/// let (a, b) = (b"HEAGAWGHEE", b"PAWHEAE");
+/// let alignment = needleman_wunsch(a, b, &BLOSUM62, GapPenalty::affine(10, 1));
+/// assert_eq!(alignment.range_a(), 0..10);
+/// assert_eq!(alignment.range_b(), 0..7);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(nm), in O(nm) space. See [`hirschberg`](super::hirschberg) for linear space.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Needleman%E2%80%93Wunsch_algorithm)
+/// - [Gotoh, O. (1982). An improved algorithm for matching biological sequences](https://doi.org/10.1016/0022-2836(82)90398-9)
+#[must_use]
+pub fn needleman_wunsch<T, S>(a: &[T], b: &[T], scoring: &S, gap: GapPenalty) -> Alignment
+where
+    S: Scoring<T>,
+{
+    let tables = fill(a, b, scoring, gap, false);
+    let (state, score) = tables.best(a.len(), b.len());
+    Alignment {
+        score,
+        columns: trace(
+            &tables,
+            a,
+            b,
+            scoring,
+            gap,
+            (a.len(), b.len(), state),
+            false,
+        ),
+    }
+}
+
+/// Finds a best local alignment of two sequences, aligning a substring of
+/// one with a substring of the other, with the Smith–Waterman algorithm,
+/// extended by Gotoh to affine gap penalties.
+///
+/// The alignment starts and ends with pairs, and is empty with a score of
+/// zero if no pair scores above zero.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::alignment::{smith_waterman, GapPenalty, MatchMismatch};
+///
+/// let scoring = MatchMismatch {
+///     matched: 3,
+///     mismatched: -3,
+/// };
+/// let (a, b) = (b"TGTTACGG", b"GGTTGACTA");
+/// let alignment = smith_waterman(a, b, &scoring, GapPenalty::linear(2));
+/// assert_eq!(alignment.score, 13);
+/// assert_eq!(&a[alignment.range_a()], b"GTTAC");
+/// assert_eq!(&b[alignment.range_b()], b"GTTGAC");
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(nm), in O(nm) space.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Smith%E2%80%93Waterman_algorithm)
+/// - [Smith, T. F., & Waterman, M. S. (1981). Identification of common molecular subsequences](https://doi.org/10.1016/0022-2836(81)90087-5)
+#[must_use]
+pub fn smith_waterman<T, S>(a: &[T], b: &[T], scoring: &S, gap: GapPenalty) -> Alignment
+where
+    S: Scoring<T>,
+{
+    let tables = fill(a, b, scoring, gap, true);
+    let (mut end, mut score) = ((0, 0), 0);
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            if tables.get(State::Pair, i, j) > score {
+                (end, score) = ((i, j), tables.get(State::Pair, i, j));
+            }
+        }
+    }
+    if score == 0 {
+        return Alignment::default();
+    }
+    Alignment {
+        score,
+        columns: trace(
+            &tables,
+            a,
+            b,
+            scoring,
+            gap,
+            (end.0, end.1, State::Pair),
+            true,
+        ),
+    }
+}
+
+/// Fills in the tables, letting local alignments start at any pair.
+fn fill<T, S>(a: &[T], b: &[T], scoring: &S, gap: GapPenalty, local: bool) -> Tables
+where
+    S: Scoring<T>,
+{
+    let columns = b.len() + 1;
+    let cells = (a.len() + 1) * columns;
+    let mut tables = Tables {
+        columns,
+        pair: vec![NONE; cells],
+        deletion: vec![NONE; cells],
+        insertion: vec![NONE; cells],
+    };
+    if !local {
+        tables.pair[0] = 0;
+    }
+
+    for i in 0..=a.len() {
+        for j in 0..=b.len() {
+            let cell = i * columns + j;
+            if i > 0 && j > 0 {
+                let (_, before) = tables.best(i - 1, j - 1);
+                let before = if local { before.max(0) } else { before };
+                tables.pair[cell] = before + scoring.score(&a[i - 1], &b[j - 1]);
+            }
+            if i > 0 {
+                let above = cell - columns;
+                tables.deletion[cell] = (tables.pair[above] - gap.open)
+                    .max(tables.deletion[above] - gap.extend)
+                    .max(tables.insertion[above] - gap.open)
+                    .max(NONE);
+            }
+            if j > 0 {
+                let left = cell - 1;
+                tables.insertion[cell] = (tables.pair[left] - gap.open)
+                    .max(tables.insertion[left] - gap.extend)
+                    .max(tables.deletion[left] - gap.open)
+                    .max(NONE);
+            }
+        }
+    }
+    tables
+}
+
+/// Follows the tables back from the last column of an alignment to its first.
+fn trace<T, S>(
+    tables: &Tables,
+    a: &[T],
+    b: &[T],
+    scoring: &S,
+    gap: GapPenalty,
+    (mut i, mut j, mut state): (usize, usize, State),
+    local: bool,
+) -> Vec<Column>
+where
+    S: Scoring<T>,
+{
+    let mut columns = Vec::new();
+    while i > 0 || j > 0 {
+        let score = tables.get(state, i, j);
+        match state {
+            State::Pair => {
+                let before = score - scoring.score(&a[i - 1], &b[j - 1]);
+                (i, j) = (i - 1, j - 1);
+                columns.push(Column::Pair { a: i, b: j });
+                if local && before == 0 {
+                    break;
+                }
+                state = [State::Pair, State::Deletion, State::Insertion]
+                    .into_iter()
+                    .find(|&state| tables.get(state, i, j) == before)
+                    .unwrap_or(State::Pair);
+            }
+            State::Deletion => {
+                i -= 1;
+                columns.push(Column::Deletion { a: i });
+                state = if score == tables.get(State::Deletion, i, j) - gap.extend {
+                    State::Deletion
+                } else if score == tables.get(State::Pair, i, j) - gap.open {
+                    State::Pair
+                } else {
+                    State::Insertion
+                };
+            }
+            State::Insertion => {
+                j -= 1;
+                columns.push(Column::Insertion { b: j });
+                state = if score == tables.get(State::Insertion, i, j) - gap.extend {
+                    State::Insertion
+                } else if score == tables.get(State::Pair, i, j) - gap.open {
+                    State::Pair
+                } else {
+                    State::Deletion
+                };
+            }
+        }
+    }
+    columns.reverse();
+    columns
+}
+
+/// Scores an alignment column by column.
+pub(super) fn rescore<T, S: Scoring<T>>(
+    alignment: &Alignment,
+    a: &[T],
+    b: &[T],
+    scoring: &S,
+    gap: GapPenalty,
+) -> i32 {
+    let mut score = 0;
+    let mut previous = None;
+    for &column in &alignment.columns {
+        score += match (previous, column) {
+            (_, Column::Pair { a: i, b: j }) => scoring.score(&a[i], &b[j]),
+            (Some(Column::Deletion { .. }), Column::Deletion { .. })
+            | (Some(Column::Insertion { .. }), Column::Insertion { .. }) => -gap.extend,
+            _ => -gap.open,
+        };
+        previous = Some(column);
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{MatchMismatch, BLOSUM62};
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use alloc::vec::Vec;
+
+    const DNA: MatchMismatch = MatchMismatch {
+        matched: 1,
+        mismatched: -1,
+    };
+
+    /// Checks that the columns of a global alignment cover both sequences in order.
+    fn check_global(alignment: &Alignment, lengths: (usize, usize)) {
+        let (mut i, mut j) = (0, 0);
+        for &column in &alignment.columns {
+            match column {
+                Column::Pair { a: x, b: y } => {
+                    assert_eq!((x, y), (i, j));
+                    (i, j) = (i + 1, j + 1);
+                }
+                Column::Deletion { a: x } => {
+                    assert_eq!(x, i);
+                    i += 1;
+                }
+                Column::Insertion { b: y } => {
+                    assert_eq!(y, j);
+                    j += 1;
+                }
+            }
+        }
+        assert_eq!((i, j), lengths);
+    }
+
+    /// The best score of a global alignment, by trying every alignment.
+    fn brute_force(a: &[u8], b: &[u8], gap: GapPenalty, last: Option<State>) -> i32 {
+        if a.is_empty() && b.is_empty() {
+            return 0;
+        }
+        let penalty = |state| {
+            if last == Some(state) {
+                gap.extend
+            } else {
+                gap.open
+            }
+        };
+        let mut best = NONE;
+        if !a.is_empty() && !b.is_empty() {
+            let pair = DNA.score(&a[0], &b[0]);
+            best = best.max(pair + brute_force(&a[1..], &b[1..], gap, Some(State::Pair)));
+        }
+        if !a.is_empty() {
+            let rest = brute_force(&a[1..], b, gap, Some(State::Deletion));
+            best = best.max(rest - penalty(State::Deletion));
+        }
+        if !b.is_empty() {
+            let rest = brute_force(a, &b[1..], gap, Some(State::Insertion));
+            best = best.max(rest - penalty(State::Insertion));
+        }
+        best
+    }
+
+    /// The best score of a local alignment, by aligning every pair of substrings.
+    fn best_local<S: Scoring<u8>>(a: &[u8], b: &[u8], scoring: &S, gap: GapPenalty) -> i32 {
+        let mut best = 0;
+        for i in 0..a.len() {
+            for j in i + 1..=a.len() {
+                for k in 0..b.len() {
+                    for l in k + 1..=b.len() {
+                        let score = needleman_wunsch(&a[i..j], &b[k..l], scoring, gap).score;
+                        best = best.max(score);
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn needleman_wunsch_example() {
+        let (a, b) = (b"GATTACA", b"GCATGCU");
+        let alignment = needleman_wunsch(a, b, &DNA, GapPenalty::linear(1));
+        assert_eq!(alignment.score, 0);
+        check_global(&alignment, (a.len(), b.len()));
+        assert_eq!(rescore(&alignment, a, b, &DNA, GapPenalty::linear(1)), 0);
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0xace1);
+
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..6))
+                .map(|_| b"ACGT"[rng.gen_range(0..4)])
+                .collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..6))
+                .map(|_| b"ACGT"[rng.gen_range(0..4)])
+                .collect();
+            for gap in [GapPenalty::linear(1), GapPenalty::affine(3, 1)] {
+                let alignment = needleman_wunsch(&a, &b, &DNA, gap);
+                assert_eq!(alignment.score, brute_force(&a, &b, gap, None));
+                assert_eq!(rescore(&alignment, &a, &b, &DNA, gap), alignment.score);
+                check_global(&alignment, (a.len(), b.len()));
+
+                let local = smith_waterman(&a, &b, &DNA, gap);
+                assert_eq!(rescore(&local, &a, &b, &DNA, gap), local.score);
+                assert_eq!(local.score, best_local(&a, &b, &DNA, gap));
+            }
+        }
+    }
+
+    #[test]
+    fn affine_gaps_are_kept_together() {
+        let (a, b) = (b"AAACCCGGGTTT", b"AAATTT");
+        let gap = GapPenalty::affine(5, 1);
+        let alignment = needleman_wunsch(a, b, &DNA, gap);
+        // One run of six deletions beats any alignment with more runs.
+        assert_eq!(alignment.score, 6 - 5 - 5);
+        assert_eq!(alignment.identities(a, b), 6);
+    }
+
+    #[test]
+    fn smith_waterman_finds_the_common_part() {
+        let (a, b) = (b"PQRHEAGAWGHEEXY", b"PAWHEAE");
+        let gap = GapPenalty::affine(10, 1);
+        let alignment = smith_waterman(a, b, &BLOSUM62, gap);
+        assert_eq!(rescore(&alignment, a, b, &BLOSUM62, gap), alignment.score);
+        assert_eq!(alignment.score, best_local(a, b, &BLOSUM62, gap));
+        assert_eq!(&a[alignment.range_a()], b"PQRHEA");
+
+        assert_eq!(
+            smith_waterman(b"AAA", b"CCC", &DNA, gap),
+            Alignment::default()
+        );
+    }
+}
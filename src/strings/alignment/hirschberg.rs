@@ -0,0 +1,481 @@
+use alloc::vec::Vec;
+
+use super::{
+    gotoh::{rescore, NONE},
+    Alignment, Column, GapPenalty, Scoring,
+};
+
+/// The best scores of aligning two prefixes, by the last column of the alignment.
+#[derive(Debug, Clone, Copy)]
+struct Cell {
+    pair: i32,
+    deletion: i32,
+    insertion: i32,
+}
+
+impl Cell {
+    /// The empty alignment, which continues a run of deletions before it if
+    /// `after_deletion`.
+    fn start(after_deletion: bool) -> Self {
+        if after_deletion {
+            Self {
+                pair: NONE,
+                deletion: 0,
+                insertion: NONE,
+            }
+        } else {
+            Self {
+                pair: 0,
+                deletion: NONE,
+                insertion: NONE,
+            }
+        }
+    }
+
+    fn best(self) -> i32 {
+        self.pair.max(self.deletion).max(self.insertion)
+    }
+
+    /// The best score of a cell above followed by a deletion.
+    fn deleted(self, gap: GapPenalty) -> i32 {
+        (self.pair - gap.open)
+            .max(self.deletion - gap.extend)
+            .max(self.insertion - gap.open)
+            .max(NONE)
+    }
+
+    /// The best score of a cell to the left followed by an insertion.
+    fn inserted(self, gap: GapPenalty) -> i32 {
+        (self.pair - gap.open)
+            .max(self.insertion - gap.extend)
+            .max(self.deletion - gap.open)
+            .max(NONE)
+    }
+}
+
+/// The last column of an alignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Pair,
+    Deletion,
+    Insertion,
+}
+
+/// Whether the columns just outside a part of the alignment are deletions,
+/// which a run of deletions at either end of the part continues.
+#[derive(Debug, Clone, Copy)]
+struct Ends {
+    after_deletion: bool,
+    before_deletion: bool,
+}
+
+/// Finds a best global alignment of two sequences in linear space with
+/// Hirschberg's algorithm, extended by Myers and Miller to affine gap
+/// penalties, for sequences too long for the tables of [`needleman_wunsch`].
+///
+/// The alignment has the same score as that of [`needleman_wunsch`] with the
+/// same gap penalty, though it may be a different one of equally good
+/// alignments.
+///
+/// # Arguments
+///
+/// * `a` - The first sequence.
+/// * `b` - The second sequence.
+/// * `scoring` - The scores of pairing items.
+/// * `gap` - The penalties for runs of gaps.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::alignment::{hirschberg, needleman_wunsch, GapPenalty, MatchMismatch};
+///
+/// let scoring = MatchMismatch {
+///     matched: 2,
+///     mismatched: -1,
+/// };
+/// let alignment = hirschberg(b"AGTACGCA", b"TATGC", &scoring, GapPenalty::linear(2));
+/// assert_eq!(alignment.score, 1);
+///
+/// let gap = GapPenalty::affine(4, 1);
+/// let alignment = hirschberg(b"AGTACGCA", b"TATGC", &scoring, gap);
+/// assert_eq!(alignment.score, needleman_wunsch(b"AGTACGCA", b"TATGC", &scoring, gap).score);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(nm), in O(n + m) space besides the alignment.
+///
+/// # Panics
+///
+/// Panics if `gap.extend` is greater than `gap.open`, since splitting a run
+/// of gaps in two would then lower its penalty.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Hirschberg%27s_algorithm)
+/// - [Hirschberg, D. S. (1975). A linear space algorithm for computing maximal common subsequences](https://doi.org/10.1145/360825.360861)
+/// - [Myers, E. W., & Miller, W. (1988). Optimal alignments in linear space](https://doi.org/10.1093/bioinformatics/4.1.11)
+///
+/// [`needleman_wunsch`]: super::needleman_wunsch
+#[must_use]
+pub fn hirschberg<T, S>(a: &[T], b: &[T], scoring: &S, gap: GapPenalty) -> Alignment
+where
+    S: Scoring<T>,
+{
+    assert!(
+        gap.extend <= gap.open,
+        "extending a gap must not cost more than opening one"
+    );
+
+    let mut alignment = Alignment {
+        score: 0,
+        columns: Vec::with_capacity(a.len() + b.len()),
+    };
+    let ends = Ends {
+        after_deletion: false,
+        before_deletion: false,
+    };
+    split(a, b, (0, 0), ends, scoring, gap, &mut alignment.columns);
+    alignment.score = rescore(&alignment, a, b, scoring, gap);
+    alignment
+}
+
+/// Aligns `a` and `b`, which start at `offset` in the whole sequences, by
+/// splitting `a` in half and `b` where a best alignment crosses the split.
+fn split<T, S>(
+    a: &[T],
+    b: &[T],
+    offset: (usize, usize),
+    ends: Ends,
+    scoring: &S,
+    gap: GapPenalty,
+    columns: &mut Vec<Column>,
+) where
+    S: Scoring<T>,
+{
+    if a.len() <= 1 || b.is_empty() {
+        align_small(a, b, offset, ends, scoring, gap, columns);
+        return;
+    }
+
+    let middle = a.len() / 2;
+    let prefixes = last_row(
+        a[..middle].iter(),
+        &b.iter(),
+        scoring,
+        gap,
+        ends.after_deletion,
+    );
+    let suffixes = last_row(
+        a[middle..].iter().rev(),
+        &b.iter().rev(),
+        scoring,
+        gap,
+        ends.before_deletion,
+    );
+
+    // A best alignment either crosses the middle in a pair or an insertion,
+    // or in a run of deletions of `a[middle - 1]` and `a[middle]`, which the
+    // two halves would each have paid to open.
+    let mut best = (NONE, 0, false);
+    for cut in 0..=b.len() {
+        let (prefix, suffix) = (prefixes[cut], suffixes[b.len() - cut]);
+        let through = prefix.best() + suffix.best();
+        let deleting = prefix.deletion + suffix.deletion + gap.open - gap.extend;
+        if through > best.0 {
+            best = (through, cut, false);
+        }
+        if deleting > best.0 {
+            best = (deleting, cut, true);
+        }
+    }
+
+    let (_, cut, deleting) = best;
+    let (left, right) = if deleting {
+        (middle - 1, middle + 1)
+    } else {
+        (middle, middle)
+    };
+    let left_ends = Ends {
+        before_deletion: deleting,
+        ..ends
+    };
+    let right_ends = Ends {
+        after_deletion: deleting,
+        ..ends
+    };
+
+    split(
+        &a[..left],
+        &b[..cut],
+        offset,
+        left_ends,
+        scoring,
+        gap,
+        columns,
+    );
+    columns.extend((left..right).map(|i| Column::Deletion { a: offset.0 + i }));
+    let offset = (offset.0 + right, offset.1 + cut);
+    split(
+        &a[right..],
+        &b[cut..],
+        offset,
+        right_ends,
+        scoring,
+        gap,
+        columns,
+    );
+}
+
+/// The best scores of aligning all of `a` with each prefix of `b`.
+fn last_row<'a, T: 'a, S>(
+    a: impl Iterator<Item = &'a T>,
+    b: &(impl Iterator<Item = &'a T> + Clone),
+    scoring: &S,
+    gap: GapPenalty,
+    after_deletion: bool,
+) -> Vec<Cell>
+where
+    S: Scoring<T>,
+{
+    let mut row = Vec::from([Cell::start(after_deletion)]);
+    for _ in b.clone() {
+        let left = row[row.len() - 1];
+        row.push(Cell {
+            pair: NONE,
+            deletion: NONE,
+            insertion: left.inserted(gap),
+        });
+    }
+    for x in a {
+        let mut diagonal = row[0];
+        row[0] = Cell {
+            pair: NONE,
+            deletion: row[0].deleted(gap),
+            insertion: NONE,
+        };
+        for (j, y) in b.clone().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = Cell {
+                pair: diagonal.best() + scoring.score(x, y),
+                deletion: above.deleted(gap),
+                insertion: row[j].inserted(gap),
+            };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+/// Aligns `a` and `b` with the whole table of Gotoh's algorithm, when one
+/// of them is short enough for it to take linear space.
+fn align_small<T, S>(
+    a: &[T],
+    b: &[T],
+    offset: (usize, usize),
+    ends: Ends,
+    scoring: &S,
+    gap: GapPenalty,
+    columns: &mut Vec<Column>,
+) where
+    S: Scoring<T>,
+{
+    let width = b.len() + 1;
+    let mut table = Vec::with_capacity((a.len() + 1) * width);
+    table.push(Cell::start(ends.after_deletion));
+    for j in 1..width {
+        let left: Cell = table[j - 1];
+        table.push(Cell {
+            pair: NONE,
+            deletion: NONE,
+            insertion: left.inserted(gap),
+        });
+    }
+    for i in 1..=a.len() {
+        for j in 0..width {
+            let above: Cell = table[(i - 1) * width + j];
+            let (pair, insertion) = if j == 0 {
+                (NONE, NONE)
+            } else {
+                let diagonal: Cell = table[(i - 1) * width + j - 1];
+                let left: Cell = table[i * width + j - 1];
+                (
+                    diagonal.best() + scoring.score(&a[i - 1], &b[j - 1]),
+                    left.inserted(gap),
+                )
+            };
+            table.push(Cell {
+                pair,
+                deletion: above.deleted(gap),
+                insertion,
+            });
+        }
+    }
+
+    // A final deletion continues the run of deletions after the part.
+    let (mut i, mut j) = (a.len(), b.len());
+    let end = table[i * width + j];
+    let joined = if ends.before_deletion {
+        gap.open - gap.extend
+    } else {
+        0
+    };
+    let best = end.pair.max(end.deletion + joined).max(end.insertion);
+    let mut state = if end.pair == best {
+        State::Pair
+    } else if end.deletion + joined == best {
+        State::Deletion
+    } else {
+        State::Insertion
+    };
+
+    let start = columns.len();
+    while i > 0 || j > 0 {
+        let cell = table[i * width + j];
+        match state {
+            State::Pair => {
+                let before = cell.pair - scoring.score(&a[i - 1], &b[j - 1]);
+                (i, j) = (i - 1, j - 1);
+                columns.push(Column::Pair {
+                    a: offset.0 + i,
+                    b: offset.1 + j,
+                });
+                let previous = table[i * width + j];
+                state = if previous.pair == before {
+                    State::Pair
+                } else if previous.deletion == before {
+                    State::Deletion
+                } else {
+                    State::Insertion
+                };
+            }
+            State::Deletion => {
+                i -= 1;
+                columns.push(Column::Deletion { a: offset.0 + i });
+                let previous = table[i * width + j];
+                state = if cell.deletion == previous.deletion - gap.extend {
+                    State::Deletion
+                } else if cell.deletion == previous.pair - gap.open {
+                    State::Pair
+                } else {
+                    State::Insertion
+                };
+            }
+            State::Insertion => {
+                j -= 1;
+                columns.push(Column::Insertion { b: offset.1 + j });
+                let previous = table[i * width + j];
+                state = if cell.insertion == previous.insertion - gap.extend {
+                    State::Insertion
+                } else if cell.insertion == previous.pair - gap.open {
+                    State::Pair
+                } else {
+                    State::Deletion
+                };
+            }
+        }
+    }
+    columns[start..].reverse();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{needleman_wunsch, MatchMismatch, BLOSUM62};
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    #[test_case(GapPenalty::linear(2); "linear")]
+    #[test_case(GapPenalty::affine(4, 1); "affine")]
+    #[test_case(GapPenalty::affine(3, 2); "nearly linear")]
+    #[test_case(GapPenalty::affine(10, 0); "free extension")]
+    fn agrees_with_needleman_wunsch(gap: GapPenalty) {
+        let scoring = MatchMismatch {
+            matched: 2,
+            mismatched: -1,
+        };
+        let mut rng = StdRng::seed_from_u64(0x2545_f491);
+
+        for _ in 0..300 {
+            let a: Vec<u8> = (0..rng.gen_range(0..40))
+                .map(|_| b"ACGT"[rng.gen_range(0..4)])
+                .collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..40))
+                .map(|_| b"ACGT"[rng.gen_range(0..4)])
+                .collect();
+            let alignment = hirschberg(&a, &b, &scoring, gap);
+            assert_eq!(
+                alignment.score,
+                needleman_wunsch(&a, &b, &scoring, gap).score,
+                "{a:?} {b:?}"
+            );
+            assert_eq!(alignment.range_a(), 0..a.len());
+            assert_eq!(alignment.range_b(), 0..b.len());
+            let mut pairs = alignment.columns.iter().filter_map(|&column| match column {
+                Column::Pair { a, b } => Some((a, b)),
+                _ => None,
+            });
+            assert!(pairs
+                .clone()
+                .zip(pairs.by_ref().skip(1))
+                .all(|(x, y)| x < y));
+        }
+    }
+
+    #[test]
+    fn long_gaps() {
+        // The best alignment deletes a run that crosses every split of `a`.
+        let scoring = MatchMismatch {
+            matched: 1,
+            mismatched: -3,
+        };
+        let a = b"ACGTTTTTTTTTTTTTTTTTTTTTTACGT";
+        let b = b"ACGTACGT";
+        let gap = GapPenalty::affine(5, 1);
+        let alignment = hirschberg(a, b, &scoring, gap);
+        assert_eq!(alignment.score, 8 - 5 - 20);
+        assert_eq!(alignment.score, needleman_wunsch(a, b, &scoring, gap).score);
+    }
+
+    #[test]
+    fn proteins() {
+        let (a, b) = (
+            b"MKTAYIAKQRQISFVKSHFSRQ",
+            b"MKTAYIAKQRQISFVKSHFSRQLEERLGLIEVQ",
+        );
+        for gap in [GapPenalty::linear(4), GapPenalty::affine(11, 1)] {
+            let alignment = hirschberg(a, b, &BLOSUM62, gap);
+            assert_eq!(
+                alignment.score,
+                needleman_wunsch(a, b, &BLOSUM62, gap).score
+            );
+            assert_eq!(alignment.identities(a, b), a.len());
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let scoring = MatchMismatch {
+            matched: 1,
+            mismatched: -1,
+        };
+        let gap = GapPenalty::affine(3, 1);
+        assert_eq!(hirschberg(b"", b"", &scoring, gap), Alignment::default());
+        assert_eq!(hirschberg(b"AC", b"", &scoring, gap).score, -4);
+        assert_eq!(hirschberg(b"", b"ACG", &scoring, gap).score, -5);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not cost more")]
+    fn rejects_cheap_opening() {
+        let scoring = MatchMismatch {
+            matched: 1,
+            mismatched: -1,
+        };
+        let _ = hirschberg(b"A", b"C", &scoring, GapPenalty::affine(1, 2));
+    }
+}
@@ -0,0 +1,18 @@
+//! Global and local alignment of biological and other sequences
+//!
+//! An alignment lines two sequences up in columns, pairing items of both
+//! or one item with a gap, and is scored by a substitution score for each
+//! pair and a penalty for each gap. [`needleman_wunsch`] finds the best
+//! alignment of two whole sequences, [`smith_waterman`] the best alignment
+//! of any two of their substrings, and [`hirschberg`] an alignment as good
+//! as the former's, under the same gap penalties, in linear space.
+
+mod columns;
+mod gotoh;
+mod hirschberg;
+mod scoring;
+
+pub use columns::*;
+pub use gotoh::*;
+pub use hirschberg::*;
+pub use scoring::*;
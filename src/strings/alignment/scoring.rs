@@ -0,0 +1,253 @@
+/// A way to score pairing two items in an alignment: positive for items
+/// that are alike, negative for items that are not.
+pub trait Scoring<T> {
+    /// The score of pairing `a` with `b`.
+    fn score(&self, a: &T, b: &T) -> i32;
+}
+
+/// Scores equal items with one score and different items with another,
+/// as is usual for DNA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MatchMismatch {
+    /// The score of pairing equal items.
+    pub matched: i32,
+    /// The score of pairing different items.
+    pub mismatched: i32,
+}
+
+impl<T: Eq> Scoring<T> for MatchMismatch {
+    fn score(&self, a: &T, b: &T) -> i32 {
+        if a == b {
+            self.matched
+        } else {
+            self.mismatched
+        }
+    }
+}
+
+/// The penalty for a run of gaps in an alignment, subtracted from its score.
+///
+/// A run of `k` gaps costs `open + (k - 1) * extend`, so an opening penalty
+/// larger than the extension penalty makes one long gap cheaper than
+/// several short ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GapPenalty {
+    /// The penalty for the first gap of a run.
+    pub open: i32,
+    /// The penalty for each further gap of a run.
+    pub extend: i32,
+}
+
+impl GapPenalty {
+    /// The same penalty for every gap.
+    #[must_use]
+    pub const fn linear(penalty: i32) -> Self {
+        Self {
+            open: penalty,
+            extend: penalty,
+        }
+    }
+
+    /// A penalty for opening a run of gaps and a smaller one for extending it.
+    #[must_use]
+    pub const fn affine(open: i32, extend: i32) -> Self {
+        Self { open, extend }
+    }
+}
+
+/// A table of the scores of pairing residues, indexed by their letters.
+///
+/// Letters are looked up without regard to case.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::alignment::{Scoring, BLOSUM62, PAM250};
+///
+/// assert_eq!(BLOSUM62.score(&b'W', &b'W'), 11);
+/// assert_eq!(BLOSUM62.score(&b'a', &b'S'), 1);
+/// assert_eq!(PAM250.score(&b'C', &b'C'), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubstitutionMatrix {
+    alphabet: &'static [u8],
+    scores: &'static [i8],
+    /// The position in the alphabet of each byte, or `u8::MAX` if it is not a letter of it.
+    index: [u8; 256],
+}
+
+impl SubstitutionMatrix {
+    /// A matrix over `alphabet` whose row for each letter is the next
+    /// `alphabet.len()` entries of `scores`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scores` does not have a row for every letter, or the
+    /// alphabet has more than 255 letters.
+    #[must_use]
+    pub const fn new(alphabet: &'static [u8], scores: &'static [i8]) -> Self {
+        assert!(alphabet.len() < 256, "alphabet has too many letters");
+        assert!(
+            scores.len() == alphabet.len() * alphabet.len(),
+            "scores must have a row for every letter"
+        );
+
+        let mut index = [u8::MAX; 256];
+        let mut i = 0;
+        while i < alphabet.len() {
+            index[alphabet[i].to_ascii_uppercase() as usize] = i as u8;
+            index[alphabet[i].to_ascii_lowercase() as usize] = i as u8;
+            i += 1;
+        }
+        Self {
+            alphabet,
+            scores,
+            index,
+        }
+    }
+
+    /// The letters the matrix scores.
+    #[must_use]
+    pub fn alphabet(&self) -> &'static [u8] {
+        self.alphabet
+    }
+}
+
+impl Scoring<u8> for SubstitutionMatrix {
+    /// # Panics
+    ///
+    /// Panics if either letter is not in the alphabet of the matrix.
+    fn score(&self, a: &u8, b: &u8) -> i32 {
+        let position = |letter: u8| match self.index[usize::from(letter)] {
+            u8::MAX => panic!("{:?} is not in the alphabet", char::from(letter)),
+            i => usize::from(i),
+        };
+        i32::from(self.scores[position(*a) * self.alphabet.len() + position(*b)])
+    }
+}
+
+/// The letters of [`BLOSUM62`] and [`PAM250`]: the twenty amino acids,
+/// the ambiguity codes `B` and `Z`, any residue `X` and the stop `*`.
+const AMINO_ACIDS: &[u8] = b"ARNDCQEGHILKMFPSTWYVBZX*";
+
+/// The BLOSUM62 matrix of Henikoff and Henikoff, the default for protein
+/// alignment in BLAST.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/BLOSUM)
+/// - [NCBI](https://ftp.ncbi.nlm.nih.gov/blast/matrices/BLOSUM62)
+#[rustfmt::skip]
+pub const BLOSUM62: SubstitutionMatrix = SubstitutionMatrix::new(AMINO_ACIDS, &[
+//   A   R   N   D   C   Q   E   G   H   I   L   K   M   F   P   S   T   W   Y   V   B   Z   X   *
+     4, -1, -2, -2,  0, -1, -1,  0, -2, -1, -1, -1, -1, -2, -1,  1,  0, -3, -2,  0, -2, -1,  0, -4, // A
+    -1,  5,  0, -2, -3,  1,  0, -2,  0, -3, -2,  2, -1, -3, -2, -1, -1, -3, -2, -3, -1,  0, -1, -4, // R
+    -2,  0,  6,  1, -3,  0,  0,  0,  1, -3, -3,  0, -2, -3, -2,  1,  0, -4, -2, -3,  3,  0, -1, -4, // N
+    -2, -2,  1,  6, -3,  0,  2, -1, -1, -3, -4, -1, -3, -3, -1,  0, -1, -4, -3, -3,  4,  1, -1, -4, // D
+     0, -3, -3, -3,  9, -3, -4, -3, -3, -1, -1, -3, -1, -2, -3, -1, -1, -2, -2, -1, -3, -3, -2, -4, // C
+    -1,  1,  0,  0, -3,  5,  2, -2,  0, -3, -2,  1,  0, -3, -1,  0, -1, -2, -1, -2,  0,  3, -1, -4, // Q
+    -1,  0,  0,  2, -4,  2,  5, -2,  0, -3, -3,  1, -2, -3, -1,  0, -1, -3, -2, -2,  1,  4, -1, -4, // E
+     0, -2,  0, -1, -3, -2, -2,  6, -2, -4, -4, -2, -3, -3, -2,  0, -2, -2, -3, -3, -1, -2, -1, -4, // G
+    -2,  0,  1, -1, -3,  0,  0, -2,  8, -3, -3, -1, -2, -1, -2, -1, -2, -2,  2, -3,  0,  0, -1, -4, // H
+    -1, -3, -3, -3, -1, -3, -3, -4, -3,  4,  2, -3,  1,  0, -3, -2, -1, -3, -1,  3, -3, -3, -1, -4, // I
+    -1, -2, -3, -4, -1, -2, -3, -4, -3,  2,  4, -2,  2,  0, -3, -2, -1, -2, -1,  1, -4, -3, -1, -4, // L
+    -1,  2,  0, -1, -3,  1,  1, -2, -1, -3, -2,  5, -1, -3, -1,  0, -1, -3, -2, -2,  0,  1, -1, -4, // K
+    -1, -1, -2, -3, -1,  0, -2, -3, -2,  1,  2, -1,  5,  0, -2, -1, -1, -1, -1,  1, -3, -1, -1, -4, // M
+    -2, -3, -3, -3, -2, -3, -3, -3, -1,  0,  0, -3,  0,  6, -4, -2, -2,  1,  3, -1, -3, -3, -1, -4, // F
+    -1, -2, -2, -1, -3, -1, -1, -2, -2, -3, -3, -1, -2, -4,  7, -1, -1, -4, -3, -2, -2, -1, -2, -4, // P
+     1, -1,  1,  0, -1,  0,  0,  0, -1, -2, -2,  0, -1, -2, -1,  4,  1, -3, -2, -2,  0,  0,  0, -4, // S
+     0, -1,  0, -1, -1, -1, -1, -2, -2, -1, -1, -1, -1, -2, -1,  1,  5, -2, -2,  0, -1, -1,  0, -4, // T
+    -3, -3, -4, -4, -2, -2, -3, -2, -2, -3, -2, -3, -1,  1, -4, -3, -2, 11,  2, -3, -4, -3, -2, -4, // W
+    -2, -2, -2, -3, -2, -1, -2, -3,  2, -1, -1, -2, -1,  3, -3, -2, -2,  2,  7, -1, -3, -2, -1, -4, // Y
+     0, -3, -3, -3, -1, -2, -2, -3, -3,  3,  1, -2,  1, -1, -2, -2,  0, -3, -1,  4, -3, -2, -1, -4, // V
+    -2, -1,  3,  4, -3,  0,  1, -1,  0, -3, -4,  0, -3, -3, -2,  0, -1, -4, -3, -3,  4,  1, -1, -4, // B
+    -1,  0,  0,  1, -3,  3,  4, -2,  0, -3, -3,  1, -1, -3, -1,  0, -1, -3, -2, -2,  1,  4, -1, -4, // Z
+     0, -1, -1, -1, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -2,  0,  0, -2, -1, -1, -1, -1, -1, -4, // X
+    -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4,  1, // *
+]);
+
+/// The PAM250 matrix of Dayhoff, for distantly related proteins.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Point_accepted_mutation)
+/// - [NCBI](https://ftp.ncbi.nlm.nih.gov/blast/matrices/PAM250)
+#[rustfmt::skip]
+pub const PAM250: SubstitutionMatrix = SubstitutionMatrix::new(AMINO_ACIDS, &[
+//   A   R   N   D   C   Q   E   G   H   I   L   K   M   F   P   S   T   W   Y   V   B   Z   X   *
+     2, -2,  0,  0, -2,  0,  0,  1, -1, -1, -2, -1, -1, -3,  1,  1,  1, -6, -3,  0,  0,  0,  0, -8, // A
+    -2,  6,  0, -1, -4,  1, -1, -3,  2, -2, -3,  3,  0, -4,  0,  0, -1,  2, -4, -2, -1,  0, -1, -8, // R
+     0,  0,  2,  2, -4,  1,  1,  0,  2, -2, -3,  1, -2, -3,  0,  1,  0, -4, -2, -2,  2,  1,  0, -8, // N
+     0, -1,  2,  4, -5,  2,  3,  1,  1, -2, -4,  0, -3, -6, -1,  0,  0, -7, -4, -2,  3,  3, -1, -8, // D
+    -2, -4, -4, -5, 12, -5, -5, -3, -3, -2, -6, -5, -5, -4, -3,  0, -2, -8,  0, -2, -4, -5, -3, -8, // C
+     0,  1,  1,  2, -5,  4,  2, -1,  3, -2, -2,  1, -1, -5,  0, -1, -1, -5, -4, -2,  1,  3, -1, -8, // Q
+     0, -1,  1,  3, -5,  2,  4,  0,  1, -2, -3,  0, -2, -5, -1,  0,  0, -7, -4, -2,  3,  3, -1, -8, // E
+     1, -3,  0,  1, -3, -1,  0,  5, -2, -3, -4, -2, -3, -5,  0,  1,  0, -7, -5, -1,  0,  0, -1, -8, // G
+    -1,  2,  2,  1, -3,  3,  1, -2,  6, -2, -2,  0, -2, -2,  0, -1, -1, -3,  0, -2,  1,  2, -1, -8, // H
+    -1, -2, -2, -2, -2, -2, -2, -3, -2,  5,  2, -2,  2,  1, -2, -1,  0, -5, -1,  4, -2, -2, -1, -8, // I
+    -2, -3, -3, -4, -6, -2, -3, -4, -2,  2,  6, -3,  4,  2, -3, -3, -2, -2, -1,  2, -3, -3, -1, -8, // L
+    -1,  3,  1,  0, -5,  1,  0, -2,  0, -2, -3,  5,  0, -5, -1,  0,  0, -3, -4, -2,  1,  0, -1, -8, // K
+    -1,  0, -2, -3, -5, -1, -2, -3, -2,  2,  4,  0,  6,  0, -2, -2, -1, -4, -2,  2, -2, -2, -1, -8, // M
+    -3, -4, -3, -6, -4, -5, -5, -5, -2,  1,  2, -5,  0,  9, -5, -3, -3,  0,  7, -1, -4, -5, -2, -8, // F
+     1,  0,  0, -1, -3,  0, -1,  0,  0, -2, -3, -1, -2, -5,  6,  1,  0, -6, -5, -1, -1,  0, -1, -8, // P
+     1,  0,  1,  0,  0, -1,  0,  1, -1, -1, -3,  0, -2, -3,  1,  2,  1, -2, -3, -1,  0,  0,  0, -8, // S
+     1, -1,  0,  0, -2, -1,  0,  0, -1,  0, -2,  0, -1, -3,  0,  1,  3, -5, -3,  0,  0, -1,  0, -8, // T
+    -6,  2, -4, -7, -8, -5, -7, -7, -3, -5, -2, -3, -4,  0, -6, -2, -5, 17,  0, -6, -5, -6, -4, -8, // W
+    -3, -4, -2, -4,  0, -4, -4, -5,  0, -1, -1, -4, -2,  7, -5, -3, -3,  0, 10, -2, -3, -4, -2, -8, // Y
+     0, -2, -2, -2, -2, -2, -2, -1, -2,  4,  2, -2,  2, -1, -1, -1,  0, -6, -2,  4, -2, -2, -1, -8, // V
+     0, -1,  2,  3, -4,  1,  3,  0,  1, -2, -3,  1, -2, -4, -1,  0,  0, -5, -3, -2,  3,  2, -1, -8, // B
+     0,  0,  1,  3, -5,  3,  3,  0,  2, -2, -3,  0, -2, -5,  0,  0, -1, -6, -4, -2,  2,  3, -1, -8, // Z
+     0, -1,  0, -1, -3, -1, -1, -1, -1, -1, -1, -1, -1, -2, -1,  0,  0, -4, -2, -1, -1, -1, -1, -8, // X
+    -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,  1, // *
+]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(&BLOSUM62)]
+    #[test_case(&PAM250)]
+    fn matrices_are_symmetric(matrix: &SubstitutionMatrix) {
+        for &a in matrix.alphabet() {
+            for &b in matrix.alphabet() {
+                assert_eq!(matrix.score(&a, &b), matrix.score(&b, &a));
+            }
+        }
+        // No amino acid is more like another than like itself.
+        for &a in &matrix.alphabet()[..20] {
+            assert!(matrix.alphabet()[..20]
+                .iter()
+                .all(|b| matrix.score(&a, b) <= matrix.score(&a, &a)));
+        }
+    }
+
+    #[test_case(&BLOSUM62, b'A', b'A', 4)]
+    #[test_case(&BLOSUM62, b'C', b'C', 9)]
+    #[test_case(&BLOSUM62, b'W', b'C', -2)]
+    #[test_case(&BLOSUM62, b'I', b'V', 3)]
+    #[test_case(&BLOSUM62, b'e', b'q', 2)]
+    #[test_case(&PAM250, b'W', b'W', 17)]
+    #[test_case(&PAM250, b'W', b'R', 2)]
+    #[test_case(&PAM250, b'F', b'Y', 7)]
+    fn test_scores(matrix: &SubstitutionMatrix, a: u8, b: u8, expected: i32) {
+        assert_eq!(matrix.score(&a, &b), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "not in the alphabet")]
+    fn rejects_unknown_letters() {
+        let _ = BLOSUM62.score(&b'J', &b'A');
+    }
+
+    #[test]
+    fn match_mismatch() {
+        let scoring = MatchMismatch {
+            matched: 2,
+            mismatched: -1,
+        };
+        assert_eq!(scoring.score(&'a', &'a'), 2);
+        assert_eq!(scoring.score(&'a', &'b'), -1);
+    }
+}
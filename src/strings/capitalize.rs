@@ -1,16 +1,19 @@
 use alloc::string::String;
 
+use super::{Locale, WordBoundaries};
+
 /// A trait for capitalizing the first letter of a word or sentence.
 ///
-/// This trait provides a method to capitalize the first character of a string slice or a string,
-/// converting it to uppercase if it is a lowercase ASCII character.
+/// This trait provides methods to capitalize the first character of a string slice, or the
+/// first letter of each of its words, with the full Unicode case mappings, so that `ß` becomes
+/// `Ss` and `ǆ` becomes `ǅ`.
 pub trait Capitalizable {
     /// This function will capitalize the first letter of a sentence or a word
     ///
     /// # Returns
     ///
-    /// A new `String` with the first letter capitalized. If the string is empty,
-    /// an empty string is returned.
+    /// A new `String` with the first character in title case, and the rest as they were. If the
+    /// string is empty, an empty string is returned.
     ///
     /// # Examples
     ///
@@ -20,25 +23,80 @@ pub trait Capitalizable {
     /// let text = "hello world";
     /// let capitalized = text.capitalized();
     /// assert_eq!(capitalized, "Hello world");
+    /// assert_eq!("élan vital".capitalized(), "Élan vital");
+    /// ```
+    #[must_use]
+    fn capitalized(&self) -> String {
+        self.capitalized_in(Locale::Neutral)
+    }
+
+    /// This function will capitalize the first letter of a sentence or a word, following the
+    /// case rules of a language.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - The language whose case rules to follow.
+    ///
+    /// # Examples
+    ///
     /// ```
-    fn capitalized(&self) -> String;
+    /// use algoritmer::strings::{Capitalizable, Locale};
+    ///
+    /// assert_eq!("istanbul".capitalized_in(Locale::Neutral), "Istanbul");
+    /// assert_eq!("istanbul".capitalized_in(Locale::Turkish), "İstanbul");
+    /// ```
+    #[must_use]
+    fn capitalized_in(&self, locale: Locale) -> String;
+
+    /// This function will put every word in title case: its first cased character in title
+    /// case, and the rest in lower case.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundaries` - Where the words end. Whatever separates them is kept as it is.
+    /// * `locale` - The language whose case rules to follow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::{Capitalizable, Locale, WordBoundaries};
+    ///
+    /// let text = "the QUICK brown-fox's ßtory";
+    /// assert_eq!(
+    ///     text.title_cased(WordBoundaries::WHITESPACE, Locale::Neutral),
+    ///     "The Quick Brown-fox's Sstory"
+    /// );
+    /// let boundaries = WordBoundaries { punctuation: true, ..WordBoundaries::WHITESPACE };
+    /// assert_eq!(
+    ///     text.title_cased(boundaries, Locale::Neutral),
+    ///     "The Quick Brown-Fox'S Sstory"
+    /// );
+    /// ```
+    #[must_use]
+    fn title_cased(&self, boundaries: WordBoundaries, locale: Locale) -> String;
 }
 
 impl Capitalizable for str {
-    fn capitalized(&self) -> String {
-        if *self == String::new() {
-            String::new()
-        } else {
-            let mut new_string = String::new();
-            for (i, ch) in self.char_indices() {
-                if i == 0 && self.starts_with(|c: char| c.is_ascii_lowercase()) {
-                    new_string.insert(i, ch.to_ascii_uppercase());
-                } else {
-                    new_string.insert(i, ch);
-                }
-            }
-            new_string
+    fn capitalized_in(&self, locale: Locale) -> String {
+        let mut chars = self.chars();
+        let mut capitalized = String::with_capacity(self.len());
+        if let Some(first) = chars.next() {
+            locale.push_title_char(first, &mut capitalized);
+        }
+        capitalized.push_str(chars.as_str());
+        capitalized
+    }
+
+    fn title_cased(&self, boundaries: WordBoundaries, locale: Locale) -> String {
+        let mut title_cased = String::with_capacity(self.len());
+        let mut end = 0;
+        for word in boundaries.ranges(self) {
+            title_cased.push_str(&self[end..word.start]);
+            locale.push_titlecase(&self[word.clone()], &mut title_cased);
+            end = word.end;
         }
+        title_cased.push_str(&self[end..]);
+        title_cased
     }
 }
 
@@ -52,8 +110,38 @@ mod tests {
     #[test_case("hello world", "Hello world")]
     #[test_case("a", "A")]
     #[test_case("", "")]
+    #[test_case("élan", "Élan"; "accented")]
+    #[test_case("ßa", "Ssa"; "sharp s")]
+    #[test_case("ǆungla", "ǅungla"; "digraph")]
+    #[test_case("ﬁsh", "Fish"; "ligature")]
     fn does_it_capitalize(sentence: &str, expected: &str) {
         let actual = sentence.capitalized();
         assert_eq!(expected, actual);
     }
+
+    #[test_case(
+        WordBoundaries::WHITESPACE,
+        Locale::Neutral,
+        "hello  wORLD\n",
+        "Hello  World\n"
+    )]
+    #[test_case(WordBoundaries::WHITESPACE, Locale::Neutral, "ΟΔΟΣ ΣΤΟ", "Οδος Στο"; "final sigma")]
+    #[test_case(
+        WordBoundaries::WHITESPACE,
+        Locale::Turkish,
+        "ılık iklim",
+        "Ilık İklim";
+        "turkish i"
+    )]
+    #[test_case(
+        WordBoundaries::IDENTIFIER,
+        Locale::Neutral,
+        "snake_case_name",
+        "Snake_Case_Name"
+    )]
+    #[test_case(WordBoundaries::IDENTIFIER, Locale::Neutral, "camelCase", "CamelCase")]
+    #[test_case(WordBoundaries::default(), Locale::Neutral, "one two", "One two")]
+    fn title_case(boundaries: WordBoundaries, locale: Locale, text: &str, expected: &str) {
+        assert_eq!(text.title_cased(boundaries, locale), expected);
+    }
 }
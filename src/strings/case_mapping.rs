@@ -0,0 +1,317 @@
+use alloc::string::String;
+
+use super::segmentation::extends_grapheme;
+
+/// The language whose rules case mapping follows.
+///
+/// Most languages share the default mappings of the Unicode standard. The
+/// exceptions covered here are those of
+/// [SpecialCasing.txt](https://www.unicode.org/Public/UCD/latest/ucd/SpecialCasing.txt)
+/// that do not depend on anything but the text.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::Locale;
+///
+/// assert_eq!(Locale::Neutral.uppercase("straße"), "STRASSE");
+/// assert_eq!(Locale::Neutral.uppercase("istanbul"), "ISTANBUL");
+/// assert_eq!(Locale::Turkish.uppercase("istanbul"), "İSTANBUL");
+/// assert_eq!(Locale::Turkish.lowercase("DİYARBAKIR"), "diyarbakır");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// The default mappings, for languages without special rules.
+    #[default]
+    Neutral,
+    /// Turkish and Azerbaijani, where dotted `i` and `İ` and dotless `ı` and `I`
+    /// are pairs of distinct letters.
+    Turkish,
+}
+
+/// Title case mappings of more than one character, or that differ from
+/// upper case in ways the ranges in [`Locale::push_title_char`] do not cover,
+/// sorted by character.
+const SPECIAL_TITLECASE: &[(char, &str)] = &[
+    ('\u{DF}', "Ss"),
+    ('\u{587}', "\u{535}\u{582}"),
+    ('\u{1FB2}', "\u{1FBA}\u{345}"),
+    ('\u{1FB4}', "\u{386}\u{345}"),
+    ('\u{1FB7}', "\u{391}\u{342}\u{345}"),
+    ('\u{1FC2}', "\u{1FCA}\u{345}"),
+    ('\u{1FC4}', "\u{389}\u{345}"),
+    ('\u{1FC7}', "\u{397}\u{342}\u{345}"),
+    ('\u{1FF2}', "\u{1FFA}\u{345}"),
+    ('\u{1FF4}', "\u{38F}\u{345}"),
+    ('\u{1FF7}', "\u{3A9}\u{342}\u{345}"),
+    ('\u{FB00}', "Ff"),
+    ('\u{FB01}', "Fi"),
+    ('\u{FB02}', "Fl"),
+    ('\u{FB03}', "Ffi"),
+    ('\u{FB04}', "Ffl"),
+    ('\u{FB05}', "St"),
+    ('\u{FB06}', "St"),
+    ('\u{FB13}', "\u{544}\u{576}"),
+    ('\u{FB14}', "\u{544}\u{565}"),
+    ('\u{FB15}', "\u{544}\u{56B}"),
+    ('\u{FB16}', "\u{54E}\u{576}"),
+    ('\u{FB17}', "\u{544}\u{56D}"),
+];
+
+impl Locale {
+    /// Maps every character of a text to lower case.
+    ///
+    /// A capital sigma becomes `ς` at the end of a word and `σ` elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::Locale;
+    ///
+    /// assert_eq!(Locale::Neutral.lowercase("ΟΔΥΣΣΕΥΣ"), "οδυσσευς");
+    /// assert_eq!(Locale::Turkish.lowercase("KIŞ"), "kış");
+    /// ```
+    #[must_use]
+    pub fn lowercase(self, text: &str) -> String {
+        let mut lowercase = String::with_capacity(text.len());
+        for (i, c) in text.char_indices() {
+            self.push_lower_char(text, i, c, &mut lowercase);
+        }
+        lowercase
+    }
+
+    /// Maps every character of a text to upper case, which may take more
+    /// characters than there are, as `ß` becomes `SS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::Locale;
+    ///
+    /// assert_eq!(Locale::Neutral.uppercase("ﬁnal"), "FINAL");
+    /// assert_eq!(Locale::Turkish.uppercase("kış"), "KIŞ");
+    /// ```
+    #[must_use]
+    pub fn uppercase(self, text: &str) -> String {
+        let mut uppercase = String::with_capacity(text.len());
+        for c in text.chars() {
+            self.push_upper_char(c, &mut uppercase);
+        }
+        uppercase
+    }
+
+    /// Maps the first cased character of a word to title case and the rest
+    /// to lower case.
+    ///
+    /// Title case is upper case for most characters, but not for digraphs
+    /// and ligatures, where only the first letter is capital.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::Locale;
+    ///
+    /// assert_eq!(Locale::Neutral.titlecase("ǆEMAL"), "ǅemal");
+    /// assert_eq!(Locale::Neutral.titlecase("ßig"), "Ssig");
+    /// assert_eq!(Locale::Neutral.titlecase("'élan'"), "'Élan'");
+    /// assert_eq!(Locale::Turkish.titlecase("izmir"), "İzmir");
+    /// ```
+    #[must_use]
+    pub fn titlecase(self, word: &str) -> String {
+        let mut titlecase = String::with_capacity(word.len());
+        self.push_titlecase(word, &mut titlecase);
+        titlecase
+    }
+
+    /// Folds the case of every character of a text, so that texts that
+    /// differ only in case fold to the same one.
+    ///
+    /// A character folds to the lower case of the upper case of its lower
+    /// case, so `ß`, `ẞ` and `SS` all fold to `ss`, and final `ς` folds to
+    /// `σ` like `Σ` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::Locale;
+    ///
+    /// assert_eq!(Locale::Neutral.fold("Straße"), Locale::Neutral.fold("STRASSE"));
+    /// assert_eq!(Locale::Neutral.fold("ὈΔΥΣΣΕΎΣ"), "ὀδυσσεύσ");
+    /// assert_eq!(Locale::Turkish.fold("IĞDIR"), "ığdır");
+    /// ```
+    #[must_use]
+    pub fn fold(self, text: &str) -> String {
+        let mut folded = String::with_capacity(text.len());
+        for c in text.chars() {
+            self.push_folded_char(c, &mut folded);
+        }
+        folded
+    }
+
+    /// Appends the case folding of a character.
+    pub(super) fn push_folded_char(self, c: char, out: &mut String) {
+        match (self, c) {
+            // Dotless `i` is a letter of its own, which only Turkish pairs with `I`.
+            (Self::Turkish, 'I' | 'ı') | (Self::Neutral, 'ı') => out.push('ı'),
+            (Self::Turkish, 'i' | 'İ') => out.push('i'),
+            _ => {
+                for upper in c.to_lowercase().flat_map(char::to_uppercase) {
+                    out.extend(upper.to_lowercase());
+                }
+            }
+        }
+    }
+
+    /// Appends [`Locale::titlecase`] of a word.
+    pub(super) fn push_titlecase(self, word: &str, out: &mut String) {
+        let mut titled = false;
+        for (i, c) in word.char_indices() {
+            if !titled && is_cased(c) {
+                self.push_title_char(c, out);
+                titled = true;
+            } else {
+                self.push_lower_char(word, i, c, out);
+            }
+        }
+    }
+
+    /// Appends the lower case of the character `c` at byte `i` of `text`,
+    /// which depends on the characters around it.
+    pub(super) fn push_lower_char(self, text: &str, i: usize, c: char, out: &mut String) {
+        match (self, c) {
+            (Self::Turkish, 'I') if text[i + 1..].starts_with('\u{307}') => out.push('i'),
+            (Self::Turkish, 'I') => out.push('ı'),
+            (Self::Turkish, 'İ') => out.push('i'),
+            // The dot above that made an `I` dotted goes with it.
+            (Self::Turkish, '\u{307}') if text[..i].ends_with('I') => {}
+            (_, 'Σ') if is_final_sigma(text, i) => out.push('ς'),
+            _ => out.extend(c.to_lowercase()),
+        }
+    }
+
+    /// Appends the upper case of a character.
+    pub(super) fn push_upper_char(self, c: char, out: &mut String) {
+        match (self, c) {
+            (Self::Turkish, 'i') => out.push('İ'),
+            _ => out.extend(c.to_uppercase()),
+        }
+    }
+
+    /// Appends the title case of a character.
+    pub(super) fn push_title_char(self, c: char, out: &mut String) {
+        match (self, c) {
+            (Self::Turkish, 'i') => out.push('İ'),
+            (_, '\u{1C4}'..='\u{1C6}') => out.push('\u{1C5}'),
+            (_, '\u{1C7}'..='\u{1C9}') => out.push('\u{1C8}'),
+            (_, '\u{1CA}'..='\u{1CC}') => out.push('\u{1CB}'),
+            (_, '\u{1F1}'..='\u{1F3}') => out.push('\u{1F2}'),
+            // Greek vowels with a subscript iota, which stays a subscript.
+            (_, '\u{1F80}'..='\u{1FAF}') => {
+                out.extend(char::from_u32(u32::from(c) | 8));
+            }
+            (_, '\u{1FB3}' | '\u{1FBC}') => out.push('\u{1FBC}'),
+            (_, '\u{1FC3}' | '\u{1FCC}') => out.push('\u{1FCC}'),
+            (_, '\u{1FF3}' | '\u{1FFC}') => out.push('\u{1FFC}'),
+            // Georgian Mkhedruli has upper case letters, but only for all caps.
+            (_, '\u{10D0}'..='\u{10FA}' | '\u{10FD}'..='\u{10FF}') => out.push(c),
+            _ => match SPECIAL_TITLECASE.binary_search_by_key(&c, |&(c, _)| c) {
+                Ok(i) => out.push_str(SPECIAL_TITLECASE[i].1),
+                Err(_) => out.extend(c.to_uppercase()),
+            },
+        }
+    }
+}
+
+/// Whether a character has case, being lower, upper or title case.
+pub(super) fn is_cased(c: char) -> bool {
+    c.is_lowercase() || c.is_uppercase() || c.to_lowercase().ne([c])
+}
+
+/// Whether a character is skipped when looking for the cased letters around
+/// a sigma: combining marks and joiners, and the punctuation that goes
+/// inside words.
+fn is_case_ignorable(c: char) -> bool {
+    extends_grapheme(c)
+        || matches!(
+            c,
+            '\'' | '.'
+                | ':'
+                | '^'
+                | '`'
+                | '\u{AD}'
+                | '\u{B7}'
+                | '\u{2018}'
+                | '\u{2019}'
+                | '\u{2024}'
+                | '\u{2027}'
+        )
+}
+
+/// Whether the capital sigma at byte `i` of `text` ends a word: it follows
+/// a cased letter and does not precede one.
+fn is_final_sigma(text: &str, i: usize) -> bool {
+    let mut before = text[..i]
+        .chars()
+        .rev()
+        .skip_while(|&c| is_case_ignorable(c));
+    let mut after = text[i + 'Σ'.len_utf8()..]
+        .chars()
+        .skip_while(|&c| is_case_ignorable(c));
+    before.next().is_some_and(is_cased) && !after.next().is_some_and(is_cased)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test]
+    fn special_titlecase_is_sorted() {
+        assert!(SPECIAL_TITLECASE
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test_case(Locale::Neutral, "HELLO", "hello")]
+    #[test_case(Locale::Neutral, "ΣΑΣ ΣΕ", "σας σε"; "final sigma")]
+    #[test_case(Locale::Neutral, "Σ", "σ"; "lone sigma")]
+    #[test_case(Locale::Neutral, "ΑΣ.", "ας."; "sigma before period")]
+    #[test_case(Locale::Neutral, "ΑΣ'Α", "ασ'α"; "sigma before apostrophe")]
+    #[test_case(Locale::Neutral, "İ", "i\u{307}"; "dotted capital i")]
+    #[test_case(Locale::Turkish, "IİI\u{307}", "ıii"; "turkish i")]
+    fn lowercase(locale: Locale, text: &str, expected: &str) {
+        assert_eq!(locale.lowercase(text), expected);
+    }
+
+    #[test_case(Locale::Neutral, "straße", "STRASSE"; "sharp s")]
+    #[test_case(Locale::Neutral, "ŉ", "\u{2BC}N"; "n preceded by apostrophe")]
+    #[test_case(Locale::Neutral, "ᾳ", "ΑΙ"; "alpha with ypogegrammeni")]
+    #[test_case(Locale::Neutral, "ıi", "II"; "dotless i")]
+    #[test_case(Locale::Turkish, "ıi", "Iİ"; "turkish i")]
+    fn uppercase(locale: Locale, text: &str, expected: &str) {
+        assert_eq!(locale.uppercase(text), expected);
+    }
+
+    #[test_case(Locale::Neutral, "Hello", "hello")]
+    #[test_case(Locale::Neutral, "ßẞ", "ssss"; "sharp s")]
+    #[test_case(Locale::Neutral, "ﬁſ", "fis"; "ligature and long s")]
+    #[test_case(Locale::Neutral, "ıİ", "ıi\u{307}"; "dotless and dotted i")]
+    #[test_case(Locale::Turkish, "ıIiİ", "ııii"; "turkish i")]
+    fn fold(locale: Locale, text: &str, expected: &str) {
+        assert_eq!(locale.fold(text), expected);
+    }
+
+    #[test_case(Locale::Neutral, "", "")]
+    #[test_case(Locale::Neutral, "123abc", "123Abc")]
+    #[test_case(Locale::Neutral, "ǉubljana", "ǈubljana"; "digraph")]
+    #[test_case(Locale::Neutral, "ﬄuent", "Ffluent"; "ligature")]
+    #[test_case(Locale::Neutral, "ᾀδω", "ᾈδω"; "alpha with psili and ypogegrammeni")]
+    #[test_case(Locale::Neutral, "ᾳ", "ᾼ"; "alpha with ypogegrammeni")]
+    #[test_case(Locale::Neutral, "ΟΔΟΣ", "Οδος"; "final sigma")]
+    #[test_case(Locale::Neutral, "ცხრა", "ცხრა"; "georgian")]
+    #[test_case(Locale::Turkish, "ILIK", "Ilık"; "turkish i")]
+    fn titlecase(locale: Locale, word: &str, expected: &str) {
+        assert_eq!(locale.titlecase(word), expected);
+    }
+}
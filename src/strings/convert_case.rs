@@ -0,0 +1,285 @@
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+use super::{segmentation::extends_grapheme, Locale};
+
+/// Where one word of a text ends and the next begins.
+///
+/// Whitespace and punctuation separate words and belong to none, while
+/// case changes and digits split a run of letters into words.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::WordBoundaries;
+///
+/// let text = "parseHTTPRequest v2-beta";
+/// assert_eq!(WordBoundaries::WHITESPACE.words(text), ["parseHTTPRequest", "v2-beta"]);
+/// assert_eq!(
+///     WordBoundaries::IDENTIFIER.words(text),
+///     ["parse", "HTTP", "Request", "v2", "beta"]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct WordBoundaries {
+    /// Split at whitespace.
+    pub whitespace: bool,
+    /// Split at characters that are neither alphanumeric, whitespace nor
+    /// combining marks, such as `-`, `_` and `.`.
+    pub punctuation: bool,
+    /// Split before an upper case letter that follows a lower case letter or
+    /// a digit, and before the last of a run of upper case letters followed
+    /// by a lower case one, as in `parse|HTTP|Request`.
+    pub case_change: bool,
+    /// Split between letters and digits.
+    pub digits: bool,
+}
+
+impl WordBoundaries {
+    /// Words are separated by whitespace only, as in prose.
+    pub const WHITESPACE: Self = Self {
+        whitespace: true,
+        punctuation: false,
+        case_change: false,
+        digits: false,
+    };
+
+    /// Words are separated by whitespace and punctuation, and split where the
+    /// case changes, as in identifiers written in any case.
+    pub const IDENTIFIER: Self = Self {
+        whitespace: true,
+        punctuation: true,
+        case_change: true,
+        digits: false,
+    };
+
+    /// Splits a text into words.
+    #[must_use]
+    pub fn words(self, text: &str) -> Vec<&str> {
+        self.ranges(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    /// The byte ranges of the words of a text.
+    pub(super) fn ranges(self, text: &str) -> Vec<Range<usize>> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let mut ranges = Vec::new();
+        let mut start = None;
+        for (k, &(i, c)) in chars.iter().enumerate() {
+            if self.separates(c) {
+                if let Some(start) = start.take() {
+                    ranges.push(start..i);
+                }
+            } else if let Some(word) = start {
+                let next = chars.get(k + 1).map(|&(_, c)| c);
+                if self.splits(chars[k - 1].1, c, next) {
+                    ranges.push(word..i);
+                    start = Some(i);
+                }
+            } else {
+                start = Some(i);
+            }
+        }
+        if let Some(start) = start {
+            ranges.push(start..text.len());
+        }
+        ranges
+    }
+
+    /// Whether a character separates words.
+    fn separates(self, c: char) -> bool {
+        if c.is_whitespace() {
+            self.whitespace
+        } else {
+            self.punctuation && !c.is_alphanumeric() && !extends_grapheme(c)
+        }
+    }
+
+    /// Whether a word ends between `previous` and `c`, followed by `next`.
+    fn splits(self, previous: char, c: char, next: Option<char>) -> bool {
+        let digits = self.digits
+            && (previous.is_alphabetic() && c.is_numeric()
+                || previous.is_numeric() && c.is_alphabetic());
+        let case_change = self.case_change
+            && c.is_uppercase()
+            && (previous.is_lowercase()
+                || previous.is_numeric()
+                || previous.is_uppercase() && next.is_some_and(char::is_lowercase));
+        digits || case_change
+    }
+}
+
+/// A way of writing the words of an identifier together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+}
+
+/// A trait for converting text between the cases of identifiers.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::CaseConverter;
+///
+/// assert_eq!("XMLHttpRequest".to_snake_case(), "xml_http_request");
+/// assert_eq!("max_retry_count".to_camel_case(), "maxRetryCount");
+/// assert_eq!("Straße der Einheit".to_kebab_case(), "straße-der-einheit");
+/// ```
+pub trait CaseConverter {
+    /// Converts the words of the text to a case.
+    ///
+    /// # Arguments
+    ///
+    /// * `case` - The case to write the words in.
+    /// * `boundaries` - Where the words of the text end.
+    /// * `locale` - The rules to map the case of the words by.
+    ///
+    /// # Returns
+    ///
+    /// The words joined in the case, without whatever separated them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::{Case, CaseConverter, Locale, WordBoundaries};
+    ///
+    /// let boundaries = WordBoundaries::IDENTIFIER;
+    /// assert_eq!("iş yeri".to_case(Case::Pascal, boundaries, Locale::Neutral), "IşYeri");
+    /// assert_eq!("iş yeri".to_case(Case::ScreamingSnake, boundaries, Locale::Turkish), "İŞ_YERİ");
+    /// ```
+    #[must_use]
+    fn to_case(&self, case: Case, boundaries: WordBoundaries, locale: Locale) -> String;
+
+    /// Converts the text to `snake_case`.
+    #[must_use]
+    fn to_snake_case(&self) -> String {
+        self.to_case(Case::Snake, WordBoundaries::IDENTIFIER, Locale::Neutral)
+    }
+
+    /// Converts the text to `kebab-case`.
+    #[must_use]
+    fn to_kebab_case(&self) -> String {
+        self.to_case(Case::Kebab, WordBoundaries::IDENTIFIER, Locale::Neutral)
+    }
+
+    /// Converts the text to `camelCase`.
+    #[must_use]
+    fn to_camel_case(&self) -> String {
+        self.to_case(Case::Camel, WordBoundaries::IDENTIFIER, Locale::Neutral)
+    }
+
+    /// Converts the text to `PascalCase`.
+    #[must_use]
+    fn to_pascal_case(&self) -> String {
+        self.to_case(Case::Pascal, WordBoundaries::IDENTIFIER, Locale::Neutral)
+    }
+}
+
+impl CaseConverter for str {
+    fn to_case(&self, case: Case, boundaries: WordBoundaries, locale: Locale) -> String {
+        let mut converted = String::with_capacity(self.len());
+        for (n, word) in boundaries.words(self).into_iter().enumerate() {
+            match case {
+                Case::Snake | Case::ScreamingSnake if n > 0 => converted.push('_'),
+                Case::Kebab if n > 0 => converted.push('-'),
+                _ => {}
+            }
+            match case {
+                Case::Snake | Case::Kebab => {
+                    for (i, c) in word.char_indices() {
+                        locale.push_lower_char(word, i, c, &mut converted);
+                    }
+                }
+                Case::ScreamingSnake => {
+                    for c in word.chars() {
+                        locale.push_upper_char(c, &mut converted);
+                    }
+                }
+                Case::Camel if n == 0 => {
+                    for (i, c) in word.char_indices() {
+                        locale.push_lower_char(word, i, c, &mut converted);
+                    }
+                }
+                Case::Camel | Case::Pascal => locale.push_titlecase(word, &mut converted),
+            }
+        }
+        converted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(WordBoundaries::IDENTIFIER, "", &[])]
+    #[test_case(WordBoundaries::IDENTIFIER, "  __ ", &[])]
+    #[test_case(WordBoundaries::IDENTIFIER, "helloWorld", &["hello", "World"])]
+    #[test_case(WordBoundaries::IDENTIFIER, "IOError", &["IO", "Error"])]
+    #[test_case(WordBoundaries::IDENTIFIER, "utf8String", &["utf8", "String"])]
+    #[test_case(WordBoundaries::IDENTIFIER, "ÉcoleNormale", &["École", "Normale"]; "accented")]
+    #[test_case(WordBoundaries::IDENTIFIER, "cafe\u{301}_au_lait", &["cafe\u{301}", "au", "lait"])]
+    #[test_case(WordBoundaries { digits: true, ..WordBoundaries::IDENTIFIER }, "utf8String", &["utf", "8", "String"])]
+    #[test_case(WordBoundaries::WHITESPACE, " don't\tstop ", &["don't", "stop"])]
+    #[test_case(WordBoundaries::default(), "a b", &["a b"])]
+    fn words(boundaries: WordBoundaries, text: &str, expected: &[&str]) {
+        assert_eq!(boundaries.words(text), expected);
+    }
+
+    #[test_case(
+        "hello world",
+        "hello_world",
+        "hello-world",
+        "helloWorld",
+        "HelloWorld"
+    )]
+    #[test_case(
+        "HTTPServer2",
+        "http_server2",
+        "http-server2",
+        "httpServer2",
+        "HttpServer2"
+    )]
+    #[test_case("__init__", "init", "init", "init", "Init")]
+    #[test_case("ΟΔΟΣ ΣΤΟ", "οδος_στο", "οδος-στο", "οδοςΣτο", "ΟδοςΣτο"; "final sigma")]
+    #[test_case(
+        "straße_nummer",
+        "straße_nummer",
+        "straße-nummer",
+        "straßeNummer",
+        "StraßeNummer"
+    )]
+    fn conversions(text: &str, snake: &str, kebab: &str, camel: &str, pascal: &str) {
+        assert_eq!(text.to_snake_case(), snake);
+        assert_eq!(text.to_kebab_case(), kebab);
+        assert_eq!(text.to_camel_case(), camel);
+        assert_eq!(text.to_pascal_case(), pascal);
+    }
+
+    #[test]
+    fn screaming_snake() {
+        let boundaries = WordBoundaries::IDENTIFIER;
+        assert_eq!(
+            "maxSize".to_case(Case::ScreamingSnake, boundaries, Locale::Neutral),
+            "MAX_SIZE"
+        );
+        assert_eq!(
+            "straße".to_case(Case::ScreamingSnake, boundaries, Locale::Neutral),
+            "STRASSE"
+        );
+    }
+}
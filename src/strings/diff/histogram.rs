@@ -0,0 +1,206 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use super::{common_ends, from_matches, myers_matches, DiffOp};
+
+/// Items that occur more often than this in the old sequence are not lined up first.
+const MAX_OCCURRENCES: usize = 64;
+
+/// Finds a diff of two sequences with histogram diff, as in git.
+///
+/// The longest run of equal items around the item of the old sequence that
+/// occurs least often in it is lined up first, and the items either side of
+/// it are diffed the same way. Parts where no two items in a row that occur
+/// at most 64 times are common are diffed with [`myers`](super::myers),
+/// since lining up their common items one at a time would take quadratic
+/// time for little gain.
+///
+/// Like [`patience`](super::patience), this lines up distinctive items
+/// first, but it also makes use of items that are rare without being unique.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::diff::{histogram, DiffOp};
+///
+/// let old = ["}", "fn a() {", "}", "fn b() {", "}"];
+/// let new = ["}", "fn b() {", "}"];
+/// let ops = histogram(&old, &new);
+/// assert_eq!(ops[1], DiffOp::Delete { old: 1, new: 1, len: 2 });
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O((n + m) k) for each run lined up, where k is the number of occurrences
+/// of its rarest item, besides the diffs of the parts without any.
+///
+/// # References
+///
+/// - [git's `xhistogram.c`](https://github.com/git/git/blob/master/xdiff/xhistogram.c)
+#[must_use]
+pub fn histogram<T: Eq + Hash>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    let mut matches = Vec::new();
+    histogram_matches(old, new, (0, 0), &mut matches);
+    from_matches(old.len(), new.len(), &matches)
+}
+
+fn histogram_matches<T: Eq + Hash>(
+    mut old: &[T],
+    mut new: &[T],
+    mut offset: (usize, usize),
+    matches: &mut Vec<(usize, usize, usize)>,
+) {
+    // The part after each run is diffed in the next iteration, like git
+    // does, so that the stack does not grow with the number of runs.
+    let mut suffixes = Vec::new();
+    loop {
+        let (prefix, suffix) = common_ends(old, new);
+        if prefix > 0 {
+            matches.push((offset.0, offset.1, prefix));
+        }
+        (old, new) = (
+            &old[prefix..old.len() - suffix],
+            &new[prefix..new.len() - suffix],
+        );
+        offset = (offset.0 + prefix, offset.1 + prefix);
+        if suffix > 0 {
+            suffixes.push((offset.0 + old.len(), offset.1 + new.len(), suffix));
+        }
+        if old.is_empty() || new.is_empty() {
+            break;
+        }
+
+        let Some((i, j, len)) = rarest_run(old, new) else {
+            myers_matches(old, new, offset, matches);
+            break;
+        };
+        histogram_matches(&old[..i], &new[..j], offset, matches);
+        matches.push((offset.0 + i, offset.1 + j, len));
+        (old, new) = (&old[i + len..], &new[j + len..]);
+        offset = (offset.0 + i + len, offset.1 + j + len);
+    }
+    matches.extend(suffixes.into_iter().rev());
+}
+
+/// Finds the run of equal items whose rarest item occurs least often in
+/// `old`, preferring longer runs, as `(old, new, len)`.
+///
+/// # Returns
+///
+/// `None` unless some run is longer than one item.
+fn rarest_run<T: Eq + Hash>(old: &[T], new: &[T]) -> Option<(usize, usize, usize)> {
+    let mut positions: HashMap<&T, Vec<usize>> = HashMap::new();
+    for (i, item) in old.iter().enumerate() {
+        positions.entry(item).or_default().push(i);
+    }
+
+    // The best run so far, and the occurrences of its rarest item.
+    let mut best: Option<((usize, usize, usize), usize)> = None;
+    let mut longest = 0;
+    let mut j = 0;
+    while j < new.len() {
+        let mut next = j + 1;
+        let Some(starts) = positions
+            .get(&new[j])
+            .filter(|p| p.len() <= MAX_OCCURRENCES)
+        else {
+            j = next;
+            continue;
+        };
+        for &i in starts {
+            let before = old[..i]
+                .iter()
+                .rev()
+                .zip(new[..j].iter().rev())
+                .take_while(|(x, y)| x == y)
+                .count();
+            let after = old[i..]
+                .iter()
+                .zip(&new[j..])
+                .take_while(|(x, y)| x == y)
+                .count();
+            let run = (i - before, j - before, before + after);
+            let rarity = old[run.0..run.0 + run.2]
+                .iter()
+                .map(|item| positions[item].len())
+                .min()
+                .unwrap_or(usize::MAX);
+            longest = longest.max(run.2);
+            if best.is_none_or(|(best, occurrences)| {
+                rarity < occurrences || (rarity == occurrences && run.2 > best.2)
+            }) {
+                best = Some((run, rarity));
+            }
+            next = next.max(j + after);
+        }
+        j = next;
+    }
+    best.filter(|_| longest > 1).map(|(run, _)| run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{changes, check, myers};
+    use super::*;
+
+    #[test]
+    fn lines_up_rare_items() {
+        let old = ["{", "}", "x", "{", "}", "y", "{", "}"];
+        let new = ["{", "}", "y", "{", "}", "x", "{", "}"];
+        let ops = histogram(&old, &new);
+        check(&old, &new, &ops);
+        // The braces between `x` and `y` are unique once those at the ends are lined up.
+        assert_eq!(changes(&ops), 4);
+    }
+
+    #[test]
+    fn rarest_run_prefers_rare_items() {
+        assert_eq!(rarest_run(b"aaxbb", b"bbxaa"), Some((2, 2, 1)));
+        assert_eq!(rarest_run(b"ab", b"cd"), None);
+    }
+
+    #[test]
+    fn skips_common_items() {
+        // The rare items are lined up only where they line up two in a row.
+        let mut old = [b'a'; 101];
+        (old[99], old[100]) = (b'b', b'c');
+        let mut new = [b'a'; 101];
+        (new[0], new[1]) = (b'c', b'b');
+        assert_eq!(rarest_run(&old, &new), None);
+        check(&old, &new, &histogram(&old, &new));
+        (new[0], new[1]) = (b'b', b'c');
+        assert_eq!(rarest_run(&old, &new), Some((99, 0, 2)));
+        check(&old, &new, &histogram(&old, &new));
+    }
+
+    #[test]
+    fn single_items_fall_back_to_myers() {
+        // Every other item changed: lining them up one at a time would be
+        // quadratic, and nest a call for each.
+        let old: Vec<usize> = (0..2_000).collect();
+        let new: Vec<usize> = (0..2_000)
+            .map(|i| if i % 2 == 0 { i } else { i + 2_000 })
+            .collect();
+        assert_eq!(rarest_run(&old, &new), None);
+        let ops = histogram(&old, &new);
+        check(&old, &new, &ops);
+        assert_eq!(ops, myers(&old, &new));
+    }
+
+    #[test]
+    fn many_runs_in_a_row() {
+        // Runs of two common items between changes, which are lined up
+        // one after another without nesting calls.
+        let old: Vec<usize> = (0..3_000).collect();
+        let new: Vec<usize> = (0..3_000)
+            .map(|i| if i % 3 == 2 { i + 3_000 } else { i })
+            .collect();
+        let ops = histogram(&old, &new);
+        check(&old, &new, &ops);
+        assert_eq!(changes(&ops), 2_000);
+    }
+}
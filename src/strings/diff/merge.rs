@@ -0,0 +1,181 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use super::{diff, tokenize, Algorithm, DiffOp, Granularity};
+
+/// The result of a [`merge`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Merge {
+    /// The merged text, with conflict markers around the conflicting changes.
+    pub text: String,
+    /// The number of conflicts.
+    pub conflicts: usize,
+}
+
+impl Merge {
+    /// Whether the changes merged without conflicts.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.conflicts == 0
+    }
+}
+
+/// Merges the changes two texts made to a common base line by line, as
+/// `diff3 -m` and `git merge-file` do.
+///
+/// Changes to different lines of the base are both kept, as long as a line
+/// both left alone is between them, and so are changes both texts made
+/// alike. Where they changed the same lines differently, the merged text
+/// has both versions between conflict markers:
+///
+/// ```text
+/// <<<<<<< ours
+/// our lines
+/// =======
+/// their lines
+/// >>>>>>> theirs
+/// ```
+///
+/// # Arguments
+///
+/// * `base` - The text both changed.
+/// * `ours` - One changed version of it.
+/// * `theirs` - The other changed version.
+/// * `algorithm` - The algorithm to diff the base with each version.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::diff::{merge, Algorithm};
+///
+/// let base = "host = a\nuser = b\nport = 80\n";
+/// let ours = "host = a\nuser = b\nport = 8080\n";
+/// let theirs = "host = c\nuser = b\nport = 80\n";
+/// let merged = merge(base, ours, theirs, Algorithm::Myers);
+/// assert!(merged.is_clean());
+/// assert_eq!(merged.text, "host = c\nuser = b\nport = 8080\n");
+///
+/// let theirs = "host = a\nuser = b\nport = 443\n";
+/// let merged = merge(base, ours, theirs, Algorithm::Myers);
+/// assert_eq!(merged.conflicts, 1);
+/// assert_eq!(
+///     merged.text,
+///     "host = a\nuser = b\n<<<<<<< ours\nport = 8080\n=======\nport = 443\n>>>>>>> theirs\n"
+/// );
+/// ```
+///
+/// # References
+///
+/// - [Khanna, S., Kuber, K., & Pierce, B. C. (2007). A Formal Investigation of Diff3](https://www.cis.upenn.edu/~bcpierce/papers/diff3-short.pdf)
+#[must_use]
+pub fn merge(base: &str, ours: &str, theirs: &str, algorithm: Algorithm) -> Merge {
+    let base = tokenize(base, Granularity::Lines);
+    let ours = tokenize(ours, Granularity::Lines);
+    let theirs = tokenize(theirs, Granularity::Lines);
+    let (to_ours, to_theirs) = (
+        matched(&base, &ours, algorithm),
+        matched(&base, &theirs, algorithm),
+    );
+
+    let mut merged = Merge::default();
+    let (mut i, mut j, mut k) = (0, 0, 0);
+    loop {
+        // Lines unchanged in both, lined up in all three texts.
+        let stable = (i..base.len())
+            .take_while(|&at| to_ours[at] == Some(j + at - i) && to_theirs[at] == Some(k + at - i))
+            .count();
+        merged.text.extend(base[i..i + stable].iter().copied());
+        (i, j, k) = (i + stable, j + stable, k + stable);
+
+        // The changes up to the next line of the base that both kept.
+        let next = (i..base.len()).find(|&at| to_ours[at].is_some() && to_theirs[at].is_some());
+        let (next_i, next_j, next_k) = match next {
+            Some(at) => (at, to_ours[at].unwrap_or(j), to_theirs[at].unwrap_or(k)),
+            None => (base.len(), ours.len(), theirs.len()),
+        };
+        if (next_i, next_j, next_k) == (i, j, k) {
+            return merged;
+        }
+        let (original, mine, yours) = (&base[i..next_i], &ours[j..next_j], &theirs[k..next_k]);
+        if mine == original || mine == yours {
+            merged.text.extend(yours.iter().copied());
+        } else if yours == original {
+            merged.text.extend(mine.iter().copied());
+        } else {
+            merged.conflicts += 1;
+            merged.text.push_str("<<<<<<< ours\n");
+            push_lines(&mut merged.text, mine);
+            merged.text.push_str("=======\n");
+            push_lines(&mut merged.text, yours);
+            merged.text.push_str(">>>>>>> theirs\n");
+        }
+        (i, j, k) = (next_i, next_j, next_k);
+    }
+}
+
+/// The position in `new` of each line of `old` that a diff keeps.
+fn matched(old: &[&str], new: &[&str], algorithm: Algorithm) -> Vec<Option<usize>> {
+    let mut positions = vec![None; old.len()];
+    for op in diff(old, new, algorithm) {
+        if let DiffOp::Equal { old, new, len } = op {
+            for offset in 0..len {
+                positions[old + offset] = Some(new + offset);
+            }
+        }
+    }
+    positions
+}
+
+/// Adds lines to a text, ending the last one if it has no line ending.
+fn push_lines(text: &mut String, lines: &[&str]) {
+    text.extend(lines.iter().copied());
+    if lines.last().is_some_and(|line| !line.ends_with('\n')) {
+        text.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case("a\nb\nc\n", "a\nb\nc\n", "a\nb\nc\n", "a\nb\nc\n"; "unchanged")]
+    #[test_case("a\nb\nc\n", "a\nB\nc\n", "a\nb\nc\n", "a\nB\nc\n"; "ours only")]
+    #[test_case("a\nb\nc\n", "a\nb\nc\n", "a\nc\n", "a\nc\n"; "theirs deletes")]
+    #[test_case("a\nb\nc\n", "a\nB\nc\n", "a\nB\nc\n", "a\nB\nc\n"; "same change")]
+    #[test_case("a\nb\nc\n", "0\na\nb\nc\n", "a\nb\nc\nd\n", "0\na\nb\nc\nd\n"; "both ends")]
+    #[test_case("", "a\n", "", "a\n"; "empty base")]
+    fn clean_merges(base: &str, ours: &str, theirs: &str, expected: &str) {
+        for algorithm in [Algorithm::Myers, Algorithm::Patience, Algorithm::Histogram] {
+            let merged = merge(base, ours, theirs, algorithm);
+            assert_eq!(merged.text, expected);
+            assert!(merged.is_clean());
+            assert_eq!(merge(base, theirs, ours, algorithm).text, expected);
+        }
+    }
+
+    #[test]
+    fn conflicts() {
+        let merged = merge("a\nb", "a\nx", "a\ny", Algorithm::Myers);
+        assert_eq!(merged.conflicts, 1);
+        assert_eq!(
+            merged.text,
+            "a\n<<<<<<< ours\nx\n=======\ny\n>>>>>>> theirs\n"
+        );
+
+        let merged = merge(
+            "1\n2\n3\n4\n5\n",
+            "1\nA\n3\n4\nC\n",
+            "1\nB\n3\n4\nD\n",
+            Algorithm::Histogram,
+        );
+        assert_eq!(merged.conflicts, 2);
+
+        // A deletion conflicts with a change to the same line.
+        let merged = merge("a\nb\nc\n", "a\nc\n", "a\nB\nc\n", Algorithm::Myers);
+        assert_eq!(
+            merged.text,
+            "a\n<<<<<<< ours\n=======\nB\n>>>>>>> theirs\nc\n"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+//! Diffs of texts and other sequences, patches and three-way merges
+//!
+//! [`diff`] finds the runs of items kept, deleted and inserted to turn one
+//! sequence into another, with [`myers`], [`patience`] or [`histogram`]
+//! diff. [`TextDiff`] diffs texts by lines, words or characters and writes
+//! [`Patch`]es in the unified format, which can be parsed and applied to
+//! other texts, and [`merge`] merges the changes two texts made to a
+//! common base.
+
+mod histogram;
+mod merge;
+mod myers;
+mod ops;
+mod patch;
+mod patience;
+mod text;
+
+pub use histogram::*;
+pub use merge::*;
+pub use myers::*;
+pub use ops::*;
+pub use patch::*;
+pub use patience::*;
+pub use text::*;
@@ -0,0 +1,175 @@
+use alloc::{vec, vec::Vec};
+
+use super::{from_matches, with_common_ends, DiffOp};
+
+/// Finds a shortest diff of two sequences, one with the fewest deleted and
+/// inserted items, with Myers' algorithm.
+///
+/// The algorithm searches from both ends at once for a middle run of equal
+/// items that a shortest diff goes through, then diffs either side of it,
+/// so it needs only linear space.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::diff::{myers, DiffOp};
+///
+/// let ops = myers(b"ABCABBA", b"CBABAC");
+/// let changed: usize = ops
+///     .iter()
+///     .filter(|op| !matches!(op, DiffOp::Equal { .. }))
+///     .map(|op| op.old_range().len() + op.new_range().len())
+///     .sum();
+/// assert_eq!(changed, 5);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O((n + m)D), where D is the number of deleted and inserted items, in
+/// O(n + m) space.
+///
+/// # References
+///
+/// - [Myers, E. W. (1986). An O(ND) difference algorithm and its variations](https://doi.org/10.1007/BF01840446)
+#[must_use]
+pub fn myers<T: Eq>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    let mut matches = Vec::new();
+    myers_matches(old, new, (0, 0), &mut matches);
+    from_matches(old.len(), new.len(), &matches)
+}
+
+/// Adds the runs of equal items of a shortest diff of `old` and `new`,
+/// which start at `offset` in the whole sequences, to `matches`.
+pub(super) fn myers_matches<T: Eq>(
+    old: &[T],
+    new: &[T],
+    offset: (usize, usize),
+    matches: &mut Vec<(usize, usize, usize)>,
+) {
+    with_common_ends(old, new, offset, matches, |old, new, offset, matches| {
+        // The sequences differ at both ends, so a shortest diff has at least
+        // two changes, and either side of the middle fewer.
+        let (start, end) = middle_snake(old, new);
+        myers_matches(&old[..start.0], &new[..start.1], offset, matches);
+        if end.0 > start.0 {
+            matches.push((offset.0 + start.0, offset.1 + start.1, end.0 - start.0));
+        }
+        let after = (offset.0 + end.0, offset.1 + end.1);
+        myers_matches(&old[end.0..], &new[end.1..], after, matches);
+    });
+}
+
+/// Finds the start and end of a run of equal items, possibly empty, in the
+/// middle of a shortest diff.
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+fn middle_snake<T: Eq>(old: &[T], new: &[T]) -> ((usize, usize), (usize, usize)) {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let delta = n - m;
+    let max = (n + m + 1) / 2;
+    // `forward[k]` is the furthest position in `old` reached on diagonal
+    // `k`, where the position in `new` is that in `old` less `k`, and
+    // `backward[k]` the same from the ends of the sequences.
+    let size = 2 * max as usize + 3;
+    let (mut forward, mut backward) = (vec![0; size], vec![0; size]);
+    let at = |k: isize| (k + max + 1) as usize;
+
+    for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && forward[at(k - 1)] < forward[at(k + 1)]) {
+                forward[at(k + 1)]
+            } else {
+                forward[at(k - 1)] + 1
+            };
+            let start = (x, x - k);
+            while x < n && x - k < m && old[x as usize] == new[(x - k) as usize] {
+                x += 1;
+            }
+            forward[at(k)] = x;
+            let reverse = delta - k;
+            if delta % 2 != 0 && (1 - d..d).contains(&reverse) && x + backward[at(reverse)] >= n {
+                let start = (start.0 as usize, start.1 as usize);
+                return (start, (x as usize, (x - k) as usize));
+            }
+        }
+
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && backward[at(k - 1)] < backward[at(k + 1)]) {
+                backward[at(k + 1)]
+            } else {
+                backward[at(k - 1)] + 1
+            };
+            let end = (n - x, m - x + k);
+            while x < n && x - k < m && old[(n - x - 1) as usize] == new[(m - x + k - 1) as usize] {
+                x += 1;
+            }
+            backward[at(k)] = x;
+            let forward_k = delta - k;
+            if delta % 2 == 0 && (-d..=d).contains(&forward_k) && x + forward[at(forward_k)] >= n {
+                let start = ((n - x) as usize, (m - x + k) as usize);
+                return (start, (end.0 as usize, end.1 as usize));
+            }
+        }
+    }
+    unreachable!("a shortest diff has at most n + m changes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{changes, check, diff, Algorithm};
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use alloc::vec::Vec;
+
+    #[test]
+    fn shortest_on_random_sequences() {
+        let mut rng = StdRng::seed_from_u64(0x9e37_79b9);
+
+        for _ in 0..500 {
+            let old: Vec<u8> = (0..rng.gen_range(0..30))
+                .map(|_| b"abc"[rng.gen_range(0..3)])
+                .collect();
+            let new: Vec<u8> = (0..rng.gen_range(0..30))
+                .map(|_| b"abc"[rng.gen_range(0..3)])
+                .collect();
+            let ops = myers(&old, &new);
+            check(&old, &new, &ops);
+            let common = crate::strings::distance::lcs_length(&old, &new);
+            assert_eq!(changes(&ops), old.len() + new.len() - 2 * common);
+            for algorithm in [Algorithm::Patience, Algorithm::Histogram] {
+                check(&old, &new, &diff(&old, &new, algorithm));
+            }
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert!(myers::<u8>(b"", b"").is_empty());
+        assert_eq!(
+            myers(b"", b"ab"),
+            [DiffOp::Insert {
+                old: 0,
+                new: 0,
+                len: 2
+            }]
+        );
+        assert_eq!(
+            myers(b"ab", b""),
+            [DiffOp::Delete {
+                old: 0,
+                new: 0,
+                len: 2
+            }]
+        );
+        assert_eq!(
+            myers(b"ab", b"ab"),
+            [DiffOp::Equal {
+                old: 0,
+                new: 0,
+                len: 2
+            }]
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use alloc::vec::Vec;
+use core::{hash::Hash, ops::Range};
+
+use super::{histogram, myers, patience};
+
+/// A run of items of a diff of an old sequence and a new one.
+///
+/// Every run starts at position `old` in the old sequence and `new` in the
+/// new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffOp {
+    /// `len` items that are in both sequences.
+    Equal {
+        /// The position in the old sequence.
+        old: usize,
+        /// The position in the new sequence.
+        new: usize,
+        /// The number of items.
+        len: usize,
+    },
+    /// `len` items of the old sequence that are not in the new one.
+    Delete {
+        /// The position in the old sequence.
+        old: usize,
+        /// The position in the new sequence.
+        new: usize,
+        /// The number of items.
+        len: usize,
+    },
+    /// `len` items of the new sequence that are not in the old one.
+    Insert {
+        /// The position in the old sequence.
+        old: usize,
+        /// The position in the new sequence.
+        new: usize,
+        /// The number of items.
+        len: usize,
+    },
+}
+
+impl DiffOp {
+    /// The positions of the items of the old sequence in the run.
+    #[must_use]
+    pub fn old_range(&self) -> Range<usize> {
+        match *self {
+            Self::Equal { old, len, .. } | Self::Delete { old, len, .. } => old..old + len,
+            Self::Insert { old, .. } => old..old,
+        }
+    }
+
+    /// The positions of the items of the new sequence in the run.
+    #[must_use]
+    pub fn new_range(&self) -> Range<usize> {
+        match *self {
+            Self::Equal { new, len, .. } | Self::Insert { new, len, .. } => new..new + len,
+            Self::Delete { new, .. } => new..new,
+        }
+    }
+}
+
+/// An algorithm to [`diff`] with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    /// Myers' algorithm, which finds a shortest diff. See [`myers`].
+    #[default]
+    Myers,
+    /// Patience diff, which lines up items that are unique to both
+    /// sequences first. See [`patience`].
+    Patience,
+    /// Histogram diff, which lines up the rarest items first. See [`histogram`].
+    Histogram,
+}
+
+/// Finds the runs of items that were kept, deleted and inserted to turn
+/// `old` into `new`.
+///
+/// The runs are in order and cover both sequences. No two runs next to each
+/// other have the same kind, and deletions come before insertions.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::diff::{diff, Algorithm, DiffOp};
+///
+/// let ops = diff(b"abcd", b"acbd", Algorithm::Myers);
+/// assert_eq!(
+///     ops,
+///     [
+///         DiffOp::Equal { old: 0, new: 0, len: 1 },
+///         DiffOp::Delete { old: 1, new: 1, len: 1 },
+///         DiffOp::Equal { old: 2, new: 1, len: 1 },
+///         DiffOp::Insert { old: 3, new: 2, len: 1 },
+///         DiffOp::Equal { old: 3, new: 3, len: 1 },
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn diff<T: Eq + Hash>(old: &[T], new: &[T], algorithm: Algorithm) -> Vec<DiffOp> {
+    match algorithm {
+        Algorithm::Myers => myers(old, new),
+        Algorithm::Patience => patience(old, new),
+        Algorithm::Histogram => histogram(old, new),
+    }
+}
+
+/// Turns runs of equal items, as `(old, new, len)` in order, into the runs
+/// of a diff of sequences of `old_len` and `new_len` items.
+pub(super) fn from_matches(
+    old_len: usize,
+    new_len: usize,
+    matches: &[(usize, usize, usize)],
+) -> Vec<DiffOp> {
+    let mut ops: Vec<DiffOp> = Vec::new();
+    let (mut old, mut new) = (0, 0);
+    for &(next_old, next_new, len) in matches.iter().chain([(old_len, new_len, 0)].iter()) {
+        if next_old > old {
+            ops.push(DiffOp::Delete {
+                old,
+                new,
+                len: next_old - old,
+            });
+        }
+        if next_new > new {
+            ops.push(DiffOp::Insert {
+                old: next_old,
+                new,
+                len: next_new - new,
+            });
+        }
+        if len > 0 {
+            match ops.last_mut() {
+                Some(DiffOp::Equal { len: last, .. }) => *last += len,
+                _ => ops.push(DiffOp::Equal {
+                    old: next_old,
+                    new: next_new,
+                    len,
+                }),
+            }
+        }
+        (old, new) = (next_old + len, next_new + len);
+    }
+    ops
+}
+
+/// The lengths of the runs of equal items at the start and end of `old` and
+/// `new`, which do not overlap.
+pub(super) fn common_ends<T: Eq>(old: &[T], new: &[T]) -> (usize, usize) {
+    let prefix = old.iter().zip(new).take_while(|(x, y)| x == y).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    (prefix, suffix)
+}
+
+/// Adds the runs of equal items at the start and end of `old` and `new`,
+/// which start at `offset` in the whole sequences, to `matches`, and those
+/// `middle` finds in between if neither is empty there.
+pub(super) fn with_common_ends<T: Eq>(
+    old: &[T],
+    new: &[T],
+    offset: (usize, usize),
+    matches: &mut Vec<(usize, usize, usize)>,
+    middle: impl FnOnce(&[T], &[T], (usize, usize), &mut Vec<(usize, usize, usize)>),
+) {
+    let (prefix, suffix) = common_ends(old, new);
+    if prefix > 0 {
+        matches.push((offset.0, offset.1, prefix));
+    }
+    let (old, new) = (
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    );
+    let offset = (offset.0 + prefix, offset.1 + prefix);
+
+    if !old.is_empty() && !new.is_empty() {
+        middle(old, new, offset, matches);
+    }
+    if suffix > 0 {
+        matches.push((offset.0 + old.len(), offset.1 + new.len(), suffix));
+    }
+}
+
+/// A group of changes of a diff that are close together, with some of the
+/// equal items around them for context.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hunk {
+    /// The positions of the items of the old sequence in the hunk.
+    pub old: Range<usize>,
+    /// The positions of the items of the new sequence in the hunk.
+    pub new: Range<usize>,
+    /// The runs of the hunk, starting and ending with at most `context`
+    /// equal items.
+    pub ops: Vec<DiffOp>,
+}
+
+/// Groups the changes of a diff into hunks, with up to `context` equal
+/// items before and after every change.
+///
+/// Changes with at most `2 * context` equal items between them share a
+/// hunk, as in the output of `diff -U`.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::diff::{diff, hunks, Algorithm};
+///
+/// let (old, new) = (b"abcdefghij", b"abXdefghiY");
+/// let hunks = hunks(&diff(old, new, Algorithm::Myers), 1);
+/// assert_eq!(hunks.len(), 2);
+/// assert_eq!((hunks[0].old.clone(), hunks[0].new.clone()), (1..4, 1..4));
+/// assert_eq!((hunks[1].old.clone(), hunks[1].new.clone()), (8..10, 8..10));
+/// ```
+#[must_use]
+pub fn hunks(ops: &[DiffOp], context: usize) -> Vec<Hunk> {
+    let mut hunks = Vec::new();
+    let mut current: Vec<DiffOp> = Vec::new();
+    for (index, &op) in ops.iter().enumerate() {
+        let DiffOp::Equal { old, new, len } = op else {
+            if current.is_empty() {
+                let before = index.checked_sub(1).map(|i| ops[i]);
+                if let Some(DiffOp::Equal { old, new, len }) = before {
+                    let kept = len.min(context);
+                    if kept > 0 {
+                        current.push(DiffOp::Equal {
+                            old: old + len - kept,
+                            new: new + len - kept,
+                            len: kept,
+                        });
+                    }
+                }
+            }
+            current.push(op);
+            continue;
+        };
+        if current.is_empty() {
+            continue;
+        }
+        if index + 1 < ops.len() && len <= 2 * context {
+            current.push(op);
+            continue;
+        }
+        if context > 0 {
+            current.push(DiffOp::Equal {
+                old,
+                new,
+                len: len.min(context),
+            });
+        }
+        hunks.push(hunk(core::mem::take(&mut current)));
+    }
+    if !current.is_empty() {
+        hunks.push(hunk(current));
+    }
+    hunks
+}
+
+fn hunk(ops: Vec<DiffOp>) -> Hunk {
+    let (first, last) = (ops[0], ops[ops.len() - 1]);
+    Hunk {
+        old: first.old_range().start..last.old_range().end,
+        new: first.new_range().start..last.new_range().end,
+        ops,
+    }
+}
+
+/// The number of deleted and inserted items of a diff.
+#[cfg(test)]
+pub(super) fn changes(ops: &[DiffOp]) -> usize {
+    ops.iter()
+        .filter(|op| !matches!(op, DiffOp::Equal { .. }))
+        .map(|op| op.old_range().len() + op.new_range().len())
+        .sum()
+}
+
+/// Checks that a diff covers both sequences in order and pairs equal items.
+#[cfg(test)]
+pub(super) fn check<T: Eq + core::fmt::Debug>(old: &[T], new: &[T], ops: &[DiffOp]) {
+    let (mut at_old, mut at_new) = (0, 0);
+    for op in ops {
+        assert_eq!(
+            (op.old_range().start, op.new_range().start),
+            (at_old, at_new)
+        );
+        if let DiffOp::Equal { .. } = op {
+            assert_eq!(&old[op.old_range()], &new[op.new_range()]);
+        }
+        (at_old, at_new) = (op.old_range().end, op.new_range().end);
+    }
+    assert_eq!((at_old, at_new), (old.len(), new.len()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(&[], 3, 0; "empty")]
+    #[test_case(&[(0, 0, 5)], 5, 5; "equal")]
+    #[test_case(&[(0, 1, 2), (3, 3, 1)], 4, 4; "mixed")]
+    fn matches_become_ops(matches: &[(usize, usize, usize)], old_len: usize, new_len: usize) {
+        let ops = from_matches(old_len, new_len, matches);
+        let (mut old, mut new) = (0, 0);
+        for op in &ops {
+            assert_eq!((op.old_range().start, op.new_range().start), (old, new));
+            (old, new) = (op.old_range().end, op.new_range().end);
+        }
+        assert_eq!((old, new), (old_len, new_len));
+        assert!(ops
+            .windows(2)
+            .all(|pair| core::mem::discriminant(&pair[0]) != core::mem::discriminant(&pair[1])));
+    }
+
+    #[test]
+    fn hunks_share_close_changes() {
+        let ops = diff(b"abcdefghij", b"aXcdefghYj", Algorithm::Myers);
+        assert_eq!(hunks(&ops, 3).len(), 1);
+        assert_eq!(hunks(&ops, 2).len(), 2);
+        assert_eq!(hunks(&ops, 0)[0].ops.len(), 2);
+        assert!(hunks(&diff(b"abc", b"abc", Algorithm::Myers), 3).is_empty());
+    }
+}
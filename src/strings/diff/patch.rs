@@ -0,0 +1,322 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use super::{tokenize, Granularity};
+
+/// The marker of a line of a patch without a line ending.
+const NO_NEWLINE: &str = "\\ No newline at end of file";
+
+/// A line of a [`PatchHunk`], with its line ending if it has one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatchLine {
+    /// A line of both texts.
+    Context(String),
+    /// A line of the old text only.
+    Delete(String),
+    /// A line of the new text only.
+    Insert(String),
+}
+
+impl PatchLine {
+    /// The text of the line.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Context(text) | Self::Delete(text) | Self::Insert(text) => text,
+        }
+    }
+}
+
+/// A hunk of a [`Patch`]: lines to replace at a position of the old text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PatchHunk {
+    /// The position of the first line of the hunk in the old text, from zero.
+    pub old_start: usize,
+    /// The position of the first line of the hunk in the new text, from zero.
+    pub new_start: usize,
+    /// The lines of the hunk.
+    pub lines: Vec<PatchLine>,
+}
+
+impl PatchHunk {
+    /// The lines of the old text the hunk covers.
+    pub fn old_lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().filter_map(|line| match line {
+            PatchLine::Context(text) | PatchLine::Delete(text) => Some(text.as_str()),
+            PatchLine::Insert(_) => None,
+        })
+    }
+
+    /// The lines of the new text the hunk covers.
+    pub fn new_lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().filter_map(|line| match line {
+            PatchLine::Context(text) | PatchLine::Insert(text) => Some(text.as_str()),
+            PatchLine::Delete(_) => None,
+        })
+    }
+}
+
+/// A patch in the unified format of `diff -u`, which turns one text into another.
+///
+/// Patches are made by [`TextDiff::patch`](super::TextDiff::patch) or
+/// parsed, and written by their [`Display`](fmt::Display) implementation.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::diff::{Algorithm, Granularity, Patch, TextDiff};
+///
+/// let (old, new) = ("a\nb\nc\n", "a\nB\nc\nd\n");
+/// let text = TextDiff::new(old, new, Granularity::Lines, Algorithm::Myers).unified("old", "new", 3);
+/// let patch = Patch::parse(&text).unwrap();
+/// assert_eq!(patch.apply(old).as_deref(), Some(new));
+/// // Hunks are found where they moved to.
+/// assert_eq!(patch.apply("0\na\nb\nc\n").as_deref(), Some("0\na\nB\nc\nd\n"));
+/// assert_eq!(patch.apply("a\nx\nc\n"), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Patch {
+    /// The name of the old text.
+    pub old_name: String,
+    /// The name of the new text.
+    pub new_name: String,
+    /// The hunks, in order.
+    pub hunks: Vec<PatchHunk>,
+}
+
+impl Patch {
+    /// Parses a patch in the unified format.
+    ///
+    /// Lines before the `---` header, such as those of `diff --git`, are
+    /// skipped, and so are timestamps after the names.
+    ///
+    /// # Returns
+    ///
+    /// The patch, or `None` if the text has no header or a hunk is cut short
+    /// or has a malformed line.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut lines = text.split_inclusive('\n').peekable();
+        let old_name = lines.find_map(|line| line.strip_prefix("--- "))?;
+        let new_name = lines.next()?.strip_prefix("+++ ")?;
+        let name = |name: &str| {
+            String::from(
+                name.trim_end_matches(['\n', '\r'])
+                    .split('\t')
+                    .next()
+                    .unwrap_or(""),
+            )
+        };
+        let mut patch = Self {
+            old_name: name(old_name),
+            new_name: name(new_name),
+            hunks: Vec::new(),
+        };
+
+        while let Some(header) = lines.next() {
+            let Some(ranges) = header.strip_prefix("@@ -") else {
+                continue;
+            };
+            let (old, rest) = ranges.split_once(" +")?;
+            let (new, _) = rest.split_once(" @@")?;
+            let ((old_start, mut old_left), (new_start, mut new_left)) = (range(old)?, range(new)?);
+
+            let mut hunk = PatchHunk {
+                old_start,
+                new_start,
+                lines: Vec::new(),
+            };
+            loop {
+                if let Some(marker) = lines.next_if(|line| line.starts_with('\\')) {
+                    let last = hunk.lines.last_mut()?;
+                    if !marker.starts_with(NO_NEWLINE) {
+                        return None;
+                    }
+                    let (PatchLine::Context(text)
+                    | PatchLine::Delete(text)
+                    | PatchLine::Insert(text)) = last;
+                    let _ = text.pop();
+                    continue;
+                }
+                if old_left == 0 && new_left == 0 {
+                    break;
+                }
+                let line = lines.next()?;
+                // Some tools drop the space of empty context lines.
+                let (kind, text) = match line.chars().next() {
+                    Some(kind @ (' ' | '-' | '+')) => (kind, String::from(&line[1..])),
+                    Some('\n') => (' ', String::from(line)),
+                    _ => return None,
+                };
+                match kind {
+                    ' ' if old_left > 0 && new_left > 0 => {
+                        (old_left, new_left) = (old_left - 1, new_left - 1);
+                        hunk.lines.push(PatchLine::Context(text));
+                    }
+                    '-' if old_left > 0 => {
+                        old_left -= 1;
+                        hunk.lines.push(PatchLine::Delete(text));
+                    }
+                    '+' if new_left > 0 => {
+                        new_left -= 1;
+                        hunk.lines.push(PatchLine::Insert(text));
+                    }
+                    _ => return None,
+                }
+            }
+            patch.hunks.push(hunk);
+        }
+        Some(patch)
+    }
+
+    /// Applies the patch to `old`.
+    ///
+    /// Each hunk is applied where the lines it covers are in `old` nearest
+    /// to its position, after the previous hunk, so that the patch still
+    /// applies to texts whose other lines have changed.
+    ///
+    /// # Returns
+    ///
+    /// The patched text, or `None` if the lines of a hunk are not in `old`.
+    #[must_use]
+    pub fn apply(&self, old: &str) -> Option<String> {
+        let lines = tokenize(old, Granularity::Lines);
+        let mut text = String::with_capacity(old.len());
+        let mut done = 0;
+        for hunk in &self.hunks {
+            let expected: Vec<&str> = hunk.old_lines().collect();
+            let position = (done..=lines.len().checked_sub(expected.len())?)
+                .filter(|&at| lines[at..at + expected.len()] == expected[..])
+                .min_by_key(|&at| at.abs_diff(hunk.old_start))?;
+            text.extend(lines[done..position].iter().copied());
+            text.extend(hunk.new_lines());
+            done = position + expected.len();
+        }
+        text.extend(lines[done..].iter().copied());
+        Some(text)
+    }
+}
+
+/// Parses the range of a hunk header, `start,len` or `start`, into the
+/// position of its first line from zero and its number of lines.
+fn range(text: &str) -> Option<(usize, usize)> {
+    let (start, len) = match text.split_once(',') {
+        Some((start, len)) => (start.parse::<usize>().ok()?, len.parse().ok()?),
+        None => (text.parse().ok()?, 1),
+    };
+    // An empty range starts after the line it names.
+    Some((
+        if len == 0 {
+            start
+        } else {
+            start.checked_sub(1)?
+        },
+        len,
+    ))
+}
+
+/// Writes the range of a hunk header, the inverse of [`range`].
+fn write_range(f: &mut fmt::Formatter<'_>, start: usize, len: usize) -> fmt::Result {
+    match len {
+        0 => write!(f, "{start},0"),
+        1 => write!(f, "{}", start + 1),
+        _ => write!(f, "{},{len}", start + 1),
+    }
+}
+
+impl fmt::Display for Patch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.hunks.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "--- {}", self.old_name)?;
+        writeln!(f, "+++ {}", self.new_name)?;
+        for hunk in &self.hunks {
+            let (old, new) = (hunk.old_lines().count(), hunk.new_lines().count());
+            write!(f, "@@ -")?;
+            write_range(f, hunk.old_start, old)?;
+            write!(f, " +")?;
+            write_range(f, hunk.new_start, new)?;
+            writeln!(f, " @@")?;
+            for line in &hunk.lines {
+                let prefix = match line {
+                    PatchLine::Context(_) => ' ',
+                    PatchLine::Delete(_) => '-',
+                    PatchLine::Insert(_) => '+',
+                };
+                write!(f, "{prefix}{}", line.text())?;
+                if !line.text().ends_with('\n') {
+                    writeln!(f, "\n{NO_NEWLINE}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Algorithm, TextDiff};
+    use super::*;
+
+    use alloc::string::ToString;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn round_trips_random_texts() {
+        let mut rng = StdRng::seed_from_u64(0x1234_5678);
+        let text = |rng: &mut StdRng| {
+            let mut text = String::new();
+            for _ in 0..rng.gen_range(0..12) {
+                text.push_str(["a", "b", "c", "", "}"][rng.gen_range(0..5)]);
+                text.push('\n');
+            }
+            if rng.gen_ratio(1, 3) {
+                let _ = text.pop();
+            }
+            text
+        };
+
+        for _ in 0..300 {
+            let (old, new) = (text(&mut rng), text(&mut rng));
+            for algorithm in [Algorithm::Myers, Algorithm::Patience, Algorithm::Histogram] {
+                for context in [0, 1, 3] {
+                    let diff = TextDiff::new(&old, &new, Granularity::Lines, algorithm);
+                    let patch = diff.patch("old", "new", context);
+                    assert_eq!(patch.apply(&old).as_deref(), Some(new.as_str()));
+                    let unified = patch.to_string();
+                    if !diff.is_empty() {
+                        assert_eq!(Patch::parse(&unified).as_ref(), Some(&patch));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parses_git_patches() {
+        let text = "diff --git a/f b/f\nindex 1..2 100644\n--- a/f\t2024-01-01\n+++ b/f\n@@ -0,0 +1 @@\n+new\n";
+        let patch = Patch::parse(text).unwrap();
+        assert_eq!(
+            (patch.old_name.as_str(), patch.new_name.as_str()),
+            ("a/f", "b/f")
+        );
+        assert_eq!(patch.hunks[0].old_start, 0);
+        assert_eq!(patch.apply("").as_deref(), Some("new\n"));
+    }
+
+    #[test]
+    fn rejects_malformed_patches() {
+        assert_eq!(Patch::parse("no header"), None);
+        assert_eq!(Patch::parse("--- a\n+++ b\n@@ -1,2 +1 @@\n a\n"), None);
+        assert_eq!(Patch::parse("--- a\n+++ b\n@@ -1 +1 @@\n?a\n"), None);
+    }
+
+    #[test]
+    fn range_headers() {
+        assert_eq!(range("3,2"), Some((2, 2)));
+        assert_eq!(range("3"), Some((2, 1)));
+        assert_eq!(range("3,0"), Some((3, 0)));
+        assert_eq!(range("0"), None);
+    }
+}
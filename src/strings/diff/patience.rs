@@ -0,0 +1,148 @@
+use alloc::{vec, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use super::{from_matches, myers_matches, with_common_ends, DiffOp};
+
+/// Finds a diff of two sequences with patience diff.
+///
+/// Items that occur exactly once in each sequence are lined up first,
+/// keeping the longest run of them that is in the same order in both, and
+/// the gaps between them are diffed the same way. Gaps without such items
+/// are diffed with [`myers`](super::myers).
+///
+/// The diff is not always shortest, but lines up distinctive items such as
+/// function signatures rather than blank lines and braces, which tends to
+/// read better for source code.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::diff::{patience, DiffOp};
+///
+/// let old = ["fn a() {", "}", "", "fn b() {", "}"];
+/// let new = ["fn a() {", "}", "", "fn c() {", "}", "", "fn b() {", "}"];
+/// let ops = patience(&old, &new);
+/// assert_eq!(ops[1], DiffOp::Insert { old: 3, new: 3, len: 3 });
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O((n + m) log(n + m)) to line up unique items, besides the diffs of the
+/// gaps without any.
+///
+/// # References
+///
+/// - [Bram Cohen. Patience Diff Advantages](https://bramcohen.livejournal.com/73318.html)
+#[must_use]
+pub fn patience<T: Eq + Hash>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    let mut matches = Vec::new();
+    patience_matches(old, new, (0, 0), &mut matches);
+    from_matches(old.len(), new.len(), &matches)
+}
+
+fn patience_matches<T: Eq + Hash>(
+    old: &[T],
+    new: &[T],
+    offset: (usize, usize),
+    matches: &mut Vec<(usize, usize, usize)>,
+) {
+    with_common_ends(old, new, offset, matches, |old, new, offset, matches| {
+        let anchors = unique_anchors(old, new);
+        if anchors.is_empty() {
+            myers_matches(old, new, offset, matches);
+            return;
+        }
+
+        let (mut i, mut j) = (0, 0);
+        for (anchor_old, anchor_new) in anchors {
+            let at = (offset.0 + i, offset.1 + j);
+            patience_matches(&old[i..anchor_old], &new[j..anchor_new], at, matches);
+            matches.push((offset.0 + anchor_old, offset.1 + anchor_new, 1));
+            (i, j) = (anchor_old + 1, anchor_new + 1);
+        }
+        patience_matches(&old[i..], &new[j..], (offset.0 + i, offset.1 + j), matches);
+    });
+}
+
+/// The longest run of items unique to both sequences that is in the same
+/// order in both, as positions in `old` and `new`.
+fn unique_anchors<T: Eq + Hash>(old: &[T], new: &[T]) -> Vec<(usize, usize)> {
+    // The number of occurrences in each sequence, and the last position in `new`.
+    let mut counts: HashMap<&T, (usize, usize, usize)> = HashMap::new();
+    for item in old {
+        counts.entry(item).or_default().0 += 1;
+    }
+    for (j, item) in new.iter().enumerate() {
+        if let Some(count) = counts.get_mut(item) {
+            count.1 += 1;
+            count.2 = j;
+        }
+    }
+    let unique: Vec<(usize, usize)> = old
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| match counts[item] {
+            (1, 1, j) => Some((i, j)),
+            _ => None,
+        })
+        .collect();
+
+    // Patience sorting: `piles[p]` is the anchor that ends the best run of
+    // `p + 1` anchors seen so far, and `previous` links each run back.
+    let mut piles: Vec<usize> = Vec::new();
+    let mut previous = vec![None; unique.len()];
+    for (index, &(_, j)) in unique.iter().enumerate() {
+        let pile = piles.partition_point(|&top| unique[top].1 < j);
+        if pile > 0 {
+            previous[index] = Some(piles[pile - 1]);
+        }
+        if pile == piles.len() {
+            piles.push(index);
+        } else {
+            piles[pile] = index;
+        }
+    }
+
+    let mut anchors = Vec::with_capacity(piles.len());
+    let mut next = piles.last().copied();
+    while let Some(index) = next {
+        anchors.push(unique[index]);
+        next = previous[index];
+    }
+    anchors.reverse();
+    anchors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{changes, check, myers};
+    use super::*;
+
+    #[test]
+    fn lines_up_unique_lines() {
+        let old = ["a", "{", "x", "}", "b", "{", "y", "}"];
+        let new = ["b", "{", "y", "}", "a", "{", "x", "}"];
+        let ops = patience(&old, &new);
+        check(&old, &new, &ops);
+        assert_eq!(changes(&ops), changes(&myers(&old, &new)));
+    }
+
+    #[test]
+    fn anchors_are_increasing() {
+        let old = b"abcdefg";
+        let new = b"gfbdcae";
+        assert_eq!(unique_anchors(old, new), [(1, 2), (3, 3), (4, 6)]);
+    }
+
+    #[test]
+    fn without_unique_items() {
+        let (old, new) = (b"aabb", b"bbaa");
+        let ops = patience(old, new);
+        check(old, new, &ops);
+        assert_eq!(changes(&ops), 4);
+    }
+}
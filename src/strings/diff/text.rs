@@ -0,0 +1,217 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::{diff, hunks, Algorithm, DiffOp, Hunk, Patch, PatchHunk, PatchLine};
+
+/// The items to split text into to diff it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Granularity {
+    /// Lines, each with its line ending.
+    #[default]
+    Lines,
+    /// Runs of whitespace and runs of other characters.
+    Words,
+    /// Characters.
+    Chars,
+}
+
+/// Splits text into the items to diff it by, which join back into it.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::diff::{tokenize, Granularity};
+///
+/// assert_eq!(tokenize("a\nb", Granularity::Lines), ["a\n", "b"]);
+/// assert_eq!(tokenize("to be,  or", Granularity::Words), ["to", " ", "be,", "  ", "or"]);
+/// assert_eq!(tokenize("añb", Granularity::Chars), ["a", "ñ", "b"]);
+/// ```
+#[must_use]
+pub fn tokenize(text: &str, granularity: Granularity) -> Vec<&str> {
+    match granularity {
+        Granularity::Lines => text.split_inclusive('\n').collect(),
+        Granularity::Words => {
+            let mut words = Vec::new();
+            let mut start = 0;
+            let mut chars = text.char_indices().peekable();
+            while let Some((_, c)) = chars.next() {
+                if let Some(&(end, next)) = chars.peek() {
+                    if next.is_whitespace() != c.is_whitespace() {
+                        words.push(&text[start..end]);
+                        start = end;
+                    }
+                }
+            }
+            if start < text.len() {
+                words.push(&text[start..]);
+            }
+            words
+        }
+        Granularity::Chars => text
+            .char_indices()
+            .map(|(i, c)| &text[i..i + c.len_utf8()])
+            .collect(),
+    }
+}
+
+/// A diff of two texts.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::diff::{Algorithm, Granularity, TextDiff};
+///
+/// let old = "port = 80\nhost = a\nuser = b\n";
+/// let new = "port = 8080\nhost = a\nuser = b\n";
+/// let diff = TextDiff::new(old, new, Granularity::Lines, Algorithm::Myers);
+/// assert_eq!(
+///     diff.unified("a/app.conf", "b/app.conf", 1),
+///     "--- a/app.conf\n+++ b/app.conf\n@@ -1,2 +1,2 @@\n-port = 80\n+port = 8080\n host = a\n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextDiff<'a> {
+    old: Vec<&'a str>,
+    new: Vec<&'a str>,
+    ops: Vec<DiffOp>,
+}
+
+impl<'a> TextDiff<'a> {
+    /// Diffs `old` and `new`, split by `granularity`, with `algorithm`.
+    #[must_use]
+    pub fn new(old: &'a str, new: &'a str, granularity: Granularity, algorithm: Algorithm) -> Self {
+        let (old, new) = (tokenize(old, granularity), tokenize(new, granularity));
+        let ops = diff(&old, &new, algorithm);
+        Self { old, new, ops }
+    }
+
+    /// The items of the old text.
+    #[must_use]
+    pub fn old_tokens(&self) -> &[&'a str] {
+        &self.old
+    }
+
+    /// The items of the new text.
+    #[must_use]
+    pub fn new_tokens(&self) -> &[&'a str] {
+        &self.new
+    }
+
+    /// The runs of items of the diff. See [`diff`].
+    #[must_use]
+    pub fn ops(&self) -> &[DiffOp] {
+        &self.ops
+    }
+
+    /// Whether the texts are the same.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ops.iter().all(|op| matches!(op, DiffOp::Equal { .. }))
+    }
+
+    /// Groups the changes into hunks with `context` items around them. See [`hunks`].
+    #[must_use]
+    pub fn hunks(&self, context: usize) -> Vec<Hunk> {
+        hunks(&self.ops, context)
+    }
+
+    /// Makes a patch that turns the old text into the new one, with
+    /// `context` items around each change.
+    ///
+    /// The items are the lines of the patch, so it is only a usual patch
+    /// for diffs by [`Granularity::Lines`].
+    #[must_use]
+    pub fn patch(&self, old_name: &str, new_name: &str, context: usize) -> Patch {
+        let hunks = self
+            .hunks(context)
+            .into_iter()
+            .map(|hunk| PatchHunk {
+                old_start: hunk.old.start,
+                new_start: hunk.new.start,
+                lines: hunk
+                    .ops
+                    .iter()
+                    .flat_map(|&op| {
+                        let (tokens, line): (&[&str], fn(String) -> PatchLine) = match op {
+                            DiffOp::Equal { .. } => (&self.old[op.old_range()], PatchLine::Context),
+                            DiffOp::Delete { .. } => (&self.old[op.old_range()], PatchLine::Delete),
+                            DiffOp::Insert { .. } => (&self.new[op.new_range()], PatchLine::Insert),
+                        };
+                        tokens.iter().map(move |&token| line(String::from(token)))
+                    })
+                    .collect(),
+            })
+            .collect();
+        Patch {
+            old_name: String::from(old_name),
+            new_name: String::from(new_name),
+            hunks,
+        }
+    }
+
+    /// Writes the diff in the unified format of `diff -u`, with `context`
+    /// lines around each change, or nothing if the texts are the same.
+    #[must_use]
+    pub fn unified(&self, old_name: &str, new_name: &str, context: usize) -> String {
+        self.patch(old_name, new_name, context).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(Granularity::Lines)]
+    #[test_case(Granularity::Words)]
+    #[test_case(Granularity::Chars)]
+    fn tokens_join_into_text(granularity: Granularity) {
+        for text in [
+            "",
+            "a",
+            "\n\n",
+            "  leading and trailing  ",
+            "one\ntwo\r\nthree",
+            "日本 語\t",
+        ] {
+            assert_eq!(tokenize(text, granularity).concat(), text);
+        }
+    }
+
+    #[test]
+    fn word_diff() {
+        let diff = TextDiff::new(
+            "the quick fox",
+            "the slow fox",
+            Granularity::Words,
+            Algorithm::Myers,
+        );
+        let changed: Vec<&str> = diff
+            .ops()
+            .iter()
+            .filter(|op| matches!(op, DiffOp::Insert { .. }))
+            .flat_map(|op| &diff.new_tokens()[op.new_range()])
+            .copied()
+            .collect();
+        assert_eq!(changed, ["slow"]);
+    }
+
+    #[test]
+    fn missing_newline() {
+        let diff = TextDiff::new("a\nb", "a\nc\n", Granularity::Lines, Algorithm::Myers);
+        assert_eq!(
+            diff.unified("old", "new", 3),
+            "--- old\n+++ new\n@@ -1,2 +1,2 @@\n a\n-b\n\\ No newline at end of file\n+c\n"
+        );
+    }
+
+    #[test]
+    fn same_texts() {
+        let diff = TextDiff::new("a\nb\n", "a\nb\n", Granularity::Lines, Algorithm::Patience);
+        assert!(diff.is_empty());
+        assert_eq!(diff.unified("old", "new", 3), "");
+    }
+}
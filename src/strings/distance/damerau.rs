@@ -0,0 +1,287 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use super::{trace, EditCosts, EditScript, Table};
+
+/// Computes the optimal string alignment distance between two sequences:
+/// the fewest insertions, deletions, substitutions and swaps of adjacent
+/// items that turn one into the other, when no item is edited again after
+/// being swapped.
+///
+/// This restriction makes it cheaper to compute than
+/// [`damerau_levenshtein`], but it is not a metric: `"ca"` is three edits
+/// from `"abc"`, although swapping and then inserting takes two.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::osa_distance;
+///
+/// assert_eq!(osa_distance(b"abcd", b"acbd"), 1);
+/// assert_eq!(osa_distance(b"ca", b"abc"), 3);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(nm), in O(m) space.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance#Optimal_string_alignment_distance)
+#[must_use]
+pub fn osa_distance<T: Eq>(a: &[T], b: &[T]) -> usize {
+    osa_distance_with_costs(a, b, &EditCosts::UNIT)
+}
+
+/// Computes the optimal string alignment distance between two sequences
+/// with the given costs of each operation.
+#[must_use]
+pub fn osa_distance_with_costs<T: Eq>(a: &[T], b: &[T], costs: &EditCosts) -> usize {
+    // The rows two above, one above and of the current prefix of `a`.
+    let mut before: Vec<usize> = Vec::new();
+    let mut above: Vec<usize> = (0..=b.len()).map(|j| j * costs.insertion).collect();
+    let mut row = Vec::with_capacity(b.len() + 1);
+
+    for (i, x) in a.iter().enumerate() {
+        row.clear();
+        row.push((i + 1) * costs.deletion);
+        for (j, y) in b.iter().enumerate() {
+            let substitution = if x == y { 0 } else { costs.substitution };
+            let mut cell = (above[j] + substitution)
+                .min(above[j + 1] + costs.deletion)
+                .min(row[j] + costs.insertion);
+            if i > 0 && j > 0 && *x == b[j - 1] && a[i - 1] == *y {
+                cell = cell.min(before[j - 1] + costs.transposition);
+            }
+            row.push(cell);
+        }
+        core::mem::swap(&mut before, &mut above);
+        core::mem::swap(&mut above, &mut row);
+    }
+    above[b.len()]
+}
+
+/// Finds a cheapest way to turn one sequence into another with insertions,
+/// deletions, substitutions and swaps of adjacent items, when no item is
+/// edited again after being swapped.
+///
+/// # Returns
+///
+/// The optimal string alignment distance with the given costs and the edits that make it up.
+#[must_use]
+pub fn osa_script<T: Eq>(a: &[T], b: &[T], costs: &EditCosts) -> EditScript {
+    let mut table = Table::new(a.len(), b.len(), costs);
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            let substitution = if x == y { 0 } else { costs.substitution };
+            let mut cell = (table[(i, j)] + substitution)
+                .min(table[(i, j + 1)] + costs.deletion)
+                .min(table[(i + 1, j)] + costs.insertion);
+            if swapped(a, b, i + 1, j + 1) {
+                cell = cell.min(table[(i - 1, j - 1)] + costs.transposition);
+            }
+            table[(i + 1, j + 1)] = cell;
+        }
+    }
+    trace(&table, a, b, costs, |i, j| {
+        swapped(a, b, i, j).then_some((i - 1, j - 1))
+    })
+}
+
+/// Whether the last two items of `a[..i]` are the last two of `b[..j]` swapped.
+fn swapped<T: Eq>(a: &[T], b: &[T], i: usize, j: usize) -> bool {
+    i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1]
+}
+
+/// Computes the Damerau–Levenshtein distance between two sequences: the
+/// fewest insertions, deletions, substitutions and swaps of adjacent items
+/// that turn one into the other.
+///
+/// Unlike [`osa_distance`], items may be edited after being swapped, which
+/// makes this a metric.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::damerau_levenshtein;
+///
+/// assert_eq!(damerau_levenshtein(b"abcd", b"acbd"), 1);
+/// assert_eq!(damerau_levenshtein(b"ca", b"abc"), 2);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(nm), in O(nm) space.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance#Distance_with_adjacent_transpositions)
+/// - [Lowrance, R., & Wagner, R. A. (1975). An Extension of the String-to-String Correction Problem](https://doi.org/10.1145/321879.321880)
+#[must_use]
+pub fn damerau_levenshtein<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    damerau_levenshtein_with_costs(a, b, &EditCosts::UNIT)
+}
+
+/// Computes the Damerau–Levenshtein distance between two sequences with
+/// the given costs of each operation.
+///
+/// The distance is only guaranteed to be the cheapest when swapping two
+/// items costs at least half as much as deleting one and inserting another,
+/// as it does for the default costs.
+#[must_use]
+pub fn damerau_levenshtein_with_costs<T: Eq + Hash>(a: &[T], b: &[T], costs: &EditCosts) -> usize {
+    damerau_levenshtein_table(a, b, costs)[(a.len(), b.len())]
+}
+
+/// Finds a cheapest way to turn one sequence into another with insertions,
+/// deletions, substitutions and swaps of adjacent items.
+///
+/// # Returns
+///
+/// The Damerau–Levenshtein distance with the given costs and the edits
+/// that make it up. A swap of two items with other items deleted from
+/// between them in the first sequence and inserted between them in the
+/// second is a single [`Edit::Transpose`](super::Edit::Transpose).
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::{damerau_levenshtein_script, Edit, EditCosts};
+///
+/// let script = damerau_levenshtein_script(b"ca", b"abc", &EditCosts::UNIT);
+/// assert_eq!(script.distance, 2);
+/// assert_eq!(script.edits, [Edit::Transpose { a: 0..2, b: 0..3 }]);
+/// ```
+#[must_use]
+pub fn damerau_levenshtein_script<T: Eq + Hash>(a: &[T], b: &[T], costs: &EditCosts) -> EditScript {
+    let table = damerau_levenshtein_table(a, b, costs);
+    trace(&table, a, b, costs, |i, j| {
+        let k = a[..i - 1].iter().rposition(|x| *x == b[j - 1])?;
+        let l = b[..j - 1].iter().rposition(|y| *y == a[i - 1])?;
+        Some((k + 1, l + 1))
+    })
+}
+
+/// Fills in the table of the Lowrance–Wagner algorithm.
+fn damerau_levenshtein_table<T: Eq + Hash>(a: &[T], b: &[T], costs: &EditCosts) -> Table {
+    let mut table = Table::new(a.len(), b.len(), costs);
+    // The last prefix length of `a` that ends with each item.
+    let mut last_row: HashMap<&T, usize> = HashMap::new();
+
+    for i in 1..=a.len() {
+        // The last prefix length of `b` so far that ends with `a[i - 1]`.
+        let mut last_column = 0;
+        for j in 1..=b.len() {
+            let equal = a[i - 1] == b[j - 1];
+            let substitution = if equal { 0 } else { costs.substitution };
+            let mut cell = (table[(i - 1, j - 1)] + substitution)
+                .min(table[(i - 1, j)] + costs.deletion)
+                .min(table[(i, j - 1)] + costs.insertion);
+
+            // The other ends of a swap of `a[i - 1]` and `b[j - 1]`, with edits between them.
+            let (start_a, start_b) = (last_row.get(&b[j - 1]).copied().unwrap_or(0), last_column);
+            if start_a > 0 && start_b > 0 {
+                cell = cell.min(
+                    table[(start_a - 1, start_b - 1)]
+                        + (i - start_a - 1) * costs.deletion
+                        + costs.transposition
+                        + (j - start_b - 1) * costs.insertion,
+                );
+            }
+            if equal {
+                last_column = j;
+            }
+            table[(i, j)] = cell;
+        }
+        let _ = last_row.insert(&a[i - 1], i);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{check_script, levenshtein};
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    #[test_case("", "", 0, 0)]
+    #[test_case("ab", "ba", 1, 1)]
+    #[test_case("ca", "abc", 3, 2)]
+    #[test_case("abcdef", "abcfed", 2, 2)]
+    #[test_case("a cat", "an act", 2, 2)]
+    #[test_case("a cat", "a abct", 3, 2)]
+    #[test_case("specter", "spectre", 1, 1)]
+    fn test_transpositions(a: &str, b: &str, osa: usize, damerau: usize) {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        let costs = EditCosts::UNIT;
+        assert_eq!(osa_distance(a, b), osa);
+        assert_eq!(damerau_levenshtein(a, b), damerau);
+
+        let script = osa_script(a, b, &costs);
+        assert_eq!(script.distance, osa);
+        check_script(&script, a, b, &costs);
+        let script = damerau_levenshtein_script(a, b, &costs);
+        assert_eq!(script.distance, damerau);
+        check_script(&script, a, b, &costs);
+    }
+
+    #[test]
+    fn agrees_with_definitions() {
+        let mut rng = StdRng::seed_from_u64(0x0bad_5eed);
+        let weighted = EditCosts {
+            insertion: 3,
+            deletion: 2,
+            substitution: 4,
+            transposition: 3,
+        };
+
+        for _ in 0..300 {
+            let a: Vec<u8> = (0..rng.gen_range(0..10))
+                .map(|_| b"abc"[rng.gen_range(0..3)])
+                .collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..10))
+                .map(|_| b"abc"[rng.gen_range(0..3)])
+                .collect();
+            let (osa, damerau) = (osa_distance(&a, &b), damerau_levenshtein(&a, &b));
+            assert!(damerau <= osa && osa <= levenshtein(&a, &b));
+            assert_eq!(damerau, damerau_levenshtein(&b, &a));
+
+            for costs in [EditCosts::UNIT, weighted] {
+                let script = osa_script(&a, &b, &costs);
+                assert_eq!(script.distance, osa_distance_with_costs(&a, &b, &costs));
+                check_script(&script, &a, &b, &costs);
+
+                let script = damerau_levenshtein_script(&a, &b, &costs);
+                assert_eq!(
+                    script.distance,
+                    damerau_levenshtein_with_costs(&a, &b, &costs)
+                );
+                check_script(&script, &a, &b, &costs);
+            }
+        }
+    }
+
+    #[test]
+    fn satisfies_the_triangle_inequality() {
+        let words: [&[u8]; 6] = [b"ca", b"ac", b"abc", b"cab", b"bca", b"acb"];
+        for x in words {
+            for y in words {
+                for z in words {
+                    assert!(
+                        damerau_levenshtein(x, z)
+                            <= damerau_levenshtein(x, y) + damerau_levenshtein(y, z)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{Index, IndexMut, Range};
+
+use super::{
+    damerau_levenshtein, hamming, indel_distance, lcs_distance, levenshtein, osa_distance,
+};
+
+/// The cost of each operation of an edit distance.
+///
+/// The default costs are all one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EditCosts {
+    /// The cost of inserting an item.
+    pub insertion: usize,
+    /// The cost of deleting an item.
+    pub deletion: usize,
+    /// The cost of replacing an item by a different one.
+    pub substitution: usize,
+    /// The cost of swapping two items.
+    pub transposition: usize,
+}
+
+impl EditCosts {
+    /// Every operation costs one.
+    pub const UNIT: Self = Self {
+        insertion: 1,
+        deletion: 1,
+        substitution: 1,
+        transposition: 1,
+    };
+}
+
+impl Default for EditCosts {
+    fn default() -> Self {
+        Self::UNIT
+    }
+}
+
+/// One step of an [`EditScript`] turning a sequence `a` into a sequence `b`.
+///
+/// The positions are indices into `a` and `b`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Edit {
+    /// `a[a]` is kept as `b[b]`, which equals it.
+    Keep {
+        /// The position in `a`.
+        a: usize,
+        /// The position in `b`.
+        b: usize,
+    },
+    /// `a[a]` is replaced by `b[b]`.
+    Substitute {
+        /// The position in `a`.
+        a: usize,
+        /// The position in `b`.
+        b: usize,
+    },
+    /// `a[a]` is deleted.
+    Delete {
+        /// The position in `a`.
+        a: usize,
+    },
+    /// `b[b]` is inserted.
+    Insert {
+        /// The position in `b`.
+        b: usize,
+    },
+    /// The first and last items of `a[a]` swap places to become the last
+    /// and first items of `b[b]`, and the items between them are deleted
+    /// from `a` and inserted from `b`.
+    ///
+    /// Both ranges have length two unless the distance allows edits between
+    /// transposed items, as the true Damerau–Levenshtein distance does.
+    Transpose {
+        /// The positions in `a`.
+        a: Range<usize>,
+        /// The positions in `b`.
+        b: Range<usize>,
+    },
+}
+
+/// The edits of a cheapest way to turn one sequence into another.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct EditScript {
+    /// The total cost of the edits.
+    pub distance: usize,
+    /// The edits, in the order of the positions they cover.
+    pub edits: Vec<Edit>,
+}
+
+/// The distances between every prefix of one sequence and every prefix of another.
+#[derive(Debug, Clone)]
+pub(super) struct Table {
+    columns: usize,
+    cells: Vec<usize>,
+}
+
+impl Table {
+    /// A table for prefixes of sequences of lengths `n` and `m`, with the
+    /// costs of deleting every prefix of the first and inserting every
+    /// prefix of the second filled in.
+    pub(super) fn new(n: usize, m: usize, costs: &EditCosts) -> Self {
+        let mut table = Self {
+            columns: m + 1,
+            cells: vec![0; (n + 1) * (m + 1)],
+        };
+        for i in 0..=n {
+            table[(i, 0)] = i * costs.deletion;
+        }
+        for j in 0..=m {
+            table[(0, j)] = j * costs.insertion;
+        }
+        table
+    }
+}
+
+impl Index<(usize, usize)> for Table {
+    type Output = usize;
+
+    fn index(&self, (i, j): (usize, usize)) -> &usize {
+        &self.cells[i * self.columns + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Table {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut usize {
+        &mut self.cells[i * self.columns + j]
+    }
+}
+
+/// Follows a filled table back from its last cell to find the edits behind its distance.
+///
+/// # Arguments
+///
+/// * `transposition` - For a pair of prefix lengths `(i, j)`, the lengths
+///   `(k, l)` such that `a[k - 1]` and `b[l - 1]` are the other ends of a
+///   transposition ending at `a[i - 1]` and `b[j - 1]`, if there is one.
+pub(super) fn trace<T: Eq>(
+    table: &Table,
+    a: &[T],
+    b: &[T],
+    costs: &EditCosts,
+    transposition: impl Fn(usize, usize) -> Option<(usize, usize)>,
+) -> EditScript {
+    let (mut i, mut j) = (a.len(), b.len());
+    let mut edits = Vec::new();
+
+    while i > 0 || j > 0 {
+        let here = table[(i, j)];
+        if i > 0 && j > 0 {
+            let equal = a[i - 1] == b[j - 1];
+            let substitution = if equal { 0 } else { costs.substitution };
+            if here == table[(i - 1, j - 1)] + substitution {
+                i -= 1;
+                j -= 1;
+                edits.push(if equal {
+                    Edit::Keep { a: i, b: j }
+                } else {
+                    Edit::Substitute { a: i, b: j }
+                });
+                continue;
+            }
+
+            if let Some((k, l)) = transposition(i, j) {
+                let cost = table[(k - 1, l - 1)]
+                    + (i - k - 1) * costs.deletion
+                    + costs.transposition
+                    + (j - l - 1) * costs.insertion;
+                if here == cost {
+                    edits.push(Edit::Transpose {
+                        a: k - 1..i,
+                        b: l - 1..j,
+                    });
+                    (i, j) = (k - 1, l - 1);
+                    continue;
+                }
+            }
+        }
+
+        if i > 0 && here == table[(i - 1, j)] + costs.deletion {
+            i -= 1;
+            edits.push(Edit::Delete { a: i });
+        } else {
+            j -= 1;
+            edits.push(Edit::Insert { b: j });
+        }
+    }
+
+    edits.reverse();
+    EditScript {
+        distance: table[(a.len(), b.len())],
+        edits,
+    }
+}
+
+/// A trait for measuring edit distances between the characters of strings.
+///
+/// Each method counts edits of whole characters, so accented and other
+/// multibyte characters count once. Use the functions of this module with
+/// `str::as_bytes` to count edits of bytes instead.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::EditDistance;
+///
+/// assert_eq!("kitten".levenshtein("sitting"), 3);
+/// assert_eq!("straße".levenshtein("strasse"), 2);
+/// assert_eq!("ca".osa_distance("abc"), 3);
+/// assert_eq!("ca".damerau_levenshtein("abc"), 2);
+/// ```
+pub trait EditDistance {
+    /// The Levenshtein distance to `other`: the fewest insertions,
+    /// deletions and substitutions that turn one into the other.
+    fn levenshtein(&self, other: &str) -> usize;
+
+    /// The optimal string alignment distance to `other`: the Levenshtein
+    /// distance with swaps of adjacent characters, where no substring is
+    /// edited more than once.
+    fn osa_distance(&self, other: &str) -> usize;
+
+    /// The Damerau–Levenshtein distance to `other`: the Levenshtein distance
+    /// with swaps of adjacent characters.
+    fn damerau_levenshtein(&self, other: &str) -> usize;
+
+    /// The Hamming distance to `other`: the number of positions at which
+    /// the characters differ.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strings do not have the same number of characters.
+    fn hamming(&self, other: &str) -> usize;
+
+    /// The Indel distance to `other`: the fewest insertions and deletions
+    /// that turn one into the other.
+    fn indel_distance(&self, other: &str) -> usize;
+
+    /// The longest common subsequence distance to `other`: the number of
+    /// characters of the longer string outside their longest common subsequence.
+    fn lcs_distance(&self, other: &str) -> usize;
+}
+
+impl EditDistance for str {
+    fn levenshtein(&self, other: &str) -> usize {
+        levenshtein(&chars(self), &chars(other))
+    }
+
+    fn osa_distance(&self, other: &str) -> usize {
+        osa_distance(&chars(self), &chars(other))
+    }
+
+    fn damerau_levenshtein(&self, other: &str) -> usize {
+        damerau_levenshtein(&chars(self), &chars(other))
+    }
+
+    fn hamming(&self, other: &str) -> usize {
+        hamming(&chars(self), &chars(other))
+    }
+
+    fn indel_distance(&self, other: &str) -> usize {
+        indel_distance(&chars(self), &chars(other))
+    }
+
+    fn lcs_distance(&self, other: &str) -> usize {
+        lcs_distance(&chars(self), &chars(other))
+    }
+}
+
+fn chars(text: &str) -> Vec<char> {
+    text.chars().collect()
+}
+
+/// Checks that `script` turns `a` into `b` and costs its distance.
+#[cfg(test)]
+pub(super) fn check_script<T: Eq>(script: &EditScript, a: &[T], b: &[T], costs: &EditCosts) {
+    let (mut i, mut j, mut cost) = (0, 0, 0);
+    for edit in &script.edits {
+        match edit.clone() {
+            Edit::Keep { a: from, b: to } => {
+                assert_eq!((from, to), (i, j));
+                assert!(a[from] == b[to]);
+                (i, j) = (i + 1, j + 1);
+            }
+            Edit::Substitute { a: from, b: to } => {
+                assert_eq!((from, to), (i, j));
+                assert!(a[from] != b[to]);
+                (i, j, cost) = (i + 1, j + 1, cost + costs.substitution);
+            }
+            Edit::Delete { a: from } => {
+                assert_eq!(from, i);
+                (i, cost) = (i + 1, cost + costs.deletion);
+            }
+            Edit::Insert { b: to } => {
+                assert_eq!(to, j);
+                (j, cost) = (j + 1, cost + costs.insertion);
+            }
+            Edit::Transpose { a: from, b: to } => {
+                assert_eq!((from.start, to.start), (i, j));
+                assert!(from.len() >= 2 && to.len() >= 2);
+                assert!(a[from.start] == b[to.end - 1] && a[from.end - 1] == b[to.start]);
+                cost += costs.transposition
+                    + (from.len() - 2) * costs.deletion
+                    + (to.len() - 2) * costs.insertion;
+                (i, j) = (from.end, to.end);
+            }
+        }
+    }
+    assert_eq!((i, j), (a.len(), b.len()));
+    assert_eq!(cost, script.distance);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case("", "", [0, 0, 0, 0, 0])]
+    #[test_case("kitten", "sitting", [3, 3, 3, 5, 3])]
+    #[test_case("ca", "abc", [3, 3, 2, 3, 2])]
+    #[test_case("straße", "strasse", [2, 2, 2, 3, 2])]
+    #[test_case("\u{e6}\u{f8}\u{e5}", "\u{f8}\u{e6}\u{e5}", [2, 1, 1, 2, 1])]
+    fn measures_characters(a: &str, b: &str, expected: [usize; 5]) {
+        let actual = [
+            a.levenshtein(b),
+            a.osa_distance(b),
+            a.damerau_levenshtein(b),
+            a.indel_distance(b),
+            a.lcs_distance(b),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn hamming_counts_characters() {
+        assert_eq!("straße".hamming("strasz"), 2);
+        assert_eq!("karolin".hamming("kathrin"), 3);
+    }
+}
@@ -0,0 +1,85 @@
+use core::iter::zip;
+
+use super::{Edit, EditScript};
+
+/// Computes the Hamming distance between two sequences of the same length:
+/// the number of positions at which their items differ.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::hamming;
+///
+/// assert_eq!(hamming(b"karolin", b"kathrin"), 3);
+/// assert_eq!(hamming(&[1, 0, 1, 1], &[1, 0, 0, 1]), 1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the sequences do not have the same length.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Hamming_distance)
+#[must_use]
+pub fn hamming<T: Eq>(a: &[T], b: &[T]) -> usize {
+    assert_eq!(a.len(), b.len(), "sequences must have the same length");
+    zip(a, b).filter(|(x, y)| x != y).count()
+}
+
+/// Lists the substitutions that turn one sequence into another of the same length.
+///
+/// # Returns
+///
+/// The Hamming distance and an edit for every position, which keeps the
+/// item if both sequences agree there and substitutes it otherwise.
+///
+/// # Panics
+///
+/// Panics if the sequences do not have the same length.
+#[must_use]
+pub fn hamming_script<T: Eq>(a: &[T], b: &[T]) -> EditScript {
+    assert_eq!(a.len(), b.len(), "sequences must have the same length");
+    let edits: alloc::vec::Vec<Edit> = zip(a, b)
+        .enumerate()
+        .map(|(i, (x, y))| {
+            if x == y {
+                Edit::Keep { a: i, b: i }
+            } else {
+                Edit::Substitute { a: i, b: i }
+            }
+        })
+        .collect();
+    let distance = edits
+        .iter()
+        .filter(|edit| matches!(edit, Edit::Substitute { .. }))
+        .count();
+    EditScript { distance, edits }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{check_script, EditCosts};
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case("", "", 0)]
+    #[test_case("karolin", "kathrin", 3)]
+    #[test_case("1011101", "1001001", 2)]
+    #[test_case("2173896", "2233796", 3)]
+    fn test_hamming(a: &str, b: &str, expected: usize) {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        assert_eq!(hamming(a, b), expected);
+
+        let script = hamming_script(a, b);
+        assert_eq!(script.distance, expected);
+        check_script(&script, a, b, &EditCosts::UNIT);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn rejects_different_lengths() {
+        let _ = hamming(b"abc", b"ab");
+    }
+}
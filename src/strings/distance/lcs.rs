@@ -0,0 +1,177 @@
+use alloc::{vec, vec::Vec};
+
+use super::{Edit, EditScript};
+
+/// Finds a longest common subsequence of two sequences: the most items
+/// that occur in both in the same order, not necessarily next to each other.
+///
+/// # Returns
+///
+/// The positions of the items of the subsequence in `a` and in `b`, in order.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::longest_common_subsequence;
+///
+/// let pairs = longest_common_subsequence(b"ABCBDAB", b"BDCABA");
+/// assert_eq!(pairs.len(), 4);
+/// let common: Vec<u8> = pairs.iter().map(|&(i, _)| b"ABCBDAB"[i]).collect();
+/// assert_eq!(common, b"BDAB");
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(nm), in O(nm) space.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Longest_common_subsequence)
+#[must_use]
+pub fn longest_common_subsequence<T: Eq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    // `table[i][j]` is the length of a longest common subsequence of `a[i..]` and `b[j..]`.
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            table[i][j] = if a[i] == b[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut pairs = Vec::with_capacity(table[0][0]);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            pairs.push((i, j));
+            (i, j) = (i + 1, j + 1);
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+/// Computes the length of a longest common subsequence of two sequences.
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(nm), in O(m) space.
+#[must_use]
+pub fn lcs_length<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let mut row = vec![0; b.len() + 1];
+    for x in a {
+        let mut diagonal = 0;
+        for (j, y) in b.iter().enumerate() {
+            let cell = if x == y {
+                diagonal + 1
+            } else {
+                row[j + 1].max(row[j])
+            };
+            diagonal = row[j + 1];
+            row[j + 1] = cell;
+        }
+    }
+    row[b.len()]
+}
+
+/// Computes the longest common subsequence distance between two sequences:
+/// the number of items of the longer one that are not in a longest common
+/// subsequence of both.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::lcs_distance;
+///
+/// assert_eq!(lcs_distance(b"kitten", b"sitting"), 3);
+/// ```
+#[must_use]
+pub fn lcs_distance<T: Eq>(a: &[T], b: &[T]) -> usize {
+    a.len().max(b.len()) - lcs_length(a, b)
+}
+
+/// Computes the Indel distance between two sequences: the fewest
+/// insertions and deletions of single items that turn one into the other.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::indel_distance;
+///
+/// assert_eq!(indel_distance(b"kitten", b"sitting"), 5);
+/// ```
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Levenshtein_distance#Relationship_with_other_edit_distance_metrics)
+#[must_use]
+pub fn indel_distance<T: Eq>(a: &[T], b: &[T]) -> usize {
+    a.len() + b.len() - 2 * lcs_length(a, b)
+}
+
+/// Finds the fewest insertions and deletions that turn one sequence into another.
+///
+/// # Returns
+///
+/// The Indel distance and the edits that make it up, which keep a longest
+/// common subsequence and delete or insert everything else.
+#[must_use]
+pub fn indel_script<T: Eq>(a: &[T], b: &[T]) -> EditScript {
+    let pairs = longest_common_subsequence(a, b);
+    let distance = a.len() + b.len() - 2 * pairs.len();
+
+    let mut edits = Vec::with_capacity(distance + pairs.len());
+    let (mut i, mut j) = (0, 0);
+    let end = (a.len(), b.len());
+    for (x, y) in pairs.into_iter().chain([end]) {
+        edits.extend((i..x).map(|a| Edit::Delete { a }));
+        edits.extend((j..y).map(|b| Edit::Insert { b }));
+        if (x, y) != end {
+            edits.push(Edit::Keep { a: x, b: y });
+        }
+        (i, j) = (x + 1, y + 1);
+    }
+
+    EditScript { distance, edits }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{check_script, EditCosts};
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case("", "", 0)]
+    #[test_case("abc", "", 0)]
+    #[test_case("ABCBDAB", "BDCABA", 4)]
+    #[test_case("AGGTAB", "GXTXAYB", 4)]
+    #[test_case("kitten", "sitting", 4)]
+    fn test_longest_common_subsequence(a: &str, b: &str, expected: usize) {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        let pairs = longest_common_subsequence(a, b);
+        assert_eq!(pairs.len(), expected);
+        assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+        assert!(pairs.iter().all(|&(i, j)| a[i] == b[j]));
+        assert_eq!(lcs_length(a, b), expected);
+        assert_eq!(lcs_distance(a, b), a.len().max(b.len()) - expected);
+        assert_eq!(indel_distance(a, b), a.len() + b.len() - 2 * expected);
+
+        let script = indel_script(a, b);
+        assert_eq!(script.distance, indel_distance(a, b));
+        let costs = EditCosts {
+            substitution: usize::MAX,
+            ..EditCosts::UNIT
+        };
+        check_script(&script, a, b, &costs);
+    }
+}
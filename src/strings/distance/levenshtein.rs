@@ -0,0 +1,275 @@
+use alloc::{vec, vec::Vec};
+
+use super::{trace, EditCosts, EditScript, Table};
+
+/// Computes the Levenshtein distance between two sequences: the fewest
+/// insertions, deletions and substitutions of single items that turn one
+/// into the other.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::levenshtein;
+///
+/// assert_eq!(levenshtein(b"kitten", b"sitting"), 3);
+/// assert_eq!(levenshtein(&[1, 2, 3], &[2, 3, 4]), 2);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(nm), in O(m) space.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Levenshtein_distance)
+#[must_use]
+pub fn levenshtein<T: Eq>(a: &[T], b: &[T]) -> usize {
+    levenshtein_with_costs(a, b, &EditCosts::UNIT)
+}
+
+/// Computes the Levenshtein distance between two sequences with the given
+/// costs of insertion, deletion and substitution.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::{levenshtein_with_costs, EditCosts};
+///
+/// let costs = EditCosts {
+///     substitution: 3,
+///     ..EditCosts::UNIT
+/// };
+/// // Deleting and inserting is cheaper than substituting.
+/// assert_eq!(levenshtein_with_costs(b"abc", b"axc", &costs), 2);
+/// ```
+#[must_use]
+pub fn levenshtein_with_costs<T: Eq>(a: &[T], b: &[T], costs: &EditCosts) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).map(|j| j * costs.insertion).collect();
+    for (i, x) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = (i + 1) * costs.deletion;
+        for (j, y) in b.iter().enumerate() {
+            let substitution = diagonal + if x == y { 0 } else { costs.substitution };
+            diagonal = row[j + 1];
+            row[j + 1] = substitution
+                .min(row[j + 1] + costs.deletion)
+                .min(row[j] + costs.insertion);
+        }
+    }
+    row[b.len()]
+}
+
+/// Computes the Levenshtein distance between two sequences if it is at most `max`.
+///
+/// Only the cells of the table close enough to its diagonal to be reached
+/// within `max` are filled in, and the search stops as soon as every cell
+/// of a row costs more than `max`. This makes it much faster than
+/// [`levenshtein_with_costs`] for checking whether two long sequences are close.
+///
+/// # Returns
+///
+/// The distance, or `None` if it is more than `max`.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::{levenshtein_bounded, EditCosts};
+///
+/// let costs = EditCosts::UNIT;
+/// assert_eq!(levenshtein_bounded(b"kitten", b"sitting", &costs, 3), Some(3));
+/// assert_eq!(levenshtein_bounded(b"kitten", b"sitting", &costs, 2), None);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n · k) for `k = max / min(insertion, deletion)`, in O(m) space.
+#[must_use]
+pub fn levenshtein_bounded<T: Eq>(
+    a: &[T],
+    b: &[T],
+    costs: &EditCosts,
+    max: usize,
+) -> Option<usize> {
+    // A cell more than `band` off the diagonal takes more than `max` to reach or leave.
+    let band = match costs.insertion.min(costs.deletion) {
+        0 => a.len().max(b.len()),
+        gap => max / gap,
+    };
+    if a.len().abs_diff(b.len()) > band {
+        return None;
+    }
+
+    let mut row = vec![usize::MAX; b.len() + 1];
+    for (j, cell) in row.iter_mut().enumerate().take(band + 1) {
+        *cell = j * costs.insertion;
+    }
+
+    for i in 1..=a.len() {
+        let (low, high) = (i.saturating_sub(band), b.len().min(i + band));
+
+        // `diagonal` is the cell above and to the left, `left` the one to the left.
+        let (mut diagonal, mut left, start) = if low == 0 {
+            let diagonal = row[0];
+            row[0] = i * costs.deletion;
+            (diagonal, row[0], 1)
+        } else {
+            let diagonal = row[low - 1];
+            row[low - 1] = usize::MAX;
+            (diagonal, usize::MAX, low)
+        };
+        let mut best = left;
+
+        for j in start..=high {
+            let substitution = if a[i - 1] == b[j - 1] {
+                0
+            } else {
+                costs.substitution
+            };
+            let cell = diagonal
+                .saturating_add(substitution)
+                .min(row[j].saturating_add(costs.deletion))
+                .min(left.saturating_add(costs.insertion));
+            diagonal = row[j];
+            row[j] = cell;
+            left = cell;
+            best = best.min(cell);
+        }
+
+        if best > max {
+            return None;
+        }
+    }
+
+    Some(row[b.len()]).filter(|&distance| distance <= max)
+}
+
+/// Finds a cheapest way to turn one sequence into another with insertions,
+/// deletions and substitutions.
+///
+/// # Returns
+///
+/// The Levenshtein distance with the given costs and the edits that make
+/// it up. Among equally cheap edits, keeping or substituting an item is
+/// preferred to deleting it, which is preferred to inserting one.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::distance::{levenshtein_script, Edit, EditCosts};
+///
+/// let script = levenshtein_script(b"cat", b"cut", &EditCosts::UNIT);
+/// assert_eq!(script.distance, 1);
+/// assert_eq!(
+///     script.edits,
+///     [
+///         Edit::Keep { a: 0, b: 0 },
+///         Edit::Substitute { a: 1, b: 1 },
+///         Edit::Keep { a: 2, b: 2 },
+///     ]
+/// );
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(nm), in O(nm) space.
+#[must_use]
+pub fn levenshtein_script<T: Eq>(a: &[T], b: &[T], costs: &EditCosts) -> EditScript {
+    let mut table = Table::new(a.len(), b.len(), costs);
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            let substitution = if x == y { 0 } else { costs.substitution };
+            table[(i + 1, j + 1)] = (table[(i, j)] + substitution)
+                .min(table[(i, j + 1)] + costs.deletion)
+                .min(table[(i + 1, j)] + costs.insertion);
+        }
+    }
+    trace(&table, a, b, costs, |_, _| None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::check_script;
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    #[test_case("", "", 0)]
+    #[test_case("abc", "", 3)]
+    #[test_case("", "abc", 3)]
+    #[test_case("kitten", "sitting", 3)]
+    #[test_case("flaw", "lawn", 2)]
+    #[test_case("intention", "execution", 5)]
+    #[test_case("gumbo", "gambol", 2)]
+    fn test_levenshtein(a: &str, b: &str, expected: usize) {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        assert_eq!(levenshtein(a, b), expected);
+        assert_eq!(levenshtein(b, a), expected);
+
+        let script = levenshtein_script(a, b, &EditCosts::UNIT);
+        assert_eq!(script.distance, expected);
+        check_script(&script, a, b, &EditCosts::UNIT);
+    }
+
+    #[test]
+    fn bounded_agrees_with_full() {
+        let mut rng = StdRng::seed_from_u64(0x1234_5678);
+        let weighted = EditCosts {
+            insertion: 2,
+            deletion: 3,
+            substitution: 4,
+            transposition: 1,
+        };
+
+        for _ in 0..300 {
+            let a: Vec<u8> = (0..rng.gen_range(0..12))
+                .map(|_| b"abc"[rng.gen_range(0..3)])
+                .collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..12))
+                .map(|_| b"abc"[rng.gen_range(0..3)])
+                .collect();
+            for costs in [EditCosts::UNIT, weighted] {
+                let distance = levenshtein_with_costs(&a, &b, &costs);
+                for max in 0..distance + 3 {
+                    let expected = (distance <= max).then_some(distance);
+                    assert_eq!(levenshtein_bounded(&a, &b, &costs, max), expected);
+                }
+
+                let script = levenshtein_script(&a, &b, &costs);
+                assert_eq!(script.distance, distance);
+                check_script(&script, &a, &b, &costs);
+            }
+        }
+    }
+
+    #[test]
+    fn weighted_costs() {
+        let costs = EditCosts {
+            insertion: 1,
+            deletion: 1,
+            substitution: 3,
+            transposition: 1,
+        };
+        let script = levenshtein_script(b"abc", b"axc", &costs);
+        assert_eq!(script.distance, 2);
+        check_script(&script, b"abc", b"axc", &costs);
+        assert!(!script
+            .edits
+            .iter()
+            .any(|edit| matches!(edit, super::super::Edit::Substitute { .. })));
+    }
+
+    #[test]
+    fn compares_any_items() {
+        let a = ["the", "quick", "brown", "fox"];
+        let b = ["the", "slow", "brown", "dog", "barks"];
+        assert_eq!(levenshtein(&a, &b), 3);
+    }
+}
@@ -0,0 +1,19 @@
+//! Distances between strings and other sequences, measured by the edits
+//! that turn one into the other
+//!
+//! Every distance works over any slice of comparable items: pass
+//! `str::as_bytes` to compare bytes, or use the [`EditDistance`] trait to
+//! compare the characters of two strings. The `_script` functions also
+//! return the edits that make up the distance.
+
+mod damerau;
+mod edit;
+mod hamming;
+mod lcs;
+mod levenshtein;
+
+pub use damerau::*;
+pub use edit::*;
+pub use hamming::*;
+pub use lcs::*;
+pub use levenshtein::*;
@@ -0,0 +1,289 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+use hashbrown::HashMap;
+
+use crate::strings::tokenize::Tokenizer;
+
+/// The number of a document in an [`InvertedIndex`], in the order the
+/// documents were added.
+pub type DocId = u32;
+
+/// The documents that a term occurs in, with the positions it occurs at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct Postings {
+    /// The documents, in ascending order.
+    pub(super) docs: Vec<DocId>,
+    /// Where the positions in each document start in `positions`.
+    pub(super) offsets: Vec<u32>,
+    /// The positions of the term in each document, in ascending order.
+    pub(super) positions: Vec<u32>,
+}
+
+impl Postings {
+    /// The positions of the term in the `k`th of its documents.
+    pub(super) fn positions(&self, k: usize) -> &[u32] {
+        let start = self.offsets[k] as usize;
+        let end = self
+            .offsets
+            .get(k + 1)
+            .map_or(self.positions.len(), |&end| end as usize);
+        &self.positions[start..end]
+    }
+
+    /// The positions of the term in a document, empty if it does not occur there.
+    pub(super) fn positions_in(&self, doc: DocId) -> &[u32] {
+        self.docs
+            .binary_search(&doc)
+            .map_or(&[], |k| self.positions(k))
+    }
+}
+
+/// Where queries find the postings of terms and the lengths of documents:
+/// an [`InvertedIndex`], or an [`IndexView`](super::IndexView) that decodes its postings from
+/// bytes as queries need them.
+pub(super) trait PostingsSource {
+    /// The tokenizer that splits the texts of queries.
+    fn tokenizer(&self) -> &Tokenizer;
+
+    /// The number of documents.
+    fn documents(&self) -> usize;
+
+    /// The number of terms kept from a document.
+    fn length(&self, doc: DocId) -> u32;
+
+    /// The number of terms kept from all documents.
+    fn total_length(&self) -> u64;
+
+    /// The postings of a term, if it occurs in any document.
+    fn term_postings(&self, term: &str) -> Option<Cow<'_, Postings>>;
+}
+
+/// An index of the documents that each term occurs in, and where, to find
+/// the documents that match a [`Query`](super::Query) and rank them.
+///
+/// Documents are split into terms by a [`Tokenizer`], and so are the texts
+/// of queries, so that a query finds terms however the tokenizer normalized
+/// them. Positions count the tokens that the tokenizer leaves out, such as
+/// stop words, so phrases do not match across them, while the length of a
+/// document counts only the terms kept.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::{
+///     index::{InvertedIndex, Query, Scoring},
+///     tokenize::Tokenizer,
+/// };
+///
+/// let tokenizer = Tokenizer::builder()
+///     .case_folding(true)
+///     .strip_punctuation(true)
+///     .build();
+/// let mut index = InvertedIndex::new(tokenizer);
+/// let a = index.add("The quick brown fox.");
+/// let b = index.add("A quick brown dog, and a lazy fox!");
+/// let c = index.add("Brown bread.");
+///
+/// assert_eq!(index.search(&Query::phrase("quick brown")), [a, b]);
+/// assert_eq!(index.search(&Query::and([Query::term("fox"), Query::term("lazy")])), [b]);
+/// assert_eq!(index.search(&Query::not(Query::term("quick"))), [c]);
+///
+/// let ranked = index.top_k(&Query::or([Query::term("fox"), Query::term("brown")]), 2, Scoring::BM25);
+/// assert_eq!(ranked.iter().map(|&(doc, _)| doc).collect::<Vec<_>>(), [a, b]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// Adding a document of `n` tokens takes O(n) expected time.
+///
+/// ## Space Complexity
+///
+/// O(t + p) - for `t` distinct terms and `p` tokens in all documents.
+///
+/// # References
+///
+/// - [Manning, Raghavan and Schütze, Introduction to Information Retrieval](https://nlp.stanford.edu/IR-book/)
+#[derive(Debug, Clone)]
+pub struct InvertedIndex {
+    pub(super) tokenizer: Tokenizer,
+    /// The index in `postings` of each term.
+    pub(super) terms: HashMap<String, usize>,
+    pub(super) postings: Vec<Postings>,
+    /// The number of tokens in each document.
+    pub(super) lengths: Vec<u32>,
+}
+
+impl InvertedIndex {
+    /// Creates an empty index that splits documents and queries with a tokenizer.
+    #[must_use]
+    pub fn new(tokenizer: Tokenizer) -> Self {
+        Self {
+            tokenizer,
+            terms: HashMap::new(),
+            postings: Vec::new(),
+            lengths: Vec::new(),
+        }
+    }
+
+    /// Adds a document to the index.
+    ///
+    /// # Returns
+    ///
+    /// The id of the document, one more than that of the last one added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index already has `u32::MAX` documents, the document
+    /// has more than `u32::MAX` tokens, counting those the tokenizer leaves
+    /// out, or one of its terms occurs more than `u32::MAX` times in the
+    /// documents before it.
+    pub fn add(&mut self, document: &str) -> DocId {
+        let doc = DocId::try_from(self.lengths.len()).expect("too many documents");
+        let tokens = self.tokenizer.positioned_tokens(document);
+        let length = u32::try_from(tokens.len()).expect("too many tokens in the document");
+        for (position, token) in tokens {
+            let position = u32::try_from(position).expect("too many tokens in the document");
+            let term = if let Some(&term) = self.terms.get(token.as_ref()) {
+                term
+            } else {
+                let term = self.postings.len();
+                let _ = self.terms.insert(Cow::into_owned(token), term);
+                self.postings.push(Postings::default());
+                term
+            };
+            let postings = &mut self.postings[term];
+            if postings.docs.last() != Some(&doc) {
+                postings.docs.push(doc);
+                let offset = u32::try_from(postings.positions.len())
+                    .expect("too many occurrences of the term");
+                postings.offsets.push(offset);
+            }
+            postings.positions.push(position);
+        }
+        self.lengths.push(length);
+        doc
+    }
+
+    /// The tokenizer that splits documents and queries.
+    #[must_use]
+    pub fn tokenizer(&self) -> &Tokenizer {
+        &self.tokenizer
+    }
+
+    /// The number of documents.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lengths.len()
+    }
+
+    /// Whether the index has no documents.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lengths.is_empty()
+    }
+
+    /// The number of distinct terms in all documents.
+    #[must_use]
+    pub fn term_count(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// The number of terms kept from a document.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no document with the id.
+    #[must_use]
+    pub fn document_len(&self, doc: DocId) -> usize {
+        self.lengths[doc as usize] as usize
+    }
+
+    /// The documents that a term occurs in, in ascending order.
+    ///
+    /// The term must be as the tokenizer normalizes it.
+    #[must_use]
+    pub fn documents(&self, term: &str) -> &[DocId] {
+        self.postings(term).map_or(&[], |postings| &postings.docs)
+    }
+
+    /// The positions of a term in a document, counted in tokens from zero,
+    /// including the tokens left out.
+    ///
+    /// The term must be as the tokenizer normalizes it.
+    #[must_use]
+    pub fn positions(&self, term: &str, doc: DocId) -> &[u32] {
+        self.postings(term)
+            .map_or(&[], |postings| postings.positions_in(doc))
+    }
+
+    pub(super) fn postings(&self, term: &str) -> Option<&Postings> {
+        self.terms.get(term).map(|&term| &self.postings[term])
+    }
+}
+
+impl PostingsSource for InvertedIndex {
+    fn tokenizer(&self) -> &Tokenizer {
+        &self.tokenizer
+    }
+
+    fn documents(&self) -> usize {
+        self.lengths.len()
+    }
+
+    fn length(&self, doc: DocId) -> u32 {
+        self.lengths[doc as usize]
+    }
+
+    fn total_length(&self) -> u64 {
+        self.lengths.iter().map(|&len| u64::from(len)).sum()
+    }
+
+    fn term_postings(&self, term: &str) -> Option<Cow<'_, Postings>> {
+        self.postings(term).map(Cow::Borrowed)
+    }
+}
+
+impl Default for InvertedIndex {
+    fn default() -> Self {
+        Self::new(Tokenizer::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn postings() {
+        let mut index = InvertedIndex::default();
+        assert!(index.is_empty());
+        assert_eq!(index.add("a b a"), 0);
+        assert_eq!(index.add(""), 1);
+        assert_eq!(index.add("b c b b"), 2);
+
+        assert_eq!((index.len(), index.term_count()), (3, 3));
+        assert_eq!(index.documents("a"), [0]);
+        assert_eq!(index.documents("b"), [0, 2]);
+        assert!(index.documents("d").is_empty());
+        assert_eq!(index.positions("a", 0), [0, 2]);
+        assert_eq!(index.positions("b", 2), [0, 2, 3]);
+        assert!(index.positions("b", 1).is_empty());
+        assert_eq!(index.document_len(1), 0);
+        assert_eq!(index.document_len(2), 4);
+    }
+
+    #[test]
+    fn positions_count_the_tokens_left_out() {
+        let tokenizer = Tokenizer::builder()
+            .stop_words(crate::strings::tokenize::ENGLISH_STOP_WORDS)
+            .min_len(2)
+            .build();
+        let mut index = InvertedIndex::new(tokenizer);
+        let _ = index.add("quick x and the fox");
+        assert_eq!(index.positions("quick", 0), [0]);
+        assert_eq!(index.positions("fox", 0), [4]);
+        assert_eq!(index.document_len(0), 2);
+    }
+}
@@ -0,0 +1,19 @@
+//! Full-text search over a collection of documents
+//!
+//! An [`InvertedIndex`] maps each term to the documents it occurs in and
+//! its positions there. It finds the documents that match boolean and
+//! phrase [`Query`]s, ranks them by TF-IDF or BM25 [`Scoring`], and
+//! serializes to a compact binary format, which
+//! [`InvertedIndex::from_bytes`] decodes back into an index and an
+//! [`IndexView`] queries in place.
+
+mod inverted_index;
+mod persist;
+mod query;
+mod scoring;
+mod view;
+
+pub use inverted_index::{DocId, InvertedIndex};
+pub use query::*;
+pub use scoring::*;
+pub use view::IndexView;
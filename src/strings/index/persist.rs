@@ -0,0 +1,329 @@
+use alloc::{string::String, vec::Vec};
+
+use hashbrown::HashMap;
+
+use super::{
+    inverted_index::{Postings, PostingsSource},
+    DocId, IndexView, InvertedIndex,
+};
+use crate::strings::tokenize::Tokenizer;
+
+/// The bytes that every serialized index starts with.
+pub(super) const MAGIC: &[u8; 4] = b"AIDX";
+
+/// The version of the format, after [`MAGIC`].
+pub(super) const VERSION: u8 = 2;
+
+/// Appends an integer in LEB128: seven bits per byte, least significant
+/// first, with the high bit set on all bytes but the last.
+fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads the parts of a serialized index, failing at malformed input
+/// rather than panicking.
+pub(super) struct Reader<'a> {
+    pub(super) bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.bytes.len() {
+            return None;
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Some(taken)
+    }
+
+    fn varint(&mut self) -> Option<u64> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let (&byte, rest) = self.bytes.split_first()?;
+            self.bytes = rest;
+            let bits = u64::from(byte & 0x7F);
+            if bits << shift >> shift != bits {
+                return None;
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.varint().and_then(|value| u32::try_from(value).ok())
+    }
+
+    /// Reads a count of items, each at least one byte long.
+    fn count(&mut self) -> Option<usize> {
+        self.varint()
+            .and_then(|count| usize::try_from(count).ok())
+            .filter(|&count| count <= self.bytes.len())
+    }
+}
+
+impl InvertedIndex {
+    /// Serializes the index into a compact binary format.
+    ///
+    /// The format starts with the lengths of the documents and a directory
+    /// of the terms in sorted order, with the offsets of each term and of its
+    /// postings, so that an [`IndexView`] can query it in place. The ids of
+    /// documents and the positions in them are stored as variable-length
+    /// differences from the previous ones, so frequent terms take about a
+    /// byte per occurrence. The tokenizer is not stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::{
+    ///     index::{InvertedIndex, Query},
+    ///     tokenize::Tokenizer,
+    /// };
+    ///
+    /// let mut index = InvertedIndex::new(Tokenizer::new());
+    /// let _ = index.add("hello world");
+    /// let _ = index.add("hello again");
+    ///
+    /// let bytes = index.to_bytes();
+    /// let loaded = InvertedIndex::from_bytes(&bytes, Tokenizer::new()).unwrap();
+    /// assert_eq!(loaded.search(&Query::term("hello")), [0, 1]);
+    /// assert!(InvertedIndex::from_bytes(&bytes[1..], Tokenizer::new()).is_none());
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut terms: Vec<(&String, &usize)> = self.terms.iter().collect();
+        terms.sort_unstable();
+
+        let mut postings = Vec::new();
+        let mut postings_offsets = Vec::with_capacity(terms.len() + 1);
+        postings_offsets.push(0);
+        for &(_, &k) in &terms {
+            write_postings(&self.postings[k], &mut postings);
+            postings_offsets.push(postings.len() as u64);
+        }
+
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        out.extend_from_slice(&(self.lengths.len() as u64).to_le_bytes());
+        out.extend_from_slice(&(terms.len() as u64).to_le_bytes());
+        for &length in &self.lengths {
+            out.extend_from_slice(&length.to_le_bytes());
+        }
+        let mut term_offset = 0_u64;
+        out.extend_from_slice(&term_offset.to_le_bytes());
+        for (term, _) in &terms {
+            term_offset += term.len() as u64;
+            out.extend_from_slice(&term_offset.to_le_bytes());
+        }
+        for offset in postings_offsets {
+            out.extend_from_slice(&offset.to_le_bytes());
+        }
+        for (term, _) in &terms {
+            out.extend_from_slice(term.as_bytes());
+        }
+        out.extend_from_slice(&postings);
+        out
+    }
+
+    /// Loads an index serialized by [`InvertedIndex::to_bytes`].
+    ///
+    /// Loading decodes the whole index, so the loaded index takes as much
+    /// memory as one built by adding the documents. To query the bytes in
+    /// place instead, open an [`IndexView`] on them.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The serialized index.
+    /// * `tokenizer` - The tokenizer to split queries with, which should be
+    ///   configured as the one that built the index.
+    ///
+    /// # Returns
+    ///
+    /// The index, or `None` if the bytes are not a valid serialized index.
+    ///
+    /// # Performance
+    ///
+    /// ## Time Complexity
+    ///
+    /// O(n) expected, for `n` bytes.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8], tokenizer: Tokenizer) -> Option<Self> {
+        let view = IndexView::from_bytes(bytes, tokenizer)?;
+        let lengths = (0..view.len())
+            .map(|doc| view.length(doc as DocId))
+            .collect();
+        let mut terms = HashMap::with_capacity(view.term_count());
+        let mut postings = Vec::with_capacity(view.term_count());
+        for k in 0..view.term_count() {
+            let _ = terms.insert(String::from(view.term(k)), k);
+            postings.push(decode_postings(view.block(k), view.len())?);
+        }
+        Some(Self {
+            tokenizer: view.tokenizer,
+            terms,
+            postings,
+            lengths,
+        })
+    }
+}
+
+/// Appends the postings of a term: the number of documents, then the gap
+/// from the previous document, the number of positions and the gaps
+/// between them for each document.
+fn write_postings(postings: &Postings, out: &mut Vec<u8>) {
+    write_varint(postings.docs.len() as u64, out);
+    let mut previous_doc = 0;
+    for (k, &doc) in postings.docs.iter().enumerate() {
+        write_varint(u64::from(doc - previous_doc), out);
+        previous_doc = doc;
+
+        let positions = postings.positions(k);
+        write_varint(positions.len() as u64, out);
+        let mut previous = 0;
+        for &position in positions {
+            write_varint(u64::from(position - previous), out);
+            previous = position;
+        }
+    }
+}
+
+/// Decodes the postings of a term written by [`write_postings`], in an
+/// index of `documents` documents.
+///
+/// # Returns
+///
+/// The postings, or `None` if the block is malformed.
+pub(super) fn decode_postings(block: &[u8], documents: usize) -> Option<Postings> {
+    let mut reader = Reader { bytes: block };
+    let df = reader.count()?;
+    let mut postings = Postings {
+        docs: Vec::with_capacity(df),
+        offsets: Vec::with_capacity(df),
+        positions: Vec::new(),
+    };
+    let mut doc: Option<DocId> = None;
+    for _ in 0..df {
+        let gap = reader.u32()?;
+        let next = match doc {
+            None => gap,
+            Some(doc) if gap > 0 => doc.checked_add(gap)?,
+            Some(_) => return None,
+        };
+        if next as usize >= documents {
+            return None;
+        }
+        doc = Some(next);
+        postings.docs.push(next);
+        postings
+            .offsets
+            .push(u32::try_from(postings.positions.len()).ok()?);
+
+        let tf = reader.count()?;
+        if tf == 0 {
+            return None;
+        }
+        let mut position: Option<u32> = None;
+        for _ in 0..tf {
+            let gap = reader.u32()?;
+            let next = match position {
+                None => gap,
+                Some(position) if gap > 0 => position.checked_add(gap)?,
+                Some(_) => return None,
+            };
+            position = Some(next);
+            postings.positions.push(next);
+        }
+    }
+    reader.bytes.is_empty().then_some(postings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::strings::index::{Query, Scoring};
+
+    fn index() -> InvertedIndex {
+        let mut index = InvertedIndex::default();
+        for document in [
+            "a rose is a rose is a rose",
+            "",
+            "is it a rose",
+            "roses are red",
+        ] {
+            let _ = index.add(document);
+        }
+        index
+    }
+
+    #[test]
+    fn round_trip() {
+        let index = index();
+        let loaded = InvertedIndex::from_bytes(&index.to_bytes(), Tokenizer::new()).unwrap();
+        assert_eq!(loaded.lengths, index.lengths);
+        assert_eq!(loaded.term_count(), index.term_count());
+        for term in index.terms.keys() {
+            assert_eq!(loaded.postings(term), index.postings(term));
+        }
+        let query = Query::or([Query::phrase("a rose"), Query::term("red")]);
+        assert_eq!(
+            loaded.top_k(&query, 10, Scoring::BM25),
+            index.top_k(&query, 10, Scoring::BM25)
+        );
+        assert_eq!(loaded.to_bytes(), index.to_bytes());
+    }
+
+    #[test]
+    fn empty() {
+        let index = InvertedIndex::default();
+        let bytes = index.to_bytes();
+        // No documents, no terms, and the first offsets of the empty term
+        // and postings tables.
+        assert_eq!(bytes, [b"AIDX\x02".as_slice(), &[0; 32]].concat());
+        assert!(InvertedIndex::from_bytes(&bytes, Tokenizer::new())
+            .is_some_and(|index| index.is_empty()));
+    }
+
+    #[test]
+    fn varints() {
+        for value in [0, 1, 127, 128, 300, u64::from(u32::MAX), u64::MAX] {
+            let mut bytes = Vec::new();
+            write_varint(value, &mut bytes);
+            let mut reader = Reader { bytes: &bytes };
+            assert_eq!(reader.varint(), Some(value));
+            assert!(reader.bytes.is_empty());
+        }
+        let mut reader = Reader { bytes: &[0xFF; 10] };
+        assert_eq!(reader.varint(), None);
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let bytes = index().to_bytes();
+        // Every truncation, and every change of a single byte, either fails
+        // to load or loads without panicking.
+        for len in 0..bytes.len() {
+            assert!(InvertedIndex::from_bytes(&bytes[..len], Tokenizer::new()).is_none());
+        }
+        for i in 0..bytes.len() {
+            for byte in [0x00, 0x01, 0x7F, 0x80, 0xFF] {
+                let mut corrupted = bytes.clone();
+                corrupted[i] = byte;
+                if let Some(index) = InvertedIndex::from_bytes(&corrupted, Tokenizer::new()) {
+                    let _ = index.top_k(&Query::phrase("a rose is"), 10, Scoring::BM25);
+                }
+            }
+        }
+        let mut longer = bytes;
+        longer.push(0);
+        assert!(InvertedIndex::from_bytes(&longer, Tokenizer::new()).is_none());
+    }
+}
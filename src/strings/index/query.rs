@@ -0,0 +1,262 @@
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+
+use super::{
+    inverted_index::{Postings, PostingsSource},
+    DocId, InvertedIndex,
+};
+use crate::search::{difference_into, intersection, intersection_into, union_into};
+
+/// A condition on the terms of documents, to find them in an [`InvertedIndex`].
+///
+/// The texts of terms and phrases are split into terms by the tokenizer of
+/// the index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    /// Documents with a term. A text of several terms matches as a phrase.
+    Term(String),
+    /// Documents with the terms of a text one after the other.
+    Phrase(String),
+    /// Documents that match every query. An empty list matches all documents.
+    And(Vec<Query>),
+    /// Documents that match any of the queries.
+    Or(Vec<Query>),
+    /// Documents that do not match the query.
+    Not(Box<Query>),
+}
+
+impl Query {
+    /// Documents with a term.
+    #[must_use]
+    pub fn term(term: &str) -> Self {
+        Self::Term(term.into())
+    }
+
+    /// Documents with the terms of a text one after the other.
+    #[must_use]
+    pub fn phrase(text: &str) -> Self {
+        Self::Phrase(text.into())
+    }
+
+    /// Documents that match every query.
+    #[must_use]
+    pub fn and<I: IntoIterator<Item = Self>>(queries: I) -> Self {
+        Self::And(queries.into_iter().collect())
+    }
+
+    /// Documents that match any of the queries.
+    #[must_use]
+    pub fn or<I: IntoIterator<Item = Self>>(queries: I) -> Self {
+        Self::Or(queries.into_iter().collect())
+    }
+
+    /// Documents that do not match a query.
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(query: Self) -> Self {
+        Self::Not(Box::new(query))
+    }
+
+    /// Appends the texts of the terms and phrases that a document must or may
+    /// have to match, but not those it must not have.
+    pub(super) fn positive_texts<'q>(&'q self, texts: &mut Vec<&'q str>) {
+        match self {
+            Self::Term(text) | Self::Phrase(text) => texts.push(text),
+            Self::And(queries) | Self::Or(queries) => {
+                for query in queries {
+                    query.positive_texts(texts);
+                }
+            }
+            Self::Not(_) => {}
+        }
+    }
+}
+
+impl InvertedIndex {
+    /// Finds the documents that match a query.
+    ///
+    /// # Returns
+    ///
+    /// The ids of the documents, in ascending order.
+    ///
+    /// # Performance
+    ///
+    /// ## Time Complexity
+    ///
+    /// Conjunctions gallop through the longer postings lists, so they take
+    /// O(m log(n / m)) for lists of lengths `m` and `n`. Disjunctions and
+    /// negations are linear in the lengths of the lists.
+    #[must_use]
+    pub fn search(&self, query: &Query) -> Vec<DocId> {
+        search(self, query)
+    }
+}
+
+/// Finds the documents of a source that match a query.
+pub(super) fn search<S: PostingsSource>(source: &S, query: &Query) -> Vec<DocId> {
+    match query {
+        Query::Term(text) | Query::Phrase(text) => {
+            phrase(source, &source.tokenizer().positioned_tokens(text))
+        }
+        Query::And(queries) => {
+            let (negated, required): (Vec<&Query>, Vec<&Query>) = queries
+                .iter()
+                .partition(|query| matches!(query, Query::Not(_)));
+            let mut found = required.iter().map(|query| search(source, query));
+            let mut docs = found.next().unwrap_or_else(|| all(source));
+            for other in found {
+                let mut both = Vec::new();
+                intersection_into(&docs, &other, &mut both);
+                docs = both;
+            }
+            // Leaving out documents costs less than finding all those that
+            // do not match.
+            for query in negated {
+                let Query::Not(query) = query else {
+                    continue;
+                };
+                let mut rest = Vec::new();
+                difference_into(&docs, &search(source, query), &mut rest);
+                docs = rest;
+            }
+            docs
+        }
+        Query::Or(queries) => queries.iter().fold(Vec::new(), |docs, query| {
+            let mut either = Vec::new();
+            union_into(&docs, &search(source, query), &mut either);
+            either
+        }),
+        Query::Not(query) => {
+            let mut rest = Vec::new();
+            difference_into(&all(source), &search(source, query), &mut rest);
+            rest
+        }
+    }
+}
+
+/// All the documents of a source.
+fn all<S: PostingsSource>(source: &S) -> Vec<DocId> {
+    (0..source.documents() as DocId).collect()
+}
+
+/// Finds the documents with the terms at the same distances from each
+/// other as in the query.
+fn phrase<S: PostingsSource>(source: &S, terms: &[(usize, Cow<'_, str>)]) -> Vec<DocId> {
+    let Some(postings) = terms
+        .iter()
+        .map(|(_, term)| source.term_postings(term))
+        .collect::<Option<Vec<Cow<'_, Postings>>>>()
+    else {
+        return Vec::new();
+    };
+    let Some((first, rest)) = postings.split_first() else {
+        return Vec::new();
+    };
+    let Some(offsets) = terms
+        .iter()
+        .skip(1)
+        .map(|&(position, _)| u32::try_from(position - terms[0].0).ok())
+        .collect::<Option<Vec<u32>>>()
+    else {
+        return Vec::new();
+    };
+
+    // Only the documents with every term need their positions compared,
+    // and the rarest term has the fewest of them.
+    let mut by_rarity: Vec<&Postings> = postings.iter().map(AsRef::as_ref).collect();
+    by_rarity.sort_unstable_by_key(|postings| postings.docs.len());
+    let mut candidates = by_rarity[0].docs.clone();
+    for postings in &by_rarity[1..] {
+        candidates = intersection(&candidates, &postings.docs).copied().collect();
+    }
+    if rest.is_empty() {
+        return candidates;
+    }
+
+    candidates
+        .into_iter()
+        .filter(|&doc| {
+            first.positions_in(doc).iter().any(|&start| {
+                offsets.iter().zip(rest).all(|(&offset, postings)| {
+                    start.checked_add(offset).is_some_and(|position| {
+                        postings.positions_in(doc).binary_search(&position).is_ok()
+                    })
+                })
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    use crate::strings::tokenize::{Tokenizer, ENGLISH_STOP_WORDS};
+
+    fn index() -> InvertedIndex {
+        let mut index = InvertedIndex::default();
+        for document in [
+            "to be or not to be",
+            "not to worry",
+            "be quick",
+            "",
+            "to be to be",
+        ] {
+            let _ = index.add(document);
+        }
+        index
+    }
+
+    #[test_case(&Query::term("be"), &[0, 2, 4]; "term")]
+    #[test_case(&Query::term("maybe"), &[]; "missing term")]
+    #[test_case(&Query::term(""), &[]; "no terms")]
+    #[test_case(&Query::phrase("to be"), &[0, 4]; "phrase")]
+    #[test_case(&Query::term("to be"), &[0, 4]; "term of several words")]
+    #[test_case(&Query::phrase("be to be"), &[4]; "repeated terms")]
+    #[test_case(&Query::phrase("be to"), &[4]; "phrase in order")]
+    #[test_case(&Query::phrase("not to worry"), &[1]; "whole document")]
+    #[test_case(&Query::and([Query::term("to"), Query::term("not")]), &[0, 1]; "and")]
+    #[test_case(&Query::and([]), &[0, 1, 2, 3, 4]; "empty and")]
+    #[test_case(&Query::or([Query::term("quick"), Query::term("worry")]), &[1, 2]; "or")]
+    #[test_case(&Query::or([]), &[]; "empty or")]
+    #[test_case(&Query::not(Query::term("to")), &[2, 3]; "not")]
+    #[test_case(&Query::and([Query::term("be"), Query::not(Query::term("or"))]), &[2, 4]; "and not")]
+    #[test_case(&Query::and([Query::not(Query::term("be")), Query::not(Query::term("worry"))]), &[3]; "only nots")]
+    fn search(query: &Query, expected: &[DocId]) {
+        assert_eq!(index().search(query), expected);
+    }
+
+    #[test]
+    fn phrases_do_not_match_across_stop_words() {
+        let tokenizer = Tokenizer::builder().stop_words(ENGLISH_STOP_WORDS).build();
+        let mut index = InvertedIndex::new(tokenizer);
+        for document in ["quick and the fox", "quick fox", "the quick the fox"] {
+            let _ = index.add(document);
+        }
+        assert_eq!(index.search(&Query::phrase("quick fox")), [1]);
+        assert_eq!(index.search(&Query::phrase("quick a fox")), [2]);
+        assert_eq!(index.search(&Query::phrase("quick to be fox")), [0]);
+    }
+
+    #[test]
+    fn agrees_with_scanning() {
+        let documents = ["a b c", "b a", "c c a b", "a", "b c a b c", "c b"];
+        let mut index = InvertedIndex::default();
+        for document in documents {
+            let _ = index.add(document);
+        }
+        for phrase in ["a b", "b c", "c a b", "a b c", "c c", "b a b"] {
+            let expected: Vec<DocId> = (0..)
+                .zip(documents)
+                .filter(|(_, document)| {
+                    let words: Vec<&str> = document.split(' ').collect();
+                    let phrase: Vec<&str> = phrase.split(' ').collect();
+                    words.windows(phrase.len()).any(|window| window == phrase)
+                })
+                .map(|(doc, _)| doc)
+                .collect();
+            assert_eq!(index.search(&Query::phrase(phrase)), expected, "{phrase}");
+        }
+    }
+}
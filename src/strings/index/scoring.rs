@@ -0,0 +1,202 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use super::{
+    inverted_index::{Postings, PostingsSource},
+    query::search,
+    DocId, InvertedIndex, Query,
+};
+
+/// How [`InvertedIndex::top_k`] scores the documents that match a query.
+///
+/// A document scores the sum of the weights of the query terms in it.
+/// Terms under a [`Query::Not`] add nothing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scoring {
+    /// Weights a term by `(1 + ln tf) ln(1 + N / df)`, for `tf` occurrences
+    /// of it in the document, `df` documents with it, and `N` documents in
+    /// all.
+    TfIdf,
+    /// Okapi BM25: weights a term by
+    /// `idf (k1 + 1) tf / (tf + k1 (1 - b + b len / avglen))`, with
+    /// `idf = ln(1 + (N - df + 0.5) / (df + 0.5))`, so that each further
+    /// occurrence of a term adds less, and occurrences in long documents
+    /// count for less.
+    Bm25 {
+        /// How quickly further occurrences of a term stop adding to its
+        /// weight, from `0`, where only whether it occurs counts.
+        k1: f64,
+        /// How much the length of a document is taken into account, from
+        /// `0` to `1`.
+        b: f64,
+    },
+}
+
+impl Scoring {
+    /// BM25 with the usual parameters, `k1 = 1.2` and `b = 0.75`.
+    pub const BM25: Self = Self::Bm25 { k1: 1.2, b: 0.75 };
+
+    /// The weight of a term in the `k`th document of its postings, in
+    /// documents of `average` length.
+    fn weight<S>(self, source: &S, postings: &Postings, k: usize, average: f64) -> f64
+    where
+        S: PostingsSource,
+    {
+        let n = source.documents() as f64;
+        let df = postings.docs.len() as f64;
+        let tf = postings.positions(k).len() as f64;
+        match self {
+            Self::TfIdf => (1.0 + tf.ln()) * (n / df).ln_1p(),
+            Self::Bm25 { k1, b } => {
+                let len = f64::from(source.length(postings.docs[k]));
+                let norm = if average > 0.0 {
+                    1.0 - b + b * len / average
+                } else {
+                    1.0
+                };
+                let idf = ((n - df + 0.5) / (df + 0.5)).ln_1p();
+                idf * tf * (k1 + 1.0) / (tf + k1 * norm)
+            }
+        }
+    }
+}
+
+impl InvertedIndex {
+    /// Finds the `k` documents that match a query best.
+    ///
+    /// # Returns
+    ///
+    /// The ids of the documents with their scores, from the highest score
+    /// to the lowest, and in ascending order of id among equal scores.
+    ///
+    /// # Performance
+    ///
+    /// ## Time Complexity
+    ///
+    /// O(m t + k log k) on top of [`InvertedIndex::search`], for `m`
+    /// matching documents and `t` terms in the query.
+    #[must_use]
+    pub fn top_k(&self, query: &Query, k: usize, scoring: Scoring) -> Vec<(DocId, f64)> {
+        top_k(self, query, k, scoring)
+    }
+}
+
+/// Finds the `k` documents of a source that match a query best.
+pub(super) fn top_k<S: PostingsSource>(
+    source: &S,
+    query: &Query,
+    k: usize,
+    scoring: Scoring,
+) -> Vec<(DocId, f64)> {
+    let docs = search(source, query);
+    let mut scores = alloc::vec![0.0; docs.len()];
+
+    let mut texts = Vec::new();
+    query.positive_texts(&mut texts);
+    let mut terms: Vec<Cow<'_, str>> = texts
+        .into_iter()
+        .flat_map(|text| source.tokenizer().tokens(text))
+        .collect();
+    terms.sort_unstable();
+    terms.dedup();
+
+    let average = source.total_length() as f64 / source.documents() as f64;
+
+    // Both the matching documents and the postings are sorted, so each
+    // term is scored in one merge.
+    for postings in terms.iter().filter_map(|term| source.term_postings(term)) {
+        let mut at = 0;
+        for (doc, score) in docs.iter().zip(&mut scores) {
+            at += postings.docs[at..].partition_point(|other| other < doc);
+            if postings.docs.get(at) == Some(doc) {
+                *score += scoring.weight(source, &postings, at, average);
+            }
+        }
+    }
+
+    let mut ranked: Vec<(DocId, f64)> = docs.into_iter().zip(scores).collect();
+    let by_score = |a: &(DocId, f64), b: &(DocId, f64)| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0));
+    if k < ranked.len() {
+        let _ = ranked.select_nth_unstable_by(k, by_score);
+        ranked.truncate(k);
+    }
+    ranked.sort_unstable_by(by_score);
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> InvertedIndex {
+        let mut index = InvertedIndex::default();
+        for document in [
+            "apple banana",
+            "apple apple apple cherry",
+            "banana cherry cherry",
+            "apple banana cherry date elderberry fig grape",
+            "date",
+        ] {
+            let _ = index.add(document);
+        }
+        index
+    }
+
+    fn ids(ranked: &[(DocId, f64)]) -> Vec<DocId> {
+        ranked.iter().map(|&(doc, _)| doc).collect()
+    }
+
+    #[test]
+    fn more_occurrences_score_higher() {
+        let index = index();
+        for scoring in [Scoring::TfIdf, Scoring::BM25] {
+            let ranked = index.top_k(&Query::term("apple"), 10, scoring);
+            assert_eq!(ids(&ranked)[0], 1);
+            assert_eq!(ranked.len(), 3);
+        }
+    }
+
+    #[test]
+    fn shorter_documents_score_higher_with_bm25() {
+        let ranked = index().top_k(&Query::term("banana"), 10, Scoring::BM25);
+        assert_eq!(ids(&ranked), [0, 2, 3]);
+        let flat = index().top_k(
+            &Query::term("banana"),
+            10,
+            Scoring::Bm25 { k1: 1.2, b: 0.0 },
+        );
+        assert!(flat
+            .windows(2)
+            .all(|pair| (pair[0].1 - pair[1].1).abs() < 1e-12));
+        assert_eq!(ids(&flat), [0, 2, 3]);
+    }
+
+    #[test]
+    fn rarer_terms_weigh_more() {
+        let index = index();
+        let query = Query::or([Query::term("elderberry"), Query::term("apple")]);
+        for scoring in [Scoring::TfIdf, Scoring::BM25] {
+            assert_eq!(ids(&index.top_k(&query, 1, scoring)), [3]);
+        }
+    }
+
+    #[test]
+    fn negated_terms_do_not_score() {
+        let index = index();
+        let query = Query::and([Query::term("date"), Query::not(Query::term("apple"))]);
+        let ranked = index.top_k(&query, 10, Scoring::TfIdf);
+        assert_eq!(ids(&ranked), [4]);
+        assert!(ranked[0].1 > 0.0);
+    }
+
+    #[test]
+    fn top_k_agrees_with_sorting() {
+        let index = index();
+        let query = Query::or(["apple", "banana", "cherry", "date"].map(Query::term));
+        let all = index.top_k(&query, usize::MAX, Scoring::BM25);
+        assert_eq!(all.len(), 5);
+        assert!(all.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        for k in 0..=5 {
+            assert_eq!(index.top_k(&query, k, Scoring::BM25), all[..k]);
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use super::{
+    inverted_index::{Postings, PostingsSource},
+    persist::{decode_postings, Reader, MAGIC, VERSION},
+    query::search,
+    scoring::top_k,
+    DocId, Query, Scoring,
+};
+use crate::strings::tokenize::Tokenizer;
+
+/// A read-only index that answers queries in place over the bytes written
+/// by [`InvertedIndex::to_bytes`](super::InvertedIndex::to_bytes), such as
+/// a memory-mapped file.
+///
+/// Opening the view checks the lengths of the documents and the directory
+/// of terms, without copying them. A query finds its terms by binary search
+/// in the directory and decodes only their postings, so memory is bounded
+/// by the postings of the terms of one query rather than by the size of the
+/// index.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::{
+///     index::{IndexView, InvertedIndex, Query, Scoring},
+///     tokenize::Tokenizer,
+/// };
+///
+/// let mut index = InvertedIndex::new(Tokenizer::new());
+/// let _ = index.add("a rose is a rose");
+/// let _ = index.add("roses are red");
+/// let bytes = index.to_bytes();
+///
+/// let view = IndexView::from_bytes(&bytes, Tokenizer::new()).unwrap();
+/// assert_eq!(view.search(&Query::phrase("a rose")), [0]);
+/// assert_eq!(view.documents("red"), [1]);
+/// assert_eq!(
+///     view.top_k(&Query::term("rose"), 1, Scoring::BM25),
+///     index.top_k(&Query::term("rose"), 1, Scoring::BM25)
+/// );
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// Opening takes O(d + t + b), for `d` documents, `t` terms and `b` bytes
+/// of terms. Finding the postings of a term takes O(log t) comparisons and
+/// time linear in the size of its postings.
+///
+/// ## Space Complexity
+///
+/// O(1) - on top of the bytes, which are borrowed.
+#[derive(Debug, Clone)]
+pub struct IndexView<'a> {
+    pub(super) tokenizer: Tokenizer,
+    /// The number of terms kept from each document, as `u32`s.
+    lengths: &'a [u8],
+    /// Where each term starts in `terms`, and where the last one ends, as `u64`s.
+    term_offsets: &'a [u8],
+    /// Where the postings of each term start in `postings`, and where the
+    /// last ones end, as `u64`s.
+    postings_offsets: &'a [u8],
+    /// The terms, in sorted order.
+    terms: &'a [u8],
+    /// The postings of the terms, in the order of the terms.
+    postings: &'a [u8],
+    total_length: u64,
+}
+
+impl<'a> IndexView<'a> {
+    /// Opens a view on an index serialized by
+    /// [`InvertedIndex::to_bytes`](super::InvertedIndex::to_bytes).
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The serialized index.
+    /// * `tokenizer` - The tokenizer to split queries with, which should be
+    ///   configured as the one that built the index.
+    ///
+    /// # Returns
+    ///
+    /// The view, or `None` if the lengths of the documents or the directory
+    /// of terms are malformed. The postings are only checked as queries
+    /// decode them: the postings of a term that turn out malformed are
+    /// taken to be empty.
+    #[must_use]
+    pub fn from_bytes(bytes: &'a [u8], tokenizer: Tokenizer) -> Option<Self> {
+        let mut reader = Reader { bytes };
+        if reader.take(MAGIC.len())? != MAGIC || reader.take(1)? != [VERSION] {
+            return None;
+        }
+
+        let documents = usize::try_from(read_u64(reader.take(8)?)).ok()?;
+        let term_count = usize::try_from(read_u64(reader.take(8)?)).ok()?;
+        let lengths = reader.take(documents.checked_mul(4)?)?;
+        let table_len = term_count.checked_add(1)?.checked_mul(8)?;
+        let term_offsets = reader.take(table_len)?;
+        let postings_offsets = reader.take(table_len)?;
+        let terms = reader.take(offsets_end(term_offsets)?)?;
+        let postings = reader.take(offsets_end(postings_offsets)?)?;
+        if !reader.bytes.is_empty() {
+            return None;
+        }
+
+        let view = Self {
+            tokenizer,
+            lengths,
+            term_offsets,
+            postings_offsets,
+            terms,
+            postings,
+            total_length: lengths
+                .chunks_exact(4)
+                .map(|length| u64::from(read_u32(length)))
+                .sum(),
+        };
+        // The terms must be strictly increasing for binary search to find
+        // them.
+        let mut previous: Option<&str> = None;
+        for k in 0..term_count {
+            let term = core::str::from_utf8(view.term_bytes(k)).ok()?;
+            if previous.is_some_and(|previous| previous >= term) {
+                return None;
+            }
+            previous = Some(term);
+        }
+        Some(view)
+    }
+
+    /// The tokenizer that splits queries.
+    #[must_use]
+    pub fn tokenizer(&self) -> &Tokenizer {
+        &self.tokenizer
+    }
+
+    /// The number of documents.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lengths.len() / 4
+    }
+
+    /// Whether the index has no documents.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lengths.is_empty()
+    }
+
+    /// The number of distinct terms in all documents.
+    #[must_use]
+    pub fn term_count(&self) -> usize {
+        self.term_offsets.len() / 8 - 1
+    }
+
+    /// The number of terms kept from a document.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no document with the id.
+    #[must_use]
+    pub fn document_len(&self, doc: DocId) -> usize {
+        self.length(doc) as usize
+    }
+
+    /// The documents that a term occurs in, in ascending order.
+    ///
+    /// The term must be as the tokenizer normalizes it.
+    #[must_use]
+    pub fn documents(&self, term: &str) -> Vec<DocId> {
+        self.term_postings(term)
+            .map_or_else(Vec::new, |postings| postings.into_owned().docs)
+    }
+
+    /// The positions of a term in a document, counted in tokens from zero,
+    /// including the tokens left out.
+    ///
+    /// The term must be as the tokenizer normalizes it.
+    #[must_use]
+    pub fn positions(&self, term: &str, doc: DocId) -> Vec<u32> {
+        self.term_postings(term)
+            .map_or_else(Vec::new, |postings| postings.positions_in(doc).to_vec())
+    }
+
+    /// Finds the documents that match a query, as
+    /// [`InvertedIndex::search`](super::InvertedIndex::search) does.
+    #[must_use]
+    pub fn search(&self, query: &Query) -> Vec<DocId> {
+        search(self, query)
+    }
+
+    /// Finds the `k` documents that match a query best, as
+    /// [`InvertedIndex::top_k`](super::InvertedIndex::top_k) does.
+    #[must_use]
+    pub fn top_k(&self, query: &Query, k: usize, scoring: Scoring) -> Vec<(DocId, f64)> {
+        top_k(self, query, k, scoring)
+    }
+
+    /// The `k`th term in sorted order.
+    pub(super) fn term(&self, k: usize) -> &'a str {
+        // SAFETY: `from_bytes` checked that every term is UTF-8.
+        unsafe { core::str::from_utf8_unchecked(self.term_bytes(k)) }
+    }
+
+    /// The encoded postings of the `k`th term in sorted order.
+    pub(super) fn block(&self, k: usize) -> &'a [u8] {
+        &self.postings[range(self.postings_offsets, k)]
+    }
+
+    fn term_bytes(&self, k: usize) -> &'a [u8] {
+        &self.terms[range(self.term_offsets, k)]
+    }
+}
+
+impl PostingsSource for IndexView<'_> {
+    fn tokenizer(&self) -> &Tokenizer {
+        &self.tokenizer
+    }
+
+    fn documents(&self) -> usize {
+        self.len()
+    }
+
+    fn length(&self, doc: DocId) -> u32 {
+        let at = doc as usize * 4;
+        read_u32(&self.lengths[at..at + 4])
+    }
+
+    fn total_length(&self) -> u64 {
+        self.total_length
+    }
+
+    fn term_postings(&self, term: &str) -> Option<Cow<'_, Postings>> {
+        let mut lo = 0;
+        let mut hi = self.term_count();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.term_bytes(mid).cmp(term.as_bytes()) {
+                core::cmp::Ordering::Less => lo = mid + 1,
+                core::cmp::Ordering::Greater => hi = mid,
+                core::cmp::Ordering::Equal => {
+                    return decode_postings(self.block(mid), self.len()).map(Cow::Owned);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes.try_into().expect("four bytes"))
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes.try_into().expect("eight bytes"))
+}
+
+/// The `k`th of a table of `u64` offsets.
+fn offset(offsets: &[u8], k: usize) -> usize {
+    read_u64(&offsets[k * 8..k * 8 + 8]) as usize
+}
+
+/// The range of the `k`th item of a table of offsets.
+fn range(offsets: &[u8], k: usize) -> core::ops::Range<usize> {
+    offset(offsets, k)..offset(offsets, k + 1)
+}
+
+/// The end of the last item of a table of offsets, if they start at zero
+/// and never decrease.
+fn offsets_end(offsets: &[u8]) -> Option<usize> {
+    let mut previous = 0;
+    for (k, offset) in offsets.chunks_exact(8).map(read_u64).enumerate() {
+        if (k == 0 && offset != 0) || offset < previous {
+            return None;
+        }
+        previous = offset;
+    }
+    usize::try_from(previous).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::strings::index::InvertedIndex;
+
+    fn index() -> InvertedIndex {
+        let mut index = InvertedIndex::default();
+        for document in [
+            "a rose is a rose is a rose",
+            "",
+            "is it a rose",
+            "roses are red",
+        ] {
+            let _ = index.add(document);
+        }
+        index
+    }
+
+    #[test]
+    fn agrees_with_the_index() {
+        let index = index();
+        let bytes = index.to_bytes();
+        let view = IndexView::from_bytes(&bytes, Tokenizer::new()).unwrap();
+        assert_eq!((view.len(), view.term_count()), (4, 7));
+        for term in index.terms.keys() {
+            assert_eq!(view.documents(term), index.documents(term));
+            assert_eq!(view.positions(term, 0), index.positions(term, 0));
+        }
+        assert!(view.documents("lily").is_empty());
+        assert!(view.documents("").is_empty());
+        for doc in 0..4 {
+            assert_eq!(view.document_len(doc), index.document_len(doc));
+        }
+        for query in [
+            Query::phrase("a rose is"),
+            Query::or([Query::term("rose"), Query::term("red")]),
+            Query::and([Query::term("is"), Query::not(Query::term("it"))]),
+            Query::not(Query::term("a")),
+        ] {
+            assert_eq!(view.search(&query), index.search(&query));
+            assert_eq!(
+                view.top_k(&query, 10, Scoring::BM25),
+                index.top_k(&query, 10, Scoring::BM25)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let bytes = index().to_bytes();
+        for len in 0..bytes.len() {
+            assert!(IndexView::from_bytes(&bytes[..len], Tokenizer::new()).is_none());
+        }
+        // A change of a single byte either fails to open, or opens and
+        // answers queries without panicking.
+        for i in 0..bytes.len() {
+            for byte in [0x00, 0x01, 0x7F, 0x80, 0xFF] {
+                let mut corrupted = bytes.clone();
+                corrupted[i] = byte;
+                if let Some(view) = IndexView::from_bytes(&corrupted, Tokenizer::new()) {
+                    let _ = view.top_k(&Query::phrase("a rose is"), 10, Scoring::BM25);
+                    let _ = view.search(&Query::not(Query::term("red")));
+                }
+            }
+        }
+    }
+}
@@ -1,15 +1,247 @@
-use core::iter::zip;
+use alloc::{vec, vec::Vec};
 
-use alloc::{
-    format,
-    string::{String, ToString},
-    vec::Vec,
-};
+/// The weights of the common prefix bonus of [`jaro_winkler_with`].
+///
+/// The defaults are Winkler's: a weight of 0.1 for each of up to four
+/// leading characters in common, applied when the Jaro similarity is above 0.7.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JaroWinklerOptions {
+    /// How much of the remaining distance each common leading character makes up.
+    pub prefix_weight: f64,
+    /// The Jaro similarity the strings need to be above to get the bonus.
+    pub boost_threshold: f64,
+    /// The most leading characters that count towards the bonus.
+    pub max_prefix: usize,
+}
+
+impl Default for JaroWinklerOptions {
+    fn default() -> Self {
+        Self {
+            prefix_weight: 0.1,
+            boost_threshold: 0.7,
+            max_prefix: 4,
+        }
+    }
+}
+
+/// Computes the Jaro similarity between two strings.
+///
+/// Two characters match if they are equal and no further apart in their
+/// strings than half the length of the longer string, less one. With `m`
+/// matches, of which `t` pairs are out of order, the similarity is the mean
+/// of `m / |a|`, `m / |b|` and `(m - t) / m`, or zero without matches.
+/// Lengths and positions count characters, not bytes.
+///
+/// # Returns
+///
+/// The similarity, from 0.0 for strings without matches to 1.0 for equal strings.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::jaro;
+///
+/// assert!((jaro("MARTHA", "MARHTA") - 0.944).abs() < 1e-3);
+/// assert!((jaro("DIXON", "DICKSONX") - 0.767).abs() < 1e-3);
+/// assert_eq!(jaro("", ""), 1.0);
+/// assert_eq!(jaro("abc", "xyz"), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// The positions matched so far are kept in bit sets on the stack for
+/// strings of up to 256 characters, so only longer strings allocate.
+///
+/// ## Time Complexity
+///
+/// O(n · max(n, m))
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance#Jaro_similarity)
+/// - [Winkler, W. E. (1990). String Comparator Metrics and Enhanced Decision Rules in the Fellegi-Sunter Model of Record Linkage](https://files.eric.ed.gov/fulltext/ED325505.pdf)
+#[must_use]
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let (len_a, len_b) = (a.chars().count(), b.chars().count());
+    if len_a == 0 || len_b == 0 {
+        return if len_a == len_b { 1.0 } else { 0.0 };
+    }
+
+    let window = (len_a.max(len_b) / 2).saturating_sub(1);
+    let (mut matched_a, mut matched_b) = (Positions::new(len_a), Positions::new(len_b));
+    let mut matches = 0;
+
+    // The characters of `b` from the start of the window of the current character of `a`.
+    let mut rest = b.chars();
+    let mut rest_start = 0;
+    for (i, x) in a.chars().enumerate() {
+        let (low, high) = (i.saturating_sub(window), len_b.min(i + window + 1));
+        while rest_start < low {
+            let _ = rest.next();
+            rest_start += 1;
+        }
+
+        let found = rest
+            .clone()
+            .take(high.saturating_sub(low))
+            .enumerate()
+            .find(|&(j, y)| y == x && !matched_b.contains(low + j));
+        if let Some((j, _)) = found {
+            matched_a.insert(i);
+            matched_b.insert(low + j);
+            matches += 1;
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let in_order_a = a
+        .chars()
+        .enumerate()
+        .filter(|&(i, _)| matched_a.contains(i));
+    let in_order_b = b
+        .chars()
+        .enumerate()
+        .filter(|&(j, _)| matched_b.contains(j));
+    let transpositions = in_order_a
+        .zip(in_order_b)
+        .filter(|((_, x), (_, y))| x != y)
+        .count()
+        / 2;
+
+    let matches_f = matches as f64;
+    (matches_f / len_a as f64
+        + matches_f / len_b as f64
+        + (matches - transpositions) as f64 / matches_f)
+        / 3.0
+}
+
+/// Computes the Jaro–Winkler similarity between two strings with Winkler's weights.
+///
+/// See [`jaro_winkler_with`].
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::jaro_winkler;
+///
+/// assert!((jaro_winkler("MARTHA", "MARHTA") - 0.961).abs() < 1e-3);
+/// assert!((jaro_winkler("DWAYNE", "DUANE") - 0.84).abs() < 1e-3);
+/// ```
+#[must_use]
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    jaro_winkler_with(a, b, &JaroWinklerOptions::default())
+}
+
+/// Computes the Jaro–Winkler similarity between two strings: the
+/// [`jaro`] similarity raised for strings that start alike.
+///
+/// With Jaro similarity `s` above the boost threshold and `l` leading
+/// characters in common, up to the maximum prefix length, the similarity
+/// is `s + l * p * (1 - s)` for the prefix weight `p`.
+///
+/// # Returns
+///
+/// The similarity, from 0.0 for strings without matches to 1.0 for equal strings.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::{jaro, jaro_winkler_with, JaroWinklerOptions};
+///
+/// let options = JaroWinklerOptions {
+///     boost_threshold: 0.0,
+///     ..JaroWinklerOptions::default()
+/// };
+/// // The common prefix "h" counts although the strings are not much alike.
+/// let similarity = jaro_winkler_with("hello", "hallway", &options);
+/// assert!(similarity > jaro("hello", "hallway"));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the prefix weight is negative or the bonus could raise the
+/// similarity above 1.0, that is if the prefix weight times the maximum
+/// prefix length is more than 1.0.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance#Jaro%E2%80%93Winkler_similarity)
+#[must_use]
+pub fn jaro_winkler_with(a: &str, b: &str, options: &JaroWinklerOptions) -> f64 {
+    assert!(
+        options.prefix_weight >= 0.0 && options.prefix_weight * options.max_prefix as f64 <= 1.0,
+        "prefix weight must be between 0 and 1 / max_prefix"
+    );
+
+    let similarity = jaro(a, b);
+    if similarity <= options.boost_threshold {
+        return similarity;
+    }
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take(options.max_prefix)
+        .take_while(|(x, y)| x == y)
+        .count();
+    similarity + prefix as f64 * options.prefix_weight * (1.0 - similarity)
+}
+
+/// Computes the Jaro distance between two strings: one less their [`jaro`] similarity.
+#[must_use]
+pub fn jaro_distance(a: &str, b: &str) -> f64 {
+    1.0 - jaro(a, b)
+}
 
-/// A trait for computing the Jaro–Winkler distance between two strings.
+/// Computes the Jaro–Winkler distance between two strings: one less their
+/// [`jaro_winkler_with`] similarity.
 ///
-/// Jaro–Winkler distance is a string metric measuring an edit distance between two
-/// sequences. The output value is between 0.0 and 1.0.
+/// # Panics
+///
+/// Panics under the same conditions as [`jaro_winkler_with`].
+#[must_use]
+pub fn jaro_winkler_distance(a: &str, b: &str, options: &JaroWinklerOptions) -> f64 {
+    1.0 - jaro_winkler_with(a, b, options)
+}
+
+/// A set of character positions, kept on the stack for short strings.
+#[derive(Debug)]
+enum Positions {
+    Inline([u64; 4]),
+    Heap(Vec<u64>),
+}
+
+impl Positions {
+    fn new(len: usize) -> Self {
+        if len <= 256 {
+            Self::Inline([0; 4])
+        } else {
+            Self::Heap(vec![0; len.div_ceil(64)])
+        }
+    }
+
+    fn words(&self) -> &[u64] {
+        match self {
+            Self::Inline(words) => words,
+            Self::Heap(words) => words,
+        }
+    }
+
+    fn contains(&self, position: usize) -> bool {
+        self.words()[position / 64] >> (position % 64) & 1 == 1
+    }
+
+    fn insert(&mut self, position: usize) {
+        let words = match self {
+            Self::Inline(words) => &mut words[..],
+            Self::Heap(words) => &mut words[..],
+        };
+        words[position / 64] |= 1 << (position % 64);
+    }
+}
+
+/// A trait for computing the Jaro–Winkler similarity between two strings.
 ///
 /// # Example
 ///
@@ -25,7 +257,8 @@ use alloc::{
 ///
 /// [Jaro–Winkler Algorithm](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)
 pub trait JaroWinkler {
-    /// Calculates the Jaro–Winkler distance between the calling string and another string.
+    /// Calculates the Jaro–Winkler similarity between the calling string
+    /// and another string, with Winkler's weights.
     ///
     /// # Arguments
     ///
@@ -33,7 +266,8 @@ pub trait JaroWinkler {
     ///
     /// # Returns
     ///
-    /// The Jaro–Winkler distance as a `f64` value between 0.0 and 1.0.
+    /// The Jaro–Winkler similarity as a `f64` value between 0.0 and 1.0, as
+    /// computed by [`jaro_winkler`].
     ///
     /// # Example
     ///
@@ -48,78 +282,16 @@ pub trait JaroWinkler {
 }
 
 impl JaroWinkler for str {
-    #[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
     fn jaro_winklered(&self, other: &str) -> f64 {
-        let get_matched_chars = |str1: &str, str2: &str| -> String {
-            let mut istr2 = str2.to_string();
-            let mut matched_chars = Vec::new();
-            let limit = (str1.len().min(str2.len()) as f32 / 2.0) as usize;
-
-            for (i, l) in str1.chars().enumerate() {
-                let left = (i as isize - limit as isize).max(0) as usize;
-                let right = (i + limit + 1).min(istr2.len());
-
-                if istr2
-                    .chars()
-                    .skip(left)
-                    .take(right)
-                    .collect::<String>()
-                    .contains(l)
-                {
-                    matched_chars.push(l);
-                    let l_index = istr2.find(l).unwrap();
-                    istr2 = format!("{} {}", &istr2[..l_index], &istr2[l_index + 1..]);
-                }
-            }
-
-            matched_chars.into_iter().collect()
-        };
-
-        let matched_chars_1 = get_matched_chars(self, other);
-        let matched_chars_2 = get_matched_chars(other, self);
-
-        let match_count = matched_chars_1.len();
-        let transpositions = f64::floor(
-            zip(matched_chars_1.chars(), matched_chars_2.chars())
-                .filter(|(a, b)| a != b)
-                .count() as f64
-                / 2.,
-        ) as usize;
-
-        if match_count == 0 {
-            return 0.;
-        }
-        let jaro = {
-            let match_count_f = match_count as f64;
-            1.0 / 3.0
-                * (match_count_f / self.len() as f64
-                    + match_count_f / other.len() as f64
-                    + (match_count_f - transpositions as f64) / match_count_f)
-        };
-
-        let mut prefix_length = 0;
-        let mut bool_list = Vec::new();
-
-        if self.len() == other.len() {
-            for (c1, c2) in self.chars().zip(other.chars()) {
-                if c1 == c2 {
-                    bool_list.push(true);
-                } else {
-                    bool_list.push(false);
-                }
-            }
-            if bool_list.contains(&false) {
-                prefix_length += bool_list.iter().position(|&x| !x).unwrap_or(0);
-            }
-        }
-
-        jaro + 0.1 * prefix_length as f64 * (1. - jaro)
+        jaro_winkler(self, other)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    use alloc::string::String;
     use test_case::test_case;
 
     #[test_case("martha", "marhta", 0.961_111_111_111_111_1)]
@@ -134,4 +306,94 @@ mod tests {
     fn test_jaro_winkler(str1: &str, str2: &str, expected: f64) {
         assert_eq!(str1.jaro_winklered(str2), expected);
     }
+
+    // Winkler (1990), table 2, and the example of the strsim crate.
+    #[test_case("MARTHA", "MARHTA", 0.944, 0.961)]
+    #[test_case("DWAYNE", "DUANE", 0.822, 0.840)]
+    #[test_case("DIXON", "DICKSONX", 0.767, 0.813)]
+    #[test_case("SHACKLEFORD", "SHACKELFORD", 0.970, 0.982)]
+    #[test_case("DUNNINGHAM", "CUNNIGHAM", 0.896, 0.896)]
+    #[test_case("NICHLESON", "NICHULSON", 0.926, 0.956)]
+    #[test_case("JONES", "JOHNSON", 0.790, 0.832)]
+    #[test_case("MASSEY", "MASSIE", 0.889, 0.933)]
+    #[test_case("ABROMS", "ABRAMS", 0.889, 0.922)]
+    #[test_case("JERALDINE", "GERALDINE", 0.926, 0.926)]
+    #[test_case("Friedrich Nietzsche", "Jean-Paul Sartre", 0.392, 0.392)]
+    fn matches_published_values(a: &str, b: &str, jaro_expected: f64, winkler_expected: f64) {
+        assert!((jaro(a, b) - jaro_expected).abs() < 1e-3);
+        assert!((jaro_winkler(a, b) - winkler_expected).abs() < 1e-3);
+        assert!((jaro(b, a) - jaro_expected).abs() < 1e-3);
+        assert!((jaro_distance(a, b) - (1.0 - jaro_expected)).abs() < 1e-3);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn counts_characters_not_bytes() {
+        // Two-byte characters would double the lengths and windows if bytes were counted.
+        assert_eq!(jaro("\u{e9}t\u{e9}", "\u{e9}t\u{e9}"), 1.0);
+        assert_eq!(jaro("\u{c5}se", "\u{c5}sa"), jaro("Ase", "Asa"));
+        assert_eq!(
+            jaro("\u{df}\u{e6}\u{f8}\u{e5}", "\u{e6}\u{df}\u{e5}\u{f8}"),
+            jaro("abcd", "badc")
+        );
+        assert_eq!(
+            jaro_winkler("\u{e6}\u{f8}\u{e5}xyz", "\u{e6}\u{f8}\u{e5}xzy"),
+            jaro_winkler("abcxyz", "abcxzy")
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn long_strings_use_the_heap() {
+        let a: String = "abcdefghij".repeat(40);
+        let b: String = "abcdefghji".repeat(40);
+        let similarity = jaro(&a, &b);
+        assert!(similarity > 0.9 && similarity < 1.0);
+        assert_eq!(jaro(&a, &a), 1.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn configurable_bonus() {
+        let plain = JaroWinklerOptions {
+            prefix_weight: 0.0,
+            ..JaroWinklerOptions::default()
+        };
+        assert_eq!(
+            jaro_winkler_with("MARTHA", "MARHTA", &plain),
+            jaro("MARTHA", "MARHTA")
+        );
+
+        let longer = JaroWinklerOptions {
+            max_prefix: 6,
+            ..JaroWinklerOptions::default()
+        };
+        assert!(
+            jaro_winkler_with("SHACKLEFORD", "SHACKELFORD", &longer)
+                > jaro_winkler("SHACKLEFORD", "SHACKELFORD")
+        );
+
+        let strict = JaroWinklerOptions {
+            boost_threshold: 0.95,
+            ..JaroWinklerOptions::default()
+        };
+        assert_eq!(
+            jaro_winkler_with("MARTHA", "MARHTA", &strict),
+            jaro("MARTHA", "MARHTA")
+        );
+        assert_eq!(
+            jaro_winkler_distance("MARTHA", "MARHTA", &strict),
+            jaro_distance("MARTHA", "MARHTA")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "prefix weight")]
+    fn rejects_bonus_above_one() {
+        let options = JaroWinklerOptions {
+            prefix_weight: 0.3,
+            ..JaroWinklerOptions::default()
+        };
+        let _ = jaro_winkler_with("a", "a", &options);
+    }
 }
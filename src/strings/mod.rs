@@ -2,20 +2,37 @@
 
 mod anagram;
 mod capitalize;
+mod case_mapping;
+mod convert_case;
 mod jaro_winkler;
 mod palindrome;
 mod pangram;
 mod remove_duplicates;
 mod reverse_words;
+mod suffix_array;
+mod suffix_automaton;
+mod suffix_tree;
 mod swap_case;
 
 pub use anagram::*;
 pub use capitalize::*;
+pub use case_mapping::*;
+pub use convert_case::*;
 pub use jaro_winkler::*;
 pub use palindrome::*;
 pub use pangram::*;
 pub use remove_duplicates::*;
 pub use reverse_words::*;
+pub use suffix_array::*;
+pub use suffix_automaton::*;
+pub use suffix_tree::*;
 pub use swap_case::*;
 
+pub mod alignment;
+pub mod diff;
+pub mod distance;
+pub mod index;
+pub mod regex;
 pub mod search;
+pub mod segmentation;
+pub mod tokenize;
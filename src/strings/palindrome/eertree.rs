@@ -0,0 +1,268 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use super::Normalization;
+
+/// The root of the palindromes of odd length, standing for a palindrome of length -1.
+const ODD_ROOT: usize = 0;
+/// The root of the palindromes of even length, standing for the empty palindrome.
+const EVEN_ROOT: usize = 1;
+
+/// A node of an [`Eertree`], standing for a distinct palindrome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node<T> {
+    /// The palindromes made by putting an item on both sides of this one.
+    next: Vec<(T, usize)>,
+    /// The node of the longest proper palindromic suffix.
+    link: usize,
+    /// The length of the palindrome, zero for both roots.
+    len: usize,
+    /// The position just past the end of the first occurrence.
+    first_end: usize,
+    /// The number of positions where this is the longest palindromic suffix.
+    ends: usize,
+}
+
+/// The palindromic tree, or eertree, of a sequence: a node for each of its
+/// distinct palindromic substrings, built online as items are appended.
+///
+/// A sequence of `n` items has at most `n` distinct non-empty palindromes,
+/// since each item ends at most one new one, and appending an item takes
+/// amortized constant time besides looking up an edge.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::{Eertree, Normalization};
+///
+/// let mut tree = Eertree::from_text("eertree", Normalization::RAW);
+/// // e, r, t, ee, rtr, ertre, eertree
+/// assert_eq!(tree.distinct(), 7);
+/// assert_eq!(tree.longest_suffix(), 7);
+///
+/// assert!(!tree.push('t'));
+/// assert_eq!(tree.distinct(), 7);
+/// assert!(tree.push('e'));
+/// assert_eq!(tree.longest_suffix(), 3); // ete
+/// ```
+///
+/// # References
+///
+/// - [Rubinchik, M., & Shur, A. M. (2018). EERTREE: An efficient data structure for processing palindromes in strings](https://doi.org/10.1016/j.ejc.2017.07.021)
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Palindrome_tree)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eertree<T> {
+    items: Vec<T>,
+    nodes: Vec<Node<T>>,
+    /// The node of the longest palindromic suffix of the items.
+    last: usize,
+}
+
+impl<T: Eq + Clone> Eertree<T> {
+    /// Creates the eertree of the empty sequence.
+    #[must_use]
+    pub fn new() -> Self {
+        let root = |link| Node {
+            next: Vec::new(),
+            link,
+            len: 0,
+            first_end: 0,
+            ends: 0,
+        };
+        Self {
+            items: Vec::new(),
+            nodes: Vec::from([root(ODD_ROOT), root(ODD_ROOT)]),
+            last: EVEN_ROOT,
+        }
+    }
+
+    /// The items appended so far.
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Appends an item.
+    ///
+    /// # Returns
+    ///
+    /// Whether the sequence has a palindrome it did not have before, which
+    /// is then its longest palindromic suffix.
+    pub fn push(&mut self, item: T) -> bool {
+        self.items.push(item.clone());
+        let node = self.suffix_extending(self.last);
+        if let Some(child) = self.child(node, &item) {
+            self.last = child;
+            self.nodes[child].ends += 1;
+            return false;
+        }
+
+        let len = if node == ODD_ROOT {
+            1
+        } else {
+            self.nodes[node].len + 2
+        };
+        let link = if len == 1 {
+            EVEN_ROOT
+        } else {
+            let suffix = self.suffix_extending(self.nodes[node].link);
+            self.child(suffix, &item).unwrap_or(EVEN_ROOT)
+        };
+        let child = self.nodes.len();
+        self.nodes.push(Node {
+            next: Vec::new(),
+            link,
+            len,
+            first_end: self.items.len(),
+            ends: 1,
+        });
+        self.nodes[node].next.push((item, child));
+        self.last = child;
+        true
+    }
+
+    /// The number of distinct non-empty palindromic substrings.
+    #[must_use]
+    pub fn distinct(&self) -> usize {
+        self.nodes.len() - 2
+    }
+
+    /// The length of the longest palindromic suffix of the items.
+    #[must_use]
+    pub fn longest_suffix(&self) -> usize {
+        self.nodes[self.last].len
+    }
+
+    /// Lists the distinct non-empty palindromic substrings, in the order
+    /// they first occurred.
+    ///
+    /// # Returns
+    ///
+    /// The range of the first occurrence of each palindrome, with the
+    /// number of its occurrences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::Eertree;
+    ///
+    /// let tree: Eertree<u8> = b"abaab".iter().copied().collect();
+    /// let palindromes = tree.palindromes();
+    /// // a, b, aba, aa, baab
+    /// assert_eq!(
+    ///     palindromes,
+    ///     [(0..1, 3), (1..2, 2), (0..3, 1), (2..4, 1), (1..5, 1)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn palindromes(&self) -> Vec<(Range<usize>, usize)> {
+        // Every occurrence of a palindrome is the longest palindromic suffix
+        // somewhere, or a suffix of one that is. Links point to earlier
+        // nodes, so adding counts from the last node back covers them all.
+        let mut counts: Vec<usize> = self.nodes.iter().map(|node| node.ends).collect();
+        for node in (2..self.nodes.len()).rev() {
+            counts[self.nodes[node].link] += counts[node];
+        }
+        self.nodes
+            .iter()
+            .zip(counts)
+            .skip(2)
+            .map(|(node, count)| (node.first_end - node.len..node.first_end, count))
+            .collect()
+    }
+
+    fn child(&self, node: usize, item: &T) -> Option<usize> {
+        self.nodes[node]
+            .next
+            .iter()
+            .find(|(edge, _)| edge == item)
+            .map(|&(_, child)| child)
+    }
+
+    /// Follows suffix links from `node` to the longest palindromic suffix
+    /// that the last item extends: one preceded by an item equal to it.
+    fn suffix_extending(&self, mut node: usize) -> usize {
+        let last = self.items.len() - 1;
+        loop {
+            let len = self.nodes[node].len;
+            if node == ODD_ROOT || (last > len && self.items[last - len - 1] == self.items[last]) {
+                return node;
+            }
+            node = self.nodes[node].link;
+        }
+    }
+}
+
+impl Eertree<char> {
+    /// Builds the eertree of the characters of a text that count under a normalization.
+    #[must_use]
+    pub fn from_text(text: &str, normalization: Normalization) -> Self {
+        normalization.chars(text).map(|(_, c)| c).collect()
+    }
+}
+
+impl<T: Eq + Clone> Default for Eertree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq + Clone> Extend<T> for Eertree<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            let _ = self.push(item);
+        }
+    }
+}
+
+impl<T: Eq + Clone> FromIterator<T> for Eertree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let mut tree = Self::new();
+        tree.extend(items);
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{collections::BTreeMap, vec::Vec};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0x1357_9bdf);
+
+        for _ in 0..200 {
+            let items: Vec<u8> = (0..rng.gen_range(0..25))
+                .map(|_| b"abc"[rng.gen_range(0..3)])
+                .collect();
+            let tree: Eertree<u8> = items.iter().copied().collect();
+
+            // Each distinct palindrome, with its first occurrence and count.
+            let mut expected: BTreeMap<&[u8], (Range<usize>, usize)> = BTreeMap::new();
+            for end in 1..=items.len() {
+                for start in (0..end).rev() {
+                    let slice = &items[start..end];
+                    if slice.iter().eq(slice.iter().rev()) {
+                        expected.entry(slice).or_insert((start..end, 0)).1 += 1;
+                    }
+                }
+            }
+            let mut found: Vec<(Range<usize>, usize)> = tree.palindromes();
+            assert_eq!(found.len(), tree.distinct());
+            found.sort_by_key(|(range, _)| &items[range.clone()]);
+            let expected: Vec<_> = expected.into_values().collect();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn normalized_text() {
+        let tree = Eertree::from_text("No, on!", Normalization::LOOSE);
+        assert_eq!(tree.items(), ['n', 'o', 'o', 'n']);
+        assert_eq!(tree.longest_suffix(), 4);
+        assert_eq!(Eertree::from_text("", Normalization::RAW).distinct(), 0);
+    }
+}
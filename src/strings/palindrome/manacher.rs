@@ -0,0 +1,194 @@
+use alloc::{vec, vec::Vec};
+
+use super::{Normalization, Normalized};
+
+/// Finds the longest palindrome around every center of a sequence with
+/// Manacher's algorithm.
+///
+/// A sequence of `n` items has `2n + 1` centers: center `2i + 1` is item
+/// `i`, and center `2i` the gap before it. The palindrome of length `len`
+/// around center `c` spans the items `(c - len) / 2..(c + len) / 2`.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::manacher;
+///
+/// // Centers:             | a | b | b | a | c |
+/// assert_eq!(manacher(b"abbac"), [0, 1, 0, 1, 4, 1, 0, 1, 0, 1, 0]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n)
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Longest_palindromic_substring#Manacher's_algorithm)
+/// - [CP Algorithms](https://cp-algorithms.com/string/manacher.html)
+#[must_use]
+pub fn manacher<T: Eq>(items: &[T]) -> Vec<usize> {
+    let size = 2 * items.len() + 1;
+    let mut lengths = vec![0; size];
+    // The center of the palindrome that reaches furthest right, and where it ends.
+    let (mut center, mut right) = (0, 0);
+    for c in 0..size {
+        // Within the palindrome around `center`, the one around `c` mirrors
+        // that around `2 * center - c`, as far as the former reaches.
+        let mut len = if c < right {
+            lengths[2 * center - c].min(right - c)
+        } else {
+            0
+        };
+        // Centers and lengths count gaps as well as items, and gaps always match.
+        while c > len
+            && c + len + 1 < size
+            && ((c - len) % 2 == 1 || items[(c - len) / 2 - 1] == items[c.midpoint(len)])
+        {
+            len += 1;
+        }
+        lengths[c] = len;
+        if c + len > right {
+            (center, right) = (c, c + len);
+        }
+    }
+    lengths
+}
+
+/// Finds the first longest palindromic substring of a text.
+///
+/// With a normalization that skips characters, the substring runs from the
+/// first character of the palindrome to the last, with those skipped in
+/// between.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::{longest_palindromic_substring, Normalization};
+///
+/// assert_eq!(longest_palindromic_substring("bananas", Normalization::RAW), "anana");
+/// assert_eq!(
+///     longest_palindromic_substring("He said: Step on no pets!", Normalization::LOOSE),
+///     "Step on no pets"
+/// );
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n)
+#[must_use]
+pub fn longest_palindromic_substring(text: &str, normalization: Normalization) -> &str {
+    let normalized = Normalized::new(text, normalization);
+    let lengths = manacher(&normalized.chars);
+    let (mut best, mut best_len) = (0, 0);
+    for (c, &len) in lengths.iter().enumerate() {
+        if len > best_len {
+            (best, best_len) = (c, len);
+        }
+    }
+    &text[normalized.bytes((best - best_len) / 2..best.midpoint(best_len))]
+}
+
+/// Counts the palindromic substrings of a text, by position, so that a
+/// palindrome that occurs twice counts twice.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::{count_palindromic_substrings, Normalization};
+///
+/// // a, a, a, aa, aa, aaa
+/// assert_eq!(count_palindromic_substrings("aaa", Normalization::RAW), 6);
+/// assert_eq!(count_palindromic_substrings("A a", Normalization::LOOSE), 3);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n)
+#[must_use]
+pub fn count_palindromic_substrings(text: &str, normalization: Normalization) -> usize {
+    let normalized = Normalized::new(text, normalization);
+    // A palindrome of length `len` has `(len + 1) / 2` shorter ones around
+    // its center, counting itself.
+    manacher(&normalized.chars)
+        .into_iter()
+        .map(|len| len.div_ceil(2))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use test_case::test_case;
+
+    fn is_palindrome<T: Eq>(items: &[T]) -> bool {
+        items.iter().eq(items.iter().rev())
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0x0bad_cafe);
+
+        for _ in 0..300 {
+            let items: Vec<u8> = (0..rng.gen_range(0..20))
+                .map(|_| b"ab"[rng.gen_range(0..2)])
+                .collect();
+            let lengths = manacher(&items);
+            assert_eq!(lengths.len(), 2 * items.len() + 1);
+            for (c, &len) in lengths.iter().enumerate() {
+                let (start, end) = ((c - len) / 2, c.midpoint(len));
+                assert!(is_palindrome(&items[start..end]));
+                assert_eq!(end - start, len);
+                // It cannot grow by an item on either side.
+                if start > 0 && end < items.len() {
+                    assert!(!is_palindrome(&items[start - 1..=end]));
+                }
+            }
+
+            let count = (0..items.len())
+                .flat_map(|i| (i + 1..=items.len()).map(move |j| (i, j)))
+                .filter(|&(i, j)| is_palindrome(&items[i..j]))
+                .count();
+            let text = core::str::from_utf8(&items).unwrap();
+            assert_eq!(
+                count_palindromic_substrings(text, Normalization::RAW),
+                count
+            );
+        }
+    }
+
+    #[test_case("", "")]
+    #[test_case("a", "a")]
+    #[test_case("ab", "a")]
+    #[test_case("abba", "abba")]
+    #[test_case("forgeeksskeegfor", "geeksskeeg")]
+    #[test_case("ñoño", "ñoñ")]
+    fn longest_raw(text: &str, expected: &str) {
+        assert_eq!(
+            longest_palindromic_substring(text, Normalization::RAW),
+            expected
+        );
+    }
+
+    #[test]
+    fn longest_loose() {
+        let text = "Was it a car or a cat I saw?";
+        assert_eq!(
+            longest_palindromic_substring(text, Normalization::LOOSE),
+            "Was it a car or a cat I saw"
+        );
+        assert_eq!(
+            longest_palindromic_substring("!?", Normalization::LOOSE),
+            ""
+        );
+    }
+}
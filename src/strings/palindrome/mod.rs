@@ -1,3 +1,15 @@
+mod eertree;
+mod manacher;
+mod normalization;
+mod partition;
+mod shortest;
+
+pub use eertree::*;
+pub use manacher::*;
+pub use normalization::*;
+pub use partition::*;
+pub use shortest::*;
+
 /// Trait to determine whether a string is a palindrome.
 ///
 /// This trait provides a method to check if a given string is a palindrome, ignoring non-alphanumeric
@@ -28,14 +40,8 @@ pub trait Palindrome {
 /// Default implementation of the `Palindrome` trait.
 impl Palindrome for str {
     fn is_palindrome(&self) -> bool {
-        self.chars()
-            .filter(|c| c.is_alphanumeric())
-            .map(|c| c.to_ascii_lowercase())
-            .eq(self
-                .chars()
-                .filter(|c| c.is_alphanumeric())
-                .map(|c| c.to_ascii_lowercase())
-                .rev())
+        let chars = || Normalization::LOOSE.chars(self).map(|(_, c)| c);
+        chars().eq(chars().rev())
     }
 }
 
@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Which characters of a text count, and how, when looking for palindromes in it.
+///
+/// The default counts every character as it is. [`Normalization::LOOSE`]
+/// is that of [`Palindrome::is_palindrome`](super::Palindrome::is_palindrome),
+/// so that "A man, a plan, a canal: Panama" is a palindrome.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Normalization {
+    /// Skip characters that are not alphanumeric, such as spaces and punctuation.
+    pub alphanumeric_only: bool,
+    /// Compare ASCII letters regardless of case.
+    pub ignore_ascii_case: bool,
+}
+
+impl Normalization {
+    /// Every character counts, as it is.
+    pub const RAW: Self = Self {
+        alphanumeric_only: false,
+        ignore_ascii_case: false,
+    };
+
+    /// Only alphanumeric characters count, regardless of ASCII case.
+    pub const LOOSE: Self = Self {
+        alphanumeric_only: true,
+        ignore_ascii_case: true,
+    };
+
+    /// The characters of `text` that count, normalized, with their byte offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::Normalization;
+    ///
+    /// let chars: Vec<_> = Normalization::LOOSE.chars("A b!").collect();
+    /// assert_eq!(chars, [(0, 'a'), (2, 'b')]);
+    /// ```
+    #[must_use]
+    pub fn chars(self, text: &str) -> impl DoubleEndedIterator<Item = (usize, char)> + '_ {
+        text.char_indices()
+            .filter(move |(_, c)| !self.alphanumeric_only || c.is_alphanumeric())
+            .map(move |(i, c)| {
+                if self.ignore_ascii_case {
+                    (i, c.to_ascii_lowercase())
+                } else {
+                    (i, c)
+                }
+            })
+    }
+}
+
+/// The characters of a text that count, and where they are in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Normalized {
+    pub(super) chars: Vec<char>,
+    /// The byte range of each character in the text.
+    ranges: Vec<Range<usize>>,
+}
+
+impl Normalized {
+    pub(super) fn new(text: &str, normalization: Normalization) -> Self {
+        let (chars, ranges) = normalization
+            .chars(text)
+            .map(|(i, c)| {
+                let len = text[i..].chars().next().map_or(0, char::len_utf8);
+                (c, i..i + len)
+            })
+            .unzip();
+        Self { chars, ranges }
+    }
+
+    /// The byte range of the text from the first to the last of a range of characters.
+    pub(super) fn bytes(&self, chars: Range<usize>) -> Range<usize> {
+        if chars.is_empty() {
+            return 0..0;
+        }
+        self.ranges[chars.start].start..self.ranges[chars.end - 1].end
+    }
+}
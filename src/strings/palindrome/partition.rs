@@ -0,0 +1,120 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
+use super::{manacher, Normalization, Normalized};
+
+/// Splits a sequence into as few palindromes as possible.
+///
+/// # Returns
+///
+/// The ranges of the palindromes, in order. The empty sequence has none.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::min_palindrome_partition;
+///
+/// assert_eq!(min_palindrome_partition(b"aabbc"), [0..2, 2..4, 4..5]);
+/// assert_eq!(min_palindrome_partition(b"noonabbad"), [0..4, 4..8, 8..9]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n²), in O(n) space.
+///
+/// # References
+///
+/// - [GeeksforGeeks](https://www.geeksforgeeks.org/palindrome-partitioning-dp-17/)
+#[must_use]
+pub fn min_palindrome_partition<T: Eq>(items: &[T]) -> Vec<Range<usize>> {
+    let lengths = manacher(items);
+    // `pieces[j]` is the fewest palindromes `items[..j]` splits into, and
+    // `starts[j]` where the last of them starts.
+    let mut pieces = vec![0; items.len() + 1];
+    let mut starts = vec![0; items.len() + 1];
+    for end in 1..=items.len() {
+        pieces[end] = usize::MAX;
+        for start in 0..end {
+            // `items[start..end]` is a palindrome if the longest one around
+            // its center is at least as long.
+            if lengths[start + end] >= end - start && pieces[start] + 1 < pieces[end] {
+                (pieces[end], starts[end]) = (pieces[start] + 1, start);
+            }
+        }
+    }
+
+    let mut ranges = Vec::with_capacity(pieces[items.len()]);
+    let mut end = items.len();
+    while end > 0 {
+        ranges.push(starts[end]..end);
+        end = starts[end];
+    }
+    ranges.reverse();
+    ranges
+}
+
+/// Splits a text into as few palindromes as possible, under a normalization.
+///
+/// With a normalization that skips characters, each piece runs from its
+/// first character that counts to its last, and skipped characters between
+/// pieces are in none.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::{palindrome_partition, Normalization};
+///
+/// assert_eq!(palindrome_partition("racecarannakayak", Normalization::RAW), ["racecar", "anna", "kayak"]);
+/// assert_eq!(palindrome_partition("Anna, Bob!", Normalization::LOOSE), ["Anna", "Bob"]);
+/// ```
+#[must_use]
+pub fn palindrome_partition(text: &str, normalization: Normalization) -> Vec<&str> {
+    let normalized = Normalized::new(text, normalization);
+    min_palindrome_partition(&normalized.chars)
+        .into_iter()
+        .map(|range| &text[normalized.bytes(range)])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn is_palindrome<T: Eq>(items: &[T]) -> bool {
+        items.iter().eq(items.iter().rev())
+    }
+
+    /// The fewest palindromes a sequence splits into, trying every split.
+    fn fewest(items: &[u8]) -> usize {
+        (1..=items.len())
+            .filter(|&len| is_palindrome(&items[..len]))
+            .map(|len| 1 + fewest(&items[len..]))
+            .min()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0x2468_ace0);
+
+        for _ in 0..300 {
+            let items: Vec<u8> = (0..rng.gen_range(0..12))
+                .map(|_| b"ab"[rng.gen_range(0..2)])
+                .collect();
+            let ranges = min_palindrome_partition(&items);
+            assert_eq!(ranges.len(), fewest(&items));
+            assert!(ranges
+                .iter()
+                .all(|range| is_palindrome(&items[range.clone()])));
+            assert_eq!(
+                ranges.iter().flat_map(Clone::clone).collect::<Vec<_>>(),
+                (0..items.len()).collect::<Vec<_>>()
+            );
+        }
+    }
+}
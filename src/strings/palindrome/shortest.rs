@@ -0,0 +1,115 @@
+use alloc::string::String;
+
+use super::{manacher, Normalization, Normalized};
+
+/// The length of the longest palindromic prefix of a sequence.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::longest_palindromic_prefix;
+///
+/// assert_eq!(longest_palindromic_prefix(b"abacus"), 3);
+/// assert_eq!(longest_palindromic_prefix(b""), 0);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n)
+#[must_use]
+pub fn longest_palindromic_prefix<T: Eq>(items: &[T]) -> usize {
+    // The palindrome `items[..len]` is around center `len`.
+    manacher(items)
+        .into_iter()
+        .enumerate()
+        .filter(|&(center, len)| len >= center)
+        .map(|(center, _)| center)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Makes the shortest palindrome that starts with some characters followed
+/// by a text, under a normalization.
+///
+/// The characters put in front are those after the longest palindromic
+/// prefix of the text, reversed, as they are in the text.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::{shortest_palindrome, Normalization};
+///
+/// assert_eq!(shortest_palindrome("aacecaaa", Normalization::RAW), "aaacecaaa");
+/// assert_eq!(shortest_palindrome("abcd", Normalization::RAW), "dcbabcd");
+/// assert_eq!(shortest_palindrome("Race car!", Normalization::LOOSE), "Race car!");
+/// assert_eq!(shortest_palindrome("Ab, c", Normalization::LOOSE), "cbAb, c");
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n)
+///
+/// # References
+///
+/// - [LeetCode](https://leetcode.com/problems/shortest-palindrome/)
+#[must_use]
+pub fn shortest_palindrome(text: &str, normalization: Normalization) -> String {
+    let normalized = Normalized::new(text, normalization);
+    let prefix = longest_palindromic_prefix(&normalized.chars);
+    let rest = normalized.bytes(prefix..normalized.chars.len());
+    let mut palindrome: String = text[rest]
+        .chars()
+        .rev()
+        .filter(|&c| !normalization.alphanumeric_only || c.is_alphanumeric())
+        .collect();
+    palindrome.push_str(text);
+    palindrome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn is_palindrome<T: Eq>(items: &[T]) -> bool {
+        items.iter().eq(items.iter().rev())
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0x2468_ace0);
+
+        for _ in 0..300 {
+            let items: Vec<u8> = (0..rng.gen_range(0..20))
+                .map(|_| b"ab"[rng.gen_range(0..2)])
+                .collect();
+            let prefix = longest_palindromic_prefix(&items);
+            assert!(is_palindrome(&items[..prefix]));
+            assert!((prefix + 1..=items.len()).all(|len| !is_palindrome(&items[..len])));
+
+            let text = core::str::from_utf8(&items).unwrap();
+            let palindrome = shortest_palindrome(text, Normalization::RAW);
+            assert!(is_palindrome(palindrome.as_bytes()));
+            assert!(palindrome.ends_with(text));
+            assert_eq!(palindrome.len(), 2 * items.len() - prefix);
+        }
+    }
+
+    #[test]
+    fn loose() {
+        let palindrome = shortest_palindrome("Madam, I'm Adam Smith", Normalization::LOOSE);
+        assert_eq!(palindrome, "htimSMadam, I'm Adam Smith");
+        let normalized: Vec<char> = Normalization::LOOSE
+            .chars(&palindrome)
+            .map(|(_, c)| c)
+            .collect();
+        assert!(is_palindrome(&normalized));
+        assert_eq!(shortest_palindrome("", Normalization::LOOSE), "");
+    }
+}
@@ -0,0 +1,325 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// The first character above the last one with a case mapping, so case
+/// folding need not look at the characters of larger ranges.
+const LAST_CASED: char = '\u{1F000}';
+
+/// Characters that map to a character by their case mappings, but that
+/// it does not map back to, such as `ſ`, whose upper case is `S`.
+///
+/// Each is paired with the lower case character of its case folding.
+const CASE_VARIANTS: &[(char, char)] = &[
+    ('\u{B5}', '\u{3BC}'),
+    ('\u{17F}', '\u{73}'),
+    ('\u{1C5}', '\u{1C6}'),
+    ('\u{1C8}', '\u{1C9}'),
+    ('\u{1CB}', '\u{1CC}'),
+    ('\u{1F2}', '\u{1F3}'),
+    ('\u{345}', '\u{3B9}'),
+    ('\u{3C2}', '\u{3C3}'),
+    ('\u{3D0}', '\u{3B2}'),
+    ('\u{3D1}', '\u{3B8}'),
+    ('\u{3D5}', '\u{3C6}'),
+    ('\u{3D6}', '\u{3C0}'),
+    ('\u{3F0}', '\u{3BA}'),
+    ('\u{3F1}', '\u{3C1}'),
+    ('\u{3F4}', '\u{3B8}'),
+    ('\u{3F5}', '\u{3B5}'),
+    ('\u{1C80}', '\u{432}'),
+    ('\u{1C81}', '\u{434}'),
+    ('\u{1C82}', '\u{43E}'),
+    ('\u{1C83}', '\u{441}'),
+    ('\u{1C84}', '\u{442}'),
+    ('\u{1C85}', '\u{442}'),
+    ('\u{1C86}', '\u{44A}'),
+    ('\u{1C87}', '\u{463}'),
+    ('\u{1C88}', '\u{A64B}'),
+    ('\u{1E9B}', '\u{1E61}'),
+    ('\u{1E9E}', '\u{DF}'),
+    ('\u{1F88}', '\u{1F80}'),
+    ('\u{1F89}', '\u{1F81}'),
+    ('\u{1F8A}', '\u{1F82}'),
+    ('\u{1F8B}', '\u{1F83}'),
+    ('\u{1F8C}', '\u{1F84}'),
+    ('\u{1F8D}', '\u{1F85}'),
+    ('\u{1F8E}', '\u{1F86}'),
+    ('\u{1F8F}', '\u{1F87}'),
+    ('\u{1F98}', '\u{1F90}'),
+    ('\u{1F99}', '\u{1F91}'),
+    ('\u{1F9A}', '\u{1F92}'),
+    ('\u{1F9B}', '\u{1F93}'),
+    ('\u{1F9C}', '\u{1F94}'),
+    ('\u{1F9D}', '\u{1F95}'),
+    ('\u{1F9E}', '\u{1F96}'),
+    ('\u{1F9F}', '\u{1F97}'),
+    ('\u{1FA8}', '\u{1FA0}'),
+    ('\u{1FA9}', '\u{1FA1}'),
+    ('\u{1FAA}', '\u{1FA2}'),
+    ('\u{1FAB}', '\u{1FA3}'),
+    ('\u{1FAC}', '\u{1FA4}'),
+    ('\u{1FAD}', '\u{1FA5}'),
+    ('\u{1FAE}', '\u{1FA6}'),
+    ('\u{1FAF}', '\u{1FA7}'),
+    ('\u{1FBC}', '\u{1FB3}'),
+    ('\u{1FBE}', '\u{3B9}'),
+    ('\u{1FCC}', '\u{1FC3}'),
+    ('\u{1FFC}', '\u{1FF3}'),
+    ('\u{2126}', '\u{3C9}'),
+    ('\u{212A}', '\u{6B}'),
+    ('\u{212B}', '\u{E5}'),
+];
+
+/// The character after `c`, skipping the surrogates.
+pub(super) fn next_char(c: char) -> Option<char> {
+    match c {
+        '\u{D7FF}' => Some('\u{E000}'),
+        _ => char::from_u32(u32::from(c) + 1),
+    }
+}
+
+/// The character before `c`, skipping the surrogates.
+fn previous_char(c: char) -> Option<char> {
+    match c {
+        '\u{E000}' => Some('\u{D7FF}'),
+        _ => u32::from(c).checked_sub(1).and_then(char::from_u32),
+    }
+}
+
+/// A set of characters, as sorted ranges that neither overlap nor touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct CharClass {
+    ranges: Vec<(char, char)>,
+}
+
+impl CharClass {
+    /// The set of the characters in the ranges, which may overlap.
+    pub(super) fn new<I: IntoIterator<Item = (char, char)>>(ranges: I) -> Self {
+        let mut class = Self {
+            ranges: ranges.into_iter().collect(),
+        };
+        class.canonicalize();
+        class
+    }
+
+    /// The set of a single character.
+    pub(super) fn char(c: char) -> Self {
+        Self {
+            ranges: alloc::vec![(c, c)],
+        }
+    }
+
+    /// The set of every character.
+    pub(super) fn any() -> Self {
+        Self::char('\0').negated()
+    }
+
+    pub(super) fn ranges(&self) -> &[(char, char)] {
+        &self.ranges
+    }
+
+    pub(super) fn contains(&self, c: char) -> bool {
+        self.ranges
+            .binary_search_by(|&(lo, hi)| {
+                if hi < c {
+                    Ordering::Less
+                } else if lo > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Adds the characters of another set.
+    pub(super) fn union(&mut self, other: &Self) {
+        self.ranges.extend_from_slice(&other.ranges);
+        self.canonicalize();
+    }
+
+    /// The set of the characters not in this one.
+    pub(super) fn negated(&self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut next = Some('\0');
+        for &(lo, hi) in &self.ranges {
+            if let Some(start) = next.filter(|&start| start < lo) {
+                // `lo` is above `start`, so there is a character before it.
+                ranges.push((start, previous_char(lo).unwrap_or(lo)));
+            }
+            next = next_char(hi);
+        }
+        if let Some(start) = next {
+            ranges.push((start, char::MAX));
+        }
+        Self { ranges }
+    }
+
+    /// Adds the characters that map to or from those of the set by simple,
+    /// one character, case mappings.
+    pub(super) fn case_fold(&mut self) {
+        let mut folded = Vec::new();
+        for &(lo, hi) in &self.ranges {
+            if lo >= LAST_CASED {
+                break;
+            }
+            let hi = hi.min(LAST_CASED);
+            for c in lo..=hi {
+                for mapped in [simple_lowercase(c), simple_uppercase(c)]
+                    .into_iter()
+                    .flatten()
+                {
+                    folded.push((mapped, mapped));
+                    for again in [simple_lowercase(mapped), simple_uppercase(mapped)]
+                        .into_iter()
+                        .flatten()
+                    {
+                        folded.push((again, again));
+                    }
+                }
+            }
+        }
+        self.ranges.extend(folded);
+        self.canonicalize();
+
+        let variants: Vec<(char, char)> = CASE_VARIANTS
+            .iter()
+            .filter(|&&(_, folded)| self.contains(folded))
+            .map(|&(variant, _)| (variant, variant))
+            .collect();
+        self.ranges.extend(variants);
+        self.canonicalize();
+    }
+
+    /// Sorts and merges the ranges.
+    fn canonicalize(&mut self) {
+        self.ranges.sort_unstable();
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(self.ranges.len());
+        for &(lo, hi) in &self.ranges {
+            match merged.last_mut() {
+                Some(last) if next_char(last.1).is_none_or(|after| lo <= after) => {
+                    last.1 = last.1.max(hi);
+                }
+                _ => merged.push((lo, hi)),
+            }
+        }
+        self.ranges = merged;
+    }
+}
+
+/// The lower case of a character, if it is a single other character.
+fn simple_lowercase(c: char) -> Option<char> {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) if lower != c => Some(lower),
+        _ => None,
+    }
+}
+
+/// The upper case of a character, if it is a single other character.
+fn simple_uppercase(c: char) -> Option<char> {
+    // Dotless `ı` is a letter of its own, which only Turkish pairs with `I`.
+    if c == 'ı' {
+        return None;
+    }
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) if upper != c => Some(upper),
+        _ => None,
+    }
+}
+
+/// The ASCII classes of POSIX, as in `[[:alpha:]]`.
+pub(super) fn posix_class(name: &str) -> Option<CharClass> {
+    let ranges: &[(char, char)] = match name {
+        "alnum" => &[('0', '9'), ('A', 'Z'), ('a', 'z')],
+        "alpha" => &[('A', 'Z'), ('a', 'z')],
+        "ascii" => &[('\0', '\x7F')],
+        "blank" => &[('\t', '\t'), (' ', ' ')],
+        "cntrl" => &[('\0', '\x1F'), ('\x7F', '\x7F')],
+        "digit" => &[('0', '9')],
+        "graph" => &[('!', '~')],
+        "lower" => &[('a', 'z')],
+        "print" => &[(' ', '~')],
+        "punct" => &[('!', '/'), (':', '@'), ('[', '`'), ('{', '~')],
+        "space" => &[('\t', '\r'), (' ', ' ')],
+        "upper" => &[('A', 'Z')],
+        "word" => &[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')],
+        "xdigit" => &[('0', '9'), ('A', 'F'), ('a', 'f')],
+        _ => return None,
+    };
+    Some(CharClass::new(ranges.iter().copied()))
+}
+
+/// The classes of Perl, as in `\d`, which are ASCII as in RE2.
+pub(super) fn perl_class(name: char) -> Option<CharClass> {
+    let class = match name.to_ascii_lowercase() {
+        'd' => posix_class("digit"),
+        's' => Some(CharClass::new([('\t', '\n'), ('\x0C', '\r'), (' ', ' ')])),
+        'w' => posix_class("word"),
+        _ => None,
+    }?;
+    Some(if name.is_ascii_uppercase() {
+        class.negated()
+    } else {
+        class
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test]
+    fn canonical() {
+        let class = CharClass::new([('d', 'f'), ('a', 'b'), ('c', 'c'), ('x', 'z'), ('y', 'y')]);
+        assert_eq!(class.ranges(), [('a', 'f'), ('x', 'z')]);
+    }
+
+    #[test_case(&[], &[('\0', char::MAX)]; "empty")]
+    #[test_case(&[('\0', char::MAX)], &[]; "everything")]
+    #[test_case(&[('b', 'c'), ('x', 'x')], &[('\0', 'a'), ('d', 'w'), ('y', char::MAX)]; "ranges")]
+    #[test_case(&[('\u{D000}', '\u{D7FF}')], &[('\0', '\u{CFFF}'), ('\u{E000}', char::MAX)]; "surrogates")]
+    fn negated(ranges: &[(char, char)], expected: &[(char, char)]) {
+        let class = CharClass::new(ranges.iter().copied());
+        assert_eq!(class.negated().ranges(), expected);
+        assert_eq!(class.negated().negated(), class);
+    }
+
+    #[test]
+    fn case_fold() {
+        let mut class = CharClass::new([('a', 'c'), ('σ', 'σ'), ('1', '1')]);
+        class.case_fold();
+        for c in ['a', 'B', 'c', '1', 'Σ', 'ς', 'σ'] {
+            assert!(class.contains(c), "{c}");
+        }
+        assert!(!class.contains('d'));
+
+        let mut class = CharClass::new([('k', 'k'), ('s', 's'), ('ı', 'ı')]);
+        class.case_fold();
+        assert_eq!(
+            class.ranges(),
+            [
+                ('K', 'K'),
+                ('S', 'S'),
+                ('k', 'k'),
+                ('s', 's'),
+                ('ı', 'ı'),
+                ('ſ', 'ſ'),
+                ('\u{212A}', '\u{212A}')
+            ]
+        );
+    }
+
+    #[test]
+    fn perl_classes() {
+        let word = perl_class('w').unwrap();
+        assert!(word.contains('_') && !word.contains('é'));
+        assert!(perl_class('W').unwrap().contains('é'));
+        assert!(perl_class('s').unwrap().contains('\r'));
+        assert!(!perl_class('s').unwrap().contains('\x0B'));
+        assert!(perl_class('x').is_none());
+    }
+}
@@ -0,0 +1,183 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::mem;
+
+use hashbrown::HashMap;
+
+use super::{
+    class::next_char,
+    nfa::{Kind, Nfa, State},
+};
+
+/// The most states kept at once, after which the cache starts over.
+const MAX_CACHED: usize = 4096;
+
+/// A transition not computed yet.
+const UNKNOWN: u32 = u32::MAX;
+/// A transition on which the pattern matched.
+const MATCHED: u32 = u32::MAX - 1;
+/// The end of the text, where the pattern did not match.
+const UNMATCHED: u32 = u32::MAX - 2;
+
+/// Where a character changes what the assertions see: the first of each
+/// run of word, newline and other characters.
+const KIND_BOUNDARIES: [char; 10] = ['\n', '\x0B', '0', ':', 'A', '[', '_', '`', 'a', '{'];
+
+/// A DFA built from an NFA as a text is read, which tells whether the
+/// pattern matches anywhere in it.
+///
+/// A state of the DFA is the set of NFA states that the text read so far
+/// leads to, with the kind of its last character for the assertions. Each
+/// state is built the first time a transition leads to it, so that only
+/// those a text needs are ever built, and a transition costs a table lookup
+/// once built. Characters that no class of the pattern tells apart share
+/// their transitions.
+///
+/// The cache of states is bounded: when it is full, it starts over, so that
+/// each character costs at most the time to build one state, linear in the
+/// size of the NFA.
+///
+/// # References
+///
+/// - [Russ Cox, Regular Expression Matching in the Wild](https://swtch.com/~rsc/regexp/regexp3.html)
+#[derive(Debug, Clone)]
+pub(super) struct LazyDfa {
+    /// The first character of each class but the one starting at `'\0'`.
+    boundaries: Vec<char>,
+    /// The NFA states each state is in before following the empty
+    /// transitions, and the kind of the character before it.
+    states: Vec<(Box<[usize]>, Kind)>,
+    ids: HashMap<(Box<[usize]>, Kind), u32>,
+    /// The transitions of each state, on each class and at the end of the text.
+    table: Vec<u32>,
+    stride: usize,
+    /// Scratch space for following the empty transitions.
+    seen: Vec<bool>,
+    stack: Vec<usize>,
+    visited: Vec<usize>,
+}
+
+impl LazyDfa {
+    pub(super) fn new(nfa: &Nfa) -> Self {
+        let mut boundaries = KIND_BOUNDARIES.to_vec();
+        for state in &nfa.states {
+            if let State::Class(class, _) = state {
+                for &(lo, hi) in class.ranges() {
+                    boundaries.push(lo);
+                    boundaries.extend(next_char(hi));
+                }
+            }
+        }
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        let stride = boundaries.len() + 2;
+        Self {
+            boundaries,
+            states: Vec::new(),
+            ids: HashMap::new(),
+            table: Vec::new(),
+            stride,
+            seen: vec![false; nfa.states.len()],
+            stack: Vec::new(),
+            visited: Vec::new(),
+        }
+    }
+
+    /// Whether the NFA matches anywhere in the text.
+    pub(super) fn is_match(&mut self, nfa: &Nfa, text: &str) -> bool {
+        let mut state = self.intern(Box::new([]), Kind::Edge);
+        for c in text.chars() {
+            let class = self.boundaries.partition_point(|&b| b <= c);
+            state = match self.table[state as usize * self.stride + class] {
+                UNKNOWN => self.compute(nfa, state, class),
+                next => next,
+            };
+            if state == MATCHED {
+                return true;
+            }
+        }
+        let end = self.stride - 1;
+        let matched = match self.table[state as usize * self.stride + end] {
+            UNKNOWN => self.compute(nfa, state, end),
+            next => next,
+        };
+        matched == MATCHED
+    }
+
+    /// Computes and caches the transition of a state on a class, or at the
+    /// end of the text.
+    fn compute(&mut self, nfa: &Nfa, state: u32, column: usize) -> u32 {
+        let c = match column {
+            0 => Some('\0'),
+            _ => self.boundaries.get(column - 1).copied(),
+        };
+        let before = self.states[state as usize].1;
+        let after = Kind::of(c);
+
+        // A match may start at any position, so the closure always has the
+        // start state too.
+        let mut stack = mem::take(&mut self.stack);
+        stack.extend_from_slice(&self.states[state as usize].0);
+        stack.push(nfa.start);
+        let mut targets = Vec::new();
+        let mut matched = false;
+        while let Some(s) = stack.pop() {
+            if mem::replace(&mut self.seen[s], true) {
+                continue;
+            }
+            self.visited.push(s);
+            match nfa.states[s] {
+                State::Class(ref class, target) => {
+                    if c.is_some_and(|c| class.contains(c)) {
+                        targets.push(target);
+                    }
+                }
+                State::Split(first, second) => stack.extend([second, first]),
+                State::Look(look, target) => {
+                    if look.holds(before, after) {
+                        stack.push(target);
+                    }
+                }
+                State::Save(_, target) => stack.push(target),
+                State::Match => matched = true,
+            }
+        }
+        for s in self.visited.drain(..) {
+            self.seen[s] = false;
+        }
+        self.stack = stack;
+
+        if matched {
+            MATCHED
+        } else if c.is_none() {
+            UNMATCHED
+        } else {
+            targets.sort_unstable();
+            targets.dedup();
+            let cached = self.states.len();
+            let next = self.intern(targets.into_boxed_slice(), after);
+            // The state itself is gone if the cache started over.
+            if self.states.len() >= cached {
+                self.table[state as usize * self.stride + column] = next;
+            }
+            next
+        }
+    }
+
+    /// The id of a state, adding it if it is new.
+    fn intern(&mut self, kernel: Box<[usize]>, before: Kind) -> u32 {
+        let key = (kernel, before);
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        if self.states.len() == MAX_CACHED {
+            self.states.clear();
+            self.ids.clear();
+            self.table.clear();
+        }
+        let id = self.states.len() as u32;
+        self.states.push(key.clone());
+        let _ = self.ids.insert(key, id);
+        self.table.resize(self.table.len() + self.stride, UNKNOWN);
+        id
+    }
+}
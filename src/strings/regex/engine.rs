@@ -0,0 +1,678 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cell::RefCell, ops::Range};
+
+use super::{
+    dfa::LazyDfa,
+    live::Live,
+    nfa::Nfa,
+    parse::{parse, Error, ErrorKind, Flags},
+    pike,
+};
+
+/// The most capture slots that the states of a pattern may have in all,
+/// which [`Regex::captures`] keeps for each state it is in.
+const MAX_SLOTS: usize = 1 << 20;
+
+/// Configures and builds a [`Regex`].
+///
+/// The options apply to the whole pattern, and `(?ims)` groups in the
+/// pattern change them within it.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::regex::Regex;
+///
+/// let re = Regex::builder()
+///     .case_insensitive(true)
+///     .multi_line(true)
+///     .build(r"^total: \d+$")
+///     .unwrap();
+///
+/// assert!(re.is_match("items: 3\nTOTAL: 12\n"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct RegexBuilder {
+    flags: Flags,
+}
+
+impl RegexBuilder {
+    /// A builder for a case-sensitive, single-line pattern in which `.`
+    /// does not match `\n`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether letters match regardless of case, as the `i` flag does.
+    ///
+    /// Letters match those they map to or from by simple case mappings, so
+    /// `k` also matches the Kelvin sign `K`.
+    #[must_use]
+    pub fn case_insensitive(mut self, yes: bool) -> Self {
+        self.flags.case_insensitive = yes;
+        self
+    }
+
+    /// Sets whether `^` and `$` also match at the start and end of lines,
+    /// as the `m` flag does.
+    #[must_use]
+    pub fn multi_line(mut self, yes: bool) -> Self {
+        self.flags.multi_line = yes;
+        self
+    }
+
+    /// Sets whether `.` also matches `\n`, as the `s` flag does.
+    #[must_use]
+    pub fn dot_matches_new_line(mut self, yes: bool) -> Self {
+        self.flags.dot_matches_new_line = yes;
+        self
+    }
+
+    /// Compiles a pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the pattern is malformed, or would compile to
+    /// too large an automaton.
+    pub fn build(&self, pattern: &str) -> Result<Regex, Error> {
+        let (ast, names) = parse(pattern, self.flags)?;
+        let too_large = Error {
+            kind: ErrorKind::TooLarge,
+            offset: 0,
+        };
+        let nfa = Nfa::new(&ast, names.len()).map_err(|_| too_large)?;
+        if nfa.states.len() * nfa.slots > MAX_SLOTS {
+            return Err(too_large);
+        }
+        Ok(Regex {
+            pattern: pattern.to_string(),
+            dfa: RefCell::new(LazyDfa::new(&nfa)),
+            pike: RefCell::new(pike::Cache::new(&nfa)),
+            nfa,
+            names,
+        })
+    }
+}
+
+/// A compiled regular expression, which matches in time linear in the
+/// length of the text.
+///
+/// The syntax is a common subset of that of Perl and RE2:
+///
+/// - Characters match themselves, except for `\.+*?()|[]{}^$`, which are
+///   escaped with `\`, as are `\n`, `\t`, `\r`, `\f`, `\v`, `\xHH` and
+///   `\x{HHHH}`.
+/// - `.` matches any character but `\n`, and classes `[a-z_]`, `[^"]` and
+///   `[[:alpha:]]` match one of a set of characters. `\d`, `\w` and `\s`,
+///   and their negations `\D`, `\W` and `\S`, are ASCII classes.
+/// - `xy` matches `x` then `y`, and `x|y` either, preferring `x`.
+/// - `x*`, `x+`, `x?`, `x{n}`, `x{n,}` and `x{n,m}` repeat `x`, as many
+///   times as possible, or as few with a trailing `?`, as in `x*?`.
+/// - `^` and `$` match at the start and end of the text, or of lines with
+///   the `m` flag, `\A` and `\z` only at those of the text, and `\b` and
+///   `\B` at and away from ASCII word boundaries.
+/// - `(x)` and `(?<name>x)` capture what `x` matches, `(?:x)` only groups
+///   it, and `(?ims-ims)` and `(?ims-ims:x)` set flags.
+///
+/// There are no backreferences or lookaround, which no automaton can match
+/// in linear time.
+///
+/// Matches are leftmost-first, as in RE2: the match that starts first, and
+/// of those, the one whose choices come first in the pattern. A repetition
+/// does not repeat an iteration that matched the empty string, which is
+/// where this differs from a backtracking engine: `(|)*` on `""` leaves
+/// group 1 unset rather than matching it at `0..0`. Positions are byte
+/// offsets in the text.
+///
+/// A `Regex` caches the states of its DFA as [`is_match`](Self::is_match)
+/// builds them, and the buffers of its other searches, so it is not
+/// [`Sync`]; clone it to share it between threads.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::regex::Regex;
+///
+/// let re = Regex::new(r"(?<year>\d{4})-(?<month>\d{2})").unwrap();
+/// let text = "from 2024-03 to 2025-11";
+///
+/// assert!(re.is_match(text));
+/// let found: Vec<&str> = re.find_iter(text).map(|m| m.as_str()).collect();
+/// assert_eq!(found, ["2024-03", "2025-11"]);
+///
+/// let captures = re.captures(text).unwrap();
+/// assert_eq!(captures.name("year").unwrap().as_str(), "2024");
+/// assert_eq!(captures.get(2).unwrap().range(), 10..12);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Compiling: O(p) for a pattern of `p` characters, where a counted
+///   repetition counts its pattern as many times
+/// - [`is_match`](Self::is_match): O(n · m) for a text of `n` characters
+///   and an NFA of `m` states, and O(n) once the DFA has the states the
+///   text needs
+/// - [`find`](Self::find) and [`captures`](Self::captures): O(n · m)
+/// - [`find_iter`](Self::find_iter): O(n · m) for all the matches, all of
+///   which wait for a first pass over the whole text, with O(√n · m) bits
+///   of space
+///
+/// # References
+///
+/// - [Thompson, Regular Expression Search Algorithm](https://doi.org/10.1145/363347.363387)
+/// - [Russ Cox, Implementing Regular Expressions](https://swtch.com/~rsc/regexp/)
+#[derive(Debug, Clone)]
+pub struct Regex {
+    pattern: String,
+    nfa: Nfa,
+    /// The names of the capture groups, where the first is the whole match.
+    names: Vec<Option<String>>,
+    dfa: RefCell<LazyDfa>,
+    pike: RefCell<pike::Cache>,
+}
+
+impl Regex {
+    /// Compiles a pattern with the default options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the pattern is malformed, or would compile to
+    /// too large an automaton.
+    pub fn new(pattern: &str) -> Result<Self, Error> {
+        RegexBuilder::new().build(pattern)
+    }
+
+    /// A builder for configuring the options of a pattern.
+    #[must_use]
+    pub fn builder() -> RegexBuilder {
+        RegexBuilder::new()
+    }
+
+    /// The pattern the regex was compiled from.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// The number of capture groups, including the whole match.
+    #[must_use]
+    pub fn captures_len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// The names of the capture groups, in order, where the first is the
+    /// whole match and has none.
+    pub fn capture_names(&self) -> impl Iterator<Item = Option<&str>> {
+        self.names.iter().map(Option::as_deref)
+    }
+
+    /// Checks whether the pattern matches anywhere in the text.
+    ///
+    /// This runs the lazy DFA, which is faster than finding where the match is.
+    pub fn is_match(&self, text: &str) -> bool {
+        self.dfa.borrow_mut().is_match(&self.nfa, text)
+    }
+
+    /// Finds the leftmost-first match.
+    pub fn find<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        self.find_at(text, 0)
+    }
+
+    /// Finds the leftmost-first match that starts at or after `start`.
+    ///
+    /// Assertions still see the text before `start`, so `\b` and `^`
+    /// match as they would at that position of the whole text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is not at a character boundary of the text.
+    pub fn find_at<'t>(&self, text: &'t str, start: usize) -> Option<Match<'t>> {
+        // Only the slots of the whole match are tracked.
+        let slots = self.search(text, start, 2)?;
+        Some(Match {
+            text,
+            start: slots[0]?,
+            end: slots[1]?,
+        })
+    }
+
+    /// Iterates over the matches that do not overlap, from left to right.
+    ///
+    /// Each search resumes at the end of the last match, and an empty match
+    /// where the last match ended is skipped.
+    ///
+    /// The first match reads the whole text backwards to find the states
+    /// that lead to a match at each position, after which no match looks
+    /// past its end, so all the matches together take the time of a search.
+    /// The states are kept only every `√n` bytes or so, and computed again
+    /// between those as the matches reach them.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match<'a>> + 'a {
+        let mut live = None;
+        let mut start = 0;
+        let mut last_end = None;
+
+        core::iter::from_fn(move || loop {
+            if start > text.len() {
+                return None;
+            }
+            let live = live.get_or_insert_with(|| Live::new(&self.nfa, text));
+            let begin = (start..=text.len())
+                .filter(|&at| text.is_char_boundary(at))
+                .find(|&at| {
+                    live.load(&self.nfa, text, at);
+                    live.contains(at, self.nfa.start)
+                })?;
+            let found = Match {
+                text,
+                start: begin,
+                end: live.end(&self.nfa, text, begin)?,
+            };
+            if found.is_empty() && last_end == Some(found.end) {
+                start = found.end + text[found.end..].chars().next().map_or(1, char::len_utf8);
+                continue;
+            }
+            start = found.end;
+            last_end = Some(found.end);
+            return Some(found);
+        })
+    }
+
+    /// Finds the leftmost-first match, and what each capture group matched
+    /// in it.
+    pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'_, 't>> {
+        self.search(text, 0, self.nfa.slots).map(|slots| Captures {
+            text,
+            slots,
+            names: &self.names,
+        })
+    }
+
+    /// Runs the Pike VM, tracking the first `slots` capture slots.
+    fn search(&self, text: &str, start: usize, slots: usize) -> Option<Vec<Option<usize>>> {
+        assert!(
+            text.is_char_boundary(start),
+            "start must be at a character boundary"
+        );
+        pike::search(&self.nfa, &mut self.pike.borrow_mut(), text, start, slots)
+    }
+}
+
+/// A match of a [`Regex`] in a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+}
+
+impl<'t> Match<'t> {
+    /// The byte offset where the match starts.
+    #[must_use]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The byte offset just past the end of the match.
+    #[must_use]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// The byte range of the match.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// The matched part of the text.
+    #[must_use]
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.start..self.end]
+    }
+
+    /// The length of the match in bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Whether the match is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// What each capture group of a [`Regex`] matched in a match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captures<'r, 't> {
+    text: &'t str,
+    /// The start and end of each group.
+    slots: Vec<Option<usize>>,
+    names: &'r [Option<String>],
+}
+
+impl<'t> Captures<'_, 't> {
+    /// What a group matched, where group 0 is the whole match.
+    ///
+    /// # Returns
+    ///
+    /// The match, or `None` if there is no such group or it matched
+    /// nothing, as in `(a)|b` matching `b`.
+    #[must_use]
+    pub fn get(&self, group: usize) -> Option<Match<'t>> {
+        let start = (*self.slots.get(2 * group)?)?;
+        let end = self.slots[2 * group + 1]?;
+        Some(Match {
+            text: self.text,
+            start,
+            end,
+        })
+    }
+
+    /// What the group with a name matched.
+    #[must_use]
+    pub fn name(&self, name: &str) -> Option<Match<'t>> {
+        let group = self.names.iter().position(|n| n.as_deref() == Some(name))?;
+        self.get(group)
+    }
+
+    /// The number of groups, including the whole match.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Always `false`, as there is always the whole match.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// What each group matched, in order.
+    pub fn iter(&self) -> impl Iterator<Item = Option<Match<'t>>> + '_ {
+        (0..self.len()).map(|group| self.get(group))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use test_case::test_case;
+
+    use crate::strings::regex::{
+        nfa::Kind,
+        parse::{parse, Ast, Flags},
+    };
+
+    /// The spans of the matches of `find_iter`.
+    fn spans(pattern: &str, text: &str) -> Vec<(usize, usize)> {
+        Regex::new(pattern)
+            .unwrap()
+            .find_iter(text)
+            .map(|m| (m.start(), m.end()))
+            .collect()
+    }
+
+    /// The spans of the matches of `find_iter`, found instead by searching
+    /// again after each match with `find_at`.
+    fn spans_by_find_at(re: &Regex, text: &str) -> Vec<(usize, usize)> {
+        let mut spans = Vec::new();
+        let mut start = 0;
+        while let Some(found) = re.find_at(text, start) {
+            if found.is_empty() && spans.last().is_some_and(|&(_, end)| end == found.end()) {
+                match text[found.end()..].chars().next() {
+                    Some(c) => start = found.end() + c.len_utf8(),
+                    None => break,
+                }
+                continue;
+            }
+            spans.push((found.start(), found.end()));
+            start = found.end();
+        }
+        spans
+    }
+
+    #[test_case("abc", "xabcabc", &[(1, 4), (4, 7)]; "literal")]
+    #[test_case("a|ab", "ab", &[(0, 1)]; "leftmost first")]
+    #[test_case("ab|a", "ab", &[(0, 2)]; "first branch")]
+    #[test_case("a+", "baaab", &[(1, 4)]; "greedy")]
+    #[test_case("a+?", "aa", &[(0, 1), (1, 2)]; "lazy")]
+    #[test_case("a*", "baaa", &[(0, 0), (1, 4)]; "empty matches")]
+    #[test_case("", "añ", &[(0, 0), (1, 1), (3, 3)]; "empty pattern")]
+    #[test_case("x*", "ñ", &[(0, 0), (2, 2)]; "empty between characters")]
+    #[test_case("a{2,3}", "aaaaaaa", &[(0, 3), (3, 6)]; "counted")]
+    #[test_case("a{2}?", "aaa", &[(0, 2)]; "lazy counted")]
+    #[test_case("[^a-c]+", "abxyzc", &[(2, 5)]; "negated class")]
+    #[test_case(r"\d+", "a12b345", &[(1, 3), (4, 7)]; "digits")]
+    #[test_case(r"\bcat\b", "cat concat cat", &[(0, 3), (11, 14)]; "word boundary")]
+    #[test_case(r"\Ba\B", "a bab", &[(3, 4)]; "not word boundary")]
+    #[test_case("^a", "aaa", &[(0, 1)]; "start")]
+    #[test_case("a$", "aaa", &[(2, 3)]; "end")]
+    #[test_case("(?m)^a$", "a\nb\na", &[(0, 1), (4, 5)]; "lines")]
+    #[test_case("(?m)^", "a\n\nb", &[(0, 0), (2, 2), (3, 3)]; "line starts")]
+    #[test_case(".", "a\nb", &[(0, 1), (2, 3)]; "dot")]
+    #[test_case("(?s).", "a\n", &[(0, 1), (1, 2)]; "dot all")]
+    #[test_case("(?i)straße", "STRAẞE", &[(0, 8)]; "case insensitive")]
+    #[test_case("é+", "éééa", &[(0, 6)]; "multibyte")]
+    fn find_iter(pattern: &str, text: &str, expected: &[(usize, usize)]) {
+        assert_eq!(spans(pattern, text), expected);
+        let re = Regex::new(pattern).unwrap();
+        assert_eq!(spans_by_find_at(&re, text), expected);
+        assert_eq!(re.is_match(text), !expected.is_empty());
+    }
+
+    #[test_case(r"(\w+)@(\w+)\.com", "mail bob@example.com", &[Some("bob@example.com"), Some("bob"), Some("example")]; "groups")]
+    #[test_case("(a)|b", "b", &[Some("b"), None]; "unmatched group")]
+    #[test_case("(a*)+", "b", &[Some(""), Some("")]; "empty loop")]
+    #[test_case("(a|ab)(c|bcd)(d*)", "abcd", &[Some("abcd"), Some("a"), Some("bcd"), Some("")]; "first alternatives")]
+    #[test_case("(a+)(a*)", "aaa", &[Some("aaa"), Some("aaa"), Some("")]; "greedy groups")]
+    #[test_case("(a+?)(a*)", "aaa", &[Some("aaa"), Some("a"), Some("aa")]; "lazy groups")]
+    #[test_case("(?:(a)|b)+", "ab", &[Some("ab"), Some("a")]; "last iteration")]
+    #[test_case("(|)*", "", &[Some(""), None]; "empty iteration not repeated")]
+    fn captures(pattern: &str, text: &str, expected: &[Option<&str>]) {
+        let re = Regex::new(pattern).unwrap();
+        let captures = re.captures(text).unwrap();
+        let groups: Vec<Option<&str>> = captures.iter().map(|m| m.map(|m| m.as_str())).collect();
+        assert_eq!(groups, expected);
+        // Searches without captures reuse the same buffers.
+        assert_eq!(re.find(text).map(|m| m.as_str()), expected[0]);
+        assert_eq!(re.captures(text).unwrap(), captures);
+    }
+
+    #[test]
+    fn named_groups() {
+        let re = Regex::new(r"(?P<key>\w+)=(?<value>\w*)").unwrap();
+        assert_eq!(re.captures_len(), 3);
+        assert_eq!(
+            re.capture_names().collect::<Vec<_>>(),
+            [None, Some("key"), Some("value")]
+        );
+        let captures = re.captures("  x=").unwrap();
+        assert_eq!(captures.name("key").unwrap().range(), 2..3);
+        assert!(captures.name("value").unwrap().is_empty());
+        assert!(captures.name("other").is_none());
+        assert!(captures.get(3).is_none());
+    }
+
+    #[test]
+    fn find_at() {
+        let re = Regex::new(r"\bb").unwrap();
+        assert!(re.find_at("ab b", 1).is_some_and(|m| m.start() == 3));
+        assert!(Regex::new("^b").unwrap().find_at("ab", 1).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "character boundary")]
+    fn find_at_inside_character() {
+        let _ = Regex::new("a").unwrap().find_at("é", 1);
+    }
+
+    #[test]
+    fn errors() {
+        let error = Regex::new("a(b").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::UnclosedGroup);
+        assert_eq!(error.to_string(), "unclosed group at offset 1");
+        let error = Regex::new("(?:a{1000}){1000}").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::TooLarge);
+        // Few states, but too many slots for each of them.
+        let error = Regex::new(&"(a)".repeat(600)).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::TooLarge);
+        assert!(Regex::new(&"(a)".repeat(100)).is_ok());
+    }
+
+    #[test]
+    fn linear_time() {
+        // These take exponential time to backtrack through.
+        let text = "a".repeat(10_000);
+        let re = Regex::new("(a*)*b").unwrap();
+        assert!(!re.is_match(&text));
+        assert!(re.find(&text).is_none());
+
+        let pattern = "(a?){30}a{30}";
+        let re = Regex::new(pattern).unwrap();
+        let text = "a".repeat(30);
+        assert!(re.is_match(&text));
+        assert_eq!(re.find(&text).unwrap().len(), 30);
+
+        // A pattern whose DFA has exponentially many states.
+        let re = Regex::new("[ab]*a[ab]{12}c").unwrap();
+        let mut rng = StdRng::seed_from_u64(0x2545_F491_4F6C_DD1D);
+        let text: String = (0..20_000)
+            .map(|_| if rng.gen() { 'a' } else { 'b' })
+            .collect();
+        assert!(!re.is_match(&text));
+        assert!(re.is_match(&(text + "c")));
+
+        // Each match rules out the first branch only at the end of the text.
+        let re = Regex::new("[a-z]*X|[a-z]").unwrap();
+        let text = "a".repeat(100_000);
+        assert_eq!(re.find_iter(&text).count(), text.len());
+    }
+
+    /// The ends of the matches of a pattern starting at any of `starts`,
+    /// found by following the pattern over sets of positions.
+    fn ends(ast: &Ast, text: &[char], starts: &[usize]) -> Vec<usize> {
+        let mut ends: Vec<usize> = match ast {
+            Ast::Empty => starts.to_vec(),
+            Ast::Class(class) => starts
+                .iter()
+                .filter(|&&at| at < text.len() && class.contains(text[at]))
+                .map(|&at| at + 1)
+                .collect(),
+            Ast::Look(look) => starts
+                .iter()
+                .copied()
+                .filter(|&at| {
+                    let before = Kind::of(at.checked_sub(1).map(|at| text[at]));
+                    look.holds(before, Kind::of(text.get(at).copied()))
+                })
+                .collect(),
+            Ast::Capture { ast, .. } => ends(ast, text, starts),
+            Ast::Concat(items) => items
+                .iter()
+                .fold(starts.to_vec(), |at, item| ends(item, text, &at)),
+            Ast::Alternate(branches) => branches
+                .iter()
+                .flat_map(|branch| ends(branch, text, starts))
+                .collect(),
+            Ast::Repeat { ast, min, max, .. } => {
+                let mut at = starts.to_vec();
+                for _ in 0..*min {
+                    at = ends(ast, text, &at);
+                }
+                let mut all = at.clone();
+                if let Some(max) = *max {
+                    for _ in *min..max {
+                        at = ends(ast, text, &at);
+                        all.extend(&at);
+                    }
+                } else {
+                    loop {
+                        at = ends(ast, text, &at);
+                        at.retain(|end| !all.contains(end));
+                        if at.is_empty() {
+                            break;
+                        }
+                        all.extend(&at);
+                    }
+                }
+                all
+            }
+        };
+        ends.sort_unstable();
+        ends.dedup();
+        ends
+    }
+
+    #[test]
+    fn find_iter_across_windows() {
+        let mut rng = StdRng::seed_from_u64(0x2545_F491_4F6C_DD1D);
+        let text: String = (0..20_000)
+            .map(|_| ['a', 'b', ' ', 'é'][rng.gen_range(0..4)])
+            .collect();
+        for pattern in [r"\bab*", "a[abé]{0,40}b", "(?:a|é)+", "b*", r"\B", "é$"] {
+            let re = Regex::new(pattern).unwrap();
+            let all: Vec<(usize, usize)> =
+                re.find_iter(&text).map(|m| (m.start(), m.end())).collect();
+            assert_eq!(all, spans_by_find_at(&re, &text), "{pattern:?}");
+        }
+    }
+
+    #[test]
+    fn random() {
+        const PIECES: [&str; 16] = [
+            "a", "b", "[ab]", ".", "a*", "b+?", "(a|b)", "(ab|a)", "a?", "(?:ba)*", "^", "$",
+            r"\b", r"\B", "(a|)", "b{1,2}",
+        ];
+        let mut rng = StdRng::seed_from_u64(0x9E37_79B9_7F4A_7C15);
+        for _ in 0..2000 {
+            let pattern: String = (0..=rng.gen_range(0..4))
+                .map(|_| PIECES[rng.gen_range(0..PIECES.len())])
+                .collect();
+            let text: Vec<char> = (0..rng.gen_range(0..8))
+                .map(|_| ['a', 'b', ' '][rng.gen_range(0..3)])
+                .collect();
+            let string: String = text.iter().collect();
+
+            let re = Regex::new(&pattern).unwrap();
+            let (ast, _) = parse(&pattern, Flags::default()).unwrap();
+            // The leftmost position where a match starts, in characters.
+            let expected = (0..=text.len()).find(|&at| !ends(&ast, &text, &[at]).is_empty());
+            let found = re.find(&string);
+            assert_eq!(
+                found.map(|m| m.start()),
+                expected,
+                "{pattern:?} on {string:?}"
+            );
+            assert_eq!(
+                re.is_match(&string),
+                expected.is_some(),
+                "{pattern:?} on {string:?}"
+            );
+            if let Some(found) = found {
+                let possible = ends(&ast, &text, &[found.start()]);
+                assert!(possible.contains(&found.end()), "{pattern:?} on {string:?}");
+            }
+            let all: Vec<(usize, usize)> = re
+                .find_iter(&string)
+                .map(|m| (m.start(), m.end()))
+                .collect();
+            assert_eq!(
+                all,
+                spans_by_find_at(&re, &string),
+                "{pattern:?} on {string:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{mem, ops::Range};
+
+use super::nfa::{Kind, Nfa, State};
+
+/// The fewest bytes of text between two checkpoints, so that short texts
+/// are read backwards only once.
+const MIN_SPACING: usize = 4096;
+
+/// The states of an NFA from which the rest of a text still matches, at
+/// each position of the text, to find all the matches of a pattern with
+/// one pass over the text each way.
+///
+/// A Pike VM cannot tell where a leftmost-first match ends until the
+/// threads that take precedence over it die, which for `[a-z]*X|[a-z]`
+/// is only at the end of the text, for every match. Reading the text
+/// backwards once tells which states lead to a match at each position, so
+/// that a match starts at the first position whose start state does, and
+/// takes the first of the ways on that lead to a match, in the order of the
+/// Pike VM, never looking past its end.
+///
+/// Only the sets of checkpoints about `√n` bytes apart are kept from that
+/// pass. The sets between two checkpoints are computed again, backwards
+/// from the later one, when a search first reaches them, and searches move
+/// forwards, so each window is computed at most twice in all.
+///
+/// # References
+///
+/// - [Russ Cox, Regular Expression Matching in the Wild](https://swtch.com/~rsc/regexp/regexp3.html)
+#[derive(Debug, Clone)]
+pub(super) struct Live {
+    /// The states that move to each state without a character.
+    sources: Vec<Vec<usize>>,
+    /// The states that consume a character or match.
+    leaves: Vec<usize>,
+    /// The number of `u64`s in a set, a bit per state.
+    words: usize,
+    /// Positions in ascending order, from the start to the end of the text,
+    /// with their sets.
+    checkpoints: Vec<(usize, Box<[u64]>)>,
+    /// The positions whose sets are in `sets`.
+    window: Range<usize>,
+    /// The set of each position of the window, by its byte offset from the
+    /// start of the window.
+    sets: Vec<u64>,
+    /// Scratch space for following the states that need no character.
+    seen: Vec<bool>,
+    visited: Vec<usize>,
+    stack: Vec<usize>,
+}
+
+impl Live {
+    /// Reads a text backwards to find the live states at the checkpoints.
+    pub(super) fn new(nfa: &Nfa, text: &str) -> Self {
+        let mut sources = vec![Vec::new(); nfa.states.len()];
+        let mut leaves = Vec::new();
+        for (state, kind) in nfa.states.iter().enumerate() {
+            match *kind {
+                State::Split(first, second) => {
+                    sources[first].push(state);
+                    sources[second].push(state);
+                }
+                State::Look(_, target) | State::Save(_, target) => sources[target].push(state),
+                State::Class(..) | State::Match => leaves.push(state),
+            }
+        }
+
+        let words = nfa.states.len().div_ceil(64);
+        let mut live = Self {
+            sources,
+            leaves,
+            words,
+            checkpoints: Vec::new(),
+            window: 0..0,
+            sets: Vec::new(),
+            seen: vec![false; nfa.states.len()],
+            visited: Vec::new(),
+            stack: Vec::new(),
+        };
+
+        let spacing = text.len().isqrt().max(MIN_SPACING);
+        let mut set = vec![0; words];
+        let mut next = vec![0; words];
+        let mut at = text.len();
+        let mut after = None;
+        loop {
+            let before = text[..at].chars().next_back();
+            live.step(nfa, (before, after), &next, &mut set);
+            let last = live
+                .checkpoints
+                .last()
+                .map_or(usize::MAX, |&(last, _)| last);
+            if before.is_none() || last == usize::MAX || last - at >= spacing {
+                live.checkpoints.push((at, set.clone().into_boxed_slice()));
+            }
+            mem::swap(&mut set, &mut next);
+            let Some(before) = before else {
+                break;
+            };
+            at -= before.len_utf8();
+            after = Some(before);
+        }
+        live.checkpoints.reverse();
+        live
+    }
+
+    /// Computes the set of a position from the set of the position after
+    /// the next character, given the characters around it.
+    fn step(
+        &mut self,
+        nfa: &Nfa,
+        (before, after): (Option<char>, Option<char>),
+        next: &[u64],
+        set: &mut [u64],
+    ) {
+        let context = (Kind::of(before), Kind::of(after));
+        set.fill(0);
+        for &state in &self.leaves {
+            let leads = match &nfa.states[state] {
+                State::Class(class, target) => after.is_some_and(|c| {
+                    class.contains(c) && next[target / 64] & 1 << (target % 64) != 0
+                }),
+                _ => true,
+            };
+            if leads {
+                set[state / 64] |= 1 << (state % 64);
+                self.stack.push(state);
+            }
+        }
+        while let Some(state) = self.stack.pop() {
+            for &source in &self.sources[state] {
+                let holds = match nfa.states[source] {
+                    State::Look(look, _) => look.holds(context.0, context.1),
+                    _ => true,
+                };
+                if holds && set[source / 64] & 1 << (source % 64) == 0 {
+                    set[source / 64] |= 1 << (source % 64);
+                    self.stack.push(source);
+                }
+            }
+        }
+    }
+
+    /// Computes the sets of the window between the checkpoints around a
+    /// position, unless they are already there.
+    ///
+    /// `at` must be a character boundary of the text.
+    pub(super) fn load(&mut self, nfa: &Nfa, text: &str, at: usize) {
+        if self.window.contains(&at) {
+            return;
+        }
+        let last = self.checkpoints.len() - 1;
+        let i = (self.checkpoints.partition_point(|&(p, _)| p <= at) - 1).min(last.max(1) - 1);
+        let start = self.checkpoints[i].0;
+        let (end, ref end_set) = self.checkpoints[(i + 1).min(last)];
+
+        let words = self.words;
+        let mut sets = mem::take(&mut self.sets);
+        sets.clear();
+        sets.resize((end - start + 1) * words, 0);
+        sets[(end - start) * words..].copy_from_slice(end_set);
+
+        let mut at = end;
+        while at > start {
+            let after = text[..at]
+                .chars()
+                .next_back()
+                .expect("a position within the text");
+            let position = at - after.len_utf8();
+            let before = text[..position].chars().next_back();
+            let (left, right) = sets.split_at_mut((at - start) * words);
+            let set = &mut left[(position - start) * words..][..words];
+            self.step(nfa, (before, Some(after)), &right[..words], set);
+            at = position;
+        }
+
+        self.sets = sets;
+        self.window = start..end + 1;
+    }
+
+    /// Whether the rest of the text matches from a state at a position of
+    /// the window last loaded.
+    pub(super) fn contains(&self, at: usize, state: usize) -> bool {
+        let set = (at - self.window.start) * self.words;
+        self.sets[set + state / 64] & 1 << (state % 64) != 0
+    }
+
+    /// Finds where the leftmost-first match that starts at a position ends.
+    ///
+    /// At each position, this follows the states that need no character in
+    /// the order of the Pike VM, leaving out those that do not lead to a
+    /// match, until the first that matches or consumes a character.
+    ///
+    /// # Returns
+    ///
+    /// The end of the match, or `None` if none starts there.
+    pub(super) fn end(&mut self, nfa: &Nfa, text: &str, start: usize) -> Option<usize> {
+        self.load(nfa, text, start);
+        if !self.contains(start, nfa.start) {
+            return None;
+        }
+        let mut at = start;
+        let mut state = nfa.start;
+        loop {
+            self.load(nfa, text, at);
+            self.stack.push(state);
+            let step = loop {
+                let state = self.stack.pop().expect("a live state leads to a match");
+                if !self.contains(at, state) || mem::replace(&mut self.seen[state], true) {
+                    continue;
+                }
+                self.visited.push(state);
+                match nfa.states[state] {
+                    State::Class(_, target) => break Some(target),
+                    State::Split(first, second) => self.stack.extend([second, first]),
+                    State::Look(_, target) | State::Save(_, target) => self.stack.push(target),
+                    State::Match => break None,
+                }
+            };
+            self.stack.clear();
+            for state in self.visited.drain(..) {
+                self.seen[state] = false;
+            }
+
+            let Some(target) = step else {
+                return Some(at);
+            };
+            at += text[at..].chars().next().map_or(0, char::len_utf8);
+            state = target;
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Regular expressions that match in linear time
+//!
+//! A [`Regex`] parses a pattern, compiles it into a Thompson NFA, and
+//! matches it by following all the ways the pattern might match at once,
+//! so that no pattern or text makes it backtrack. [`Regex::is_match`]
+//! builds a DFA from the NFA as it reads the text, while
+//! [`Regex::find`] and [`Regex::captures`] run a Pike VM, which also
+//! tracks where each capture group matched. [`Regex::find_iter`] first
+//! reads the text backwards to learn where a match can go on from, so
+//! that it finds each match without reading past it.
+
+mod class;
+mod dfa;
+mod engine;
+mod live;
+mod nfa;
+mod parse;
+mod pike;
+
+pub use engine::*;
+pub use parse::{Error, ErrorKind};
@@ -0,0 +1,259 @@
+use alloc::vec::Vec;
+
+use super::{class::CharClass, parse::Ast};
+
+/// The most states a pattern may compile to.
+const MAX_STATES: usize = 100_000;
+
+/// An assertion about the characters around a position, which matches the
+/// empty string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum Look {
+    /// `^`, or `\A`: the start of the text.
+    StartText,
+    /// `$`, or `\z`: the end of the text.
+    EndText,
+    /// `^` in multi-line mode: the start of the text or of a line.
+    StartLine,
+    /// `$` in multi-line mode: the end of the text or of a line.
+    EndLine,
+    /// `\b`: between a word character and another character or an end.
+    WordBoundary,
+    /// `\B`: not at a word boundary.
+    NotWordBoundary,
+}
+
+/// What an assertion needs to know about the character on one side of a
+/// position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum Kind {
+    /// There is no character: the position is an end of the text.
+    Edge,
+    /// `\n`.
+    Newline,
+    /// An ASCII letter or digit, or `_`.
+    Word,
+    Other,
+}
+
+impl Kind {
+    pub(super) fn of(c: Option<char>) -> Self {
+        match c {
+            None => Self::Edge,
+            Some('\n') => Self::Newline,
+            Some(c) if c.is_ascii_alphanumeric() || c == '_' => Self::Word,
+            Some(_) => Self::Other,
+        }
+    }
+}
+
+impl Look {
+    /// Whether the assertion holds between characters of kinds `before` and `after`.
+    pub(super) fn holds(self, before: Kind, after: Kind) -> bool {
+        match self {
+            Self::StartText => before == Kind::Edge,
+            Self::EndText => after == Kind::Edge,
+            Self::StartLine => matches!(before, Kind::Edge | Kind::Newline),
+            Self::EndLine => matches!(after, Kind::Edge | Kind::Newline),
+            Self::WordBoundary => (before == Kind::Word) != (after == Kind::Word),
+            Self::NotWordBoundary => (before == Kind::Word) == (after == Kind::Word),
+        }
+    }
+}
+
+/// A state of a Thompson NFA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum State {
+    /// Moves to the next state on a character of the class.
+    Class(CharClass, usize),
+    /// Moves to both states without a character, preferring the first.
+    Split(usize, usize),
+    /// Moves to the next state without a character if the assertion holds.
+    Look(Look, usize),
+    /// Records the position in a capture slot, and moves to the next state.
+    Save(usize, usize),
+    /// The pattern matched.
+    Match,
+}
+
+/// A Thompson NFA: a pattern compiled into states that move on single
+/// characters or on none, so that matching can follow all the ways a
+/// pattern might match at once.
+///
+/// # References
+///
+/// - [Russ Cox, Regular Expression Matching Can Be Simple And Fast](https://swtch.com/~rsc/regexp/regexp1.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Nfa {
+    pub(super) states: Vec<State>,
+    pub(super) start: usize,
+    /// The number of capture slots: the start and end of each group.
+    pub(super) slots: usize,
+}
+
+/// The pattern would compile to more than [`MAX_STATES`] states.
+#[derive(Debug)]
+pub(super) struct TooLarge;
+
+impl Nfa {
+    /// Compiles a pattern with `groups` capture groups, including the
+    /// whole match.
+    pub(super) fn new(ast: &Ast, groups: usize) -> Result<Self, TooLarge> {
+        let mut compiler = Compiler { states: Vec::new() };
+        let matched = compiler.push(State::Match)?;
+        let end = compiler.push(State::Save(1, matched))?;
+        let body = compiler.compile(ast, end)?;
+        let start = compiler.push(State::Save(0, body))?;
+        Ok(Self {
+            states: compiler.states,
+            start,
+            slots: 2 * groups,
+        })
+    }
+}
+
+struct Compiler {
+    states: Vec<State>,
+}
+
+impl Compiler {
+    fn push(&mut self, state: State) -> Result<usize, TooLarge> {
+        if self.states.len() == MAX_STATES {
+            return Err(TooLarge);
+        }
+        self.states.push(state);
+        Ok(self.states.len() - 1)
+    }
+
+    /// Adds the states of a pattern, whose matches go on to `next`.
+    ///
+    /// # Returns
+    ///
+    /// The first state of the pattern.
+    fn compile(&mut self, ast: &Ast, next: usize) -> Result<usize, TooLarge> {
+        match ast {
+            Ast::Empty => Ok(next),
+            Ast::Class(class) => self.push(State::Class(class.clone(), next)),
+            Ast::Look(look) => self.push(State::Look(*look, next)),
+            Ast::Capture { index, ast } => {
+                let end = self.push(State::Save(2 * index + 1, next))?;
+                let body = self.compile(ast, end)?;
+                self.push(State::Save(2 * index, body))
+            }
+            Ast::Concat(items) => items
+                .iter()
+                .rev()
+                .try_fold(next, |next, item| self.compile(item, next)),
+            Ast::Alternate(branches) => {
+                let starts = branches
+                    .iter()
+                    .map(|branch| self.compile(branch, next))
+                    .collect::<Result<Vec<usize>, TooLarge>>()?;
+                let (&last, rest) = starts.split_last().unwrap_or((&next, &[]));
+                rest.iter()
+                    .rev()
+                    .try_fold(last, |other, &first| self.push(State::Split(first, other)))
+            }
+            &Ast::Repeat {
+                ref ast,
+                min,
+                max,
+                greedy,
+            } => self.repeat(ast, min, max, greedy, next),
+        }
+    }
+
+    fn repeat(
+        &mut self,
+        ast: &Ast,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+        next: usize,
+    ) -> Result<usize, TooLarge> {
+        let split = |body, skip| {
+            if greedy {
+                State::Split(body, skip)
+            } else {
+                State::Split(skip, body)
+            }
+        };
+        let (mut first, mandatory) = match max {
+            None => {
+                // A split that loops back to the body, which is entered
+                // before it for `x+` and through it for `x*`.
+                let looped = self.push(State::Split(next, next))?;
+                let body = self.compile(ast, looped)?;
+                self.states[looped] = split(body, next);
+                if min == 0 {
+                    (looped, 0)
+                } else {
+                    (body, min - 1)
+                }
+            }
+            Some(max) => {
+                let mut first = next;
+                for _ in min..max {
+                    let body = self.compile(ast, first)?;
+                    first = self.push(split(body, next))?;
+                }
+                (first, min)
+            }
+        };
+        for _ in 0..mandatory {
+            first = self.compile(ast, first)?;
+        }
+        Ok(first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::strings::regex::parse::{parse, Flags};
+
+    fn compile(pattern: &str) -> Result<Nfa, TooLarge> {
+        let (ast, names) = parse(pattern, Flags::default()).unwrap();
+        Nfa::new(&ast, names.len())
+    }
+
+    #[test]
+    fn states() {
+        let nfa = compile("a|b").unwrap();
+        let a = State::Class(CharClass::char('a'), 1);
+        let b = State::Class(CharClass::char('b'), 1);
+        assert_eq!(
+            nfa.states,
+            [
+                State::Match,
+                State::Save(1, 0),
+                a,
+                b,
+                State::Split(2, 3),
+                State::Save(0, 4),
+            ]
+        );
+        assert_eq!((nfa.start, nfa.slots), (5, 2));
+    }
+
+    #[test]
+    fn size() {
+        // A state per character, group boundary and optional or repeated
+        // part, and three for the whole match.
+        assert_eq!(compile("a{2,5}").unwrap().states.len(), 5 + 3 + 3);
+        assert_eq!(compile("(a)+").unwrap().states.len(), 3 + 1 + 3);
+        assert!(compile("(a{1000}){1000}").is_err());
+    }
+
+    #[test]
+    fn looks() {
+        use Kind::{Edge, Newline, Other, Word};
+        assert!(Look::StartLine.holds(Newline, Word));
+        assert!(!Look::StartText.holds(Newline, Word));
+        assert!(Look::WordBoundary.holds(Edge, Word));
+        assert!(Look::WordBoundary.holds(Word, Other));
+        assert!(Look::NotWordBoundary.holds(Other, Edge));
+        assert!(Look::EndLine.holds(Other, Newline));
+    }
+}
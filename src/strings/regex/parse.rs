@@ -0,0 +1,653 @@
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::fmt;
+
+use super::{
+    class::{perl_class, posix_class, CharClass},
+    nfa::Look,
+};
+
+/// The deepest that groups may nest, so that parsing and compiling, which
+/// recurse into groups, cannot overflow the stack.
+const MAX_NESTING: usize = 250;
+
+/// The largest count of a counted repetition, as in `a{1000}`.
+const MAX_REPETITION: u32 = 1000;
+
+/// What is wrong with a pattern that [`Regex::new`](super::Regex::new) rejects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A `(` without a `)`.
+    UnclosedGroup,
+    /// A `)` without a `(`.
+    UnopenedGroup,
+    /// A `[` without a `]`.
+    UnclosedClass,
+    /// An unknown class name in `[[:name:]]`.
+    UnknownClass,
+    /// A range in a class that ends before it starts, or at a class, as in
+    /// `[z-a]` or `[a-\d]`.
+    InvalidRange,
+    /// An unknown escape, or a `\x` escape of no character.
+    InvalidEscape,
+    /// A repetition with nothing to repeat, as in `*a` or `a**`.
+    NothingToRepeat,
+    /// A malformed counted repetition, or one whose minimum is above its
+    /// maximum, as in `a{2,1}`.
+    InvalidRepetition,
+    /// A counted repetition above 1000.
+    RepetitionTooLarge,
+    /// A flag other than `i`, `m` and `s`.
+    InvalidFlag,
+    /// A capture group name that is empty, starts with a digit, or has a
+    /// character other than a letter, a digit or `_`.
+    InvalidGroupName,
+    /// Two capture groups with the same name.
+    DuplicateGroupName,
+    /// Groups nested more than 250 deep.
+    NestingTooDeep,
+    /// A pattern that compiles to too many states, as counted repetitions
+    /// of counted repetitions do.
+    TooLarge,
+}
+
+/// An error in a pattern, and where in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Error {
+    /// What is wrong.
+    pub kind: ErrorKind,
+    /// The byte offset in the pattern of the part that is wrong.
+    pub offset: usize,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            ErrorKind::UnclosedGroup => "unclosed group",
+            ErrorKind::UnopenedGroup => "unopened group",
+            ErrorKind::UnclosedClass => "unclosed character class",
+            ErrorKind::UnknownClass => "unknown character class",
+            ErrorKind::InvalidRange => "invalid range",
+            ErrorKind::InvalidEscape => "invalid escape",
+            ErrorKind::NothingToRepeat => "nothing to repeat",
+            ErrorKind::InvalidRepetition => "invalid repetition",
+            ErrorKind::RepetitionTooLarge => "repetition count too large",
+            ErrorKind::InvalidFlag => "invalid flag",
+            ErrorKind::InvalidGroupName => "invalid group name",
+            ErrorKind::DuplicateGroupName => "duplicate group name",
+            ErrorKind::NestingTooDeep => "groups nested too deeply",
+            ErrorKind::TooLarge => "pattern too large",
+        };
+        write!(f, "{message} at offset {}", self.offset)
+    }
+}
+
+/// The options that change what a pattern matches, which `(?ims)` sets
+/// within it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct Flags {
+    /// `i`: letters match regardless of case.
+    pub(super) case_insensitive: bool,
+    /// `m`: `^` and `$` match at the start and end of lines.
+    pub(super) multi_line: bool,
+    /// `s`: `.` matches `\n`.
+    pub(super) dot_matches_new_line: bool,
+}
+
+/// A parsed pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Ast {
+    /// Matches the empty string.
+    Empty,
+    /// Matches one character of the class.
+    Class(CharClass),
+    /// Matches the empty string where the assertion holds.
+    Look(Look),
+    /// Matches what the pattern does, and records where in a group.
+    Capture { index: usize, ast: Box<Ast> },
+    /// Matches what each pattern does, one after the other.
+    Concat(Vec<Ast>),
+    /// Matches what any of the patterns does, preferring the first.
+    Alternate(Vec<Ast>),
+    /// Matches what the pattern does, from `min` to `max` times, preferring
+    /// more if `greedy`.
+    Repeat {
+        ast: Box<Ast>,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+    },
+}
+
+/// A character of a class, or a class within it, as in `[a\d]`.
+enum ClassItem {
+    Char(char),
+    Class(CharClass),
+}
+
+/// Parses a pattern.
+///
+/// # Returns
+///
+/// The parsed pattern, and the names of its capture groups, where the
+/// first group is the whole match.
+pub(super) fn parse(pattern: &str, flags: Flags) -> Result<(Ast, Vec<Option<String>>), Error> {
+    let mut parser = Parser {
+        pattern,
+        offset: 0,
+        flags,
+        depth: 0,
+        names: vec![None],
+    };
+    let ast = parser.alternation()?;
+    if parser.offset < pattern.len() {
+        // Only a `)` stops an alternation before the end.
+        return Err(parser.error(ErrorKind::UnopenedGroup));
+    }
+    Ok((ast, parser.names))
+}
+
+struct Parser<'p> {
+    pattern: &'p str,
+    offset: usize,
+    flags: Flags,
+    depth: usize,
+    names: Vec<Option<String>>,
+}
+
+impl Parser<'_> {
+    fn error(&self, kind: ErrorKind) -> Error {
+        Error {
+            kind,
+            offset: self.offset,
+        }
+    }
+
+    fn rest(&self) -> &str {
+        &self.pattern[self.offset..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.offset += c.len_utf8();
+        }
+        eaten
+    }
+
+    fn alternation(&mut self) -> Result<Ast, Error> {
+        let mut branches = vec![self.concat()?];
+        while self.eat('|') {
+            branches.push(self.concat()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.swap_remove(0)
+        } else {
+            Ast::Alternate(branches)
+        })
+    }
+
+    fn concat(&mut self) -> Result<Ast, Error> {
+        let mut items = Vec::new();
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            match self.repetition()? {
+                Ast::Empty => {}
+                item => items.push(item),
+            }
+        }
+        Ok(match items.len() {
+            0 => Ast::Empty,
+            1 => items.swap_remove(0),
+            _ => Ast::Concat(items),
+        })
+    }
+
+    fn repetition(&mut self) -> Result<Ast, Error> {
+        let mut ast = self.atom()?;
+        let mut repeated = false;
+        loop {
+            let start = self.offset;
+            let (min, max) = match self.peek() {
+                // A `{` is replaced by its counts.
+                Some('*' | '{') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                _ => return Ok(ast),
+            };
+            if repeated {
+                return Err(self.error(ErrorKind::NothingToRepeat));
+            }
+            let (min, max) = if self.bump() == Some('{') {
+                self.counted(start)?
+            } else {
+                (min, max)
+            };
+            let greedy = !self.eat('?');
+            ast = Ast::Repeat {
+                ast: Box::new(ast),
+                min,
+                max,
+                greedy,
+            };
+            repeated = true;
+        }
+    }
+
+    /// Parses the rest of a counted repetition, after the `{` at `start`.
+    fn counted(&mut self, start: usize) -> Result<(u32, Option<u32>), Error> {
+        let invalid = Error {
+            kind: ErrorKind::InvalidRepetition,
+            offset: start,
+        };
+        let min = self.number(start)?.ok_or(invalid)?;
+        let max = if self.eat(',') {
+            self.number(start)?
+        } else {
+            Some(min)
+        };
+        if !self.eat('}') || max.is_some_and(|max| max < min) {
+            return Err(invalid);
+        }
+        Ok((min, max))
+    }
+
+    /// Parses the decimal digits at the offset, if there are any.
+    fn number(&mut self, start: usize) -> Result<Option<u32>, Error> {
+        let digits = self.rest().bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return Ok(None);
+        }
+        let number = self.rest()[..digits]
+            .parse::<u32>()
+            .ok()
+            .filter(|&number| number <= MAX_REPETITION)
+            .ok_or(Error {
+                kind: ErrorKind::RepetitionTooLarge,
+                offset: start,
+            })?;
+        self.offset += digits;
+        Ok(Some(number))
+    }
+
+    fn atom(&mut self) -> Result<Ast, Error> {
+        let start = self.offset;
+        let c = self.bump().ok_or(self.error(ErrorKind::NothingToRepeat))?;
+        Ok(match c {
+            '(' => self.group(start)?,
+            '[' => Ast::Class(self.class(start)?),
+            '.' if self.flags.dot_matches_new_line => Ast::Class(CharClass::any()),
+            '.' => Ast::Class(CharClass::char('\n').negated()),
+            '^' if self.flags.multi_line => Ast::Look(Look::StartLine),
+            '^' => Ast::Look(Look::StartText),
+            '$' if self.flags.multi_line => Ast::Look(Look::EndLine),
+            '$' => Ast::Look(Look::EndText),
+            '\\' => match self.peek() {
+                Some('b') => self.look(Look::WordBoundary),
+                Some('B') => self.look(Look::NotWordBoundary),
+                Some('A') => self.look(Look::StartText),
+                Some('z') => self.look(Look::EndText),
+                _ => match self.escape(start)? {
+                    ClassItem::Char(c) => Ast::Class(self.literal(c)),
+                    ClassItem::Class(class) => Ast::Class(class),
+                },
+            },
+            '*' | '+' | '?' | '{' => {
+                return Err(Error {
+                    kind: ErrorKind::NothingToRepeat,
+                    offset: start,
+                })
+            }
+            c => Ast::Class(self.literal(c)),
+        })
+    }
+
+    /// Skips the letter of an escaped assertion.
+    fn look(&mut self, look: Look) -> Ast {
+        self.offset += 1;
+        Ast::Look(look)
+    }
+
+    /// The class of a character, with its other cases if case-insensitive.
+    fn literal(&self, c: char) -> CharClass {
+        let mut class = CharClass::char(c);
+        if self.flags.case_insensitive {
+            class.case_fold();
+        }
+        class
+    }
+
+    /// Parses the rest of an escape, after the `\` at `start`.
+    fn escape(&mut self, start: usize) -> Result<ClassItem, Error> {
+        let invalid = Error {
+            kind: ErrorKind::InvalidEscape,
+            offset: start,
+        };
+        let c = self.bump().ok_or(invalid)?;
+        if let Some(class) = perl_class(c) {
+            return Ok(ClassItem::Class(class));
+        }
+        Ok(ClassItem::Char(match c {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'f' => '\x0C',
+            'v' => '\x0B',
+            'x' => self.hex().ok_or(invalid)?,
+            c if c.is_ascii_punctuation() => c,
+            _ => return Err(invalid),
+        }))
+    }
+
+    /// Parses the code point of a `\x` escape: two hexadecimal digits, or
+    /// up to eight in braces.
+    fn hex(&mut self) -> Option<char> {
+        let (digits, len) = if self.eat('{') {
+            let digits = self.rest().find('}')?;
+            (&self.rest()[..digits], digits + 1)
+        } else {
+            (self.rest().get(..2)?, 2)
+        };
+        if digits.is_empty() || digits.len() > 8 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let c = char::from_u32(u32::from_str_radix(digits, 16).ok()?)?;
+        self.offset += len;
+        Some(c)
+    }
+
+    /// Parses the rest of a bracketed class, after the `[` at `start`.
+    fn class(&mut self, start: usize) -> Result<CharClass, Error> {
+        let negated = self.eat('^');
+        let mut class = CharClass::default();
+        let mut first = true;
+        loop {
+            let at = self.offset;
+            let item = match self.bump() {
+                None => {
+                    return Err(Error {
+                        kind: ErrorKind::UnclosedClass,
+                        offset: start,
+                    })
+                }
+                Some(']') if !first => break,
+                Some('[') if self.rest().starts_with(':') => {
+                    let name = self.rest()[1..]
+                        .split_once(":]")
+                        .map(|(name, _)| name)
+                        .filter(|name| name.bytes().all(|b| b.is_ascii_lowercase()));
+                    let posix = name.and_then(posix_class).ok_or(Error {
+                        kind: ErrorKind::UnknownClass,
+                        offset: at,
+                    })?;
+                    self.offset += name.map_or(0, str::len) + 3;
+                    ClassItem::Class(posix)
+                }
+                Some('\\') => self.escape(at)?,
+                Some(c) => ClassItem::Char(c),
+            };
+            first = false;
+
+            let ClassItem::Char(lo) = item else {
+                if let ClassItem::Class(item) = item {
+                    class.union(&item);
+                }
+                continue;
+            };
+            let hi = if self.rest().starts_with('-') && !self.rest().starts_with("-]") {
+                self.offset += 1;
+                let end = self.offset;
+                let hi = match self.bump() {
+                    Some('\\') => self.escape(end)?,
+                    Some(c) => ClassItem::Char(c),
+                    None => {
+                        return Err(Error {
+                            kind: ErrorKind::UnclosedClass,
+                            offset: start,
+                        })
+                    }
+                };
+                match hi {
+                    ClassItem::Char(hi) if lo <= hi => hi,
+                    _ => {
+                        return Err(Error {
+                            kind: ErrorKind::InvalidRange,
+                            offset: at,
+                        })
+                    }
+                }
+            } else {
+                lo
+            };
+            class.union(&CharClass::new([(lo, hi)]));
+        }
+        if self.flags.case_insensitive {
+            class.case_fold();
+        }
+        Ok(if negated { class.negated() } else { class })
+    }
+
+    /// Parses the rest of a group, after the `(` at `start`.
+    fn group(&mut self, start: usize) -> Result<Ast, Error> {
+        self.depth += 1;
+        if self.depth > MAX_NESTING {
+            return Err(Error {
+                kind: ErrorKind::NestingTooDeep,
+                offset: start,
+            });
+        }
+        let outer = self.flags;
+
+        let index = if self.eat('?') {
+            if self.eat('<') || self.rest().starts_with("P<") && self.eat('P') && self.eat('<') {
+                Some(self.group_name()?)
+            } else if self.set_flags(start)? {
+                None
+            } else {
+                // The flags apply to the rest of the enclosing group.
+                self.depth -= 1;
+                return Ok(Ast::Empty);
+            }
+        } else {
+            self.names.push(None);
+            Some(self.names.len() - 1)
+        };
+
+        let ast = self.alternation()?;
+        if !self.eat(')') {
+            return Err(Error {
+                kind: ErrorKind::UnclosedGroup,
+                offset: start,
+            });
+        }
+        self.flags = outer;
+        self.depth -= 1;
+        Ok(match index {
+            Some(index) => Ast::Capture {
+                index,
+                ast: Box::new(ast),
+            },
+            None => ast,
+        })
+    }
+
+    /// Parses the name of a capture group up to its `>`, and numbers the group.
+    fn group_name(&mut self) -> Result<usize, Error> {
+        let start = self.offset;
+        let invalid = Error {
+            kind: ErrorKind::InvalidGroupName,
+            offset: start,
+        };
+        let len = self.rest().find('>').ok_or(invalid)?;
+        let name = &self.rest()[..len];
+        let valid = name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+            && name.bytes().next().is_some_and(|b| !b.is_ascii_digit());
+        if !valid {
+            return Err(invalid);
+        }
+        if self.names.iter().flatten().any(|other| other == name) {
+            return Err(Error {
+                kind: ErrorKind::DuplicateGroupName,
+                offset: start,
+            });
+        }
+        self.names.push(Some(name.into()));
+        self.offset += len + 1;
+        Ok(self.names.len() - 1)
+    }
+
+    /// Parses the flags of a group after its `(?`, as in `(?i-s)` or
+    /// `(?m:...)`, and sets them.
+    ///
+    /// # Returns
+    ///
+    /// Whether a pattern follows the flags in the group.
+    fn set_flags(&mut self, start: usize) -> Result<bool, Error> {
+        let mut enable = true;
+        loop {
+            let flag = match self.peek() {
+                Some('i') => &mut self.flags.case_insensitive,
+                Some('m') => &mut self.flags.multi_line,
+                Some('s') => &mut self.flags.dot_matches_new_line,
+                Some('-') if enable => {
+                    enable = false;
+                    self.offset += 1;
+                    continue;
+                }
+                Some(':') => {
+                    self.offset += 1;
+                    return Ok(true);
+                }
+                Some(')') => {
+                    self.offset += 1;
+                    return Ok(false);
+                }
+                Some(_) => return Err(self.error(ErrorKind::InvalidFlag)),
+                None => {
+                    return Err(Error {
+                        kind: ErrorKind::UnclosedGroup,
+                        offset: start,
+                    })
+                }
+            };
+            *flag = enable;
+            self.offset += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    fn class(ranges: &[(char, char)]) -> Ast {
+        Ast::Class(CharClass::new(ranges.iter().copied()))
+    }
+
+    #[test]
+    fn structure() {
+        let (ast, names) = parse("a(?<x>b|c)*?$", Flags::default()).unwrap();
+        assert_eq!(
+            ast,
+            Ast::Concat(vec![
+                class(&[('a', 'a')]),
+                Ast::Repeat {
+                    ast: Box::new(Ast::Capture {
+                        index: 1,
+                        ast: Box::new(Ast::Alternate(vec![
+                            class(&[('b', 'b')]),
+                            class(&[('c', 'c')])
+                        ])),
+                    }),
+                    min: 0,
+                    max: None,
+                    greedy: false,
+                },
+                Ast::Look(Look::EndText),
+            ])
+        );
+        assert_eq!(names, [None, Some("x".into())]);
+    }
+
+    #[test_case("[a-c\\d_]", &[('0', '9'), ('_', '_'), ('a', 'c')]; "ranges and classes")]
+    #[test_case("[]a]", &[(']', ']'), ('a', 'a')]; "leading bracket")]
+    #[test_case("[a-]", &[('-', '-'), ('a', 'a')]; "trailing dash")]
+    #[test_case("[[:xdigit:]]", &[('0', '9'), ('A', 'F'), ('a', 'f')]; "posix")]
+    #[test_case("[\\x41-\\x{43}]", &[('A', 'C')]; "hex")]
+    #[test_case("(?i)[a-b]", &[('A', 'B'), ('a', 'b')]; "case insensitive")]
+    #[test_case("[^\\x00-\\x{10FFFF}]", &[]; "negated")]
+    fn classes(pattern: &str, expected: &[(char, char)]) {
+        assert_eq!(parse(pattern, Flags::default()).unwrap().0, class(expected));
+    }
+
+    #[test]
+    fn scoped_flags() {
+        let (ast, _) = parse("(?i:a)a(?i)a", Flags::default()).unwrap();
+        let folded = class(&[('A', 'A'), ('a', 'a')]);
+        assert_eq!(
+            ast,
+            Ast::Concat(vec![folded.clone(), class(&[('a', 'a')]), folded])
+        );
+        let (ast, _) = parse(
+            "(?s-m).$",
+            Flags {
+                multi_line: true,
+                ..Flags::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            ast,
+            Ast::Concat(vec![Ast::Class(CharClass::any()), Ast::Look(Look::EndText)])
+        );
+    }
+
+    #[test_case("(a", ErrorKind::UnclosedGroup, 0)]
+    #[test_case("a)", ErrorKind::UnopenedGroup, 1)]
+    #[test_case("[a", ErrorKind::UnclosedClass, 0)]
+    #[test_case("[[:alpah:]]", ErrorKind::UnknownClass, 1)]
+    #[test_case("[z-a]", ErrorKind::InvalidRange, 1)]
+    #[test_case("[a-\\d]", ErrorKind::InvalidRange, 1)]
+    #[test_case("\\q", ErrorKind::InvalidEscape, 0)]
+    #[test_case("a\\", ErrorKind::InvalidEscape, 1)]
+    #[test_case("\\x{D800}", ErrorKind::InvalidEscape, 0)]
+    #[test_case("*a", ErrorKind::NothingToRepeat, 0)]
+    #[test_case("a**", ErrorKind::NothingToRepeat, 2)]
+    #[test_case("(|*)", ErrorKind::NothingToRepeat, 2)]
+    #[test_case("a{2,1}", ErrorKind::InvalidRepetition, 1)]
+    #[test_case("a{,1}", ErrorKind::InvalidRepetition, 1)]
+    #[test_case("a{1", ErrorKind::InvalidRepetition, 1)]
+    #[test_case("a{1001}", ErrorKind::RepetitionTooLarge, 1)]
+    #[test_case("(?x)", ErrorKind::InvalidFlag, 2)]
+    #[test_case("(?<1a>)", ErrorKind::InvalidGroupName, 3)]
+    #[test_case("(?<a>)(?P<a>)", ErrorKind::DuplicateGroupName, 10)]
+    fn errors(pattern: &str, kind: ErrorKind, offset: usize) {
+        assert_eq!(
+            parse(pattern, Flags::default()),
+            Err(Error { kind, offset })
+        );
+    }
+
+    #[test]
+    fn nesting() {
+        let deep = |depth| "(".repeat(depth) + &")".repeat(depth);
+        assert!(parse(&deep(MAX_NESTING), Flags::default()).is_ok());
+        assert_eq!(
+            parse(&deep(MAX_NESTING + 1), Flags::default()).map(|_| ()),
+            Err(Error {
+                kind: ErrorKind::NestingTooDeep,
+                offset: MAX_NESTING
+            })
+        );
+    }
+}
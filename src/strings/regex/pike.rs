@@ -0,0 +1,233 @@
+use alloc::{vec, vec::Vec};
+use core::mem;
+
+use super::nfa::{Kind, Nfa, State};
+
+/// The threads of a Pike VM at one position: the states it is in, in order
+/// of priority, with the capture slots of each.
+#[derive(Debug, Clone)]
+struct Threads {
+    order: Vec<usize>,
+    present: Vec<bool>,
+    /// The slots of each state, `stride` at a time.
+    slots: Vec<Option<usize>>,
+    stride: usize,
+}
+
+impl Threads {
+    fn new(nfa: &Nfa) -> Self {
+        Self {
+            order: Vec::with_capacity(nfa.states.len()),
+            present: vec![false; nfa.states.len()],
+            slots: Vec::new(),
+            stride: 0,
+        }
+    }
+
+    /// Empties the threads, and makes room for `stride` slots per state.
+    fn reset(&mut self, stride: usize) {
+        self.clear();
+        self.slots.resize(self.present.len() * stride, None);
+        self.stride = stride;
+    }
+
+    /// Adds a state, unless it is already there.
+    ///
+    /// # Returns
+    ///
+    /// Whether the state was added.
+    fn insert(&mut self, state: usize) -> bool {
+        let inserted = !self.present[state];
+        if inserted {
+            self.present[state] = true;
+            self.order.push(state);
+        }
+        inserted
+    }
+
+    fn slots(&self, state: usize) -> &[Option<usize>] {
+        &self.slots[state * self.stride..(state + 1) * self.stride]
+    }
+
+    fn slots_mut(&mut self, state: usize) -> &mut [Option<usize>] {
+        &mut self.slots[state * self.stride..(state + 1) * self.stride]
+    }
+
+    fn clear(&mut self) {
+        for &state in &self.order {
+            self.present[state] = false;
+        }
+        self.order.clear();
+    }
+}
+
+/// The buffers of a Pike VM, kept between searches.
+#[derive(Debug, Clone)]
+pub(super) struct Cache {
+    current: Threads,
+    next: Threads,
+    stack: Vec<Frame>,
+    slots: Vec<Option<usize>>,
+}
+
+impl Cache {
+    pub(super) fn new(nfa: &Nfa) -> Self {
+        Self {
+            current: Threads::new(nfa),
+            next: Threads::new(nfa),
+            stack: Vec::new(),
+            slots: Vec::new(),
+        }
+    }
+}
+
+/// A step of following the states that need no character.
+#[derive(Debug, Clone, Copy)]
+enum Frame {
+    Explore(usize),
+    /// Undoes a `Save` once the states after it are explored.
+    Restore(usize, Option<usize>),
+}
+
+/// Finds the leftmost-first match of a pattern in a text, at or after
+/// `start`, tracking the first `slots` capture slots: two for only where
+/// the whole match is, or all of them for every group.
+///
+/// The VM moves every thread a character at a time, so each character
+/// costs at most one step per state. Threads keep the order of the choices
+/// that led to them, so the match found is the one whose choices come first
+/// in the pattern, as in RE2, apart from the empty iterations that a
+/// repetition does not repeat.
+///
+/// # Returns
+///
+/// The capture slots of the match.
+///
+/// # References
+///
+/// - [Russ Cox, Regular Expression Matching: the Virtual Machine Approach](https://swtch.com/~rsc/regexp/regexp2.html)
+pub(super) fn search(
+    nfa: &Nfa,
+    cache: &mut Cache,
+    text: &str,
+    start: usize,
+    slots: usize,
+) -> Option<Vec<Option<usize>>> {
+    let Cache {
+        current,
+        next,
+        stack,
+        slots: scratch,
+    } = cache;
+    current.reset(slots);
+    next.reset(slots);
+    scratch.resize(slots, None);
+    let mut matched = None;
+
+    let mut at = start;
+    let mut before = Kind::of(text[..start].chars().next_back());
+    let mut chars = text[start..].chars().peekable();
+    loop {
+        let c = chars.next();
+        let after = Kind::of(c);
+        if matched.is_none() {
+            // A match starting here has lower priority than those that
+            // started earlier.
+            scratch.fill(None);
+            let context = Context { at, before, after };
+            follow(nfa, current, stack, scratch, nfa.start, context);
+        }
+        if current.order.is_empty() {
+            break;
+        }
+
+        let Some(c) = c else {
+            if let Some(&state) = current
+                .order
+                .iter()
+                .find(|&&state| nfa.states[state] == State::Match)
+            {
+                matched = Some(current.slots(state).to_vec());
+            }
+            break;
+        };
+        let context = Context {
+            at: at + c.len_utf8(),
+            before: after,
+            after: Kind::of(chars.peek().copied()),
+        };
+        for &state in &current.order {
+            match &nfa.states[state] {
+                State::Class(class, target) if class.contains(c) => {
+                    scratch.copy_from_slice(current.slots(state));
+                    follow(nfa, next, stack, scratch, *target, context);
+                }
+                State::Match => {
+                    // The threads after this one have lower priority.
+                    matched = Some(current.slots(state).to_vec());
+                    break;
+                }
+                _ => {}
+            }
+        }
+        mem::swap(current, next);
+        next.clear();
+        at = context.at;
+        before = context.before;
+    }
+    matched
+}
+
+/// A position in the text, with the kinds of the characters around it.
+#[derive(Debug, Clone, Copy)]
+struct Context {
+    at: usize,
+    before: Kind,
+    after: Kind,
+}
+
+/// Adds a thread in a state, and in all the states it reaches without a
+/// character, in order of priority.
+fn follow(
+    nfa: &Nfa,
+    threads: &mut Threads,
+    stack: &mut Vec<Frame>,
+    slots: &mut [Option<usize>],
+    state: usize,
+    context: Context,
+) {
+    stack.push(Frame::Explore(state));
+    while let Some(frame) = stack.pop() {
+        let mut state = match frame {
+            Frame::Explore(state) => state,
+            Frame::Restore(slot, value) => {
+                slots[slot] = value;
+                continue;
+            }
+        };
+        while threads.insert(state) {
+            match nfa.states[state] {
+                State::Split(first, second) => {
+                    stack.push(Frame::Explore(second));
+                    state = first;
+                }
+                State::Look(look, target) if look.holds(context.before, context.after) => {
+                    state = target;
+                }
+                State::Save(slot, target) => {
+                    // Slots past those tracked are not recorded.
+                    if let Some(saved) = slots.get_mut(slot) {
+                        stack.push(Frame::Restore(slot, *saved));
+                        *saved = Some(context.at);
+                    }
+                    state = target;
+                }
+                State::Class(..) | State::Match => {
+                    threads.slots_mut(state).copy_from_slice(slots);
+                    break;
+                }
+                State::Look(..) => break,
+            }
+        }
+    }
+}
@@ -3,10 +3,15 @@ use alloc::{
     vec::Vec,
 };
 
+use super::segmentation::graphemes;
+
 /// Trait that provides methods for reversing strings and their components.
 pub trait Reversible {
     /// Reverses the order of characters in a word.
     ///
+    /// Characters are extended grapheme clusters, so accents stay on their letters and emoji
+    /// keep their modifiers.
+    ///
     /// # Arguments
     ///
     /// * `word` - The word to reverse.
@@ -22,6 +27,7 @@ pub trait Reversible {
     ///
     /// let reversed = "hello".reversed_word();
     /// assert_eq!(reversed, "olleh");
+    /// assert_eq!("cafe\u{301} 👍🏽🇳🇴".reversed_word(), "🇳🇴👍🏽 e\u{301}fac");
     /// ```
     fn reversed_word(&self) -> String;
 
@@ -47,8 +53,26 @@ pub trait Reversible {
     where
         String: From<P>;
 
+    /// Reverses the order of words in a sentence, keeping the whitespace between them as it is.
+    ///
+    /// # Returns
+    ///
+    /// A new `String` with the words in reverse order, and each run of whitespace where it was.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algoritmer::strings::Reversible;
+    ///
+    /// let reversed = "  Rust is\tawesome\n".reversed_word_order_keeping_whitespace();
+    /// assert_eq!(reversed, "  awesome is\tRust\n");
+    /// ```
+    fn reversed_word_order_keeping_whitespace(&self) -> String;
+
     /// Reverses all words longer than `n` characters in a sentence.
     ///
+    /// Characters are extended grapheme clusters, as in [`Reversible::reversed_word`].
+    ///
     /// # Arguments
     ///
     /// * `sentence` - The sentence to process.
@@ -69,11 +93,60 @@ pub trait Reversible {
     fn reversed_words_longer_than<P>(&self, n: usize, pattern: P) -> String
     where
         String: From<P>;
+
+    /// Reverses all words longer than `n` characters in a sentence, keeping the whitespace
+    /// between them as it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The minimum length of a word before it can be reversed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algoritmer::strings::Reversible;
+    ///
+    /// let result = "Rust  is great,\nJava too".reversed_words_longer_than_keeping_whitespace(4);
+    /// assert_eq!(result, "Rust  is ,taerg\nJava too");
+    /// ```
+    fn reversed_words_longer_than_keeping_whitespace(&self, n: usize) -> String;
+}
+
+/// Splits a text into its runs of whitespace and the words between them.
+///
+/// # Returns
+///
+/// Each run, with whether it is whitespace.
+fn runs(text: &str) -> Vec<(bool, &str)> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    let mut whitespace = None;
+    for (i, c) in text.char_indices() {
+        if whitespace.is_some_and(|whitespace| whitespace != c.is_whitespace()) {
+            runs.push((!c.is_whitespace(), &text[start..i]));
+            start = i;
+        }
+        whitespace = Some(c.is_whitespace());
+    }
+    if let Some(whitespace) = whitespace {
+        runs.push((whitespace, &text[start..]));
+    }
+    runs
+}
+
+/// Reverses a word if it has more than `n` characters.
+fn reversed_if_longer(word: &str, n: usize) -> String {
+    if graphemes(word).nth(n).is_some() {
+        word.reversed_word()
+    } else {
+        word.to_string()
+    }
 }
 
 impl Reversible for str {
     fn reversed_word(&self) -> String {
-        self.chars().rev().collect()
+        let clusters: Vec<&str> = graphemes(self).collect();
+        clusters.into_iter().rev().collect()
     }
 
     fn reversed_word_order<P>(&self, pattern: P) -> String
@@ -86,20 +159,41 @@ impl Reversible for str {
             .join(&String::from(pattern))
     }
 
+    fn reversed_word_order_keeping_whitespace(&self) -> String {
+        let runs = runs(self);
+        let mut words = runs.iter().filter(|(whitespace, _)| !whitespace).rev();
+        runs.iter()
+            .map(|&(whitespace, run)| {
+                if whitespace {
+                    run
+                } else {
+                    words.next().map_or(run, |&(_, word)| word)
+                }
+            })
+            .collect()
+    }
+
     fn reversed_words_longer_than<P>(&self, n: usize, pattern: P) -> String
     where
         String: From<P>,
     {
         self.split_whitespace()
-            .map(|word| {
-                if word.len() > n {
-                    word.chars().rev().collect::<String>()
+            .map(|word| reversed_if_longer(word, n))
+            .collect::<Vec<String>>()
+            .join(&String::from(pattern))
+    }
+
+    fn reversed_words_longer_than_keeping_whitespace(&self, n: usize) -> String {
+        runs(self)
+            .into_iter()
+            .map(|(whitespace, run)| {
+                if whitespace {
+                    run.to_string()
                 } else {
-                    word.to_string()
+                    reversed_if_longer(run, n)
                 }
             })
-            .collect::<Vec<String>>()
-            .join(&String::from(pattern))
+            .collect()
     }
 }
 
@@ -114,4 +208,36 @@ mod tests {
         let actual = sentence.reversed_words_longer_than(index, ' ');
         assert_eq!(actual, "Hey fellow warriors");
     }
+
+    #[test_case("", ""; "empty")]
+    #[test_case("noël", "lëon"; "precomposed")]
+    #[test_case("noe\u{308}l", "le\u{308}on"; "combining")]
+    #[test_case("🇸🇪🇩🇰", "🇩🇰🇸🇪"; "flags")]
+    #[test_case("a👨‍👩‍👧b", "b👨‍👩‍👧a"; "zwj sequence")]
+    #[test_case("x\r\ny", "y\r\nx"; "crlf")]
+    fn test_reversed_word(word: &str, expected: &str) {
+        assert_eq!(word.reversed_word(), expected);
+    }
+
+    #[test_case("", ""; "empty")]
+    #[test_case("   ", "   "; "only whitespace")]
+    #[test_case("one", "one"; "one word")]
+    #[test_case(" a  b\tc\n", " c  b\ta\n"; "runs")]
+    #[test_case("a\u{3000}b", "b\u{3000}a"; "ideographic space")]
+    fn test_reversed_word_order_keeping_whitespace(sentence: &str, expected: &str) {
+        assert_eq!(sentence.reversed_word_order_keeping_whitespace(), expected);
+    }
+
+    #[test]
+    fn test_reversed_words_longer_than_counts_graphemes() {
+        let sentence = "ce\u{301}de\u{301}  tête";
+        assert_eq!(
+            sentence.reversed_words_longer_than(4, ' '),
+            sentence.split_whitespace().collect::<Vec<_>>().join(" ")
+        );
+        assert_eq!(
+            sentence.reversed_words_longer_than_keeping_whitespace(3),
+            "e\u{301}de\u{301}c  etêt"
+        );
+    }
 }
@@ -0,0 +1,795 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::ops::Range;
+
+/// The root state of the trie, reached by the empty string.
+const ROOT: usize = 0;
+
+/// The fewest starting offsets whose preferred matches are found at once by
+/// a leftmost search.
+const BLOCK: usize = 1024;
+
+/// An occurrence of one of several patterns, such as those of an [`AhoCorasick`] automaton.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// The index of the pattern that matched, in the order the patterns were given.
+    pub pattern: usize,
+    /// The byte offset where the occurrence starts.
+    pub start: usize,
+    /// The byte offset just past the end of the occurrence.
+    pub end: usize,
+}
+
+/// Which match to report when several patterns match at overlapping positions.
+///
+/// Both kinds report the match that starts first; they differ in how they
+/// choose between the patterns that match starting at the same position.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MatchKind {
+    /// Prefer the pattern that was given first, like an alternation in a regular expression.
+    #[default]
+    LeftmostFirst,
+    /// Prefer the longest pattern.
+    LeftmostLongest,
+}
+
+/// A state of the trie of patterns.
+#[derive(Debug, Clone, Default)]
+struct State {
+    /// The transitions of the trie, sorted by byte.
+    next: Vec<(u8, usize)>,
+    /// The state of the longest proper suffix of this state's string that is in the trie.
+    fail: usize,
+    /// The nearest state along the failure links that has outputs.
+    dict: Option<usize>,
+    /// The patterns equal to this state's string.
+    outputs: Vec<usize>,
+}
+
+impl State {
+    fn goto(&self, byte: u8) -> Option<usize> {
+        self.next
+            .binary_search_by_key(&byte, |&(b, _)| b)
+            .ok()
+            .map(|i| self.next[i].1)
+    }
+}
+
+/// A full transition table, indexed by state and byte class.
+#[derive(Debug, Clone)]
+struct Dfa {
+    /// Bytes that no pattern tells apart share a class.
+    classes: [u8; 256],
+    stride: usize,
+    table: Vec<u32>,
+}
+
+/// A trie of patterns with its failure links, optionally compiled into a DFA.
+#[derive(Debug, Clone)]
+struct Trie {
+    states: Vec<State>,
+    ascii_case_insensitive: bool,
+    dfa: Option<Dfa>,
+}
+
+impl Trie {
+    /// Builds the trie of `patterns`, whose bytes are already folded if
+    /// `ascii_case_insensitive`.
+    ///
+    /// # Returns
+    ///
+    /// The trie and its states in breadth-first order.
+    fn new<I, P>(patterns: I, ascii_case_insensitive: bool, dfa: bool) -> (Self, Vec<usize>)
+    where
+        I: IntoIterator<Item = P>,
+        P: IntoIterator<Item = u8>,
+    {
+        let mut states = vec![State::default()];
+        for (index, pattern) in patterns.into_iter().enumerate() {
+            let mut state = ROOT;
+            for byte in pattern {
+                state = if let Some(next) = states[state].goto(byte) {
+                    next
+                } else {
+                    let next = states.len();
+                    states.push(State::default());
+                    let transitions = &mut states[state].next;
+                    let at = transitions.partition_point(|&(b, _)| b < byte);
+                    transitions.insert(at, (byte, next));
+                    next
+                };
+            }
+            states[state].outputs.push(index);
+        }
+
+        // Failure links point to shallower states, so they are set in breadth-first order.
+        let mut order = Vec::with_capacity(states.len());
+        let mut queue = VecDeque::from([ROOT]);
+        while let Some(state) = queue.pop_front() {
+            order.push(state);
+            for i in 0..states[state].next.len() {
+                let (byte, child) = states[state].next[i];
+                let fail = if state == ROOT {
+                    ROOT
+                } else {
+                    let mut fail = states[state].fail;
+                    loop {
+                        if let Some(next) = states[fail].goto(byte) {
+                            break next;
+                        }
+                        if fail == ROOT {
+                            break ROOT;
+                        }
+                        fail = states[fail].fail;
+                    }
+                };
+                states[child].fail = fail;
+                states[child].dict = if states[fail].outputs.is_empty() {
+                    states[fail].dict
+                } else {
+                    Some(fail)
+                };
+                queue.push_back(child);
+            }
+        }
+
+        let mut trie = Self {
+            states,
+            ascii_case_insensitive,
+            dfa: None,
+        };
+        if dfa {
+            trie.dfa = Some(trie.compile(&order));
+        }
+        (trie, order)
+    }
+
+    fn next_state(&self, state: usize, byte: u8) -> usize {
+        if let Some(dfa) = &self.dfa {
+            return dfa.table[state * dfa.stride + usize::from(dfa.classes[usize::from(byte)])]
+                as usize;
+        }
+
+        let byte = if self.ascii_case_insensitive {
+            byte.to_ascii_lowercase()
+        } else {
+            byte
+        };
+        let mut state = state;
+        loop {
+            if let Some(next) = self.states[state].goto(byte) {
+                return next;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = self.states[state].fail;
+        }
+    }
+
+    /// Precomputes every transition, visiting the states in breadth-first `order`.
+    fn compile(&self, order: &[usize]) -> Dfa {
+        let mut used = [false; 256];
+        for state in &self.states {
+            for &(byte, _) in &state.next {
+                used[usize::from(byte)] = true;
+            }
+        }
+
+        // Every byte no pattern uses goes in one class, after those that are used.
+        let mut classes = [0; 256];
+        let mut representatives = Vec::new();
+        for byte in 0..=u8::MAX {
+            if used[usize::from(byte)] {
+                classes[usize::from(byte)] = u8::try_from(representatives.len()).unwrap_or(u8::MAX);
+                representatives.push(byte);
+            }
+        }
+        let other = u8::try_from(representatives.len()).unwrap_or(u8::MAX);
+        for byte in 0..=u8::MAX {
+            if !used[usize::from(byte)] {
+                classes[usize::from(byte)] = other;
+            }
+        }
+        if self.ascii_case_insensitive {
+            for byte in b'A'..=b'Z' {
+                classes[usize::from(byte)] = classes[usize::from(byte.to_ascii_lowercase())];
+            }
+        }
+
+        let stride = representatives.len() + 1;
+        let mut table = vec![0; self.states.len() * stride];
+        for &state in order {
+            for (class, &byte) in representatives.iter().enumerate() {
+                let next = match self.states[state].goto(byte) {
+                    Some(next) => u32::try_from(next).expect("too many states for a DFA"),
+                    None if state == ROOT => 0,
+                    None => table[self.states[state].fail * stride + class],
+                };
+                table[state * stride + class] = next;
+            }
+        }
+
+        Dfa {
+            classes,
+            stride,
+            table,
+        }
+    }
+}
+
+/// Configures and builds an [`AhoCorasick`] automaton.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{AhoCorasick, MatchKind};
+///
+/// let ac = AhoCorasick::builder()
+///     .match_kind(MatchKind::LeftmostLongest)
+///     .ascii_case_insensitive(true)
+///     .dfa(true)
+///     .build(["Sam", "samwise"]);
+///
+/// let found = ac.find("Hello SAMWISE").unwrap();
+/// assert_eq!((found.pattern, found.start, found.end), (1, 6, 13));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct AhoCorasickBuilder {
+    match_kind: MatchKind,
+    ascii_case_insensitive: bool,
+    dfa: bool,
+}
+
+impl AhoCorasickBuilder {
+    /// A builder for a case-sensitive, leftmost-first automaton without a DFA.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how [`find`](AhoCorasick::find) and
+    /// [`find_iter`](AhoCorasick::find_iter) choose between matches.
+    #[must_use]
+    pub fn match_kind(mut self, match_kind: MatchKind) -> Self {
+        self.match_kind = match_kind;
+        self
+    }
+
+    /// Sets whether ASCII letters match regardless of case.
+    ///
+    /// Other bytes, including those of non-ASCII letters, must match exactly.
+    #[must_use]
+    pub fn ascii_case_insensitive(mut self, yes: bool) -> Self {
+        self.ascii_case_insensitive = yes;
+        self
+    }
+
+    /// Sets whether to compile the automaton into a DFA.
+    ///
+    /// A DFA precomputes the transition for every state and byte, so each
+    /// byte of the haystack costs a single table lookup instead of following
+    /// failure links. This takes memory proportional to the number of states
+    /// times the number of distinct bytes in the patterns, for both the trie
+    /// of the patterns and that of the reversed patterns.
+    #[must_use]
+    pub fn dfa(mut self, yes: bool) -> Self {
+        self.dfa = yes;
+        self
+    }
+
+    /// Builds an automaton searching for `patterns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a DFA is requested for patterns with more than `u32::MAX` bytes in total.
+    #[must_use]
+    pub fn build<I, P>(&self, patterns: I) -> AhoCorasick
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        let fold = |byte: &u8| {
+            if self.ascii_case_insensitive {
+                byte.to_ascii_lowercase()
+            } else {
+                *byte
+            }
+        };
+
+        let patterns: Vec<P> = patterns.into_iter().collect();
+        let lengths: Vec<usize> = patterns.iter().map(|p| p.as_ref().len()).collect();
+        let (forward, _) = Trie::new(
+            patterns.iter().map(|p| p.as_ref().iter().map(fold)),
+            self.ascii_case_insensitive,
+            self.dfa,
+        );
+        let (reverse, order) = Trie::new(
+            patterns.iter().map(|p| p.as_ref().iter().rev().map(fold)),
+            self.ascii_case_insensitive,
+            self.dfa,
+        );
+
+        // The reversed patterns that end at a state of the reversed trie are
+        // the patterns that start where the state was reached.
+        let mut preferred = vec![None; reverse.states.len()];
+        for &state in &order {
+            let own = reverse.states[state].outputs.first().copied();
+            let inherited = reverse.states[state].dict.and_then(|dict| preferred[dict]);
+            preferred[state] = match self.match_kind {
+                MatchKind::LeftmostFirst => own.into_iter().chain(inherited).min(),
+                MatchKind::LeftmostLongest => own.or(inherited),
+            };
+        }
+
+        AhoCorasick {
+            longest: lengths.iter().copied().max().unwrap_or(0),
+            forward,
+            reverse,
+            preferred,
+            lengths,
+            match_kind: self.match_kind,
+        }
+    }
+}
+
+/// An automaton that finds occurrences of many patterns at once with the
+/// Aho–Corasick algorithm.
+///
+/// The patterns are stored in a trie whose states are linked, like the
+/// failure table of the Knuth-Morris-Pratt algorithm, to the longest suffix
+/// of their string that is also in the trie. The haystack is then read once,
+/// following a trie edge or a failure link for each byte, whatever the number
+/// of patterns.
+///
+/// Leftmost searches also keep a trie of the reversed patterns. Read
+/// backwards from an offset, it reaches every pattern that starts there,
+/// so the preferred one is known without reading past the occurrences
+/// that have been reported. The haystack is read backwards in blocks, each
+/// together with the longest pattern's length past its end.
+///
+/// Patterns and haystacks are anything that can be viewed as bytes, and
+/// every position reported is a byte offset.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::AhoCorasick;
+///
+/// let ac = AhoCorasick::new(["he", "she", "his", "hers"]);
+/// let text = "ushers";
+///
+/// let found: Vec<(usize, usize)> = ac.find_iter(text).map(|m| (m.pattern, m.start)).collect();
+/// assert_eq!(found, [(1, 1)]);
+///
+/// let found: Vec<(usize, usize)> = ac
+///     .find_overlapping_iter(text)
+///     .map(|m| (m.pattern, m.start))
+///     .collect();
+/// assert_eq!(found, [(1, 1), (0, 2), (3, 2)]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Build: O(m) for patterns of total length `m`, plus O(m · σ) with a DFA
+///   over `σ` distinct bytes, for each of the two tries
+/// - Overlapping search: O(n + z) for `z` matches
+/// - Leftmost search ([`find`](Self::find) and [`find_iter`](Self::find_iter)):
+///   O(n). Blocks are at least as long as the longest pattern, so each byte
+///   is read at most twice.
+///
+/// ## Space Complexity
+///
+/// O(max(L, 1024)) while iterating over leftmost matches, for a longest
+/// pattern of `L` bytes.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm)
+/// - [Aho and Corasick, Efficient string matching: an aid to bibliographic search](https://doi.org/10.1145/360825.360855)
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    forward: Trie,
+    reverse: Trie,
+    /// The preferred pattern among those reaching each state of `reverse`.
+    preferred: Vec<Option<usize>>,
+    lengths: Vec<usize>,
+    longest: usize,
+    match_kind: MatchKind,
+}
+
+impl AhoCorasick {
+    /// Builds a case-sensitive, leftmost-first automaton searching for `patterns`.
+    #[must_use]
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        AhoCorasickBuilder::new().build(patterns)
+    }
+
+    /// A builder for configuring the automaton.
+    #[must_use]
+    pub fn builder() -> AhoCorasickBuilder {
+        AhoCorasickBuilder::new()
+    }
+
+    /// The number of patterns.
+    #[must_use]
+    pub fn patterns_len(&self) -> usize {
+        self.lengths.len()
+    }
+
+    /// How [`find`](Self::find) and [`find_iter`](Self::find_iter) choose between matches.
+    #[must_use]
+    pub fn match_kind(&self) -> MatchKind {
+        self.match_kind
+    }
+
+    /// Whether the automaton was compiled into a DFA.
+    #[must_use]
+    pub fn is_dfa(&self) -> bool {
+        self.forward.dfa.is_some()
+    }
+
+    /// Finds the first match, according to the [`MatchKind`].
+    ///
+    /// # Returns
+    ///
+    /// The match that starts first, or `None` if no pattern occurs.
+    pub fn find<H>(&self, haystack: &H) -> Option<Match>
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        self.find_iter(haystack).next()
+    }
+
+    /// Iterates over the matches that do not overlap, according to the [`MatchKind`].
+    ///
+    /// Each match resumes the search at its end. After a match of an empty
+    /// pattern, the search resumes one byte further on.
+    pub fn find_iter<'a, H>(&'a self, haystack: &'a H) -> impl Iterator<Item = Match> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let haystack = haystack.as_ref();
+        let mut start = 0;
+        let mut block = 0..0;
+        let mut preferred = Vec::new();
+
+        core::iter::from_fn(move || {
+            while start <= haystack.len() {
+                if !block.contains(&start) {
+                    block = start..(start + self.longest.max(BLOCK)).min(haystack.len() + 1);
+                    self.prefer(haystack, block.clone(), &mut preferred);
+                }
+                if let Some(pattern) = preferred[start - block.start] {
+                    let found = Match {
+                        pattern,
+                        start,
+                        end: start + self.lengths[pattern],
+                    };
+                    start = found.end + usize::from(found.start == found.end);
+                    return Some(found);
+                }
+                start += 1;
+            }
+            None
+        })
+    }
+
+    /// Iterates over every occurrence of every pattern, including those that overlap.
+    ///
+    /// Matches are ordered by their end, and matches with the same end by
+    /// decreasing length. The [`MatchKind`] does not apply.
+    pub fn find_overlapping_iter<'a, H>(
+        &'a self,
+        haystack: &'a H,
+    ) -> impl Iterator<Item = Match> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let haystack = haystack.as_ref();
+        let mut state = ROOT;
+
+        (0..=haystack.len()).flat_map(move |end| {
+            if let Some(byte) = end.checked_sub(1).map(|i| haystack[i]) {
+                state = self.forward.next_state(state, byte);
+            }
+            self.matches(state, end)
+        })
+    }
+
+    /// Checks whether any pattern occurs in the haystack.
+    pub fn is_match<H>(&self, haystack: &H) -> bool
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        self.find_overlapping_iter(haystack).next().is_some()
+    }
+
+    /// Starts searching a haystack that arrives in chunks.
+    #[must_use]
+    pub fn stream(&self) -> AhoCorasickStream<'_> {
+        AhoCorasickStream {
+            ac: self,
+            state: ROOT,
+            offset: 0,
+            started: false,
+        }
+    }
+
+    /// Finds the preferred pattern starting at each offset of `starts`.
+    fn prefer(&self, haystack: &[u8], starts: Range<usize>, preferred: &mut Vec<Option<usize>>) {
+        preferred.clear();
+        preferred.resize(starts.len(), None);
+
+        // Every pattern starting in the block ends by `end`.
+        let end = (starts.end - 1 + self.longest).min(haystack.len());
+        let mut state = ROOT;
+        for at in (starts.start..=end).rev() {
+            if at < end {
+                state = self.reverse.next_state(state, haystack[at]);
+            }
+            if at < starts.end {
+                preferred[at - starts.start] = self.preferred[state];
+            }
+        }
+    }
+
+    /// The matches ending at `end`, where the automaton is in `state`.
+    fn matches(&self, state: usize, end: usize) -> impl Iterator<Item = Match> + '_ {
+        core::iter::successors(Some(state), |&s| self.forward.states[s].dict)
+            .flat_map(|s| &self.forward.states[s].outputs)
+            .map(move |&pattern| Match {
+                pattern,
+                start: end - self.lengths[pattern],
+                end,
+            })
+    }
+}
+
+/// Searches a haystack that arrives in chunks for every occurrence of the
+/// patterns of an [`AhoCorasick`] automaton.
+///
+/// The automaton's state is kept between chunks, so occurrences that span
+/// the boundary between two chunks are found, and offsets count from the
+/// start of the first chunk. Matches are reported as by
+/// [`find_overlapping_iter`](AhoCorasick::find_overlapping_iter).
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::AhoCorasick;
+///
+/// let ac = AhoCorasick::new(["error", "warn"]);
+/// let mut stream = ac.stream();
+/// let mut matches = Vec::new();
+///
+/// stream.feed(b"ok\nwa", &mut matches);
+/// stream.feed(b"rn\ner", &mut matches);
+/// stream.feed(b"ror\n", &mut matches);
+///
+/// let found: Vec<(usize, usize)> = matches.iter().map(|m| (m.pattern, m.start)).collect();
+/// assert_eq!(found, [(1, 3), (0, 8)]);
+/// assert_eq!(stream.offset(), 14);
+/// ```
+#[derive(Debug, Clone)]
+#[allow(clippy::module_name_repetitions)]
+pub struct AhoCorasickStream<'a> {
+    ac: &'a AhoCorasick,
+    state: usize,
+    offset: usize,
+    started: bool,
+}
+
+impl AhoCorasickStream<'_> {
+    /// Searches the next chunk of the haystack, appending every match that
+    /// ends in it to `matches`.
+    pub fn feed(&mut self, chunk: &[u8], matches: &mut Vec<Match>) {
+        if !self.started {
+            // Empty patterns match before the first byte.
+            self.started = true;
+            matches.extend(self.ac.matches(self.state, self.offset));
+        }
+
+        for &byte in chunk {
+            self.state = self.ac.forward.next_state(self.state, byte);
+            self.offset += 1;
+            matches.extend(self.ac.matches(self.state, self.offset));
+        }
+    }
+
+    /// The number of bytes fed so far.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{string::String, vec::Vec};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    const PATTERNS: [&str; 9] = ["he", "she", "his", "hers", "s", "", "Samwise", "Sam", "ab"];
+
+    const HAYSTACKS: [&str; 6] = [
+        "",
+        "ushers",
+        "Samwise Gamgee",
+        "SAMWISE and sam",
+        "ababab his hers",
+        "\u{e9}she\u{e9}",
+    ];
+
+    /// Every occurrence of every pattern, found by comparing every window.
+    fn occurrences(patterns: &[&str], haystack: &str, fold: bool) -> Vec<Match> {
+        let eq = |a: &[u8], b: &[u8]| {
+            if fold {
+                a.eq_ignore_ascii_case(b)
+            } else {
+                a == b
+            }
+        };
+        let mut all = Vec::new();
+        for end in 0..=haystack.len() {
+            for (pattern, p) in patterns.iter().enumerate() {
+                if let Some(start) = end.checked_sub(p.len()) {
+                    if eq(&haystack.as_bytes()[start..end], p.as_bytes()) {
+                        all.push(Match {
+                            pattern,
+                            start,
+                            end,
+                        });
+                    }
+                }
+            }
+        }
+        all.sort_by_key(|m| (m.end, m.start, m.pattern));
+        all
+    }
+
+    /// The non-overlapping matches, chosen from every occurrence.
+    fn leftmost(all: &[Match], kind: MatchKind, len: usize) -> Vec<Match> {
+        let mut found = Vec::new();
+        let mut start = 0;
+        while start <= len {
+            let best = all.iter().filter(|m| m.start >= start).min_by_key(|m| {
+                let preference = match kind {
+                    MatchKind::LeftmostFirst => m.pattern,
+                    MatchKind::LeftmostLongest => usize::MAX - (m.end - m.start),
+                };
+                (m.start, preference, m.pattern)
+            });
+            let Some(&best) = best else { break };
+            found.push(best);
+            start = best.end + usize::from(best.start == best.end);
+        }
+        found
+    }
+
+    fn check(patterns: &[&str]) {
+        for kind in [MatchKind::LeftmostFirst, MatchKind::LeftmostLongest] {
+            for fold in [false, true] {
+                for dfa in [false, true] {
+                    let ac = AhoCorasick::builder()
+                        .match_kind(kind)
+                        .ascii_case_insensitive(fold)
+                        .dfa(dfa)
+                        .build(patterns);
+                    assert_eq!(ac.patterns_len(), patterns.len());
+                    assert_eq!(ac.is_dfa(), dfa);
+
+                    for haystack in HAYSTACKS {
+                        let context = (kind, fold, dfa, haystack);
+                        let all = occurrences(patterns, haystack, fold);
+                        let expected = leftmost(&all, kind, haystack.len());
+
+                        assert_eq!(
+                            ac.find_overlapping_iter(haystack).collect::<Vec<_>>(),
+                            all,
+                            "{context:?}"
+                        );
+                        assert_eq!(
+                            ac.find_iter(haystack).collect::<Vec<_>>(),
+                            expected,
+                            "{context:?}"
+                        );
+                        assert_eq!(ac.find(haystack), expected.first().copied(), "{context:?}");
+                        assert_eq!(ac.is_match(haystack), !all.is_empty(), "{context:?}");
+
+                        for size in 1..4 {
+                            let mut stream = ac.stream();
+                            let mut streamed = Vec::new();
+                            for chunk in haystack.as_bytes().chunks(size) {
+                                stream.feed(chunk, &mut streamed);
+                            }
+                            stream.feed(&[], &mut streamed);
+                            assert_eq!(streamed, all, "{context:?}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        check(&PATTERNS);
+        check(&PATTERNS[..5]);
+        check(&["Sam", "Samwise", "s", "S"]);
+        check(&["abab", "ba", "b", "ab"]);
+        check(&[]);
+    }
+
+    #[test]
+    fn match_kinds() {
+        let patterns = ["Sam", "Samwise"];
+        let first = AhoCorasick::new(patterns);
+        let longest = AhoCorasick::builder()
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(patterns);
+
+        assert_eq!(first.find("Samwise").map(|m| m.pattern), Some(0));
+        assert_eq!(longest.find("Samwise").map(|m| m.pattern), Some(1));
+        assert_eq!(first.match_kind(), MatchKind::LeftmostFirst);
+    }
+
+    #[test]
+    fn agrees_across_blocks() {
+        let mut rng = StdRng::seed_from_u64(35);
+        let haystack: String = (0..3 * BLOCK)
+            .map(|_| ['a', 'b', 'A'][rng.gen_range(0..3)])
+            .collect();
+        let long = &haystack[1000..2500];
+        let patterns = ["ab", "ba", "b", "abab", "aab", "aaaa", long, &long[..1200]];
+        for kind in [MatchKind::LeftmostFirst, MatchKind::LeftmostLongest] {
+            for fold in [false, true] {
+                let ac = AhoCorasick::builder()
+                    .match_kind(kind)
+                    .ascii_case_insensitive(fold)
+                    .build(patterns);
+                let all = occurrences(&patterns, &haystack, fold);
+                let expected = leftmost(&all, kind, haystack.len());
+                assert_eq!(
+                    ac.find_iter(&haystack).collect::<Vec<_>>(),
+                    expected,
+                    "{kind:?} {fold}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn leftmost_lookahead() {
+        // Each `a` is only known to be the preferred match once the long
+        // pattern has failed to match after it, which a forward search
+        // would find by reading past it again for every match.
+        let long = "a".repeat(1000) + "b";
+        let haystack = "a".repeat(200_000);
+        for patterns in [[long.as_str(), "a"], ["a", long.as_str()]] {
+            let ac = AhoCorasick::new(patterns);
+            let found: Vec<Match> = ac.find_iter(&haystack).collect();
+            assert_eq!(found.len(), 200_000);
+            assert!(found.iter().all(|m| m.end == m.start + 1));
+        }
+    }
+
+    #[test]
+    fn finds_many_keywords() {
+        let keywords: Vec<Vec<u8>> = (0..2000_u32)
+            .map(|i| alloc::format!("k{i}x").into_bytes())
+            .collect();
+        let ac = AhoCorasick::builder().dfa(true).build(&keywords);
+        let log = b"start k17x k1999x k2000x k0x end";
+
+        let found: Vec<usize> = ac.find_iter(log).map(|m| m.pattern).collect();
+        assert_eq!(found, [17, 1999, 0]);
+    }
+}
@@ -0,0 +1,487 @@
+use alloc::{vec, vec::Vec};
+
+/// The errors an approximate occurrence of a pattern may have.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// Substituted bytes only, so occurrences are as long as the pattern.
+    Hamming,
+    /// Substituted, inserted and deleted bytes.
+    #[default]
+    Levenshtein,
+}
+
+/// An approximate occurrence of a pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApproximateMatch {
+    /// The byte offset where the occurrence starts.
+    pub start: usize,
+    /// The byte offset just past the end of the occurrence.
+    pub end: usize,
+    /// The number of errors of the occurrence: the distance between the
+    /// pattern and `haystack[start..end]`.
+    pub errors: usize,
+}
+
+/// A pattern compiled for approximate searching, finding occurrences with
+/// at most a given number of errors.
+///
+/// Patterns are searched for with the Bitap (shift-or) algorithm, extended
+/// by Wu and Manber to errors, which keeps a bit per byte of the pattern for
+/// each number of errors, in as many 64-bit words as the pattern needs.
+/// Patterns of more than 64 bytes are instead searched for with Myers'
+/// bit-parallel algorithm under the [`Levenshtein`](Metric::Levenshtein)
+/// metric, which keeps the same bits whatever the number of errors.
+///
+/// Under the Levenshtein metric, an occurrence is reported at every end at
+/// which a substring of the haystack is close enough to the pattern, so a
+/// close occurrence is usually also reported one or two bytes later with
+/// more errors. Its start is that of the shortest such substring with the
+/// fewest errors, which the bit-parallel search does not track, so it is
+/// found afterwards by a dynamic program backwards from the end.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{ApproximateMatch, ApproximateMatcher, Metric};
+///
+/// let matcher = ApproximateMatcher::new("colour", 1, Metric::Levenshtein);
+/// let found: Vec<_> = matcher.find_iter("the color red").collect();
+/// assert_eq!(found[0], ApproximateMatch { start: 4, end: 9, errors: 1 });
+///
+/// let matcher = ApproximateMatcher::new("ACGT", 1, Metric::Hamming);
+/// let starts: Vec<_> = matcher.find_iter("ACCTACGTAGGT").map(|m| (m.start, m.errors)).collect();
+/// assert_eq!(starts, [(0, 1), (4, 0), (8, 1)]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Preprocessing: O(m + 256⌈m/64⌉)
+/// - Search: O(nk⌈m/64⌉) with Bitap, where k is the number of errors
+///   allowed, and O(n⌈m/64⌉) for patterns of more than 64 bytes under the
+///   Levenshtein metric.
+/// - Under the Levenshtein metric, finding the start of each occurrence
+///   takes a further O(m(m + k)), so iterating over r occurrences takes
+///   O(rm(m + k)) on top of the search. With many errors allowed, r may be
+///   close to n, which makes the starts dominate.
+///
+/// # References
+///
+/// - [Wu, S., & Manber, U. (1992). Fast text searching allowing errors](https://doi.org/10.1145/135239.135244)
+/// - [Myers, G. (1999). A fast bit-vector algorithm for approximate string matching based on dynamic programming](https://doi.org/10.1145/316542.316550)
+/// - [Hyyrö, H. (2003). A bit-vector algorithm for computing Levenshtein and Damerau edit distances](https://www.stringology.org/event/2002/p6.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApproximateMatcher {
+    pattern: Vec<u8>,
+    max_errors: usize,
+    metric: Metric,
+    /// For each block of 64 bytes of the pattern and each byte value, the
+    /// bits of the positions in the block that hold it.
+    positions: Vec<[u64; 256]>,
+}
+
+impl ApproximateMatcher {
+    /// Compiles `pattern` for searching with at most `max_errors` errors of `metric`.
+    #[must_use]
+    pub fn new<P>(pattern: &P, max_errors: usize, metric: Metric) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref().to_vec();
+        let mut positions = vec![[0; 256]; pattern.len().div_ceil(64)];
+        for (i, &byte) in pattern.iter().enumerate() {
+            positions[i / 64][usize::from(byte)] |= 1 << (i % 64);
+        }
+        Self {
+            pattern,
+            max_errors,
+            metric,
+            positions,
+        }
+    }
+
+    /// The pattern being searched for.
+    #[must_use]
+    pub fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    /// The most errors an occurrence may have.
+    #[must_use]
+    pub fn max_errors(&self) -> usize {
+        self.max_errors
+    }
+
+    /// The kind of errors an occurrence may have.
+    #[must_use]
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    /// Finds the occurrence that ends first.
+    #[must_use]
+    pub fn find<H>(&self, haystack: &H) -> Option<ApproximateMatch>
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        self.find_iter(haystack).next()
+    }
+
+    /// Checks whether the pattern occurs in the haystack.
+    #[must_use]
+    pub fn is_match<H>(&self, haystack: &H) -> bool
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        self.find(haystack).is_some()
+    }
+
+    /// Iterates over the occurrences of the pattern, by ascending end,
+    /// including those that overlap.
+    ///
+    /// An empty pattern occurs at every offset, and under the Levenshtein
+    /// metric a pattern of at most `max_errors` bytes also occurs, deleted,
+    /// at the start of the haystack.
+    ///
+    /// # Performance
+    ///
+    /// Under the Levenshtein metric, each occurrence yielded costs
+    /// O(m(m + k)) to find its start, besides the search itself.
+    pub fn find_iter<'a, H>(
+        &'a self,
+        haystack: &'a H,
+    ) -> impl Iterator<Item = ApproximateMatch> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let haystack = haystack.as_ref();
+        let (m, k) = (self.pattern.len(), self.max_errors.min(self.pattern.len()));
+        let blocks = self.positions.len();
+        // The rows of Bitap for each number of errors, and one to keep the
+        // previous row before it is advanced.
+        let mut state = match (self.metric, m) {
+            (Metric::Hamming, _) => vec![!0; (k + 2) * blocks],
+            (Metric::Levenshtein, 0..=64) => (0..=k + 1)
+                .map(|d| (!0_u64).checked_shl(d as u32).unwrap_or(0))
+                .collect(),
+            // Myers' vertical deltas: every cell of the first column is one more than the one above.
+            (Metric::Levenshtein, _) => [!0, 0].repeat(blocks),
+        };
+        // The Levenshtein distance of the pattern and the closest substring ending at `end`.
+        let mut score = m;
+        let mut end = 0;
+
+        core::iter::from_fn(move || loop {
+            if end > haystack.len() {
+                return None;
+            }
+            let at = end;
+            end += 1;
+            let errors = match (self.metric, m) {
+                (_, 0) => Some(0),
+                (Metric::Levenshtein, _) if at == 0 => Some(m).filter(|&m| m <= k),
+                (_, _) if at == 0 => None,
+                (Metric::Hamming, _) | (_, 0..=64) => self.bitap_step(&mut state, haystack[at - 1]),
+                (Metric::Levenshtein, _) => {
+                    score =
+                        score.wrapping_add_signed(self.myers_step(&mut state, haystack[at - 1]));
+                    Some(score).filter(|&score| score <= k)
+                }
+            };
+            if let Some(errors) = errors {
+                let start = match self.metric {
+                    Metric::Hamming => at - m,
+                    Metric::Levenshtein => self.start(haystack, at, errors),
+                };
+                return Some(ApproximateMatch {
+                    start,
+                    end: at,
+                    errors,
+                });
+            }
+        })
+    }
+
+    /// Advances the Bitap state by a byte of the haystack.
+    ///
+    /// A clear bit `i` of row `d` of the state means that the first `i + 1`
+    /// bytes of the pattern match a substring ending at the byte with at most
+    /// `d` errors. Each row takes a word per block of the pattern, and the
+    /// last one is scratch space.
+    fn bitap_step(&self, state: &mut [u64], byte: u8) -> Option<usize> {
+        let blocks = self.positions.len();
+        let last = 1 << ((self.pattern.len() - 1) % 64);
+        let (rows, old_below) = state.split_at_mut(state.len() - blocks);
+        let mut found = None;
+        for d in 0..rows.len() / blocks {
+            let (done, rest) = rows.split_at_mut(d * blocks);
+            let (below, row) = (
+                &done[done.len().saturating_sub(blocks)..],
+                &mut rest[..blocks],
+            );
+            // The bits shifted out of the top of the previous block.
+            let (mut carry, mut old_carry, mut new_carry) = (0, 0, 0);
+            for (block, bits) in row.iter_mut().enumerate() {
+                let old = *bits;
+                let mut new = (old << 1) | carry | !self.positions[block][usize::from(byte)];
+                carry = old >> 63;
+                if d > 0 {
+                    // A substituted byte.
+                    let previous = old_below[block];
+                    new &= (previous << 1) | old_carry;
+                    old_carry = previous >> 63;
+                    if self.metric == Metric::Levenshtein {
+                        // An inserted byte of the haystack, and a deleted one of the pattern.
+                        let new_below = below[block];
+                        new &= previous & ((new_below << 1) | new_carry);
+                        new_carry = new_below >> 63;
+                    }
+                }
+                (*bits, old_below[block]) = (new, old);
+            }
+            if found.is_none() && row[blocks - 1] & last == 0 {
+                found = Some(d);
+            }
+        }
+        found
+    }
+
+    /// Advances Myers' vertical deltas, as positive and negative bits of
+    /// each block, by a byte of the haystack.
+    ///
+    /// # Returns
+    ///
+    /// The change in the distance of the whole pattern.
+    #[allow(clippy::cast_possible_wrap)]
+    fn myers_step(&self, state: &mut [u64], byte: u8) -> isize {
+        // The horizontal delta into the top of each block: zero at the top
+        // of the pattern, since an occurrence may start anywhere.
+        let mut delta = 0_i8;
+        let blocks = self.positions.len();
+        for (block, vertical) in state.chunks_exact_mut(2).enumerate() {
+            let (pv, mv) = (vertical[0], vertical[1]);
+            let mut eq = self.positions[block][usize::from(byte)];
+            let high = if block + 1 == blocks {
+                1 << ((self.pattern.len() - 1) % 64)
+            } else {
+                1 << 63
+            };
+
+            let xv = eq | mv;
+            if delta < 0 {
+                eq |= 1;
+            }
+            let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+            let mut ph = mv | !(xh | pv);
+            let mut mh = pv & xh;
+            let out = i8::from(ph & high != 0) - i8::from(mh & high != 0);
+            ph <<= 1;
+            mh <<= 1;
+            match delta {
+                ..0 => mh |= 1,
+                1.. => ph |= 1,
+                0 => {}
+            }
+            vertical[0] = mh | !(xv | ph);
+            vertical[1] = ph & xv;
+            delta = out;
+        }
+        isize::from(delta)
+    }
+
+    /// Finds the start of the shortest substring ending at `end` that is
+    /// `errors` edits from the pattern.
+    fn start(&self, haystack: &[u8], end: usize, errors: usize) -> usize {
+        let m = self.pattern.len();
+        // `column[i]` is the distance of the last `i` bytes of the pattern
+        // and the haystack from `start` to `end`.
+        let mut column: Vec<usize> = (0..=m).collect();
+        let mut start = end;
+        while column[m] != errors && start > 0 {
+            start -= 1;
+            let byte = haystack[start];
+            let mut diagonal = column[0];
+            column[0] += 1;
+            for i in 1..=m {
+                let substitution = diagonal + usize::from(self.pattern[m - i] != byte);
+                diagonal = column[i];
+                column[i] = substitution.min(column[i] + 1).min(column[i - 1] + 1);
+            }
+        }
+        start
+    }
+}
+
+/// Finds every approximate occurrence of a pattern in a text, with at
+/// most `max_errors` errors of `metric`.
+///
+/// See [`ApproximateMatcher`] for the occurrences reported.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{approximate_search, Metric};
+///
+/// let found = approximate_search(b"mississippi", b"sip", 0, Metric::Hamming);
+/// assert_eq!(found.iter().map(|m| m.start).collect::<Vec<_>>(), [6]);
+/// let found = approximate_search(b"mississippi", b"sip", 1, Metric::Hamming);
+/// assert_eq!(found.iter().map(|m| m.start).collect::<Vec<_>>(), [3, 6]);
+/// ```
+#[must_use]
+pub fn approximate_search(
+    text: &[u8],
+    pattern: &[u8],
+    max_errors: usize,
+    metric: Metric,
+) -> Vec<ApproximateMatch> {
+    ApproximateMatcher::new(pattern, max_errors, metric)
+        .find_iter(text)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strings::distance::levenshtein;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// The fewest errors of an occurrence ending at each offset, by dynamic programming.
+    fn brute_force(text: &[u8], pattern: &[u8], metric: Metric) -> Vec<usize> {
+        let m = pattern.len();
+        match metric {
+            Metric::Hamming => (0..=text.len())
+                .map(|end| {
+                    end.checked_sub(m).map_or(usize::MAX, |start| {
+                        pattern
+                            .iter()
+                            .zip(&text[start..end])
+                            .filter(|(x, y)| x != y)
+                            .count()
+                    })
+                })
+                .collect(),
+            Metric::Levenshtein => {
+                let mut column: Vec<usize> = (0..=m).collect();
+                let mut best = vec![m];
+                for &byte in text {
+                    let mut diagonal = column[0];
+                    column[0] = 0;
+                    for i in 1..=m {
+                        let substitution = diagonal + usize::from(pattern[i - 1] != byte);
+                        diagonal = column[i];
+                        column[i] = substitution.min(column[i] + 1).min(column[i - 1] + 1);
+                    }
+                    best.push(column[m]);
+                }
+                best
+            }
+        }
+    }
+
+    fn check(text: &[u8], pattern: &[u8], max_errors: usize, metric: Metric) {
+        let expected: Vec<(usize, usize)> = brute_force(text, pattern, metric)
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, errors)| errors <= max_errors)
+            .collect();
+        let found = approximate_search(text, pattern, max_errors, metric);
+        let ends: Vec<(usize, usize)> = found.iter().map(|m| (m.end, m.errors)).collect();
+        assert_eq!(ends, expected, "{metric:?} {max_errors}");
+        for m in found {
+            let distance = match metric {
+                Metric::Hamming => {
+                    assert_eq!(m.end - m.start, pattern.len());
+                    brute_force(&text[m.start..m.end], pattern, metric)[pattern.len()]
+                }
+                Metric::Levenshtein => levenshtein(pattern, &text[m.start..m.end]),
+            };
+            assert_eq!(distance, m.errors);
+        }
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_1234);
+
+        for _ in 0..300 {
+            let text: Vec<u8> = (0..rng.gen_range(0..40))
+                .map(|_| b"abc"[rng.gen_range(0..3)])
+                .collect();
+            let pattern: Vec<u8> = (0..rng.gen_range(0..8))
+                .map(|_| b"abc"[rng.gen_range(0..3)])
+                .collect();
+            for metric in [Metric::Hamming, Metric::Levenshtein] {
+                for max_errors in 0..4 {
+                    check(&text, &pattern, max_errors, metric);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn long_patterns() {
+        let mut rng = StdRng::seed_from_u64(0xdead_beef);
+
+        for _ in 0..30 {
+            let text: Vec<u8> = (0..300).map(|_| b"acgt"[rng.gen_range(0..4)]).collect();
+            let len = 60 + rng.gen_range(0..140);
+            let start = rng.gen_range(0..text.len() - len);
+            let mut pattern = text[start..start + len].to_vec();
+            for _ in 0..rng.gen_range(0..6) {
+                let at = rng.gen_range(0..pattern.len());
+                pattern[at] = b"acgt"[rng.gen_range(0..4)];
+            }
+            for metric in [Metric::Hamming, Metric::Levenshtein] {
+                for max_errors in [0, 3, 10] {
+                    check(&text, &pattern, max_errors, metric);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn errors_across_words() {
+        let mut rng = StdRng::seed_from_u64(0x0bad_cafe);
+        let text: Vec<u8> = (0..400).map(|_| b"acgt"[rng.gen_range(0..4)]).collect();
+        let mut pattern = text[100..230].to_vec();
+        for at in [0, 63, 64, 127, 128, 129] {
+            pattern[at] = b'x';
+        }
+        let matcher = ApproximateMatcher::new(&pattern, 6, Metric::Hamming);
+        assert_eq!(
+            matcher.find(&text),
+            Some(ApproximateMatch {
+                start: 100,
+                end: 230,
+                errors: 6
+            })
+        );
+        assert!(!ApproximateMatcher::new(&pattern, 5, Metric::Hamming).is_match(&text));
+        check(&text, &pattern, 6, Metric::Hamming);
+    }
+
+    #[test]
+    fn edge_cases() {
+        let found = approximate_search(b"ab", b"", 2, Metric::Levenshtein);
+        assert_eq!(found.len(), 3);
+        assert!(found.iter().all(|m| m.errors == 0 && m.start == m.end));
+
+        // Deleting the whole pattern is an occurrence at the start.
+        let found = approximate_search(b"", b"ab", 2, Metric::Levenshtein);
+        assert_eq!(
+            found,
+            [ApproximateMatch {
+                start: 0,
+                end: 0,
+                errors: 2
+            }]
+        );
+        assert!(approximate_search(b"", b"ab", 2, Metric::Hamming).is_empty());
+
+        let pattern = [b'x'; 64];
+        let matcher = ApproximateMatcher::new(&pattern, 64, Metric::Levenshtein);
+        assert_eq!(matcher.find(b"").map(|m| m.errors), Some(64));
+        assert!(matcher.find_iter(&[b'y'; 64]).all(|m| m.errors <= 64));
+    }
+}
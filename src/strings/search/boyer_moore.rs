@@ -0,0 +1,137 @@
+use alloc::{vec, vec::Vec};
+
+use super::Matcher;
+
+/// A [`Matcher`] using the Boyer-Moore algorithm.
+///
+/// The pattern is compared with the haystack from right to left. On a
+/// mismatch the pattern is shifted by the larger of two precomputed amounts:
+///
+/// - The bad character rule lines the mismatched byte of the haystack up with
+///   its last occurrence in the pattern, or moves past it if the pattern does
+///   not contain it.
+/// - The good suffix rule lines the part of the haystack that did match up
+///   with the next occurrence of that suffix in the pattern.
+///
+/// Long patterns over large alphabets let it skip most of the haystack.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{BoyerMooreMatcher, Matcher};
+///
+/// let matcher = BoyerMooreMatcher::new("example");
+/// assert_eq!(matcher.find("here is a simple example"), Some(17));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Preprocessing: O(m + σ) for an alphabet of `σ` bytes
+/// - Best: O(n / m)
+/// - Worst: O(n * m) - when the pattern occurs at almost every offset.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Boyer%E2%80%93Moore_string-search_algorithm)
+/// - [Boyer and Moore, A fast string searching algorithm](https://doi.org/10.1145/359842.359859)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoyerMooreMatcher {
+    pattern: Vec<u8>,
+    /// One past the index of the last occurrence of each byte in the pattern, or `0`.
+    last: [usize; 256],
+    /// The shift for a mismatch just before the suffix of each length from the end.
+    good_suffix: Vec<usize>,
+}
+
+impl BoyerMooreMatcher {
+    /// Compiles `pattern` for searching.
+    #[must_use]
+    pub fn new<P>(pattern: &P) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref().to_vec();
+        let m = pattern.len();
+
+        let mut last = [0; 256];
+        for (i, &byte) in pattern.iter().enumerate() {
+            last[usize::from(byte)] = i + 1;
+        }
+
+        // `border[i]` is where the widest border of `pattern[i..]` starts.
+        let mut good_suffix = vec![0; m + 1];
+        let mut border = vec![0; m + 1];
+        let (mut i, mut j) = (m, m + 1);
+        border[i] = j;
+        while i > 0 {
+            while j <= m && pattern[i - 1] != pattern[j - 1] {
+                if good_suffix[j] == 0 {
+                    good_suffix[j] = j - i;
+                }
+                j = border[j];
+            }
+            i -= 1;
+            j -= 1;
+            border[i] = j;
+        }
+
+        // Suffixes that occur nowhere else shift by a period of the pattern.
+        j = border[0];
+        for (i, shift) in good_suffix.iter_mut().enumerate() {
+            if *shift == 0 {
+                *shift = j;
+            }
+            if i == j {
+                j = border[j];
+            }
+        }
+
+        Self {
+            pattern,
+            last,
+            good_suffix,
+        }
+    }
+}
+
+impl Matcher for BoyerMooreMatcher {
+    fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    fn find_at(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let m = self.pattern.len();
+        let mut shift = start;
+
+        while shift + m <= haystack.len() {
+            let mut j = m;
+            while j > 0 && self.pattern[j - 1] == haystack[shift + j - 1] {
+                j -= 1;
+            }
+            if j == 0 {
+                return Some(shift);
+            }
+
+            let bad_character = j.saturating_sub(self.last[usize::from(haystack[shift + j - 1])]);
+            shift += self.good_suffix[j].max(bad_character);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{vec, vec::Vec};
+    use test_case::test_case;
+
+    #[test_case("ANPANMAN" => vec![6, 6, 6, 6, 6, 6, 3, 8, 1])]
+    #[test_case("abbabab" => vec![5, 5, 5, 5, 2, 5, 4, 1])]
+    #[test_case("aaaa" => vec![1, 1, 2, 3, 4])]
+    fn test_good_suffix(pattern: &str) -> Vec<usize> {
+        BoyerMooreMatcher::new(pattern).good_suffix
+    }
+}
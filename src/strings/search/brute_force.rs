@@ -1,3 +1,7 @@
+use alloc::vec::Vec;
+
+use super::Matcher;
+
 /// Find a pattern in a string by comparing the pattern to every substring
 ///
 /// # Arguments
@@ -26,6 +30,65 @@ pub fn brute_force(text: &str, pattern: &str) -> Option<usize> {
     None
 }
 
+/// A [`Matcher`] that compares the pattern with the haystack at every offset.
+///
+/// It needs no preprocessing and no extra memory, and is usually the fastest
+/// choice for short patterns.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{BruteForceMatcher, Matcher};
+///
+/// let matcher = BruteForceMatcher::new("needle");
+/// assert_eq!(matcher.find("haystack with a needle"), Some(16));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n * m)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BruteForceMatcher {
+    pattern: Vec<u8>,
+}
+
+impl BruteForceMatcher {
+    /// Compiles `pattern` for searching.
+    #[must_use]
+    pub fn new<P>(pattern: &P) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        Self {
+            pattern: pattern.as_ref().to_vec(),
+        }
+    }
+}
+
+impl Matcher for BruteForceMatcher {
+    fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    fn find_at(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let last = haystack.len().checked_sub(self.pattern.len())?;
+        (start..=last).find(|&i| haystack[i..].starts_with(&self.pattern))
+    }
+
+    fn rfind<H>(&self, haystack: &H) -> Option<usize>
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let haystack = haystack.as_ref();
+        let last = haystack.len().checked_sub(self.pattern.len())?;
+        (0..=last)
+            .rev()
+            .find(|&i| haystack[i..].starts_with(&self.pattern))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
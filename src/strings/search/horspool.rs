@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+
+use super::Matcher;
+
+/// A [`Matcher`] using the Boyer-Moore-Horspool algorithm.
+///
+/// A simplification of Boyer-Moore that keeps only the bad character rule,
+/// applied to the haystack byte under the last byte of the pattern whatever
+/// position the mismatch was found at. Its single table is cheap to build and
+/// its inner loop is short, which makes it fast on natural-language text.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{HorspoolMatcher, Matcher};
+///
+/// let matcher = HorspoolMatcher::new("needle");
+/// assert_eq!(matcher.find("a needle in a haystack"), Some(2));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Preprocessing: O(m + σ) for an alphabet of `σ` bytes
+/// - Best: O(n / m)
+/// - Worst: O(n * m)
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Boyer%E2%80%93Moore%E2%80%93Horspool_algorithm)
+/// - [Horspool, Practical fast searching in strings](https://doi.org/10.1002/spe.4380100608)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HorspoolMatcher {
+    pattern: Vec<u8>,
+    /// The distance from the last occurrence of each byte in all but the last
+    /// byte of the pattern to the end of the pattern.
+    shift: [usize; 256],
+}
+
+impl HorspoolMatcher {
+    /// Compiles `pattern` for searching.
+    #[must_use]
+    pub fn new<P>(pattern: &P) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref().to_vec();
+        let m = pattern.len();
+
+        let mut shift = [m.max(1); 256];
+        for (i, &byte) in pattern.iter().enumerate().take(m.saturating_sub(1)) {
+            shift[usize::from(byte)] = m - 1 - i;
+        }
+
+        Self { pattern, shift }
+    }
+}
+
+impl Matcher for HorspoolMatcher {
+    fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    fn find_at(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let m = self.pattern.len();
+        let Some(&last) = self.pattern.last() else {
+            return (start <= haystack.len()).then_some(start);
+        };
+
+        let mut i = start;
+        while i + m <= haystack.len() {
+            let byte = haystack[i + m - 1];
+            if byte == last && haystack[i..i + m - 1] == self.pattern[..m - 1] {
+                return Some(i);
+            }
+            i += self.shift[usize::from(byte)];
+        }
+        None
+    }
+}
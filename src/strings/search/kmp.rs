@@ -1,5 +1,7 @@
 use alloc::vec::Vec;
 
+use super::Matcher;
+
 /// Searches for occurrences of a "word" w within a main "text string"
 ///
 /// The Knuth-Morris-Pratt Algorithm for finding a pattern within a piece of text
@@ -10,67 +12,212 @@ use alloc::vec::Vec;
 /// * `text` - The text to be searched
 /// * `pattern` - The word sought
 ///
-/// # References
+/// # Returns
 ///
-/// [Knuth-Morris-Pratt Algorithm](https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm)
+/// Returns true if the pattern is found in the text else false. The empty
+/// pattern is found in every text.
 ///
-/// # Panics
+/// # References
 ///
-/// Panics if `pattern` is empty
+/// [Knuth-Morris-Pratt Algorithm](https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm)
 #[must_use]
 #[allow(clippy::module_name_repetitions)]
 pub fn kmp_check(text: &str, pattern: &str) -> bool {
-    let mut i = 0; // The position of the current character in `s`
-    let mut j = 0; // The position of the current character in `pat`
-    let failure = get_failure_array(pattern).unwrap();
-
-    while i < text.len() {
-        if let (Some(p_i), Some(t_i)) = (pattern.chars().nth(j), text.chars().nth(i)) {
-            if p_i == t_i {
-                if j == pattern.len() - 1 {
-                    return true;
-                }
-                j += 1;
-            } else if j > 0 {
-                j = failure[j - 1];
-                continue;
-            }
-            i += 1;
-        } else {
-            return false;
-        }
-    }
-
-    false
+    let pattern = pattern.as_bytes();
+    let failure = prefix_function(pattern);
+    let (mut i, mut j) = (0, 0);
+    kmp_next(text.as_bytes(), pattern, &failure, &mut i, &mut j).is_some()
 }
 
-/// Calculates the new index if the comparison fails else returns
-/// None
+/// Finds every occurrence of a pattern in a sequence with the
+/// Knuth-Morris-Pratt algorithm.
+///
+/// Works on any sequence of comparable items, such as the bytes of a string
+/// or its `char`s.
 ///
 /// # Arguments
 ///
-/// * `pattern` - The pattern to be analyzed
+/// * `text` - The sequence to be searched
+/// * `pattern` - The sequence sought
 ///
 /// # Returns
 ///
-/// Returns an array of integers (the table to be filled) or `None`
-fn get_failure_array(p: &str) -> Option<Vec<usize>> {
-    let mut t: Vec<usize> = alloc::vec![0];
+/// The starting index of every occurrence, including overlapping ones, in
+/// ascending order. The empty pattern occurs at every index from `0` to
+/// `text.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::kmp_search;
+///
+/// assert_eq!(kmp_search(b"aaabaabaab", b"aab"), [1, 4, 7]);
+///
+/// let text: Vec<char> = "sm\u{f8}rebr\u{f8}d".chars().collect();
+/// assert_eq!(kmp_search(&text, &['\u{f8}']), [2, 7]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n + m)
+///
+/// ## Space Complexity
+///
+/// O(m)
+#[must_use]
+pub fn kmp_search<T>(text: &[T], pattern: &[T]) -> Vec<usize>
+where
+    T: Eq,
+{
+    let failure = prefix_function(pattern);
+    let (mut i, mut j) = (0, 0);
+    core::iter::from_fn(|| kmp_next(text, pattern, &failure, &mut i, &mut j)).collect()
+}
+
+/// Computes the prefix function of a sequence.
+///
+/// Entry `i` is the length of the longest proper prefix of `pattern[..=i]`
+/// that is also a suffix of it. This is the failure table of the
+/// Knuth-Morris-Pratt algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::prefix_function;
+///
+/// assert_eq!(prefix_function(b"abacaba"), [0, 0, 1, 0, 1, 2, 3]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(m)
+///
+/// # See more
+/// [CP Algorithms](https://cp-algorithms.com/string/prefix-function.html)
+#[must_use]
+pub fn prefix_function<T>(pattern: &[T]) -> Vec<usize>
+where
+    T: Eq,
+{
+    let mut pi = alloc::vec![0; pattern.len()];
+    let mut k = 0;
+    for i in 1..pattern.len() {
+        while k > 0 && pattern[i] != pattern[k] {
+            k = pi[k - 1];
+        }
+        if pattern[i] == pattern[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/// Advances the automaton from item `*i` of the text with `*j` items of the
+/// pattern matched, stopping after the next occurrence.
+fn kmp_next<T>(
+    text: &[T],
+    pattern: &[T],
+    failure: &[usize],
+    i: &mut usize,
+    j: &mut usize,
+) -> Option<usize>
+where
+    T: Eq,
+{
+    let m = pattern.len();
+    if m == 0 {
+        *i += 1;
+        return (*i <= text.len() + 1).then(|| *i - 1);
+    }
 
-    let mut pos = 1; // The current position we are computing in `t`
-    let mut cnd = 0; // The zero-based index in `w` of the next character of the current candidate substring
+    while *i < text.len() {
+        while *j > 0 && text[*i] != pattern[*j] {
+            *j = failure[*j - 1];
+        }
+        if text[*i] == pattern[*j] {
+            *j += 1;
+        }
+        *i += 1;
+
+        if *j == m {
+            *j = failure[m - 1];
+            return Some(*i - m);
+        }
+    }
+    None
+}
+
+/// A [`Matcher`] using the Knuth-Morris-Pratt algorithm.
+///
+/// The pattern's failure table tells how much of the pattern still matches
+/// after a mismatch, so the haystack is read once from left to right and
+/// never backtracks, even when finding overlapping occurrences.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{KmpMatcher, Matcher};
+///
+/// let matcher = KmpMatcher::new("aab");
+/// assert!(matcher.find_overlapping_iter("aaabaabaab").eq([1, 4, 7]));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Preprocessing: O(m)
+/// - Search: O(n)
+///
+/// # References
+///
+/// [Knuth-Morris-Pratt Algorithm](https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KmpMatcher {
+    pattern: Vec<u8>,
+    failure: Vec<usize>,
+}
 
-    while pos < p.len() {
-        if p.chars().nth(pos)? == p.chars().nth(cnd)? {
-            cnd += 1;
-        } else if cnd > 0 {
-            cnd = t[cnd - 1];
-            continue;
+impl KmpMatcher {
+    /// Compiles `pattern` for searching.
+    #[must_use]
+    pub fn new<P>(pattern: &P) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref().to_vec();
+        Self {
+            failure: prefix_function(&pattern),
+            pattern,
         }
-        pos += 1;
-        t.push(cnd);
     }
-    Some(t)
+}
+
+impl Matcher for KmpMatcher {
+    fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    fn find_at(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let (mut i, mut j) = (start, 0);
+        kmp_next(haystack, &self.pattern, &self.failure, &mut i, &mut j)
+    }
+
+    fn find_overlapping_iter<'a, H>(&'a self, haystack: &'a H) -> impl Iterator<Item = usize> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let haystack = haystack.as_ref();
+        let (mut i, mut j) = (0, 0);
+        core::iter::from_fn(move || {
+            kmp_next(haystack, &self.pattern, &self.failure, &mut i, &mut j)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -88,9 +235,27 @@ mod tests {
     }
 
     #[test_case("aabaabaaa", &[0, 1, 0, 1, 2, 3, 4, 5, 2])]
-    fn test_get_failure_array(pattern: &str, array: &[usize]) {
-        unsafe {
-            assert_eq!(get_failure_array(pattern).unwrap_unchecked(), array);
-        }
+    fn test_prefix_function(pattern: &str, array: &[usize]) {
+        assert_eq!(prefix_function(pattern.as_bytes()), array);
+    }
+
+    #[test_case("", "abc", true)]
+    #[test_case("abc", "", false)]
+    #[test_case("d", "abc", false)]
+    #[test_case("\u{e9}t\u{e9}", "\u{e9}t\u{e9} et \u{e9}t\u{e9}", true)]
+    #[test_case("tt", "\u{e9}t\u{e9}", false)]
+    fn test_kmp_check_edge_cases(pattern: &str, text: &str, expected: bool) {
+        assert_eq!(kmp_check(text, pattern), expected);
+    }
+
+    #[test]
+    fn test_kmp_search() {
+        let text: Vec<char> = "\u{3b1}\u{3b2}\u{3b1}\u{3b2}\u{3b1}".chars().collect();
+        assert_eq!(
+            kmp_search(&text, &['\u{3b1}', '\u{3b2}', '\u{3b1}']),
+            [0, 2]
+        );
+        assert_eq!(kmp_search(&[1, 2, 3], &[]), [0, 1, 2, 3]);
+        assert_eq!(kmp_search(&[1, 2], &[1, 2, 3]), []);
     }
 }
@@ -0,0 +1,266 @@
+/// A pattern compiled for searching, with a common interface over the different search algorithms.
+///
+/// Each matcher preprocesses its pattern once when it is built, and can then
+/// search any number of haystacks. Haystacks are anything that can be viewed
+/// as bytes, such as `str`, `String` and `[u8]`, and every position reported
+/// is a byte offset into the haystack.
+///
+/// Only [`pattern`](Matcher::pattern) and [`find_at`](Matcher::find_at) have
+/// to be implemented; the other methods are built on them, and matchers
+/// override them when they can do better.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{KmpMatcher, Matcher};
+///
+/// let matcher = KmpMatcher::new("ana");
+/// let text = "bananas and ananas";
+///
+/// assert_eq!(matcher.find(text), Some(1));
+/// assert_eq!(matcher.rfind(text), Some(14));
+/// assert!(matcher.find_iter(text).eq([1, 12]));
+/// assert!(matcher.find_overlapping_iter(text).eq([1, 3, 12, 14]));
+/// assert_eq!(matcher.count(text), 2);
+/// assert!(matcher.is_match(text));
+/// ```
+pub trait Matcher {
+    /// The pattern being searched for.
+    fn pattern(&self) -> &[u8];
+
+    /// Finds the first occurrence of the pattern that starts at or after `start`.
+    ///
+    /// # Returns
+    ///
+    /// The byte offset of the occurrence, or `None` if there is none. An empty
+    /// pattern occurs at every offset from `0` to `haystack.len()`.
+    fn find_at(&self, haystack: &[u8], start: usize) -> Option<usize>;
+
+    /// Finds the first occurrence of the pattern.
+    ///
+    /// # Returns
+    ///
+    /// The byte offset of the occurrence, or `None` if there is none.
+    fn find<H>(&self, haystack: &H) -> Option<usize>
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        self.find_at(haystack.as_ref(), 0)
+    }
+
+    /// Finds the last occurrence of the pattern.
+    ///
+    /// # Returns
+    ///
+    /// The byte offset of the occurrence, or `None` if there is none.
+    fn rfind<H>(&self, haystack: &H) -> Option<usize>
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        self.find_overlapping_iter(haystack).last()
+    }
+
+    /// Iterates over the occurrences of the pattern that do not overlap.
+    ///
+    /// The haystack is scanned from the left, and each occurrence found
+    /// resumes the search after its end, like [`str::match_indices`].
+    fn find_iter<'a, H>(&'a self, haystack: &'a H) -> impl Iterator<Item = usize> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let haystack = haystack.as_ref();
+        let step = self.pattern().len().max(1);
+        let mut start = 0;
+
+        core::iter::from_fn(move || {
+            let found = self.find_at(haystack, start)?;
+            start = found + step;
+            Some(found)
+        })
+    }
+
+    /// Iterates over every occurrence of the pattern, including those that overlap.
+    fn find_overlapping_iter<'a, H>(&'a self, haystack: &'a H) -> impl Iterator<Item = usize> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let haystack = haystack.as_ref();
+        let mut start = 0;
+
+        core::iter::from_fn(move || {
+            let found = self.find_at(haystack, start)?;
+            start = found + 1;
+            Some(found)
+        })
+    }
+
+    /// Counts the occurrences of the pattern that do not overlap.
+    fn count<H>(&self, haystack: &H) -> usize
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        self.find_iter(haystack).count()
+    }
+
+    /// Checks whether the pattern occurs in the haystack.
+    fn is_match<H>(&self, haystack: &H) -> bool
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        self.find(haystack).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matcher;
+    use crate::strings::search::{
+        BoyerMooreMatcher, BruteForceMatcher, HorspoolMatcher, KmpMatcher, RabinKarpMatcher,
+        SundayMatcher, TwoWayMatcher, ZMatcher,
+    };
+
+    use alloc::{vec, vec::Vec};
+
+    /// Every overlapping occurrence of `pattern`, found by comparing every window.
+    fn occurrences(haystack: &[u8], pattern: &[u8]) -> Vec<usize> {
+        (0..=haystack.len())
+            .filter(|&i| haystack[i..].starts_with(pattern))
+            .collect()
+    }
+
+    fn check<M>(build: impl Fn(&[u8]) -> M)
+    where
+        M: Matcher,
+    {
+        let haystacks: [&[u8]; 6] = [
+            b"",
+            b"a",
+            b"aaaaaaaaaa",
+            b"abracadabra abracadabra",
+            "h\u{e9}llo w\u{f6}rld, h\u{e9}llo".as_bytes(),
+            b"abababababcababab",
+        ];
+        let patterns: [&[u8]; 9] = [
+            b"",
+            b"a",
+            b"aa",
+            b"aaa",
+            b"abra",
+            b"abab",
+            b"ababc",
+            "\u{e9}llo".as_bytes(),
+            b"zzz",
+        ];
+
+        for pattern in patterns {
+            let matcher = build(pattern);
+            assert_eq!(matcher.pattern(), pattern);
+
+            for haystack in haystacks {
+                let all = occurrences(haystack, pattern);
+                let mut disjoint = vec![];
+                for &i in &all {
+                    if disjoint
+                        .last()
+                        .is_none_or(|&last: &usize| i >= last + pattern.len().max(1))
+                    {
+                        disjoint.push(i);
+                    }
+                }
+
+                let context = (
+                    core::str::from_utf8(pattern).unwrap(),
+                    core::str::from_utf8(haystack).unwrap(),
+                );
+                assert_eq!(matcher.find(haystack), all.first().copied(), "{context:?}");
+                assert_eq!(matcher.rfind(haystack), all.last().copied(), "{context:?}");
+                assert_eq!(
+                    matcher.find_overlapping_iter(haystack).collect::<Vec<_>>(),
+                    all,
+                    "{context:?}"
+                );
+                assert_eq!(
+                    matcher.find_iter(haystack).collect::<Vec<_>>(),
+                    disjoint,
+                    "{context:?}"
+                );
+                assert_eq!(matcher.count(haystack), disjoint.len(), "{context:?}");
+                assert_eq!(matcher.is_match(haystack), !all.is_empty(), "{context:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn boyer_moore_matcher() {
+        check(BoyerMooreMatcher::new);
+    }
+
+    #[test]
+    fn brute_force_matcher() {
+        check(BruteForceMatcher::new);
+    }
+
+    #[test]
+    fn horspool_matcher() {
+        check(HorspoolMatcher::new);
+    }
+
+    #[test]
+    fn kmp_matcher() {
+        check(KmpMatcher::new);
+    }
+
+    #[test]
+    fn rabin_karp_matcher() {
+        check(RabinKarpMatcher::new);
+    }
+
+    #[test]
+    fn sunday_matcher() {
+        check(SundayMatcher::new);
+    }
+
+    #[test]
+    fn two_way_matcher() {
+        check(TwoWayMatcher::new);
+    }
+
+    #[test]
+    fn z_matcher() {
+        check(ZMatcher::new);
+    }
+
+    #[test]
+    fn agrees_on_every_short_binary_pattern() {
+        let haystack = b"abaababaabaababaababaabaababaabaabbbabbabbaaaab";
+        for len in 1..=6 {
+            for bits in 0..1_u32 << len {
+                let pattern: Vec<u8> = (0..len)
+                    .map(|i| if bits >> i & 1 == 1 { b'b' } else { b'a' })
+                    .collect();
+                let all = occurrences(haystack, &pattern);
+
+                assert!(BoyerMooreMatcher::new(&pattern)
+                    .find_overlapping_iter(haystack)
+                    .eq(all.iter().copied()));
+                assert!(HorspoolMatcher::new(&pattern)
+                    .find_overlapping_iter(haystack)
+                    .eq(all.iter().copied()));
+                assert!(SundayMatcher::new(&pattern)
+                    .find_overlapping_iter(haystack)
+                    .eq(all.iter().copied()));
+                assert!(TwoWayMatcher::new(&pattern)
+                    .find_overlapping_iter(haystack)
+                    .eq(all.iter().copied()));
+            }
+        }
+    }
+
+    #[test]
+    fn reports_byte_offsets() {
+        let text = "\u{fc}ber caf\u{e9} caf\u{e9}";
+        let matcher = KmpMatcher::new("caf\u{e9}");
+        assert!(matcher.find_iter(text).eq([6, 12]));
+        assert_eq!(&text[6..11], "caf\u{e9}");
+    }
+}
@@ -1,15 +1,31 @@
 //! Useful algorithms for searching strings
 
+mod aho_corasick;
+mod bitap;
+mod boyer_moore;
 mod brute_force;
+mod horspool;
 mod kmp;
+mod matcher;
 mod naive_pattern_search;
 mod rabin_karp;
+mod rolling_hash;
+mod sunday;
+mod two_way;
 mod word_occurrences;
 mod z_function;
 
+pub use aho_corasick::*;
+pub use bitap::*;
+pub use boyer_moore::*;
 pub use brute_force::*;
+pub use horspool::*;
 pub use kmp::*;
+pub use matcher::*;
 pub use naive_pattern_search::*;
 pub use rabin_karp::*;
+pub use rolling_hash::*;
+pub use sunday::*;
+pub use two_way::*;
 pub use word_occurrences::*;
 pub use z_function::*;
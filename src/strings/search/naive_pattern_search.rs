@@ -23,15 +23,17 @@ use alloc::vec::Vec;
 /// println!("{:?}", naive_pattern_search("ABCDEGFTEST", "TEST"));
 /// ```
 ///
-/// # References
+/// # Returns
 ///
-/// [Naive Pattern Search](https://en.wikipedia.org/wiki/Naive_pattern_search)
+/// The byte offset of every occurrence of `pattern` in `s`, including
+/// overlapping ones. The empty pattern occurs at every offset.
 ///
-/// # Panics
+/// # References
 ///
-/// Panics if `s` or `pattern` is empty
+/// [Naive Pattern Search](https://en.wikipedia.org/wiki/Naive_pattern_search)
 #[must_use]
 pub fn naive_pattern_search<'a>(s: &'a str, pattern: &'a str) -> Vec<usize> {
+    let (s, pattern) = (s.as_bytes(), pattern.as_bytes());
     let pat_len = pattern.len();
     let mut positions = Vec::new();
 
@@ -42,7 +44,7 @@ pub fn naive_pattern_search<'a>(s: &'a str, pattern: &'a str) -> Vec<usize> {
     for i in 0..=(s.len() - pat_len) {
         let mut match_found = true;
         for j in 0..pat_len {
-            if s.chars().nth(i + j).unwrap() != pattern.chars().nth(j).unwrap() {
+            if s[i + j] != pattern[j] {
                 match_found = false;
                 break;
             }
@@ -59,6 +61,7 @@ pub fn naive_pattern_search<'a>(s: &'a str, pattern: &'a str) -> Vec<usize> {
 mod tests {
     use super::*;
 
+    use alloc::{vec, vec::Vec};
     use test_case::test_case;
 
     #[test_case(("ABCDEFG", "DE"))]
@@ -66,4 +69,13 @@ mod tests {
         let actual = naive_pattern_search(data.0, data.1);
         assert_eq!(actual, [3]);
     }
+
+    #[test_case("ABAAABCDBBABCDDEBCABC", "ABC" => vec![4, 10, 18])]
+    #[test_case("ABC", "ABAAABCDBBABCDDEBCABC" => Vec::<usize>::new())]
+    #[test_case("", "ABC" => Vec::<usize>::new())]
+    #[test_case("AB", "" => vec![0, 1, 2])]
+    #[test_case("gr\u{f8}d p\u{e5} gr\u{f8}d", "gr\u{f8}d" => vec![0, 10])]
+    fn test_naive_pattern_search_positions(s: &str, pattern: &str) -> Vec<usize> {
+        naive_pattern_search(s, pattern)
+    }
 }
@@ -1,87 +1,475 @@
-// Numbers of alphabet which we call base
-const ALPHABET_SIZE: isize = 256;
+use alloc::vec::Vec;
 
-// Modulus to hash a string
-const MODULUS: isize = 1_000_003;
+use hashbrown::HashMap;
 
-/// The Rabin-Karp Algorithm efficiently finds a pattern within a piece of text.
+use super::{Match, Matcher, Modulus, RollingHash};
+
+/// Checks whether a pattern occurs in a text with the Rabin-Karp algorithm.
 ///
-/// With a complexity of O(nm), it is especially effective when used with multiple patterns.
-/// The algorithm can check if any of a set of patterns match a section of text in O(1)
-/// time, given precomputed hashes.
+/// The hash of the pattern is compared with a rolling hash of each window of
+/// the text, which is updated in O(1) as the window slides by one byte. The
+/// bytes are only compared when the hashes match, so a search takes O(n + m)
+/// expected time, and O(nm) only if every window collides. To look for
+/// several patterns in one pass, use [`MultiRabinKarp`].
 ///
-/// This will be the simple version which only assumes one pattern is being searched
-/// for but it's not hard to modify
+/// # Arguments
 ///
-/// 1) Calculate pattern hash
+/// * `text` - The text to be searched
+/// * `pattern` - The word sought
 ///
-/// 2) Step through the text one character at a time passing a window with the same
-///     length as the pattern
-///     calculating the hash of the text within the window compare it with the hash
-///     of the pattern. Only testing equality if the hashes match
+/// # Returns
 ///
-/// 3) If the hashes match then check if the text is equal to the pattern
+/// Returns true if the pattern is found in the text else false. The empty
+/// pattern is found in every text.
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub fn rabin_karp_check(text: &str, pattern: &str) -> bool {
+    RabinKarpMatcher::new(pattern).is_match(text)
+}
+
+/// Finds every occurrence of a pattern in a sequence with the Rabin-Karp algorithm.
+///
+/// Works on any sequence of items that convert into `u64`, such as the bytes
+/// of a string or its `char`s. The hash uses a random base, and every
+/// window whose hash matches is compared item by item, so the result is
+/// always exact.
 ///
 /// # Arguments
 ///
-/// * `text` - The text to be searched
-/// * `pattern` - The word sought
+/// * `text` - The sequence to be searched
+/// * `pattern` - The sequence sought
 ///
 /// # Returns
 ///
-/// Returns true if the pattern is found in the text else false
-/// # Panics
+/// The starting index of every occurrence, including overlapping ones, in
+/// ascending order. The empty pattern occurs at every index from `0` to
+/// `text.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::rabin_karp_search;
+///
+/// assert_eq!(rabin_karp_search(b"abababa", b"aba"), [0, 2, 4]);
 ///
-/// Panics if `text` or `pattern` is empty
+/// let text: Vec<char> = "\u{1f600}a\u{1f600}".chars().collect();
+/// assert_eq!(rabin_karp_search(&text, &['\u{1f600}']), [0, 2]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n + m) expected
 #[must_use]
-#[allow(clippy::module_name_repetitions)]
-pub fn rabin_karp_check(text: &str, pattern: &str) -> bool {
-    let p_len = pattern.len();
-    let t_len = text.len();
+pub fn rabin_karp_search<T>(text: &[T], pattern: &[T]) -> Vec<usize>
+where
+    T: Copy + Into<u64> + Eq,
+{
+    rabin_karp_search_with(text, pattern, RollingHash::random(Modulus::Mersenne61))
+}
+
+/// Finds every occurrence of a pattern in a sequence with the Rabin-Karp
+/// algorithm, comparing windows with the given rolling hash.
+///
+/// See [`rabin_karp_search`], which uses a random base modulo 2^61 - 1.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{rabin_karp_search_with, Modulus, RollingHash};
+///
+/// let hasher = RollingHash::random(Modulus::Double);
+/// assert_eq!(rabin_karp_search_with(b"abababa", b"aba", hasher), [0, 2, 4]);
+/// ```
+#[must_use]
+pub fn rabin_karp_search_with<T>(text: &[T], pattern: &[T], hasher: RollingHash) -> Vec<usize>
+where
+    T: Copy + Into<u64> + Eq,
+{
+    let target = hasher.hash(pattern);
+    let leading = hasher.power(pattern.len());
+
+    windows(hasher, text, pattern.len(), leading)
+        .filter(|&(start, hash)| hash == target && text[start..start + pattern.len()] == *pattern)
+        .map(|(start, _)| start)
+        .collect()
+}
+
+/// The hash of every window of `len` items of `text`, with where it starts.
+fn windows<T>(
+    hasher: RollingHash,
+    text: &[T],
+    len: usize,
+    leading: u64,
+) -> impl Iterator<Item = (usize, u64)> + '_
+where
+    T: Copy + Into<u64>,
+{
+    let mut hash = text.get(..len).map(|window| hasher.hash(window));
+    (0..=text.len().saturating_sub(len)).map_while(move |start| {
+        let current = hash?;
+        if let Some(&incoming) = text.get(start + len) {
+            hash = Some(hasher.roll(current, text[start], incoming, leading));
+        }
+        Some((start, current))
+    })
+}
 
-    if p_len > t_len {
-        return false;
+/// Searches for many patterns of the same length at once with the Rabin-Karp algorithm.
+///
+/// The hashes of the patterns are kept in a hash table, so each window of
+/// the text is hashed once and looked up in constant expected time, however
+/// many patterns there are. Windows whose hash matches are compared with
+/// the patterns byte by byte, so the matches are always exact.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::MultiRabinKarp;
+///
+/// let codons = MultiRabinKarp::new(["TAA", "TAG", "TGA"]);
+/// let found: Vec<(usize, usize)> = codons
+///     .find_iter("ATGTAGCTGAT")
+///     .map(|m| (m.pattern, m.start))
+///     .collect();
+/// assert_eq!(found, [(1, 3), (2, 7)]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Preprocessing: O(k * m) for `k` patterns of length `m`
+/// - Search: O(n + z * m) expected, for `z` matches
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Rabin%E2%80%93Karp_algorithm#Multiple_pattern_search)
+#[derive(Debug, Clone)]
+pub struct MultiRabinKarp {
+    patterns: Vec<Vec<u8>>,
+    hasher: RollingHash,
+    leading: u64,
+    /// The patterns with each hash, in the order they were given.
+    table: HashMap<u64, Vec<usize>>,
+}
+
+impl MultiRabinKarp {
+    /// Prepares to search for `patterns`, with a random base modulo 2^61 - 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patterns do not all have the same length.
+    #[must_use]
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        Self::with_hasher(patterns, RollingHash::random(Modulus::Mersenne61))
     }
 
-    let mut p_hash = 0isize;
-    let mut text_hash = 0isize;
-    let mut modulus_pow = 1isize;
+    /// Prepares to search for `patterns`, comparing windows with the given rolling hash.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patterns do not all have the same length.
+    #[must_use]
+    pub fn with_hasher<I, P>(patterns: I, hasher: RollingHash) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        let patterns: Vec<Vec<u8>> = patterns.into_iter().map(|p| p.as_ref().to_vec()).collect();
+        let len = patterns.first().map_or(0, Vec::len);
+        assert!(
+            patterns.iter().all(|p| p.len() == len),
+            "the patterns must all have the same length"
+        );
 
-    // Calculating the hash of pattern and substring of text
-    for i in 0..p_len {
-        p_hash = (pattern.chars().nth(i).unwrap() as isize + p_hash * ALPHABET_SIZE) % MODULUS;
-        text_hash = (text.chars().nth(i).unwrap() as isize + text_hash * ALPHABET_SIZE) % MODULUS;
+        let mut table: HashMap<u64, Vec<usize>> = HashMap::new();
+        for (index, pattern) in patterns.iter().enumerate() {
+            table.entry(hasher.hash(pattern)).or_default().push(index);
+        }
 
-        if i == p_len - 1 {
-            continue;
+        Self {
+            leading: hasher.power(len),
+            patterns,
+            hasher,
+            table,
         }
+    }
+
+    /// The length shared by the patterns.
+    #[must_use]
+    pub fn pattern_len(&self) -> usize {
+        self.patterns.first().map_or(0, Vec::len)
+    }
 
-        modulus_pow = (modulus_pow * ALPHABET_SIZE) % MODULUS;
+    /// The number of patterns.
+    #[must_use]
+    pub fn patterns_len(&self) -> usize {
+        self.patterns.len()
     }
 
-    for i in 0..=(t_len - p_len) {
-        if text_hash == p_hash && &text[i..i + p_len] == pattern {
-            return true;
+    /// Iterates over every occurrence of every pattern, including those that overlap.
+    ///
+    /// Matches are ordered by where they start, and matches at the same
+    /// position by pattern. A pattern given more than once matches once for
+    /// each time it was given.
+    pub fn find_iter<'a, H>(&'a self, haystack: &'a H) -> impl Iterator<Item = Match> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let haystack = haystack.as_ref();
+        let len = self.pattern_len();
+
+        windows(self.hasher, haystack, len, self.leading).flat_map(move |(start, hash)| {
+            let window = &haystack[start..start + len];
+            self.table
+                .get(&hash)
+                .into_iter()
+                .flatten()
+                .filter(move |&&pattern| self.patterns[pattern] == window)
+                .map(move |&pattern| Match {
+                    pattern,
+                    start,
+                    end: start + len,
+                })
+        })
+    }
+}
+
+/// Finds every occurrence of a pattern matrix in a text matrix with the
+/// two-dimensional Rabin-Karp algorithm.
+///
+/// Each row of the text is hashed in windows as wide as the pattern with one
+/// rolling hash, and those hashes are then rolled down each column with a
+/// second one, so every window of the text gets a single hash in amortized
+/// constant time. Windows whose hash matches are compared cell by cell.
+///
+/// # Arguments
+///
+/// * `text` - The rows of the matrix to be searched
+/// * `pattern` - The rows of the matrix sought
+///
+/// # Returns
+///
+/// The row and column of the top-left corner of every occurrence, in
+/// row-major order.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::rabin_karp_2d;
+///
+/// let text = ["abcab", "cabca", "abcab", "cabca"];
+/// let text: Vec<&[u8]> = text.iter().map(|row| row.as_bytes()).collect();
+/// let pattern: [&[u8]; 2] = [b"ab", b"ca"];
+///
+/// assert_eq!(rabin_karp_2d(&text, &pattern), [(0, 0), (0, 3), (2, 0), (2, 3)]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(w * h) expected, for a text of `h` rows of `w` items
+///
+/// # Panics
+///
+/// Panics if the rows of the text, or of the pattern, do not all have the same length.
+#[must_use]
+pub fn rabin_karp_2d<T, R, S>(text: &[R], pattern: &[S]) -> Vec<(usize, usize)>
+where
+    T: Copy + Into<u64> + Eq,
+    R: AsRef<[T]>,
+    S: AsRef<[T]>,
+{
+    rabin_karp_2d_with(
+        text,
+        pattern,
+        RollingHash::random(Modulus::Mersenne61),
+        RollingHash::random(Modulus::Mersenne61),
+    )
+}
+
+/// Finds every occurrence of a pattern matrix in a text matrix with the
+/// two-dimensional Rabin-Karp algorithm, hashing the rows with `rows` and
+/// the columns of row hashes with `columns`.
+///
+/// See [`rabin_karp_2d`], which uses two random bases modulo 2^61 - 1.
+/// The two hash functions should have different bases.
+///
+/// # Panics
+///
+/// Panics if the rows of the text, or of the pattern, do not all have the same length.
+#[must_use]
+pub fn rabin_karp_2d_with<T, R, S>(
+    text: &[R],
+    pattern: &[S],
+    rows: RollingHash,
+    columns: RollingHash,
+) -> Vec<(usize, usize)>
+where
+    T: Copy + Into<u64> + Eq,
+    R: AsRef<[T]>,
+    S: AsRef<[T]>,
+{
+    let (text_width, pattern_width) = (width(text), width(pattern));
+    let (text_height, pattern_height) = (text.len(), pattern.len());
+    if pattern_height > text_height || pattern_width > text_width {
+        return Vec::new();
+    }
+
+    let row_leading = rows.power(pattern_width);
+    let column_leading = columns.power(pattern_height);
+
+    let pattern_rows: Vec<u64> = pattern.iter().map(|row| rows.hash(row.as_ref())).collect();
+    let target = columns.hash(&pattern_rows);
+
+    // `row_hashes[r][c]` is the hash of the window of row `r` starting at column `c`.
+    let row_hashes: Vec<Vec<u64>> = text
+        .iter()
+        .map(|row| {
+            windows(rows, row.as_ref(), pattern_width, row_leading)
+                .map(|(_, hash)| hash)
+                .collect()
+        })
+        .collect();
+
+    let mut found = Vec::new();
+    for column in 0..=text_width - pattern_width {
+        let cells: Vec<u64> = row_hashes.iter().map(|row| row[column]).collect();
+        for (row, hash) in windows(columns, &cells, pattern_height, column_leading) {
+            let matches = hash == target
+                && (0..pattern_height).all(|i| {
+                    text[row + i].as_ref()[column..column + pattern_width] == *pattern[i].as_ref()
+                });
+            if matches {
+                found.push((row, column));
+            }
         }
+    }
+
+    found.sort_unstable();
+    found
+}
+
+/// The length shared by the rows of a matrix.
+fn width<T, R>(matrix: &[R]) -> usize
+where
+    R: AsRef<[T]>,
+{
+    let width = matrix.first().map_or(0, |row| row.as_ref().len());
+    assert!(
+        matrix.iter().all(|row| row.as_ref().len() == width),
+        "the rows of a matrix must all have the same length"
+    );
+    width
+}
+
+/// A [`Matcher`] using the Rabin-Karp algorithm.
+///
+/// Windows of the haystack are compared by a [`RollingHash`], by default
+/// with a random base modulo the Mersenne prime 2^61 - 1, which is updated in constant
+/// time as the window slides. Windows whose hash equals the hash of the
+/// pattern are compared byte by byte, so hash collisions never produce
+/// false matches.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{Matcher, RabinKarpMatcher};
+///
+/// let matcher = RabinKarpMatcher::new("abc1abc12");
+/// assert_eq!(matcher.find("alskfjaldsabc1abc1abc12k23adsfabcabc"), Some(14));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Preprocessing: O(m)
+/// - Search: O(n) expected, O(nm) if every window collides
+///
+/// # See more
+/// [Wikipedia](https://en.wikipedia.org/wiki/Rabin%E2%80%93Karp_algorithm)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RabinKarpMatcher {
+    pattern: Vec<u8>,
+    hasher: RollingHash,
+    hash: u64,
+    /// The base raised to the length of the pattern, for removing the byte leaving the window.
+    leading: u64,
+}
 
-        if i == t_len - p_len {
-            continue;
+impl RabinKarpMatcher {
+    /// Compiles `pattern` for searching, with a random base modulo 2^61 - 1.
+    #[must_use]
+    pub fn new<P>(pattern: &P) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        Self::with_hasher(pattern, RollingHash::random(Modulus::Mersenne61))
+    }
+
+    /// Compiles `pattern` for searching, comparing windows with the given rolling hash.
+    #[must_use]
+    pub fn with_hasher<P>(pattern: &P, hasher: RollingHash) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref().to_vec();
+        Self {
+            hash: hasher.hash(&pattern),
+            leading: hasher.power(pattern.len()),
+            hasher,
+            pattern,
         }
+    }
+
+    /// The occurrences starting at or after `start`, including those that overlap.
+    fn occurrences<'a>(
+        &'a self,
+        haystack: &'a [u8],
+        start: usize,
+    ) -> impl Iterator<Item = usize> + 'a {
+        let m = self.pattern.len();
+        haystack.get(start..).into_iter().flat_map(move |rest| {
+            windows(self.hasher, rest, m, self.leading)
+                .filter(move |&(i, hash)| hash == self.hash && rest[i..i + m] == *self.pattern)
+                .map(move |(i, _)| start + i)
+        })
+    }
+}
+
+impl Matcher for RabinKarpMatcher {
+    fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    fn find_at(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        self.occurrences(haystack, start).next()
+    }
 
-        // Calculating the hash of the next character
-        // Ref: <https://en.wikipedia.org/wiki/Rolling_hash>
-        let curr_text = text.chars().nth(i).unwrap();
-        let curr_text_char_with_p_len = text.chars().nth(i + p_len).unwrap();
-        text_hash = ((((text_hash - curr_text as isize * modulus_pow) * ALPHABET_SIZE)
-            + curr_text_char_with_p_len as isize)
-            % MODULUS)
-            + MODULUS;
+    fn find_overlapping_iter<'a, H>(&'a self, haystack: &'a H) -> impl Iterator<Item = usize> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        self.occurrences(haystack.as_ref(), 0)
     }
-    false
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    use super::{Modulus, RollingHash};
+    use crate::strings::search::Matcher;
+
     #[test]
     fn test_rabin_karp_search() {
         let pattern = "abc1abc12";
@@ -89,4 +477,81 @@ mod tests {
         let result = super::rabin_karp_check(text, pattern);
         assert!(result);
     }
+
+    #[test_case("", "abc" => true)]
+    #[test_case("abc", "" => false)]
+    #[test_case("\u{e9}", "caf\u{e9}" => true)]
+    #[test_case("\u{e8}", "caf\u{e9}" => false)]
+    fn test_rabin_karp_check_edge_cases(pattern: &str, text: &str) -> bool {
+        super::rabin_karp_check(text, pattern)
+    }
+
+    #[test]
+    fn test_rabin_karp_search_positions() {
+        assert_eq!(super::rabin_karp_search(b"aaaa", b"aa"), [0, 1, 2]);
+        assert_eq!(super::rabin_karp_search(b"ab", b""), [0, 1, 2]);
+        assert_eq!(super::rabin_karp_search(b"ab", b"abc"), []);
+        assert_eq!(super::rabin_karp_search(&[0_u8, 0, 0], &[0, 0]), [0, 1]);
+    }
+
+    #[test]
+    fn test_multi_rabin_karp() {
+        let searcher = super::MultiRabinKarp::new(["aba", "bab", "aba", "xyz"]);
+        assert_eq!((searcher.patterns_len(), searcher.pattern_len()), (4, 3));
+
+        let found: Vec<(usize, usize)> = searcher
+            .find_iter("ababa")
+            .map(|m| (m.pattern, m.start))
+            .collect();
+        assert_eq!(found, [(0, 0), (2, 0), (1, 1), (0, 2), (2, 2)]);
+        assert_eq!(searcher.find_iter("ab").count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_multi_rabin_karp_rejects_lengths() {
+        let _ = super::MultiRabinKarp::new(["ab", "abc"]);
+    }
+
+    #[test]
+    fn test_rabin_karp_2d() {
+        let text = [[1_u8, 2, 1, 2], [3, 4, 3, 4], [1, 2, 1, 2], [3, 4, 3, 5]];
+        assert_eq!(
+            super::rabin_karp_2d(&text, &[[1, 2], [3, 4]]),
+            [(0, 0), (0, 2), (2, 0)]
+        );
+        assert_eq!(
+            super::rabin_karp_2d(&text, &[[4, 3, 4], [2, 1, 2]]),
+            [(1, 1)]
+        );
+        assert_eq!(super::rabin_karp_2d(&text, &[[5_u8; 5]]), []);
+
+        let rows: [&[u8]; 0] = [];
+        assert_eq!(super::rabin_karp_2d(&text, &rows).len(), 25);
+    }
+
+    #[test_case(RollingHash::random(Modulus::Double); "double")]
+    #[test_case(RollingHash::new(2, Modulus::Mersenne61); "small base")]
+    #[test_case(RollingHash::new(0, Modulus::Double); "every window collides")]
+    fn test_rabin_karp_with_hasher(hasher: RollingHash) {
+        assert_eq!(
+            super::rabin_karp_search_with(b"abababa", b"aba", hasher),
+            [0, 2, 4]
+        );
+
+        let found: Vec<(usize, usize)> = super::MultiRabinKarp::with_hasher(["ab", "ba"], hasher)
+            .find_iter("abba")
+            .map(|m| (m.pattern, m.start))
+            .collect();
+        assert_eq!(found, [(0, 0), (1, 2)]);
+
+        let text = [[1_u8, 2, 1], [3, 4, 3]];
+        assert_eq!(
+            super::rabin_karp_2d_with(&text, &[[1], [3]], hasher, hasher),
+            [(0, 0), (0, 2)]
+        );
+
+        let matcher = super::RabinKarpMatcher::with_hasher("ba", hasher);
+        assert_eq!(matcher.find("abba"), Some(2));
+    }
 }
@@ -0,0 +1,334 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use rand::Rng;
+
+/// The Mersenne prime 2^61 - 1.
+const MERSENNE_61: u64 = (1 << 61) - 1;
+
+/// The two primes of [`Modulus::Double`], each below 2^31.
+const DOUBLE: [u64; 2] = [2_147_483_629, 2_147_483_587];
+
+/// The modulus a [`RollingHash`] reduces its values by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Modulus {
+    /// The Mersenne prime 2^61 - 1, which can be reduced with shifts and
+    /// makes collisions between two strings of length `n` happen with
+    /// probability about n / 2^61 for a random base.
+    #[default]
+    Mersenne61,
+    /// Two primes below 2^31, each with its own base, whose hashes are
+    /// packed into the high and low halves of one `u64`. Two strings collide
+    /// only if they collide modulo both primes.
+    Double,
+}
+
+/// A polynomial hash function whose value can be updated in constant time
+/// as a window slides over a sequence.
+///
+/// The hash of `s` is `s[0] * b^(k-1) + s[1] * b^(k-2) + ... + s[k-1]`
+/// modulo a prime, for a base `b`. Equal sequences have equal hashes. A base
+/// chosen at random makes it unlikely that two different sequences of the
+/// same length collide, whatever the input, so a random base should be
+/// used when the input could be adversarial.
+///
+/// Items are anything that converts into `u64`, such as bytes, `char`s and
+/// the hashes themselves. Hashes of sequences of different lengths should
+/// not be compared, since leading zeros do not change the hash.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{Modulus, RollingHash};
+///
+/// let hash = RollingHash::random(Modulus::Mersenne61);
+/// let text = b"abracadabra";
+///
+/// // Slide a window of four bytes one position to the right.
+/// let leading = hash.power(4);
+/// let window = hash.hash(&text[..4]);
+/// let next = hash.roll(window, text[0], text[4], leading);
+/// assert_eq!(next, hash.hash(&text[1..5]));
+///
+/// // Hash any substring in constant time.
+/// let prefixes = hash.prefix_hashes(text);
+/// assert_eq!(prefixes.substring(0..4), prefixes.substring(7..11));
+/// assert_ne!(prefixes.substring(0..4), prefixes.substring(1..5));
+/// ```
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Rolling_hash)
+/// - [CP Algorithms](https://cp-algorithms.com/string/string-hashing.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RollingHash {
+    /// The base, packed like a hash value.
+    base: u64,
+    modulus: Modulus,
+}
+
+impl RollingHash {
+    /// A hash function with the given base, reduced by the modulus.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base of the polynomial. It should be larger than every
+    ///   item hashed, and is reduced by each prime of the modulus.
+    /// * `modulus` - The modulus the hash values are reduced by.
+    #[must_use]
+    pub fn new(base: u64, modulus: Modulus) -> Self {
+        let base = match modulus {
+            Modulus::Mersenne61 => base % MERSENNE_61,
+            Modulus::Double => pack(base % DOUBLE[0], base % DOUBLE[1]),
+        };
+        Self { base, modulus }
+    }
+
+    /// A hash function with a base drawn at random from the thread-local generator.
+    #[must_use]
+    pub fn random(modulus: Modulus) -> Self {
+        let mut rng = rand::thread_rng();
+        let base = match modulus {
+            Modulus::Mersenne61 => rng.gen_range(1 << 16..MERSENNE_61),
+            Modulus::Double => pack(
+                rng.gen_range(1 << 16..DOUBLE[0]),
+                rng.gen_range(1 << 16..DOUBLE[1]),
+            ),
+        };
+        Self { base, modulus }
+    }
+
+    /// The modulus the hash values are reduced by.
+    #[must_use]
+    pub fn modulus(&self) -> Modulus {
+        self.modulus
+    }
+
+    /// Hashes a sequence.
+    #[must_use]
+    pub fn hash<T>(&self, items: &[T]) -> u64
+    where
+        T: Copy + Into<u64>,
+    {
+        items.iter().fold(0, |hash, &item| self.push(hash, item))
+    }
+
+    /// The hash of a sequence with one more item appended.
+    #[must_use]
+    pub fn push<T>(&self, hash: u64, item: T) -> u64
+    where
+        T: Into<u64>,
+    {
+        self.add(self.mul(hash, self.base), self.reduce(item.into()))
+    }
+
+    /// The hash of a window of a sequence moved one item to the right.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - The hash of the window.
+    /// * `outgoing` - The first item of the window, which leaves it.
+    /// * `incoming` - The item after the window, which enters it.
+    /// * `leading` - The base raised to the length of the window, from [`power`](Self::power).
+    #[must_use]
+    pub fn roll<T>(&self, hash: u64, outgoing: T, incoming: T, leading: u64) -> u64
+    where
+        T: Into<u64>,
+    {
+        let hash = self.push(hash, incoming);
+        self.sub(hash, self.mul(self.reduce(outgoing.into()), leading))
+    }
+
+    /// The base raised to `exponent`, by which the hash of a sequence is
+    /// multiplied when `exponent` items are appended to it.
+    #[must_use]
+    pub fn power(&self, exponent: usize) -> u64 {
+        let (mut result, mut base, mut exponent) = (self.one(), self.base, exponent);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Hashes every prefix of a sequence, so that the hash of any of its
+    /// substrings can then be found in constant time.
+    #[must_use]
+    pub fn prefix_hashes<T>(&self, items: &[T]) -> PrefixHashes
+    where
+        T: Copy + Into<u64>,
+    {
+        let mut prefixes = Vec::with_capacity(items.len() + 1);
+        let mut powers = Vec::with_capacity(items.len() + 1);
+        let (mut hash, mut power) = (0, self.one());
+        prefixes.push(hash);
+        powers.push(power);
+        for &item in items {
+            hash = self.push(hash, item);
+            power = self.mul(power, self.base);
+            prefixes.push(hash);
+            powers.push(power);
+        }
+
+        PrefixHashes {
+            hash: *self,
+            prefixes,
+            powers,
+        }
+    }
+
+    fn one(&self) -> u64 {
+        match self.modulus {
+            Modulus::Mersenne61 => 1,
+            Modulus::Double => pack(1, 1),
+        }
+    }
+
+    fn reduce(&self, value: u64) -> u64 {
+        match self.modulus {
+            Modulus::Mersenne61 => value % MERSENNE_61,
+            Modulus::Double => pack(value % DOUBLE[0], value % DOUBLE[1]),
+        }
+    }
+
+    fn add(&self, a: u64, b: u64) -> u64 {
+        self.lanes(a, b, |a, b, p| (a + b) % p)
+    }
+
+    fn sub(&self, a: u64, b: u64) -> u64 {
+        self.lanes(a, b, |a, b, p| (a + p - b) % p)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        match self.modulus {
+            Modulus::Mersenne61 => {
+                let product = u128::from(a) * u128::from(b);
+                let folded = (product >> 61) as u64 + (product as u64 & MERSENNE_61);
+                folded % MERSENNE_61
+            }
+            Modulus::Double => self.lanes(a, b, |a, b, p| a * b % p),
+        }
+    }
+
+    /// Applies `f` to each residue of `a` and `b` with its prime.
+    fn lanes(&self, a: u64, b: u64, f: impl Fn(u64, u64, u64) -> u64) -> u64 {
+        match self.modulus {
+            Modulus::Mersenne61 => f(a, b, MERSENNE_61),
+            Modulus::Double => pack(
+                f(a >> 32, b >> 32, DOUBLE[0]),
+                f(a & u64::from(u32::MAX), b & u64::from(u32::MAX), DOUBLE[1]),
+            ),
+        }
+    }
+}
+
+/// Packs two residues below 2^32 into one `u64`.
+const fn pack(high: u64, low: u64) -> u64 {
+    high << 32 | low
+}
+
+/// The hashes of every prefix of a sequence, from [`RollingHash::prefix_hashes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixHashes {
+    hash: RollingHash,
+    prefixes: Vec<u64>,
+    /// The base raised to each length up to the length of the sequence.
+    powers: Vec<u64>,
+}
+
+impl PrefixHashes {
+    /// The length of the sequence.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.prefixes.len() - 1
+    }
+
+    /// Whether the sequence is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The hash function the prefixes were hashed with.
+    #[must_use]
+    pub fn hasher(&self) -> RollingHash {
+        self.hash
+    }
+
+    /// The hash of the items of the sequence in `range`, in constant time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds of the sequence or its start is after its end.
+    #[must_use]
+    pub fn substring(&self, range: Range<usize>) -> u64 {
+        assert!(range.start <= range.end, "range starts after it ends");
+        let shifted = self
+            .hash
+            .mul(self.prefixes[range.start], self.powers[range.len()]);
+        self.hash.sub(self.prefixes[range.end], shifted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    #[test_case(Modulus::Mersenne61)]
+    #[test_case(Modulus::Double)]
+    fn substrings_agree_with_direct_hashes(modulus: Modulus) {
+        let text = "mississippi \u{2603} mississippi";
+        let chars: Vec<char> = text.chars().collect();
+        for hash in [
+            RollingHash::random(modulus),
+            RollingHash::new(u64::MAX, modulus),
+        ] {
+            let prefixes = hash.prefix_hashes(&chars);
+            assert_eq!(prefixes.len(), chars.len());
+            assert_eq!(prefixes.hasher(), hash);
+
+            for start in 0..=chars.len() {
+                for end in start..=chars.len() {
+                    assert_eq!(
+                        prefixes.substring(start..end),
+                        hash.hash(&chars[start..end])
+                    );
+                }
+            }
+
+            for len in 1..chars.len() {
+                let leading = hash.power(len);
+                let mut window = hash.hash(&chars[..len]);
+                for i in 0..chars.len() - len {
+                    window = hash.roll(window, chars[i], chars[i + len], leading);
+                    assert_eq!(window, hash.hash(&chars[i + 1..=i + len]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn double_hashes_both_primes() {
+        let hash = RollingHash::new(257, Modulus::Double);
+        let value = hash.hash(b"ab");
+        assert_eq!(value >> 32, (97 * 257 + 98) % DOUBLE[0]);
+        assert_eq!(value & u64::from(u32::MAX), (97 * 257 + 98) % DOUBLE[1]);
+        assert_eq!(hash.power(2), pack(257 * 257, 257 * 257));
+    }
+
+    #[test]
+    fn equal_substrings_hash_equal() {
+        let hash = RollingHash::random(Modulus::default());
+        let prefixes = hash.prefix_hashes(b"abcabcabd");
+        assert_eq!(prefixes.substring(0..3), prefixes.substring(3..6));
+        assert_ne!(prefixes.substring(0..3), prefixes.substring(6..9));
+        assert_eq!(prefixes.substring(4..4), 0);
+    }
+}
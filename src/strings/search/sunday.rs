@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+
+use super::Matcher;
+
+/// A [`Matcher`] using Sunday's Quick Search algorithm.
+///
+/// A variant of Boyer-Moore-Horspool that decides the shift from the byte
+/// just past the window rather than its last byte. That byte is part of the
+/// next window whatever happens, so the shift can be one longer, and the
+/// window can be compared in any order.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{Matcher, SundayMatcher};
+///
+/// let matcher = SundayMatcher::new("gcagagag");
+/// assert_eq!(matcher.find("gcatcgcagagagtatacagtacg"), Some(5));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Preprocessing: O(m + σ) for an alphabet of `σ` bytes
+/// - Best: O(n / (m + 1))
+/// - Worst: O(n * m)
+///
+/// # References
+///
+/// - [Sunday, A very fast substring search algorithm](https://doi.org/10.1145/79173.79184)
+/// - [Exact String Matching Algorithms](https://www-igm.univ-mlv.fr/~lecroq/string/node19.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SundayMatcher {
+    pattern: Vec<u8>,
+    /// The shift when each byte follows the window.
+    shift: [usize; 256],
+}
+
+impl SundayMatcher {
+    /// Compiles `pattern` for searching.
+    #[must_use]
+    pub fn new<P>(pattern: &P) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref().to_vec();
+        let m = pattern.len();
+
+        let mut shift = [m + 1; 256];
+        for (i, &byte) in pattern.iter().enumerate() {
+            shift[usize::from(byte)] = m - i;
+        }
+
+        Self { pattern, shift }
+    }
+}
+
+impl Matcher for SundayMatcher {
+    fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    fn find_at(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let m = self.pattern.len();
+
+        let mut i = start;
+        while i + m <= haystack.len() {
+            if haystack[i..i + m] == *self.pattern {
+                return Some(i);
+            }
+            let &next = haystack.get(i + m)?;
+            i += self.shift[usize::from(next)];
+        }
+        None
+    }
+}
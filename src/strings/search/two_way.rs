@@ -0,0 +1,151 @@
+use alloc::vec::Vec;
+
+use super::Matcher;
+
+/// A [`Matcher`] using the Crochemore-Perrin Two-Way algorithm.
+///
+/// The pattern is split at a critical factorization into a left and a right
+/// part. Each window of the haystack is compared with the right part from
+/// left to right and then with the left part from right to left. A mismatch
+/// in the right part shifts the pattern past the bytes that matched; a
+/// mismatch in the left part shifts it by the period of the pattern.
+///
+/// For a periodic pattern, the prefix known to match after shifting by the
+/// period is remembered so that it is not compared again. Only the split, the
+/// period and that one length are kept, so searching takes linear time and
+/// constant extra space.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{Matcher, TwoWayMatcher};
+///
+/// let matcher = TwoWayMatcher::new("abaab");
+/// assert!(matcher.find_overlapping_iter("abaabaabaab").eq([0, 3, 6]));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Preprocessing: O(m)
+/// - Search: O(n)
+///
+/// ## Space Complexity
+///
+/// O(1) besides the pattern.
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Two-way_string-matching_algorithm)
+/// - [Crochemore and Perrin, Two-way string-matching](https://doi.org/10.1145/116825.116845)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwoWayMatcher {
+    pattern: Vec<u8>,
+    /// Where the right part of the critical factorization starts.
+    critical: usize,
+    /// The period of the pattern, or a lower bound on the shift if it is long.
+    period: usize,
+    /// Whether the pattern is not periodic, in which case no prefix is remembered.
+    long_period: bool,
+}
+
+impl TwoWayMatcher {
+    /// Compiles `pattern` for searching.
+    #[must_use]
+    pub fn new<P>(pattern: &P) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref().to_vec();
+        let m = pattern.len();
+
+        // The later of the maximal suffixes for the two orders of the alphabet
+        // gives a critical factorization.
+        let (less, less_period) = maximal_suffix(&pattern, false);
+        let (greater, greater_period) = maximal_suffix(&pattern, true);
+        let (critical, period) = if less > greater {
+            (less, less_period)
+        } else {
+            (greater, greater_period)
+        };
+
+        if m > 0 && pattern[..critical] == pattern[period..period + critical] {
+            Self {
+                pattern,
+                critical,
+                period,
+                long_period: false,
+            }
+        } else {
+            Self {
+                pattern,
+                critical,
+                period: critical.max(m - critical) + 1,
+                long_period: true,
+            }
+        }
+    }
+}
+
+/// Finds the start and the period of the lexicographically greatest suffix
+/// of `pattern`, under the reversed order of bytes if `reversed` is set.
+fn maximal_suffix(pattern: &[u8], reversed: bool) -> (usize, usize) {
+    let (mut left, mut right, mut offset, mut period) = (0, 1, 0, 1);
+
+    while let Some(&a) = pattern.get(right + offset) {
+        let b = pattern[left + offset];
+        if (a < b && !reversed) || (a > b && reversed) {
+            // The candidate at `left` stays the greatest suffix.
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            // The suffix at `right` is greater.
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+
+    (left, period)
+}
+
+impl Matcher for TwoWayMatcher {
+    fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    fn find_at(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let m = self.pattern.len();
+        let mut position = start;
+        // The length of the prefix of the pattern known to match at `position`.
+        let mut memory = 0;
+
+        while position + m <= haystack.len() {
+            let window = &haystack[position..position + m];
+            let differs = |&i: &usize| self.pattern[i] != window[i];
+
+            if let Some(i) = (self.critical.max(memory)..m).find(differs) {
+                position += i - self.critical + 1;
+                memory = 0;
+            } else if (memory..self.critical).rev().any(|i| differs(&i)) {
+                position += self.period;
+                if !self.long_period {
+                    memory = m - self.period;
+                }
+            } else {
+                return Some(position);
+            }
+        }
+        None
+    }
+}
@@ -2,6 +2,10 @@ use hashbrown::HashMap;
 
 /// Create a map containing count of each word
 ///
+/// Words are separated by whitespace and counted as they are written. A
+/// [`Tokenizer`](crate::strings::tokenize::Tokenizer) can fold their case,
+/// strip their punctuation and leave out stop words.
+///
 /// # Arguments
 ///
 /// * `text` - The text to be analyzed
@@ -10,14 +14,10 @@ use hashbrown::HashMap;
 ///
 /// Returns a map containing count of each word
 #[must_use]
-pub fn word_occurrences(text: &str) -> HashMap<&str, u32> {
-    let mut occurrence: HashMap<&str, u32> = HashMap::new();
-    for word in text.split_ascii_whitespace() {
-        if occurrence.contains_key(word) {
-            let _ = occurrence.entry(word).and_modify(|w| *w += 1);
-        } else {
-            let _ = occurrence.insert(word, 1);
-        }
+pub fn word_occurrences(text: &str) -> HashMap<&str, usize> {
+    let mut occurrence: HashMap<&str, usize> = HashMap::new();
+    for word in text.split_whitespace() {
+        *occurrence.entry(word).or_insert(0) += 1;
     }
     occurrence
 }
@@ -29,10 +29,17 @@ mod tests {
 
     #[test]
     fn hello_1_world_1() {
-        let mut expected: HashMap<&str, u32> = HashMap::new();
+        let mut expected: HashMap<&str, usize> = HashMap::new();
         let _ = expected.insert("Hello", 1);
         let _ = expected.insert("World", 1);
         let actual = word_occurrences("Hello World");
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unicode_whitespace() {
+        let actual = word_occurrences("a\u{3000}b\u{a0}a");
+        assert_eq!(actual.get("a"), Some(&2));
+        assert_eq!(actual.get("b"), Some(&1));
+    }
 }
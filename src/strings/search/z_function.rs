@@ -1,13 +1,12 @@
 use alloc::{vec, vec::Vec};
 use core::cmp::min;
 
-fn go_next(i: usize, result: &[usize], s: &str) -> bool {
-    i + result[i] < s.len() && s.chars().nth(result[i]) == s.chars().nth(i + result[i])
-}
+use super::Matcher;
 
 /// This function implements an efficient algorithm for finding pattern occurrences in a string.
 ///
 /// For a given pattern, it computes a value for each index, representing the maximal length substring starting from that index, which matches a prefix of the pattern.
+/// Indices are byte offsets, and the value at index `0` is `0`.
 ///
 /// # Performance
 ///
@@ -19,31 +18,230 @@ fn go_next(i: usize, result: &[usize], s: &str) -> bool {
 /// [CP Algorithms](https://cp-algorithms.com/string/z-function.html)
 #[must_use]
 pub fn z_function(pattern: &str) -> Vec<usize> {
-    let mut result = vec![0; pattern.len()];
-    let (mut left_pointer, mut right_pointer) = (0, 0);
+    z_array(pattern.as_bytes())
+}
+
+/// Computes the Z-array of a sequence.
+///
+/// Entry `i` is the length of the longest common prefix of `s` and `s[i..]`.
+/// As in [`z_function`], the entry at index `0` is `0`. Works on any sequence
+/// of comparable items, such as the bytes of a string or its `char`s.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::z_array;
+///
+/// assert_eq!(z_array(b"aabxaab"), [0, 1, 0, 0, 3, 1, 0]);
+///
+/// let s: Vec<char> = "\u{e5}\u{e5}b\u{e5}".chars().collect();
+/// assert_eq!(z_array(&s), [0, 1, 0, 1]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n)
+///
+/// # See more
+/// [CP Algorithms](https://cp-algorithms.com/string/z-function.html)
+#[must_use]
+pub fn z_array<T>(s: &[T]) -> Vec<usize>
+where
+    T: Eq,
+{
+    let mut z = vec![0; s.len()];
+    let (mut left, mut right) = (0, 0);
 
-    for i in 1..pattern.len() {
-        if i <= right_pointer {
-            let min_edge = min(right_pointer - i + 1, result[i - left_pointer]);
-            result[i] = min_edge;
+    for i in 1..s.len() {
+        if i < right {
+            z[i] = min(right - i, z[i - left]);
+        }
+        while i + z[i] < s.len() && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
         }
+        if i + z[i] > right {
+            (left, right) = (i, i + z[i]);
+        }
+    }
+
+    z
+}
+
+/// Finds every occurrence of a pattern in a sequence with the Z-algorithm.
+///
+/// The Z-array of the pattern is extended over the text directly, so no
+/// separator between the two is needed.
+///
+/// # Arguments
+///
+/// * `text` - The sequence to be searched
+/// * `pattern` - The sequence sought
+///
+/// # Returns
+///
+/// The starting index of every occurrence, including overlapping ones, in
+/// ascending order. The empty pattern occurs at every index from `0` to
+/// `text.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::z_search;
+///
+/// assert_eq!(z_search(b"abracadabra", b"abra"), [0, 7]);
+///
+/// let text: Vec<char> = "\u{3c0}\u{3c0}\u{3c0}".chars().collect();
+/// assert_eq!(z_search(&text, &['\u{3c0}', '\u{3c0}']), [0, 1]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n + m)
+///
+/// ## Space Complexity
+///
+/// O(m)
+#[must_use]
+pub fn z_search<T>(text: &[T], pattern: &[T]) -> Vec<usize>
+where
+    T: Eq,
+{
+    let z = pattern_z_array(pattern);
+    let mut scan = ZScan::default();
+    core::iter::from_fn(|| scan.next_match(text, pattern, &z)).collect()
+}
+
+/// The Z-array of a pattern about to be searched for, whose first entry is
+/// the length of the pattern rather than `0`.
+fn pattern_z_array<T>(pattern: &[T]) -> Vec<usize>
+where
+    T: Eq,
+{
+    let mut z = z_array(pattern);
+    if let Some(first) = z.first_mut() {
+        *first = pattern.len();
+    }
+    z
+}
+
+/// The window of the text known to match a prefix of the pattern.
+#[derive(Debug, Clone, Copy, Default)]
+struct ZScan {
+    i: usize,
+    left: usize,
+    right: usize,
+}
+
+impl ZScan {
+    /// Scans the text from `self.i`, stopping after the next occurrence.
+    fn next_match<T>(&mut self, text: &[T], pattern: &[T], z: &[usize]) -> Option<usize>
+    where
+        T: Eq,
+    {
+        let m = pattern.len();
+
+        while self.i + m <= text.len() {
+            let i = self.i;
+            self.i += 1;
+
+            let len = if i < self.right && z[i - self.left] < self.right - i {
+                z[i - self.left]
+            } else {
+                let mut len = self.right.saturating_sub(i);
+                while len < m && text[i + len] == pattern[len] {
+                    len += 1;
+                }
+                (self.left, self.right) = (i, i + len);
+                len
+            };
 
-        while go_next(i, &result, pattern) {
-            result[i] += 1;
+            if len == m {
+                return Some(i);
+            }
         }
+        None
+    }
+}
 
-        if i + result[i] - 1 > right_pointer {
-            (left_pointer, right_pointer) = (i, i + result[i] - 1);
+/// A [`Matcher`] using the Z-algorithm.
+///
+/// The Z-array of the pattern is computed once. Searching extends it over the
+/// haystack: for each offset the length of the longest prefix of the pattern
+/// starting there is derived from the Z-array inside the window already
+/// known to match, and only compared byte by byte past its end. No separator
+/// between pattern and haystack is needed, so any bytes can be searched.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::search::{Matcher, ZMatcher};
+///
+/// let matcher = ZMatcher::new("abr");
+/// assert!(matcher.find_iter("abracadabra").eq([0, 7]));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Preprocessing: O(m)
+/// - Search: O(n)
+///
+/// # See more
+/// [CP Algorithms](https://cp-algorithms.com/string/z-function.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZMatcher {
+    pattern: Vec<u8>,
+    z: Vec<usize>,
+}
+
+impl ZMatcher {
+    /// Compiles `pattern` for searching.
+    #[must_use]
+    pub fn new<P>(pattern: &P) -> Self
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref().to_vec();
+        Self {
+            z: pattern_z_array(&pattern),
+            pattern,
         }
     }
+}
+
+impl Matcher for ZMatcher {
+    fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
 
-    result
+    fn find_at(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let mut scan = ZScan {
+            i: start,
+            left: start,
+            right: start,
+        };
+        scan.next_match(haystack, &self.pattern, &self.z)
+    }
+
+    fn find_overlapping_iter<'a, H>(&'a self, haystack: &'a H) -> impl Iterator<Item = usize> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let haystack = haystack.as_ref();
+        let mut scan = ZScan::default();
+        core::iter::from_fn(move || scan.next_match(haystack, &self.pattern, &self.z))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::z_function;
-    use alloc::format;
+    use super::{z_array, z_function, z_search};
+    use alloc::{format, vec, vec::Vec};
     use test_case::test_case;
 
     #[test_case("abr", "abracadabra" => 2)]
@@ -61,4 +259,27 @@ mod tests {
 
         answer
     }
+
+    #[test_case("" => Vec::<usize>::new())]
+    #[test_case("aaaaa" => vec![0, 4, 3, 2, 1])]
+    #[test_case("aaabaab" => vec![0, 2, 1, 0, 2, 1, 0])]
+    #[test_case("abacaba" => vec![0, 0, 1, 0, 3, 0, 1])]
+    fn test_z_array(s: &str) -> Vec<usize> {
+        z_array(s.as_bytes())
+    }
+
+    #[test]
+    fn test_z_function_non_ascii() {
+        // Indices are byte offsets; "\u{e9}" is two bytes long.
+        assert_eq!(z_function("\u{e9}a\u{e9}"), [0, 0, 0, 2, 0]);
+    }
+
+    #[test]
+    fn test_z_search() {
+        let text: Vec<char> = "\u{e9}t\u{e9} et \u{e9}t\u{e9}".chars().collect();
+        assert_eq!(z_search(&text, &['\u{e9}', 't']), [0, 7]);
+        assert_eq!(z_search(&[1, 1, 1], &[1, 1]), [0, 1]);
+        assert_eq!(z_search(&[1, 2], &[]), [0, 1, 2]);
+        assert_eq!(z_search(&[1, 2], &[1, 2, 3]), []);
+    }
 }
@@ -0,0 +1,215 @@
+use core::iter::FusedIterator;
+
+use super::properties::{
+    grapheme_break, indic_conjunct_break, GraphemeBreak as G, IndicConjunctBreak,
+};
+
+/// Splits a text into extended grapheme clusters, the characters a reader
+/// perceives: a letter with its accents, a flag, or an emoji with its
+/// modifiers and joined emoji.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::segmentation::graphemes;
+///
+/// let text = "e\u{301}👍🏽🇳🇴👩‍👩‍👧\r\n";
+/// let clusters: Vec<&str> = graphemes(text).collect();
+/// assert_eq!(clusters, ["e\u{301}", "👍🏽", "🇳🇴", "👩‍👩‍👧", "\r\n"]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n log k), for `k` ranges in the property tables.
+///
+/// # References
+///
+/// - [UAX #29: Unicode Text Segmentation, Grapheme Cluster Boundaries](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+#[must_use]
+pub fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes { rest: text }
+}
+
+/// An iterator over the extended grapheme clusters of a text, made by
+/// [`graphemes`].
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Graphemes<'a> {
+    /// The part of the text not yet split.
+    #[must_use]
+    pub fn as_str(&self) -> &'a str {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+        let mut cluster = Cluster::new(first);
+        let end = chars
+            .find(|&(_, c)| cluster.push(c))
+            .map_or(self.rest.len(), |(i, _)| i);
+        let (grapheme, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(grapheme)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A cluster takes at least one character, of at most four bytes.
+        (self.rest.len().div_ceil(4), Some(self.rest.len()))
+    }
+}
+
+impl FusedIterator for Graphemes<'_> {}
+
+/// Whether the characters of a cluster so far end in an emoji that a
+/// zero width joiner can join to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Emoji {
+    None,
+    /// An `Extended_Pictographic` character, then `Extend` characters.
+    Pictographic,
+    /// Then a zero width joiner.
+    Joined,
+}
+
+/// Whether the characters of a cluster so far end in a conjunct that the
+/// next consonant can join.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conjunct {
+    None,
+    /// A consonant, then `Extend` and `Linker` characters but no `Linker`.
+    Consonant,
+    /// A consonant, then `Extend` and `Linker` characters with a `Linker`.
+    Linked,
+}
+
+/// The state of a grapheme cluster as characters are added, which is all
+/// the rules need: none of them look back past a boundary.
+#[derive(Debug, Clone, Copy)]
+struct Cluster {
+    last: G,
+    emoji: Emoji,
+    conjunct: Conjunct,
+    regional_indicators: usize,
+}
+
+impl Cluster {
+    fn new(first: char) -> Self {
+        let mut cluster = Self {
+            last: G::Other,
+            emoji: Emoji::None,
+            conjunct: Conjunct::None,
+            regional_indicators: 0,
+        };
+        cluster.update(first, grapheme_break(first));
+        cluster
+    }
+
+    /// Adds a character to the cluster, unless a new cluster starts there.
+    ///
+    /// # Returns
+    ///
+    /// Whether there is a boundary before the character.
+    fn push(&mut self, c: char) -> bool {
+        let property = grapheme_break(c);
+        let boundary = match (self.last, property) {
+            // GB3
+            (G::Cr, G::Lf) => false,
+            // GB4, GB5
+            (G::Cr | G::Lf | G::Control, _) | (_, G::Cr | G::Lf | G::Control) => true,
+            // GB6, GB7, GB8: Hangul syllables.
+            (G::L, G::L | G::V | G::Lv | G::Lvt)
+            | (G::Lv | G::V, G::V | G::T)
+            | (G::Lvt | G::T, G::T)
+            // GB9, GB9a, GB9b
+            | (_, G::Extend | G::Zwj | G::SpacingMark)
+            | (G::Prepend, _) => false,
+            // GB11: emoji joined by a zero width joiner.
+            (G::Zwj, G::ExtendedPictographic) if self.emoji == Emoji::Joined => false,
+            // GB12, GB13: flags are pairs of regional indicators.
+            (G::RegionalIndicator, G::RegionalIndicator) => self.regional_indicators.is_multiple_of(2),
+            // GB9c: Indic conjuncts.
+            _ => {
+                self.conjunct != Conjunct::Linked
+                    || indic_conjunct_break(c) != IndicConjunctBreak::Consonant
+            }
+        };
+        if !boundary {
+            self.update(c, property);
+        }
+        boundary
+    }
+
+    fn update(&mut self, c: char, property: G) {
+        self.emoji = match (self.emoji, property) {
+            (_, G::ExtendedPictographic) | (Emoji::Pictographic, G::Extend) => Emoji::Pictographic,
+            (Emoji::Pictographic, G::Zwj) => Emoji::Joined,
+            _ => Emoji::None,
+        };
+        self.conjunct = match (self.conjunct, indic_conjunct_break(c)) {
+            (_, IndicConjunctBreak::Consonant) => Conjunct::Consonant,
+            (Conjunct::None, _) | (_, IndicConjunctBreak::None) => Conjunct::None,
+            (_, IndicConjunctBreak::Linker) => Conjunct::Linked,
+            (conjunct, IndicConjunctBreak::Extend) => conjunct,
+        };
+        if property == G::RegionalIndicator {
+            self.regional_indicators += 1;
+        }
+        self.last = property;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::break_test_cases;
+    use super::*;
+
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    // Cases from GraphemeBreakTest.txt of the Unicode Character Database.
+    #[test_case("", &[]; "empty")]
+    #[test_case("\r\n\n", &["\r\n", "\n"]; "crlf")]
+    #[test_case("a\u{308}b", &["a\u{308}", "b"]; "extend")]
+    #[test_case("\u{600}a", &["\u{600}a"]; "prepend")]
+    #[test_case("\u{600}\n", &["\u{600}", "\n"]; "prepend before control")]
+    #[test_case("\u{915}\u{903}", &["\u{915}\u{903}"]; "spacing mark")]
+    #[test_case("\u{1100}\u{1161}\u{11A8}\u{AC00}\u{11A8}", &["\u{1100}\u{1161}\u{11A8}", "\u{AC00}\u{11A8}"]; "hangul")]
+    #[test_case("\u{1F1F7}\u{1F1FA}\u{1F1F8}", &["\u{1F1F7}\u{1F1FA}", "\u{1F1F8}"]; "regional indicators")]
+    #[test_case("a\u{1F1E6}\u{1F1E7}\u{200D}\u{1F1E8}", &["a", "\u{1F1E6}\u{1F1E7}\u{200D}", "\u{1F1E8}"]; "regional indicators after zwj")]
+    #[test_case("\u{1F6D1}\u{200D}\u{1F6D1}", &["\u{1F6D1}\u{200D}\u{1F6D1}"]; "zwj sequence")]
+    #[test_case("a\u{200D}\u{1F6D1}", &["a\u{200D}", "\u{1F6D1}"]; "zwj after letter")]
+    #[test_case("\u{1F476}\u{1F3FF}\u{1F476}", &["\u{1F476}\u{1F3FF}", "\u{1F476}"]; "emoji modifier")]
+    #[test_case("\u{915}\u{94D}\u{924}", &["\u{915}\u{94D}\u{924}"]; "conjunct")]
+    #[test_case("\u{915}\u{94D}\u{200D}\u{924}", &["\u{915}\u{94D}\u{200D}\u{924}"]; "conjunct with zwj")]
+    #[test_case("\u{915}\u{924}", &["\u{915}", "\u{924}"]; "consonants without linker")]
+    #[test_case("a\u{94D}\u{924}", &["a\u{94D}", "\u{924}"]; "linker without consonant")]
+    fn splits(text: &str, expected: &[&str]) {
+        assert_eq!(graphemes(text).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn as_str() {
+        let mut clusters = graphemes("ab\u{301}c");
+        assert_eq!(clusters.next(), Some("a"));
+        assert_eq!(clusters.as_str(), "b\u{301}c");
+    }
+
+    #[test]
+    #[ignore = "needs the Unicode Character Database in the `UCD` variable"]
+    fn conformance() {
+        for expected in break_test_cases("GraphemeBreakTest.txt") {
+            let text = expected.concat();
+            assert_eq!(graphemes(&text).collect::<Vec<_>>(), expected, "{text:?}");
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Text segmentation by the rules of Unicode Standard Annex #29
+//!
+//! [`graphemes`] splits a text into the characters a reader perceives, and
+//! [`word_segments`] and [`unicode_words`] split it into words. The
+//! character properties they need are in tables generated from the Unicode
+//! Character Database by `scripts/generate_segmentation_tables.py`, for the
+//! version in [`UNICODE_VERSION`].
+
+mod grapheme;
+mod properties;
+mod tables;
+mod word;
+
+pub use grapheme::*;
+pub use tables::UNICODE_VERSION;
+pub use word::*;
+
+use properties::{grapheme_break, GraphemeBreak};
+
+/// Whether a character extends the grapheme cluster before it: a combining
+/// mark, a joiner, a variation selector or an emoji modifier.
+pub(super) fn extends_grapheme(c: char) -> bool {
+    matches!(
+        grapheme_break(c),
+        GraphemeBreak::Extend | GraphemeBreak::Zwj
+    )
+}
+
+/// The cases of a break test file of the Unicode Character Database, such as
+/// `GraphemeBreakTest.txt`, as the expected segments of each line. The file
+/// is read from the unzipped UCD.zip named by the `UCD` variable, which must
+/// be the version the tables were generated from:
+///
+/// ```text
+/// UCD=<ucd> cargo test conformance -- --ignored
+/// ```
+///
+/// Lines with surrogates, which a `&str` cannot hold, are left out.
+#[cfg(test)]
+fn break_test_cases(file: &str) -> alloc::vec::Vec<alloc::vec::Vec<alloc::string::String>> {
+    extern crate std;
+
+    use alloc::{format, string::String, vec::Vec};
+
+    let ucd = std::env::var("UCD").expect("UCD names the unzipped UCD.zip");
+    let path = std::path::Path::new(&ucd).join("auxiliary").join(file);
+    let data = std::fs::read_to_string(&path).expect("the UCD has the break tests");
+    let (major, minor, update) = UNICODE_VERSION;
+    let name = file.trim_end_matches(".txt");
+    assert!(
+        data.starts_with(&format!("# {name}-{major}.{minor}.{update}.txt")),
+        "{} is not from Unicode {major}.{minor}.{update}",
+        path.display()
+    );
+
+    data.lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut segments = Vec::new();
+            let mut segment = String::new();
+            for token in line.split_whitespace() {
+                match token {
+                    "÷" if !segment.is_empty() => segments.push(core::mem::take(&mut segment)),
+                    "÷" | "×" => {}
+                    _ => segment.push(char::from_u32(u32::from_str_radix(token, 16).ok()?)?),
+                }
+            }
+            (!segments.is_empty()).then_some(segments)
+        })
+        .collect()
+}
@@ -0,0 +1,121 @@
+use core::cmp::Ordering;
+
+use super::tables::{GRAPHEME_BREAK, INDIC_CONJUNCT_BREAK, WORD_BREAK};
+
+/// The `Grapheme_Cluster_Break` property of a character, with
+/// `Extended_Pictographic` folded in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum GraphemeBreak {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+/// The `Indic_Conjunct_Break` property of a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum IndicConjunctBreak {
+    None,
+    Consonant,
+    Linker,
+    Extend,
+}
+
+/// The `Word_Break` property of a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum WordBreak {
+    Other,
+    Cr,
+    Lf,
+    Newline,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Format,
+    Katakana,
+    HebrewLetter,
+    ALetter,
+    SingleQuote,
+    DoubleQuote,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Numeric,
+    ExtendNumLet,
+    WSegSpace,
+}
+
+/// Looks a character up in a table of sorted, disjoint ranges.
+fn lookup<T: Copy>(table: &[(char, char, T)], c: char) -> Option<T> {
+    table
+        .binary_search_by(|&(first, last, _)| {
+            if last < c {
+                Ordering::Less
+            } else if first > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| table[i].2)
+}
+
+pub(super) fn grapheme_break(c: char) -> GraphemeBreak {
+    const SYLLABLES: u32 = 0xAC00;
+    const TRAILING_JAMO: u32 = 28;
+
+    match c {
+        // A syllable is LV if it has no trailing consonant.
+        '\u{AC00}'..='\u{D7A3}' if (u32::from(c) - SYLLABLES).is_multiple_of(TRAILING_JAMO) => {
+            GraphemeBreak::Lv
+        }
+        '\u{AC00}'..='\u{D7A3}' => GraphemeBreak::Lvt,
+        _ => lookup(GRAPHEME_BREAK, c).unwrap_or(GraphemeBreak::Other),
+    }
+}
+
+pub(super) fn indic_conjunct_break(c: char) -> IndicConjunctBreak {
+    lookup(INDIC_CONJUNCT_BREAK, c).unwrap_or(IndicConjunctBreak::None)
+}
+
+pub(super) fn word_break(c: char) -> WordBreak {
+    lookup(WORD_BREAK, c).unwrap_or(WordBreak::Other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_sorted_and_disjoint<T>(table: &[(char, char, T)]) -> bool {
+        table.iter().all(|&(first, last, _)| first <= last)
+            && table.windows(2).all(|pair| pair[0].1 < pair[1].0)
+    }
+
+    #[test]
+    fn tables_are_sorted_and_disjoint() {
+        assert!(is_sorted_and_disjoint(GRAPHEME_BREAK));
+        assert!(is_sorted_and_disjoint(INDIC_CONJUNCT_BREAK));
+        assert!(is_sorted_and_disjoint(WORD_BREAK));
+    }
+
+    #[test]
+    fn hangul() {
+        assert_eq!(grapheme_break('가'), GraphemeBreak::Lv);
+        assert_eq!(grapheme_break('각'), GraphemeBreak::Lvt);
+        assert_eq!(grapheme_break('\u{1100}'), GraphemeBreak::L);
+        assert_eq!(grapheme_break('\u{1160}'), GraphemeBreak::V);
+        assert_eq!(grapheme_break('\u{11A8}'), GraphemeBreak::T);
+    }
+}
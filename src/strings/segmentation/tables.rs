@@ -0,0 +1,2340 @@
+//! Unicode character properties for text segmentation.
+//!
+//! Generated by `scripts/generate_segmentation_tables.py` from the Unicode
+//! Character Database. Do not edit by hand.
+
+use super::properties::{GraphemeBreak as G, IndicConjunctBreak as I, WordBreak as W};
+
+/// The version of Unicode that segmentation follows.
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// `Grapheme_Cluster_Break` values other than `Other`, with
+/// `Extended_Pictographic`, which only `Other` characters have. Hangul
+/// syllables are left out.
+pub(super) const GRAPHEME_BREAK: &[(char, char, G)] = &[
+    ('\u{0}', '\u{9}', G::Control),
+    ('\u{A}', '\u{A}', G::Lf),
+    ('\u{B}', '\u{C}', G::Control),
+    ('\u{D}', '\u{D}', G::Cr),
+    ('\u{E}', '\u{1F}', G::Control),
+    ('\u{7F}', '\u{9F}', G::Control),
+    ('\u{A9}', '\u{A9}', G::ExtendedPictographic),
+    ('\u{AD}', '\u{AD}', G::Control),
+    ('\u{AE}', '\u{AE}', G::ExtendedPictographic),
+    ('\u{300}', '\u{36F}', G::Extend),
+    ('\u{483}', '\u{489}', G::Extend),
+    ('\u{591}', '\u{5BD}', G::Extend),
+    ('\u{5BF}', '\u{5BF}', G::Extend),
+    ('\u{5C1}', '\u{5C2}', G::Extend),
+    ('\u{5C4}', '\u{5C5}', G::Extend),
+    ('\u{5C7}', '\u{5C7}', G::Extend),
+    ('\u{600}', '\u{605}', G::Prepend),
+    ('\u{610}', '\u{61A}', G::Extend),
+    ('\u{61C}', '\u{61C}', G::Control),
+    ('\u{64B}', '\u{65F}', G::Extend),
+    ('\u{670}', '\u{670}', G::Extend),
+    ('\u{6D6}', '\u{6DC}', G::Extend),
+    ('\u{6DD}', '\u{6DD}', G::Prepend),
+    ('\u{6DF}', '\u{6E4}', G::Extend),
+    ('\u{6E7}', '\u{6E8}', G::Extend),
+    ('\u{6EA}', '\u{6ED}', G::Extend),
+    ('\u{70F}', '\u{70F}', G::Prepend),
+    ('\u{711}', '\u{711}', G::Extend),
+    ('\u{730}', '\u{74A}', G::Extend),
+    ('\u{7A6}', '\u{7B0}', G::Extend),
+    ('\u{7EB}', '\u{7F3}', G::Extend),
+    ('\u{7FD}', '\u{7FD}', G::Extend),
+    ('\u{816}', '\u{819}', G::Extend),
+    ('\u{81B}', '\u{823}', G::Extend),
+    ('\u{825}', '\u{827}', G::Extend),
+    ('\u{829}', '\u{82D}', G::Extend),
+    ('\u{859}', '\u{85B}', G::Extend),
+    ('\u{890}', '\u{891}', G::Prepend),
+    ('\u{897}', '\u{89F}', G::Extend),
+    ('\u{8CA}', '\u{8E1}', G::Extend),
+    ('\u{8E2}', '\u{8E2}', G::Prepend),
+    ('\u{8E3}', '\u{902}', G::Extend),
+    ('\u{903}', '\u{903}', G::SpacingMark),
+    ('\u{93A}', '\u{93A}', G::Extend),
+    ('\u{93B}', '\u{93B}', G::SpacingMark),
+    ('\u{93C}', '\u{93C}', G::Extend),
+    ('\u{93E}', '\u{940}', G::SpacingMark),
+    ('\u{941}', '\u{948}', G::Extend),
+    ('\u{949}', '\u{94C}', G::SpacingMark),
+    ('\u{94D}', '\u{94D}', G::Extend),
+    ('\u{94E}', '\u{94F}', G::SpacingMark),
+    ('\u{951}', '\u{957}', G::Extend),
+    ('\u{962}', '\u{963}', G::Extend),
+    ('\u{981}', '\u{981}', G::Extend),
+    ('\u{982}', '\u{983}', G::SpacingMark),
+    ('\u{9BC}', '\u{9BC}', G::Extend),
+    ('\u{9BE}', '\u{9BE}', G::Extend),
+    ('\u{9BF}', '\u{9C0}', G::SpacingMark),
+    ('\u{9C1}', '\u{9C4}', G::Extend),
+    ('\u{9C7}', '\u{9C8}', G::SpacingMark),
+    ('\u{9CB}', '\u{9CC}', G::SpacingMark),
+    ('\u{9CD}', '\u{9CD}', G::Extend),
+    ('\u{9D7}', '\u{9D7}', G::Extend),
+    ('\u{9E2}', '\u{9E3}', G::Extend),
+    ('\u{9FE}', '\u{9FE}', G::Extend),
+    ('\u{A01}', '\u{A02}', G::Extend),
+    ('\u{A03}', '\u{A03}', G::SpacingMark),
+    ('\u{A3C}', '\u{A3C}', G::Extend),
+    ('\u{A3E}', '\u{A40}', G::SpacingMark),
+    ('\u{A41}', '\u{A42}', G::Extend),
+    ('\u{A47}', '\u{A48}', G::Extend),
+    ('\u{A4B}', '\u{A4D}', G::Extend),
+    ('\u{A51}', '\u{A51}', G::Extend),
+    ('\u{A70}', '\u{A71}', G::Extend),
+    ('\u{A75}', '\u{A75}', G::Extend),
+    ('\u{A81}', '\u{A82}', G::Extend),
+    ('\u{A83}', '\u{A83}', G::SpacingMark),
+    ('\u{ABC}', '\u{ABC}', G::Extend),
+    ('\u{ABE}', '\u{AC0}', G::SpacingMark),
+    ('\u{AC1}', '\u{AC5}', G::Extend),
+    ('\u{AC7}', '\u{AC8}', G::Extend),
+    ('\u{AC9}', '\u{AC9}', G::SpacingMark),
+    ('\u{ACB}', '\u{ACC}', G::SpacingMark),
+    ('\u{ACD}', '\u{ACD}', G::Extend),
+    ('\u{AE2}', '\u{AE3}', G::Extend),
+    ('\u{AFA}', '\u{AFF}', G::Extend),
+    ('\u{B01}', '\u{B01}', G::Extend),
+    ('\u{B02}', '\u{B03}', G::SpacingMark),
+    ('\u{B3C}', '\u{B3C}', G::Extend),
+    ('\u{B3E}', '\u{B3F}', G::Extend),
+    ('\u{B40}', '\u{B40}', G::SpacingMark),
+    ('\u{B41}', '\u{B44}', G::Extend),
+    ('\u{B47}', '\u{B48}', G::SpacingMark),
+    ('\u{B4B}', '\u{B4C}', G::SpacingMark),
+    ('\u{B4D}', '\u{B4D}', G::Extend),
+    ('\u{B55}', '\u{B57}', G::Extend),
+    ('\u{B62}', '\u{B63}', G::Extend),
+    ('\u{B82}', '\u{B82}', G::Extend),
+    ('\u{BBE}', '\u{BBE}', G::Extend),
+    ('\u{BBF}', '\u{BBF}', G::SpacingMark),
+    ('\u{BC0}', '\u{BC0}', G::Extend),
+    ('\u{BC1}', '\u{BC2}', G::SpacingMark),
+    ('\u{BC6}', '\u{BC8}', G::SpacingMark),
+    ('\u{BCA}', '\u{BCC}', G::SpacingMark),
+    ('\u{BCD}', '\u{BCD}', G::Extend),
+    ('\u{BD7}', '\u{BD7}', G::Extend),
+    ('\u{C00}', '\u{C00}', G::Extend),
+    ('\u{C01}', '\u{C03}', G::SpacingMark),
+    ('\u{C04}', '\u{C04}', G::Extend),
+    ('\u{C3C}', '\u{C3C}', G::Extend),
+    ('\u{C3E}', '\u{C40}', G::Extend),
+    ('\u{C41}', '\u{C44}', G::SpacingMark),
+    ('\u{C46}', '\u{C48}', G::Extend),
+    ('\u{C4A}', '\u{C4D}', G::Extend),
+    ('\u{C55}', '\u{C56}', G::Extend),
+    ('\u{C62}', '\u{C63}', G::Extend),
+    ('\u{C81}', '\u{C81}', G::Extend),
+    ('\u{C82}', '\u{C83}', G::SpacingMark),
+    ('\u{CBC}', '\u{CBC}', G::Extend),
+    ('\u{CBE}', '\u{CBE}', G::SpacingMark),
+    ('\u{CBF}', '\u{CC0}', G::Extend),
+    ('\u{CC1}', '\u{CC1}', G::SpacingMark),
+    ('\u{CC2}', '\u{CC2}', G::Extend),
+    ('\u{CC3}', '\u{CC4}', G::SpacingMark),
+    ('\u{CC6}', '\u{CC8}', G::Extend),
+    ('\u{CCA}', '\u{CCD}', G::Extend),
+    ('\u{CD5}', '\u{CD6}', G::Extend),
+    ('\u{CE2}', '\u{CE3}', G::Extend),
+    ('\u{CF3}', '\u{CF3}', G::SpacingMark),
+    ('\u{D00}', '\u{D01}', G::Extend),
+    ('\u{D02}', '\u{D03}', G::SpacingMark),
+    ('\u{D3B}', '\u{D3C}', G::Extend),
+    ('\u{D3E}', '\u{D3E}', G::Extend),
+    ('\u{D3F}', '\u{D40}', G::SpacingMark),
+    ('\u{D41}', '\u{D44}', G::Extend),
+    ('\u{D46}', '\u{D48}', G::SpacingMark),
+    ('\u{D4A}', '\u{D4C}', G::SpacingMark),
+    ('\u{D4D}', '\u{D4D}', G::Extend),
+    ('\u{D4E}', '\u{D4E}', G::Prepend),
+    ('\u{D57}', '\u{D57}', G::Extend),
+    ('\u{D62}', '\u{D63}', G::Extend),
+    ('\u{D81}', '\u{D81}', G::Extend),
+    ('\u{D82}', '\u{D83}', G::SpacingMark),
+    ('\u{DCA}', '\u{DCA}', G::Extend),
+    ('\u{DCF}', '\u{DCF}', G::Extend),
+    ('\u{DD0}', '\u{DD1}', G::SpacingMark),
+    ('\u{DD2}', '\u{DD4}', G::Extend),
+    ('\u{DD6}', '\u{DD6}', G::Extend),
+    ('\u{DD8}', '\u{DDE}', G::SpacingMark),
+    ('\u{DDF}', '\u{DDF}', G::Extend),
+    ('\u{DF2}', '\u{DF3}', G::SpacingMark),
+    ('\u{E31}', '\u{E31}', G::Extend),
+    ('\u{E33}', '\u{E33}', G::SpacingMark),
+    ('\u{E34}', '\u{E3A}', G::Extend),
+    ('\u{E47}', '\u{E4E}', G::Extend),
+    ('\u{EB1}', '\u{EB1}', G::Extend),
+    ('\u{EB3}', '\u{EB3}', G::SpacingMark),
+    ('\u{EB4}', '\u{EBC}', G::Extend),
+    ('\u{EC8}', '\u{ECE}', G::Extend),
+    ('\u{F18}', '\u{F19}', G::Extend),
+    ('\u{F35}', '\u{F35}', G::Extend),
+    ('\u{F37}', '\u{F37}', G::Extend),
+    ('\u{F39}', '\u{F39}', G::Extend),
+    ('\u{F3E}', '\u{F3F}', G::SpacingMark),
+    ('\u{F71}', '\u{F7E}', G::Extend),
+    ('\u{F7F}', '\u{F7F}', G::SpacingMark),
+    ('\u{F80}', '\u{F84}', G::Extend),
+    ('\u{F86}', '\u{F87}', G::Extend),
+    ('\u{F8D}', '\u{F97}', G::Extend),
+    ('\u{F99}', '\u{FBC}', G::Extend),
+    ('\u{FC6}', '\u{FC6}', G::Extend),
+    ('\u{102D}', '\u{1030}', G::Extend),
+    ('\u{1031}', '\u{1031}', G::SpacingMark),
+    ('\u{1032}', '\u{1037}', G::Extend),
+    ('\u{1039}', '\u{103A}', G::Extend),
+    ('\u{103B}', '\u{103C}', G::SpacingMark),
+    ('\u{103D}', '\u{103E}', G::Extend),
+    ('\u{1056}', '\u{1057}', G::SpacingMark),
+    ('\u{1058}', '\u{1059}', G::Extend),
+    ('\u{105E}', '\u{1060}', G::Extend),
+    ('\u{1071}', '\u{1074}', G::Extend),
+    ('\u{1082}', '\u{1082}', G::Extend),
+    ('\u{1084}', '\u{1084}', G::SpacingMark),
+    ('\u{1085}', '\u{1086}', G::Extend),
+    ('\u{108D}', '\u{108D}', G::Extend),
+    ('\u{109D}', '\u{109D}', G::Extend),
+    ('\u{1100}', '\u{115F}', G::L),
+    ('\u{1160}', '\u{11A7}', G::V),
+    ('\u{11A8}', '\u{11FF}', G::T),
+    ('\u{135D}', '\u{135F}', G::Extend),
+    ('\u{1712}', '\u{1715}', G::Extend),
+    ('\u{1732}', '\u{1734}', G::Extend),
+    ('\u{1752}', '\u{1753}', G::Extend),
+    ('\u{1772}', '\u{1773}', G::Extend),
+    ('\u{17B4}', '\u{17B5}', G::Extend),
+    ('\u{17B6}', '\u{17B6}', G::SpacingMark),
+    ('\u{17B7}', '\u{17BD}', G::Extend),
+    ('\u{17BE}', '\u{17C5}', G::SpacingMark),
+    ('\u{17C6}', '\u{17C6}', G::Extend),
+    ('\u{17C7}', '\u{17C8}', G::SpacingMark),
+    ('\u{17C9}', '\u{17D3}', G::Extend),
+    ('\u{17DD}', '\u{17DD}', G::Extend),
+    ('\u{180B}', '\u{180D}', G::Extend),
+    ('\u{180E}', '\u{180E}', G::Control),
+    ('\u{180F}', '\u{180F}', G::Extend),
+    ('\u{1885}', '\u{1886}', G::Extend),
+    ('\u{18A9}', '\u{18A9}', G::Extend),
+    ('\u{1920}', '\u{1922}', G::Extend),
+    ('\u{1923}', '\u{1926}', G::SpacingMark),
+    ('\u{1927}', '\u{1928}', G::Extend),
+    ('\u{1929}', '\u{192B}', G::SpacingMark),
+    ('\u{1930}', '\u{1931}', G::SpacingMark),
+    ('\u{1932}', '\u{1932}', G::Extend),
+    ('\u{1933}', '\u{1938}', G::SpacingMark),
+    ('\u{1939}', '\u{193B}', G::Extend),
+    ('\u{1A17}', '\u{1A18}', G::Extend),
+    ('\u{1A19}', '\u{1A1A}', G::SpacingMark),
+    ('\u{1A1B}', '\u{1A1B}', G::Extend),
+    ('\u{1A55}', '\u{1A55}', G::SpacingMark),
+    ('\u{1A56}', '\u{1A56}', G::Extend),
+    ('\u{1A57}', '\u{1A57}', G::SpacingMark),
+    ('\u{1A58}', '\u{1A5E}', G::Extend),
+    ('\u{1A60}', '\u{1A60}', G::Extend),
+    ('\u{1A62}', '\u{1A62}', G::Extend),
+    ('\u{1A65}', '\u{1A6C}', G::Extend),
+    ('\u{1A6D}', '\u{1A72}', G::SpacingMark),
+    ('\u{1A73}', '\u{1A7C}', G::Extend),
+    ('\u{1A7F}', '\u{1A7F}', G::Extend),
+    ('\u{1AB0}', '\u{1ADD}', G::Extend),
+    ('\u{1AE0}', '\u{1AEB}', G::Extend),
+    ('\u{1B00}', '\u{1B03}', G::Extend),
+    ('\u{1B04}', '\u{1B04}', G::SpacingMark),
+    ('\u{1B34}', '\u{1B3D}', G::Extend),
+    ('\u{1B3E}', '\u{1B41}', G::SpacingMark),
+    ('\u{1B42}', '\u{1B44}', G::Extend),
+    ('\u{1B6B}', '\u{1B73}', G::Extend),
+    ('\u{1B80}', '\u{1B81}', G::Extend),
+    ('\u{1B82}', '\u{1B82}', G::SpacingMark),
+    ('\u{1BA1}', '\u{1BA1}', G::SpacingMark),
+    ('\u{1BA2}', '\u{1BA5}', G::Extend),
+    ('\u{1BA6}', '\u{1BA7}', G::SpacingMark),
+    ('\u{1BA8}', '\u{1BAD}', G::Extend),
+    ('\u{1BE6}', '\u{1BE6}', G::Extend),
+    ('\u{1BE7}', '\u{1BE7}', G::SpacingMark),
+    ('\u{1BE8}', '\u{1BE9}', G::Extend),
+    ('\u{1BEA}', '\u{1BEC}', G::SpacingMark),
+    ('\u{1BED}', '\u{1BED}', G::Extend),
+    ('\u{1BEE}', '\u{1BEE}', G::SpacingMark),
+    ('\u{1BEF}', '\u{1BF3}', G::Extend),
+    ('\u{1C24}', '\u{1C2B}', G::SpacingMark),
+    ('\u{1C2C}', '\u{1C33}', G::Extend),
+    ('\u{1C34}', '\u{1C35}', G::SpacingMark),
+    ('\u{1C36}', '\u{1C37}', G::Extend),
+    ('\u{1CD0}', '\u{1CD2}', G::Extend),
+    ('\u{1CD4}', '\u{1CE0}', G::Extend),
+    ('\u{1CE1}', '\u{1CE1}', G::SpacingMark),
+    ('\u{1CE2}', '\u{1CE8}', G::Extend),
+    ('\u{1CED}', '\u{1CED}', G::Extend),
+    ('\u{1CF4}', '\u{1CF4}', G::Extend),
+    ('\u{1CF7}', '\u{1CF7}', G::SpacingMark),
+    ('\u{1CF8}', '\u{1CF9}', G::Extend),
+    ('\u{1DC0}', '\u{1DFF}', G::Extend),
+    ('\u{200B}', '\u{200B}', G::Control),
+    ('\u{200C}', '\u{200C}', G::Extend),
+    ('\u{200D}', '\u{200D}', G::Zwj),
+    ('\u{200E}', '\u{200F}', G::Control),
+    ('\u{2028}', '\u{202E}', G::Control),
+    ('\u{203C}', '\u{203C}', G::ExtendedPictographic),
+    ('\u{2049}', '\u{2049}', G::ExtendedPictographic),
+    ('\u{2060}', '\u{206F}', G::Control),
+    ('\u{20D0}', '\u{20F0}', G::Extend),
+    ('\u{2122}', '\u{2122}', G::ExtendedPictographic),
+    ('\u{2139}', '\u{2139}', G::ExtendedPictographic),
+    ('\u{2194}', '\u{2199}', G::ExtendedPictographic),
+    ('\u{21A9}', '\u{21AA}', G::ExtendedPictographic),
+    ('\u{231A}', '\u{231B}', G::ExtendedPictographic),
+    ('\u{2328}', '\u{2328}', G::ExtendedPictographic),
+    ('\u{23CF}', '\u{23CF}', G::ExtendedPictographic),
+    ('\u{23E9}', '\u{23F3}', G::ExtendedPictographic),
+    ('\u{23F8}', '\u{23FA}', G::ExtendedPictographic),
+    ('\u{24C2}', '\u{24C2}', G::ExtendedPictographic),
+    ('\u{25AA}', '\u{25AB}', G::ExtendedPictographic),
+    ('\u{25B6}', '\u{25B6}', G::ExtendedPictographic),
+    ('\u{25C0}', '\u{25C0}', G::ExtendedPictographic),
+    ('\u{25FB}', '\u{25FE}', G::ExtendedPictographic),
+    ('\u{2600}', '\u{2604}', G::ExtendedPictographic),
+    ('\u{260E}', '\u{260E}', G::ExtendedPictographic),
+    ('\u{2611}', '\u{2611}', G::ExtendedPictographic),
+    ('\u{2614}', '\u{2615}', G::ExtendedPictographic),
+    ('\u{2618}', '\u{2618}', G::ExtendedPictographic),
+    ('\u{261D}', '\u{261D}', G::ExtendedPictographic),
+    ('\u{2620}', '\u{2620}', G::ExtendedPictographic),
+    ('\u{2622}', '\u{2623}', G::ExtendedPictographic),
+    ('\u{2626}', '\u{2626}', G::ExtendedPictographic),
+    ('\u{262A}', '\u{262A}', G::ExtendedPictographic),
+    ('\u{262E}', '\u{262F}', G::ExtendedPictographic),
+    ('\u{2638}', '\u{263A}', G::ExtendedPictographic),
+    ('\u{2640}', '\u{2640}', G::ExtendedPictographic),
+    ('\u{2642}', '\u{2642}', G::ExtendedPictographic),
+    ('\u{2648}', '\u{2653}', G::ExtendedPictographic),
+    ('\u{265F}', '\u{2660}', G::ExtendedPictographic),
+    ('\u{2663}', '\u{2663}', G::ExtendedPictographic),
+    ('\u{2665}', '\u{2666}', G::ExtendedPictographic),
+    ('\u{2668}', '\u{2668}', G::ExtendedPictographic),
+    ('\u{267B}', '\u{267B}', G::ExtendedPictographic),
+    ('\u{267E}', '\u{267F}', G::ExtendedPictographic),
+    ('\u{2692}', '\u{2697}', G::ExtendedPictographic),
+    ('\u{2699}', '\u{2699}', G::ExtendedPictographic),
+    ('\u{269B}', '\u{269C}', G::ExtendedPictographic),
+    ('\u{26A0}', '\u{26A1}', G::ExtendedPictographic),
+    ('\u{26A7}', '\u{26A7}', G::ExtendedPictographic),
+    ('\u{26AA}', '\u{26AB}', G::ExtendedPictographic),
+    ('\u{26B0}', '\u{26B1}', G::ExtendedPictographic),
+    ('\u{26BD}', '\u{26BE}', G::ExtendedPictographic),
+    ('\u{26C4}', '\u{26C5}', G::ExtendedPictographic),
+    ('\u{26C8}', '\u{26C8}', G::ExtendedPictographic),
+    ('\u{26CE}', '\u{26CF}', G::ExtendedPictographic),
+    ('\u{26D1}', '\u{26D1}', G::ExtendedPictographic),
+    ('\u{26D3}', '\u{26D4}', G::ExtendedPictographic),
+    ('\u{26E9}', '\u{26EA}', G::ExtendedPictographic),
+    ('\u{26F0}', '\u{26F5}', G::ExtendedPictographic),
+    ('\u{26F7}', '\u{26FA}', G::ExtendedPictographic),
+    ('\u{26FD}', '\u{26FD}', G::ExtendedPictographic),
+    ('\u{2702}', '\u{2702}', G::ExtendedPictographic),
+    ('\u{2705}', '\u{2705}', G::ExtendedPictographic),
+    ('\u{2708}', '\u{270D}', G::ExtendedPictographic),
+    ('\u{270F}', '\u{270F}', G::ExtendedPictographic),
+    ('\u{2712}', '\u{2712}', G::ExtendedPictographic),
+    ('\u{2714}', '\u{2714}', G::ExtendedPictographic),
+    ('\u{2716}', '\u{2716}', G::ExtendedPictographic),
+    ('\u{271D}', '\u{271D}', G::ExtendedPictographic),
+    ('\u{2721}', '\u{2721}', G::ExtendedPictographic),
+    ('\u{2728}', '\u{2728}', G::ExtendedPictographic),
+    ('\u{2733}', '\u{2734}', G::ExtendedPictographic),
+    ('\u{2744}', '\u{2744}', G::ExtendedPictographic),
+    ('\u{2747}', '\u{2747}', G::ExtendedPictographic),
+    ('\u{274C}', '\u{274C}', G::ExtendedPictographic),
+    ('\u{274E}', '\u{274E}', G::ExtendedPictographic),
+    ('\u{2753}', '\u{2755}', G::ExtendedPictographic),
+    ('\u{2757}', '\u{2757}', G::ExtendedPictographic),
+    ('\u{2763}', '\u{2764}', G::ExtendedPictographic),
+    ('\u{2795}', '\u{2797}', G::ExtendedPictographic),
+    ('\u{27A1}', '\u{27A1}', G::ExtendedPictographic),
+    ('\u{27B0}', '\u{27B0}', G::ExtendedPictographic),
+    ('\u{27BF}', '\u{27BF}', G::ExtendedPictographic),
+    ('\u{2934}', '\u{2935}', G::ExtendedPictographic),
+    ('\u{2B05}', '\u{2B07}', G::ExtendedPictographic),
+    ('\u{2B1B}', '\u{2B1C}', G::ExtendedPictographic),
+    ('\u{2B50}', '\u{2B50}', G::ExtendedPictographic),
+    ('\u{2B55}', '\u{2B55}', G::ExtendedPictographic),
+    ('\u{2CEF}', '\u{2CF1}', G::Extend),
+    ('\u{2D7F}', '\u{2D7F}', G::Extend),
+    ('\u{2DE0}', '\u{2DFF}', G::Extend),
+    ('\u{302A}', '\u{302F}', G::Extend),
+    ('\u{3030}', '\u{3030}', G::ExtendedPictographic),
+    ('\u{303D}', '\u{303D}', G::ExtendedPictographic),
+    ('\u{3099}', '\u{309A}', G::Extend),
+    ('\u{3297}', '\u{3297}', G::ExtendedPictographic),
+    ('\u{3299}', '\u{3299}', G::ExtendedPictographic),
+    ('\u{A66F}', '\u{A672}', G::Extend),
+    ('\u{A674}', '\u{A67D}', G::Extend),
+    ('\u{A69E}', '\u{A69F}', G::Extend),
+    ('\u{A6F0}', '\u{A6F1}', G::Extend),
+    ('\u{A802}', '\u{A802}', G::Extend),
+    ('\u{A806}', '\u{A806}', G::Extend),
+    ('\u{A80B}', '\u{A80B}', G::Extend),
+    ('\u{A823}', '\u{A824}', G::SpacingMark),
+    ('\u{A825}', '\u{A826}', G::Extend),
+    ('\u{A827}', '\u{A827}', G::SpacingMark),
+    ('\u{A82C}', '\u{A82C}', G::Extend),
+    ('\u{A880}', '\u{A881}', G::SpacingMark),
+    ('\u{A8B4}', '\u{A8C3}', G::SpacingMark),
+    ('\u{A8C4}', '\u{A8C5}', G::Extend),
+    ('\u{A8E0}', '\u{A8F1}', G::Extend),
+    ('\u{A8FF}', '\u{A8FF}', G::Extend),
+    ('\u{A926}', '\u{A92D}', G::Extend),
+    ('\u{A947}', '\u{A951}', G::Extend),
+    ('\u{A952}', '\u{A952}', G::SpacingMark),
+    ('\u{A953}', '\u{A953}', G::Extend),
+    ('\u{A960}', '\u{A97C}', G::L),
+    ('\u{A980}', '\u{A982}', G::Extend),
+    ('\u{A983}', '\u{A983}', G::SpacingMark),
+    ('\u{A9B3}', '\u{A9B3}', G::Extend),
+    ('\u{A9B4}', '\u{A9B5}', G::SpacingMark),
+    ('\u{A9B6}', '\u{A9B9}', G::Extend),
+    ('\u{A9BA}', '\u{A9BB}', G::SpacingMark),
+    ('\u{A9BC}', '\u{A9BD}', G::Extend),
+    ('\u{A9BE}', '\u{A9BF}', G::SpacingMark),
+    ('\u{A9C0}', '\u{A9C0}', G::Extend),
+    ('\u{A9E5}', '\u{A9E5}', G::Extend),
+    ('\u{AA29}', '\u{AA2E}', G::Extend),
+    ('\u{AA2F}', '\u{AA30}', G::SpacingMark),
+    ('\u{AA31}', '\u{AA32}', G::Extend),
+    ('\u{AA33}', '\u{AA34}', G::SpacingMark),
+    ('\u{AA35}', '\u{AA36}', G::Extend),
+    ('\u{AA43}', '\u{AA43}', G::Extend),
+    ('\u{AA4C}', '\u{AA4C}', G::Extend),
+    ('\u{AA4D}', '\u{AA4D}', G::SpacingMark),
+    ('\u{AA7C}', '\u{AA7C}', G::Extend),
+    ('\u{AAB0}', '\u{AAB0}', G::Extend),
+    ('\u{AAB2}', '\u{AAB4}', G::Extend),
+    ('\u{AAB7}', '\u{AAB8}', G::Extend),
+    ('\u{AABE}', '\u{AABF}', G::Extend),
+    ('\u{AAC1}', '\u{AAC1}', G::Extend),
+    ('\u{AAEB}', '\u{AAEB}', G::SpacingMark),
+    ('\u{AAEC}', '\u{AAED}', G::Extend),
+    ('\u{AAEE}', '\u{AAEF}', G::SpacingMark),
+    ('\u{AAF5}', '\u{AAF5}', G::SpacingMark),
+    ('\u{AAF6}', '\u{AAF6}', G::Extend),
+    ('\u{ABE3}', '\u{ABE4}', G::SpacingMark),
+    ('\u{ABE5}', '\u{ABE5}', G::Extend),
+    ('\u{ABE6}', '\u{ABE7}', G::SpacingMark),
+    ('\u{ABE8}', '\u{ABE8}', G::Extend),
+    ('\u{ABE9}', '\u{ABEA}', G::SpacingMark),
+    ('\u{ABEC}', '\u{ABEC}', G::SpacingMark),
+    ('\u{ABED}', '\u{ABED}', G::Extend),
+    ('\u{D7B0}', '\u{D7C6}', G::V),
+    ('\u{D7CB}', '\u{D7FB}', G::T),
+    ('\u{FB1E}', '\u{FB1E}', G::Extend),
+    ('\u{FE00}', '\u{FE0F}', G::Extend),
+    ('\u{FE20}', '\u{FE2F}', G::Extend),
+    ('\u{FEFF}', '\u{FEFF}', G::Control),
+    ('\u{FF9E}', '\u{FF9F}', G::Extend),
+    ('\u{FFF0}', '\u{FFFB}', G::Control),
+    ('\u{101FD}', '\u{101FD}', G::Extend),
+    ('\u{102E0}', '\u{102E0}', G::Extend),
+    ('\u{10376}', '\u{1037A}', G::Extend),
+    ('\u{10A01}', '\u{10A03}', G::Extend),
+    ('\u{10A05}', '\u{10A06}', G::Extend),
+    ('\u{10A0C}', '\u{10A0F}', G::Extend),
+    ('\u{10A38}', '\u{10A3A}', G::Extend),
+    ('\u{10A3F}', '\u{10A3F}', G::Extend),
+    ('\u{10AE5}', '\u{10AE6}', G::Extend),
+    ('\u{10D24}', '\u{10D27}', G::Extend),
+    ('\u{10D69}', '\u{10D6D}', G::Extend),
+    ('\u{10EAB}', '\u{10EAC}', G::Extend),
+    ('\u{10EFA}', '\u{10EFF}', G::Extend),
+    ('\u{10F46}', '\u{10F50}', G::Extend),
+    ('\u{10F82}', '\u{10F85}', G::Extend),
+    ('\u{11000}', '\u{11000}', G::SpacingMark),
+    ('\u{11001}', '\u{11001}', G::Extend),
+    ('\u{11002}', '\u{11002}', G::SpacingMark),
+    ('\u{11038}', '\u{11046}', G::Extend),
+    ('\u{11070}', '\u{11070}', G::Extend),
+    ('\u{11073}', '\u{11074}', G::Extend),
+    ('\u{1107F}', '\u{11081}', G::Extend),
+    ('\u{11082}', '\u{11082}', G::SpacingMark),
+    ('\u{110B0}', '\u{110B2}', G::SpacingMark),
+    ('\u{110B3}', '\u{110B6}', G::Extend),
+    ('\u{110B7}', '\u{110B8}', G::SpacingMark),
+    ('\u{110B9}', '\u{110BA}', G::Extend),
+    ('\u{110BD}', '\u{110BD}', G::Prepend),
+    ('\u{110C2}', '\u{110C2}', G::Extend),
+    ('\u{110CD}', '\u{110CD}', G::Prepend),
+    ('\u{11100}', '\u{11102}', G::Extend),
+    ('\u{11127}', '\u{1112B}', G::Extend),
+    ('\u{1112C}', '\u{1112C}', G::SpacingMark),
+    ('\u{1112D}', '\u{11134}', G::Extend),
+    ('\u{11145}', '\u{11146}', G::SpacingMark),
+    ('\u{11173}', '\u{11173}', G::Extend),
+    ('\u{11180}', '\u{11181}', G::Extend),
+    ('\u{11182}', '\u{11182}', G::SpacingMark),
+    ('\u{111B3}', '\u{111B5}', G::SpacingMark),
+    ('\u{111B6}', '\u{111BE}', G::Extend),
+    ('\u{111BF}', '\u{111BF}', G::SpacingMark),
+    ('\u{111C0}', '\u{111C0}', G::Extend),
+    ('\u{111C2}', '\u{111C3}', G::Prepend),
+    ('\u{111C9}', '\u{111CC}', G::Extend),
+    ('\u{111CE}', '\u{111CE}', G::SpacingMark),
+    ('\u{111CF}', '\u{111CF}', G::Extend),
+    ('\u{1122C}', '\u{1122E}', G::SpacingMark),
+    ('\u{1122F}', '\u{11231}', G::Extend),
+    ('\u{11232}', '\u{11233}', G::SpacingMark),
+    ('\u{11234}', '\u{11237}', G::Extend),
+    ('\u{1123E}', '\u{1123E}', G::Extend),
+    ('\u{11241}', '\u{11241}', G::Extend),
+    ('\u{112DF}', '\u{112DF}', G::Extend),
+    ('\u{112E0}', '\u{112E2}', G::SpacingMark),
+    ('\u{112E3}', '\u{112EA}', G::Extend),
+    ('\u{11300}', '\u{11301}', G::Extend),
+    ('\u{11302}', '\u{11303}', G::SpacingMark),
+    ('\u{1133B}', '\u{1133C}', G::Extend),
+    ('\u{1133E}', '\u{1133E}', G::Extend),
+    ('\u{1133F}', '\u{1133F}', G::SpacingMark),
+    ('\u{11340}', '\u{11340}', G::Extend),
+    ('\u{11341}', '\u{11344}', G::SpacingMark),
+    ('\u{11347}', '\u{11348}', G::SpacingMark),
+    ('\u{1134B}', '\u{1134C}', G::SpacingMark),
+    ('\u{1134D}', '\u{1134D}', G::Extend),
+    ('\u{11357}', '\u{11357}', G::Extend),
+    ('\u{11362}', '\u{11363}', G::SpacingMark),
+    ('\u{11366}', '\u{1136C}', G::Extend),
+    ('\u{11370}', '\u{11374}', G::Extend),
+    ('\u{113B8}', '\u{113B8}', G::Extend),
+    ('\u{113B9}', '\u{113BA}', G::SpacingMark),
+    ('\u{113BB}', '\u{113C0}', G::Extend),
+    ('\u{113C2}', '\u{113C2}', G::Extend),
+    ('\u{113C5}', '\u{113C5}', G::Extend),
+    ('\u{113C7}', '\u{113C9}', G::Extend),
+    ('\u{113CA}', '\u{113CA}', G::SpacingMark),
+    ('\u{113CC}', '\u{113CD}', G::SpacingMark),
+    ('\u{113CE}', '\u{113D0}', G::Extend),
+    ('\u{113D1}', '\u{113D1}', G::Prepend),
+    ('\u{113D2}', '\u{113D2}', G::Extend),
+    ('\u{113E1}', '\u{113E2}', G::Extend),
+    ('\u{11435}', '\u{11437}', G::SpacingMark),
+    ('\u{11438}', '\u{1143F}', G::Extend),
+    ('\u{11440}', '\u{11441}', G::SpacingMark),
+    ('\u{11442}', '\u{11444}', G::Extend),
+    ('\u{11445}', '\u{11445}', G::SpacingMark),
+    ('\u{11446}', '\u{11446}', G::Extend),
+    ('\u{1145E}', '\u{1145E}', G::Extend),
+    ('\u{114B0}', '\u{114B0}', G::Extend),
+    ('\u{114B1}', '\u{114B2}', G::SpacingMark),
+    ('\u{114B3}', '\u{114B8}', G::Extend),
+    ('\u{114B9}', '\u{114B9}', G::SpacingMark),
+    ('\u{114BA}', '\u{114BA}', G::Extend),
+    ('\u{114BB}', '\u{114BC}', G::SpacingMark),
+    ('\u{114BD}', '\u{114BD}', G::Extend),
+    ('\u{114BE}', '\u{114BE}', G::SpacingMark),
+    ('\u{114BF}', '\u{114C0}', G::Extend),
+    ('\u{114C1}', '\u{114C1}', G::SpacingMark),
+    ('\u{114C2}', '\u{114C3}', G::Extend),
+    ('\u{115AF}', '\u{115AF}', G::Extend),
+    ('\u{115B0}', '\u{115B1}', G::SpacingMark),
+    ('\u{115B2}', '\u{115B5}', G::Extend),
+    ('\u{115B8}', '\u{115BB}', G::SpacingMark),
+    ('\u{115BC}', '\u{115BD}', G::Extend),
+    ('\u{115BE}', '\u{115BE}', G::SpacingMark),
+    ('\u{115BF}', '\u{115C0}', G::Extend),
+    ('\u{115DC}', '\u{115DD}', G::Extend),
+    ('\u{11630}', '\u{11632}', G::SpacingMark),
+    ('\u{11633}', '\u{1163A}', G::Extend),
+    ('\u{1163B}', '\u{1163C}', G::SpacingMark),
+    ('\u{1163D}', '\u{1163D}', G::Extend),
+    ('\u{1163E}', '\u{1163E}', G::SpacingMark),
+    ('\u{1163F}', '\u{11640}', G::Extend),
+    ('\u{116AB}', '\u{116AB}', G::Extend),
+    ('\u{116AC}', '\u{116AC}', G::SpacingMark),
+    ('\u{116AD}', '\u{116AD}', G::Extend),
+    ('\u{116AE}', '\u{116AF}', G::SpacingMark),
+    ('\u{116B0}', '\u{116B7}', G::Extend),
+    ('\u{1171D}', '\u{1171D}', G::Extend),
+    ('\u{1171E}', '\u{1171E}', G::SpacingMark),
+    ('\u{1171F}', '\u{1171F}', G::Extend),
+    ('\u{11722}', '\u{11725}', G::Extend),
+    ('\u{11726}', '\u{11726}', G::SpacingMark),
+    ('\u{11727}', '\u{1172B}', G::Extend),
+    ('\u{1182C}', '\u{1182E}', G::SpacingMark),
+    ('\u{1182F}', '\u{11837}', G::Extend),
+    ('\u{11838}', '\u{11838}', G::SpacingMark),
+    ('\u{11839}', '\u{1183A}', G::Extend),
+    ('\u{11930}', '\u{11930}', G::Extend),
+    ('\u{11931}', '\u{11935}', G::SpacingMark),
+    ('\u{11937}', '\u{11938}', G::SpacingMark),
+    ('\u{1193B}', '\u{1193E}', G::Extend),
+    ('\u{1193F}', '\u{1193F}', G::Prepend),
+    ('\u{11940}', '\u{11940}', G::SpacingMark),
+    ('\u{11941}', '\u{11941}', G::Prepend),
+    ('\u{11942}', '\u{11942}', G::SpacingMark),
+    ('\u{11943}', '\u{11943}', G::Extend),
+    ('\u{119D1}', '\u{119D3}', G::SpacingMark),
+    ('\u{119D4}', '\u{119D7}', G::Extend),
+    ('\u{119DA}', '\u{119DB}', G::Extend),
+    ('\u{119DC}', '\u{119DF}', G::SpacingMark),
+    ('\u{119E0}', '\u{119E0}', G::Extend),
+    ('\u{119E4}', '\u{119E4}', G::SpacingMark),
+    ('\u{11A01}', '\u{11A0A}', G::Extend),
+    ('\u{11A33}', '\u{11A38}', G::Extend),
+    ('\u{11A39}', '\u{11A39}', G::SpacingMark),
+    ('\u{11A3B}', '\u{11A3E}', G::Extend),
+    ('\u{11A47}', '\u{11A47}', G::Extend),
+    ('\u{11A51}', '\u{11A56}', G::Extend),
+    ('\u{11A57}', '\u{11A58}', G::SpacingMark),
+    ('\u{11A59}', '\u{11A5B}', G::Extend),
+    ('\u{11A84}', '\u{11A89}', G::Prepend),
+    ('\u{11A8A}', '\u{11A96}', G::Extend),
+    ('\u{11A97}', '\u{11A97}', G::SpacingMark),
+    ('\u{11A98}', '\u{11A99}', G::Extend),
+    ('\u{11B60}', '\u{11B60}', G::Extend),
+    ('\u{11B61}', '\u{11B61}', G::SpacingMark),
+    ('\u{11B62}', '\u{11B64}', G::Extend),
+    ('\u{11B65}', '\u{11B65}', G::SpacingMark),
+    ('\u{11B66}', '\u{11B66}', G::Extend),
+    ('\u{11B67}', '\u{11B67}', G::SpacingMark),
+    ('\u{11C2F}', '\u{11C2F}', G::SpacingMark),
+    ('\u{11C30}', '\u{11C36}', G::Extend),
+    ('\u{11C38}', '\u{11C3D}', G::Extend),
+    ('\u{11C3E}', '\u{11C3E}', G::SpacingMark),
+    ('\u{11C3F}', '\u{11C3F}', G::Extend),
+    ('\u{11C92}', '\u{11CA7}', G::Extend),
+    ('\u{11CA9}', '\u{11CA9}', G::SpacingMark),
+    ('\u{11CAA}', '\u{11CB0}', G::Extend),
+    ('\u{11CB1}', '\u{11CB1}', G::SpacingMark),
+    ('\u{11CB2}', '\u{11CB3}', G::Extend),
+    ('\u{11CB4}', '\u{11CB4}', G::SpacingMark),
+    ('\u{11CB5}', '\u{11CB6}', G::Extend),
+    ('\u{11D31}', '\u{11D36}', G::Extend),
+    ('\u{11D3A}', '\u{11D3A}', G::Extend),
+    ('\u{11D3C}', '\u{11D3D}', G::Extend),
+    ('\u{11D3F}', '\u{11D45}', G::Extend),
+    ('\u{11D46}', '\u{11D46}', G::Prepend),
+    ('\u{11D47}', '\u{11D47}', G::Extend),
+    ('\u{11D8A}', '\u{11D8E}', G::SpacingMark),
+    ('\u{11D90}', '\u{11D91}', G::Extend),
+    ('\u{11D93}', '\u{11D94}', G::SpacingMark),
+    ('\u{11D95}', '\u{11D95}', G::Extend),
+    ('\u{11D96}', '\u{11D96}', G::SpacingMark),
+    ('\u{11D97}', '\u{11D97}', G::Extend),
+    ('\u{11EF3}', '\u{11EF4}', G::Extend),
+    ('\u{11EF5}', '\u{11EF6}', G::SpacingMark),
+    ('\u{11F00}', '\u{11F01}', G::Extend),
+    ('\u{11F02}', '\u{11F02}', G::Prepend),
+    ('\u{11F03}', '\u{11F03}', G::SpacingMark),
+    ('\u{11F34}', '\u{11F35}', G::SpacingMark),
+    ('\u{11F36}', '\u{11F3A}', G::Extend),
+    ('\u{11F3E}', '\u{11F3F}', G::SpacingMark),
+    ('\u{11F40}', '\u{11F42}', G::Extend),
+    ('\u{11F5A}', '\u{11F5A}', G::Extend),
+    ('\u{13430}', '\u{1343F}', G::Control),
+    ('\u{13440}', '\u{13440}', G::Extend),
+    ('\u{13447}', '\u{13455}', G::Extend),
+    ('\u{1611E}', '\u{16129}', G::Extend),
+    ('\u{1612A}', '\u{1612C}', G::SpacingMark),
+    ('\u{1612D}', '\u{1612F}', G::Extend),
+    ('\u{16AF0}', '\u{16AF4}', G::Extend),
+    ('\u{16B30}', '\u{16B36}', G::Extend),
+    ('\u{16D63}', '\u{16D63}', G::V),
+    ('\u{16D67}', '\u{16D6A}', G::V),
+    ('\u{16F4F}', '\u{16F4F}', G::Extend),
+    ('\u{16F51}', '\u{16F87}', G::SpacingMark),
+    ('\u{16F8F}', '\u{16F92}', G::Extend),
+    ('\u{16FE4}', '\u{16FE4}', G::Extend),
+    ('\u{16FF0}', '\u{16FF1}', G::Extend),
+    ('\u{1BC9D}', '\u{1BC9E}', G::Extend),
+    ('\u{1BCA0}', '\u{1BCA3}', G::Control),
+    ('\u{1CF00}', '\u{1CF2D}', G::Extend),
+    ('\u{1CF30}', '\u{1CF46}', G::Extend),
+    ('\u{1D165}', '\u{1D169}', G::Extend),
+    ('\u{1D16D}', '\u{1D172}', G::Extend),
+    ('\u{1D173}', '\u{1D17A}', G::Control),
+    ('\u{1D17B}', '\u{1D182}', G::Extend),
+    ('\u{1D185}', '\u{1D18B}', G::Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', G::Extend),
+    ('\u{1D242}', '\u{1D244}', G::Extend),
+    ('\u{1DA00}', '\u{1DA36}', G::Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', G::Extend),
+    ('\u{1DA75}', '\u{1DA75}', G::Extend),
+    ('\u{1DA84}', '\u{1DA84}', G::Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', G::Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', G::Extend),
+    ('\u{1E000}', '\u{1E006}', G::Extend),
+    ('\u{1E008}', '\u{1E018}', G::Extend),
+    ('\u{1E01B}', '\u{1E021}', G::Extend),
+    ('\u{1E023}', '\u{1E024}', G::Extend),
+    ('\u{1E026}', '\u{1E02A}', G::Extend),
+    ('\u{1E08F}', '\u{1E08F}', G::Extend),
+    ('\u{1E130}', '\u{1E136}', G::Extend),
+    ('\u{1E2AE}', '\u{1E2AE}', G::Extend),
+    ('\u{1E2EC}', '\u{1E2EF}', G::Extend),
+    ('\u{1E4EC}', '\u{1E4EF}', G::Extend),
+    ('\u{1E5EE}', '\u{1E5EF}', G::Extend),
+    ('\u{1E6E3}', '\u{1E6E3}', G::Extend),
+    ('\u{1E6E6}', '\u{1E6E6}', G::Extend),
+    ('\u{1E6EE}', '\u{1E6EF}', G::Extend),
+    ('\u{1E6F5}', '\u{1E6F5}', G::Extend),
+    ('\u{1E8D0}', '\u{1E8D6}', G::Extend),
+    ('\u{1E944}', '\u{1E94A}', G::Extend),
+    ('\u{1F004}', '\u{1F004}', G::ExtendedPictographic),
+    ('\u{1F02C}', '\u{1F02F}', G::ExtendedPictographic),
+    ('\u{1F094}', '\u{1F09F}', G::ExtendedPictographic),
+    ('\u{1F0AF}', '\u{1F0B0}', G::ExtendedPictographic),
+    ('\u{1F0C0}', '\u{1F0C0}', G::ExtendedPictographic),
+    ('\u{1F0CF}', '\u{1F0D0}', G::ExtendedPictographic),
+    ('\u{1F0F6}', '\u{1F0FF}', G::ExtendedPictographic),
+    ('\u{1F170}', '\u{1F171}', G::ExtendedPictographic),
+    ('\u{1F17E}', '\u{1F17F}', G::ExtendedPictographic),
+    ('\u{1F18E}', '\u{1F18E}', G::ExtendedPictographic),
+    ('\u{1F191}', '\u{1F19A}', G::ExtendedPictographic),
+    ('\u{1F1AE}', '\u{1F1E5}', G::ExtendedPictographic),
+    ('\u{1F1E6}', '\u{1F1FF}', G::RegionalIndicator),
+    ('\u{1F201}', '\u{1F20F}', G::ExtendedPictographic),
+    ('\u{1F21A}', '\u{1F21A}', G::ExtendedPictographic),
+    ('\u{1F22F}', '\u{1F22F}', G::ExtendedPictographic),
+    ('\u{1F232}', '\u{1F23A}', G::ExtendedPictographic),
+    ('\u{1F23C}', '\u{1F23F}', G::ExtendedPictographic),
+    ('\u{1F249}', '\u{1F25F}', G::ExtendedPictographic),
+    ('\u{1F266}', '\u{1F321}', G::ExtendedPictographic),
+    ('\u{1F324}', '\u{1F393}', G::ExtendedPictographic),
+    ('\u{1F396}', '\u{1F397}', G::ExtendedPictographic),
+    ('\u{1F399}', '\u{1F39B}', G::ExtendedPictographic),
+    ('\u{1F39E}', '\u{1F3F0}', G::ExtendedPictographic),
+    ('\u{1F3F3}', '\u{1F3F5}', G::ExtendedPictographic),
+    ('\u{1F3F7}', '\u{1F3FA}', G::ExtendedPictographic),
+    ('\u{1F3FB}', '\u{1F3FF}', G::Extend),
+    ('\u{1F400}', '\u{1F4FD}', G::ExtendedPictographic),
+    ('\u{1F4FF}', '\u{1F53D}', G::ExtendedPictographic),
+    ('\u{1F549}', '\u{1F54E}', G::ExtendedPictographic),
+    ('\u{1F550}', '\u{1F567}', G::ExtendedPictographic),
+    ('\u{1F56F}', '\u{1F570}', G::ExtendedPictographic),
+    ('\u{1F573}', '\u{1F57A}', G::ExtendedPictographic),
+    ('\u{1F587}', '\u{1F587}', G::ExtendedPictographic),
+    ('\u{1F58A}', '\u{1F58D}', G::ExtendedPictographic),
+    ('\u{1F590}', '\u{1F590}', G::ExtendedPictographic),
+    ('\u{1F595}', '\u{1F596}', G::ExtendedPictographic),
+    ('\u{1F5A4}', '\u{1F5A5}', G::ExtendedPictographic),
+    ('\u{1F5A8}', '\u{1F5A8}', G::ExtendedPictographic),
+    ('\u{1F5B1}', '\u{1F5B2}', G::ExtendedPictographic),
+    ('\u{1F5BC}', '\u{1F5BC}', G::ExtendedPictographic),
+    ('\u{1F5C2}', '\u{1F5C4}', G::ExtendedPictographic),
+    ('\u{1F5D1}', '\u{1F5D3}', G::ExtendedPictographic),
+    ('\u{1F5DC}', '\u{1F5DE}', G::ExtendedPictographic),
+    ('\u{1F5E1}', '\u{1F5E1}', G::ExtendedPictographic),
+    ('\u{1F5E3}', '\u{1F5E3}', G::ExtendedPictographic),
+    ('\u{1F5E8}', '\u{1F5E8}', G::ExtendedPictographic),
+    ('\u{1F5EF}', '\u{1F5EF}', G::ExtendedPictographic),
+    ('\u{1F5F3}', '\u{1F5F3}', G::ExtendedPictographic),
+    ('\u{1F5FA}', '\u{1F64F}', G::ExtendedPictographic),
+    ('\u{1F680}', '\u{1F6C5}', G::ExtendedPictographic),
+    ('\u{1F6CB}', '\u{1F6D2}', G::ExtendedPictographic),
+    ('\u{1F6D5}', '\u{1F6E5}', G::ExtendedPictographic),
+    ('\u{1F6E9}', '\u{1F6E9}', G::ExtendedPictographic),
+    ('\u{1F6EB}', '\u{1F6F0}', G::ExtendedPictographic),
+    ('\u{1F6F3}', '\u{1F6FF}', G::ExtendedPictographic),
+    ('\u{1F7DA}', '\u{1F7FF}', G::ExtendedPictographic),
+    ('\u{1F80C}', '\u{1F80F}', G::ExtendedPictographic),
+    ('\u{1F848}', '\u{1F84F}', G::ExtendedPictographic),
+    ('\u{1F85A}', '\u{1F85F}', G::ExtendedPictographic),
+    ('\u{1F888}', '\u{1F88F}', G::ExtendedPictographic),
+    ('\u{1F8AE}', '\u{1F8AF}', G::ExtendedPictographic),
+    ('\u{1F8BC}', '\u{1F8BF}', G::ExtendedPictographic),
+    ('\u{1F8C2}', '\u{1F8CF}', G::ExtendedPictographic),
+    ('\u{1F8D9}', '\u{1F8FF}', G::ExtendedPictographic),
+    ('\u{1F90C}', '\u{1F93A}', G::ExtendedPictographic),
+    ('\u{1F93C}', '\u{1F945}', G::ExtendedPictographic),
+    ('\u{1F947}', '\u{1F9FF}', G::ExtendedPictographic),
+    ('\u{1FA58}', '\u{1FA5F}', G::ExtendedPictographic),
+    ('\u{1FA6E}', '\u{1FAFF}', G::ExtendedPictographic),
+    ('\u{1FC00}', '\u{1FFFD}', G::ExtendedPictographic),
+    ('\u{E0000}', '\u{E001F}', G::Control),
+    ('\u{E0020}', '\u{E007F}', G::Extend),
+    ('\u{E0080}', '\u{E00FF}', G::Control),
+    ('\u{E0100}', '\u{E01EF}', G::Extend),
+    ('\u{E01F0}', '\u{E0FFF}', G::Control),
+];
+
+/// `Indic_Conjunct_Break` values other than `None`.
+pub(super) const INDIC_CONJUNCT_BREAK: &[(char, char, I)] = &[
+    ('\u{300}', '\u{36F}', I::Extend),
+    ('\u{483}', '\u{489}', I::Extend),
+    ('\u{591}', '\u{5BD}', I::Extend),
+    ('\u{5BF}', '\u{5BF}', I::Extend),
+    ('\u{5C1}', '\u{5C2}', I::Extend),
+    ('\u{5C4}', '\u{5C5}', I::Extend),
+    ('\u{5C7}', '\u{5C7}', I::Extend),
+    ('\u{610}', '\u{61A}', I::Extend),
+    ('\u{64B}', '\u{65F}', I::Extend),
+    ('\u{670}', '\u{670}', I::Extend),
+    ('\u{6D6}', '\u{6DC}', I::Extend),
+    ('\u{6DF}', '\u{6E4}', I::Extend),
+    ('\u{6E7}', '\u{6E8}', I::Extend),
+    ('\u{6EA}', '\u{6ED}', I::Extend),
+    ('\u{711}', '\u{711}', I::Extend),
+    ('\u{730}', '\u{74A}', I::Extend),
+    ('\u{7A6}', '\u{7B0}', I::Extend),
+    ('\u{7EB}', '\u{7F3}', I::Extend),
+    ('\u{7FD}', '\u{7FD}', I::Extend),
+    ('\u{816}', '\u{819}', I::Extend),
+    ('\u{81B}', '\u{823}', I::Extend),
+    ('\u{825}', '\u{827}', I::Extend),
+    ('\u{829}', '\u{82D}', I::Extend),
+    ('\u{859}', '\u{85B}', I::Extend),
+    ('\u{897}', '\u{89F}', I::Extend),
+    ('\u{8CA}', '\u{8E1}', I::Extend),
+    ('\u{8E3}', '\u{902}', I::Extend),
+    ('\u{915}', '\u{939}', I::Consonant),
+    ('\u{93A}', '\u{93A}', I::Extend),
+    ('\u{93C}', '\u{93C}', I::Extend),
+    ('\u{941}', '\u{948}', I::Extend),
+    ('\u{94D}', '\u{94D}', I::Linker),
+    ('\u{951}', '\u{957}', I::Extend),
+    ('\u{958}', '\u{95F}', I::Consonant),
+    ('\u{962}', '\u{963}', I::Extend),
+    ('\u{978}', '\u{97F}', I::Consonant),
+    ('\u{981}', '\u{981}', I::Extend),
+    ('\u{995}', '\u{9A8}', I::Consonant),
+    ('\u{9AA}', '\u{9B0}', I::Consonant),
+    ('\u{9B2}', '\u{9B2}', I::Consonant),
+    ('\u{9B6}', '\u{9B9}', I::Consonant),
+    ('\u{9BC}', '\u{9BC}', I::Extend),
+    ('\u{9BE}', '\u{9BE}', I::Extend),
+    ('\u{9C1}', '\u{9C4}', I::Extend),
+    ('\u{9CD}', '\u{9CD}', I::Linker),
+    ('\u{9D7}', '\u{9D7}', I::Extend),
+    ('\u{9DC}', '\u{9DD}', I::Consonant),
+    ('\u{9DF}', '\u{9DF}', I::Consonant),
+    ('\u{9E2}', '\u{9E3}', I::Extend),
+    ('\u{9F0}', '\u{9F1}', I::Consonant),
+    ('\u{9FE}', '\u{9FE}', I::Extend),
+    ('\u{A01}', '\u{A02}', I::Extend),
+    ('\u{A3C}', '\u{A3C}', I::Extend),
+    ('\u{A41}', '\u{A42}', I::Extend),
+    ('\u{A47}', '\u{A48}', I::Extend),
+    ('\u{A4B}', '\u{A4D}', I::Extend),
+    ('\u{A51}', '\u{A51}', I::Extend),
+    ('\u{A70}', '\u{A71}', I::Extend),
+    ('\u{A75}', '\u{A75}', I::Extend),
+    ('\u{A81}', '\u{A82}', I::Extend),
+    ('\u{A95}', '\u{AA8}', I::Consonant),
+    ('\u{AAA}', '\u{AB0}', I::Consonant),
+    ('\u{AB2}', '\u{AB3}', I::Consonant),
+    ('\u{AB5}', '\u{AB9}', I::Consonant),
+    ('\u{ABC}', '\u{ABC}', I::Extend),
+    ('\u{AC1}', '\u{AC5}', I::Extend),
+    ('\u{AC7}', '\u{AC8}', I::Extend),
+    ('\u{ACD}', '\u{ACD}', I::Linker),
+    ('\u{AE2}', '\u{AE3}', I::Extend),
+    ('\u{AF9}', '\u{AF9}', I::Consonant),
+    ('\u{AFA}', '\u{AFF}', I::Extend),
+    ('\u{B01}', '\u{B01}', I::Extend),
+    ('\u{B15}', '\u{B28}', I::Consonant),
+    ('\u{B2A}', '\u{B30}', I::Consonant),
+    ('\u{B32}', '\u{B33}', I::Consonant),
+    ('\u{B35}', '\u{B39}', I::Consonant),
+    ('\u{B3C}', '\u{B3C}', I::Extend),
+    ('\u{B3E}', '\u{B3F}', I::Extend),
+    ('\u{B41}', '\u{B44}', I::Extend),
+    ('\u{B4D}', '\u{B4D}', I::Linker),
+    ('\u{B55}', '\u{B57}', I::Extend),
+    ('\u{B5C}', '\u{B5D}', I::Consonant),
+    ('\u{B5F}', '\u{B5F}', I::Consonant),
+    ('\u{B62}', '\u{B63}', I::Extend),
+    ('\u{B71}', '\u{B71}', I::Consonant),
+    ('\u{B82}', '\u{B82}', I::Extend),
+    ('\u{BBE}', '\u{BBE}', I::Extend),
+    ('\u{BC0}', '\u{BC0}', I::Extend),
+    ('\u{BCD}', '\u{BCD}', I::Extend),
+    ('\u{BD7}', '\u{BD7}', I::Extend),
+    ('\u{C00}', '\u{C00}', I::Extend),
+    ('\u{C04}', '\u{C04}', I::Extend),
+    ('\u{C15}', '\u{C28}', I::Consonant),
+    ('\u{C2A}', '\u{C39}', I::Consonant),
+    ('\u{C3C}', '\u{C3C}', I::Extend),
+    ('\u{C3E}', '\u{C40}', I::Extend),
+    ('\u{C46}', '\u{C48}', I::Extend),
+    ('\u{C4A}', '\u{C4C}', I::Extend),
+    ('\u{C4D}', '\u{C4D}', I::Linker),
+    ('\u{C55}', '\u{C56}', I::Extend),
+    ('\u{C58}', '\u{C5A}', I::Consonant),
+    ('\u{C62}', '\u{C63}', I::Extend),
+    ('\u{C81}', '\u{C81}', I::Extend),
+    ('\u{CBC}', '\u{CBC}', I::Extend),
+    ('\u{CBF}', '\u{CC0}', I::Extend),
+    ('\u{CC2}', '\u{CC2}', I::Extend),
+    ('\u{CC6}', '\u{CC8}', I::Extend),
+    ('\u{CCA}', '\u{CCD}', I::Extend),
+    ('\u{CD5}', '\u{CD6}', I::Extend),
+    ('\u{CE2}', '\u{CE3}', I::Extend),
+    ('\u{D00}', '\u{D01}', I::Extend),
+    ('\u{D15}', '\u{D3A}', I::Consonant),
+    ('\u{D3B}', '\u{D3C}', I::Extend),
+    ('\u{D3E}', '\u{D3E}', I::Extend),
+    ('\u{D41}', '\u{D44}', I::Extend),
+    ('\u{D4D}', '\u{D4D}', I::Linker),
+    ('\u{D57}', '\u{D57}', I::Extend),
+    ('\u{D62}', '\u{D63}', I::Extend),
+    ('\u{D81}', '\u{D81}', I::Extend),
+    ('\u{DCA}', '\u{DCA}', I::Extend),
+    ('\u{DCF}', '\u{DCF}', I::Extend),
+    ('\u{DD2}', '\u{DD4}', I::Extend),
+    ('\u{DD6}', '\u{DD6}', I::Extend),
+    ('\u{DDF}', '\u{DDF}', I::Extend),
+    ('\u{E31}', '\u{E31}', I::Extend),
+    ('\u{E34}', '\u{E3A}', I::Extend),
+    ('\u{E47}', '\u{E4E}', I::Extend),
+    ('\u{EB1}', '\u{EB1}', I::Extend),
+    ('\u{EB4}', '\u{EBC}', I::Extend),
+    ('\u{EC8}', '\u{ECE}', I::Extend),
+    ('\u{F18}', '\u{F19}', I::Extend),
+    ('\u{F35}', '\u{F35}', I::Extend),
+    ('\u{F37}', '\u{F37}', I::Extend),
+    ('\u{F39}', '\u{F39}', I::Extend),
+    ('\u{F71}', '\u{F7E}', I::Extend),
+    ('\u{F80}', '\u{F84}', I::Extend),
+    ('\u{F86}', '\u{F87}', I::Extend),
+    ('\u{F8D}', '\u{F97}', I::Extend),
+    ('\u{F99}', '\u{FBC}', I::Extend),
+    ('\u{FC6}', '\u{FC6}', I::Extend),
+    ('\u{1000}', '\u{102A}', I::Consonant),
+    ('\u{102D}', '\u{1030}', I::Extend),
+    ('\u{1032}', '\u{1037}', I::Extend),
+    ('\u{1039}', '\u{1039}', I::Linker),
+    ('\u{103A}', '\u{103A}', I::Extend),
+    ('\u{103D}', '\u{103E}', I::Extend),
+    ('\u{103F}', '\u{103F}', I::Consonant),
+    ('\u{1050}', '\u{1055}', I::Consonant),
+    ('\u{1058}', '\u{1059}', I::Extend),
+    ('\u{105A}', '\u{105D}', I::Consonant),
+    ('\u{105E}', '\u{1060}', I::Extend),
+    ('\u{1061}', '\u{1061}', I::Consonant),
+    ('\u{1065}', '\u{1066}', I::Consonant),
+    ('\u{106E}', '\u{1070}', I::Consonant),
+    ('\u{1071}', '\u{1074}', I::Extend),
+    ('\u{1075}', '\u{1081}', I::Consonant),
+    ('\u{1082}', '\u{1082}', I::Extend),
+    ('\u{1085}', '\u{1086}', I::Extend),
+    ('\u{108D}', '\u{108D}', I::Extend),
+    ('\u{108E}', '\u{108E}', I::Consonant),
+    ('\u{109D}', '\u{109D}', I::Extend),
+    ('\u{135D}', '\u{135F}', I::Extend),
+    ('\u{1712}', '\u{1715}', I::Extend),
+    ('\u{1732}', '\u{1734}', I::Extend),
+    ('\u{1752}', '\u{1753}', I::Extend),
+    ('\u{1772}', '\u{1773}', I::Extend),
+    ('\u{1780}', '\u{17B3}', I::Consonant),
+    ('\u{17B4}', '\u{17B5}', I::Extend),
+    ('\u{17B7}', '\u{17BD}', I::Extend),
+    ('\u{17C6}', '\u{17C6}', I::Extend),
+    ('\u{17C9}', '\u{17D1}', I::Extend),
+    ('\u{17D2}', '\u{17D2}', I::Linker),
+    ('\u{17D3}', '\u{17D3}', I::Extend),
+    ('\u{17DD}', '\u{17DD}', I::Extend),
+    ('\u{180B}', '\u{180D}', I::Extend),
+    ('\u{180F}', '\u{180F}', I::Extend),
+    ('\u{1885}', '\u{1886}', I::Extend),
+    ('\u{18A9}', '\u{18A9}', I::Extend),
+    ('\u{1920}', '\u{1922}', I::Extend),
+    ('\u{1927}', '\u{1928}', I::Extend),
+    ('\u{1932}', '\u{1932}', I::Extend),
+    ('\u{1939}', '\u{193B}', I::Extend),
+    ('\u{1A17}', '\u{1A18}', I::Extend),
+    ('\u{1A1B}', '\u{1A1B}', I::Extend),
+    ('\u{1A20}', '\u{1A54}', I::Consonant),
+    ('\u{1A56}', '\u{1A56}', I::Extend),
+    ('\u{1A58}', '\u{1A5E}', I::Extend),
+    ('\u{1A60}', '\u{1A60}', I::Linker),
+    ('\u{1A62}', '\u{1A62}', I::Extend),
+    ('\u{1A65}', '\u{1A6C}', I::Extend),
+    ('\u{1A73}', '\u{1A7C}', I::Extend),
+    ('\u{1A7F}', '\u{1A7F}', I::Extend),
+    ('\u{1AB0}', '\u{1ADD}', I::Extend),
+    ('\u{1AE0}', '\u{1AEB}', I::Extend),
+    ('\u{1B00}', '\u{1B03}', I::Extend),
+    ('\u{1B0B}', '\u{1B0C}', I::Consonant),
+    ('\u{1B13}', '\u{1B33}', I::Consonant),
+    ('\u{1B34}', '\u{1B3D}', I::Extend),
+    ('\u{1B42}', '\u{1B43}', I::Extend),
+    ('\u{1B44}', '\u{1B44}', I::Linker),
+    ('\u{1B45}', '\u{1B4C}', I::Consonant),
+    ('\u{1B6B}', '\u{1B73}', I::Extend),
+    ('\u{1B80}', '\u{1B81}', I::Extend),
+    ('\u{1B83}', '\u{1BA0}', I::Consonant),
+    ('\u{1BA2}', '\u{1BA5}', I::Extend),
+    ('\u{1BA8}', '\u{1BAA}', I::Extend),
+    ('\u{1BAB}', '\u{1BAB}', I::Linker),
+    ('\u{1BAC}', '\u{1BAD}', I::Extend),
+    ('\u{1BAE}', '\u{1BAF}', I::Consonant),
+    ('\u{1BBB}', '\u{1BBD}', I::Consonant),
+    ('\u{1BE6}', '\u{1BE6}', I::Extend),
+    ('\u{1BE8}', '\u{1BE9}', I::Extend),
+    ('\u{1BED}', '\u{1BED}', I::Extend),
+    ('\u{1BEF}', '\u{1BF3}', I::Extend),
+    ('\u{1C2C}', '\u{1C33}', I::Extend),
+    ('\u{1C36}', '\u{1C37}', I::Extend),
+    ('\u{1CD0}', '\u{1CD2}', I::Extend),
+    ('\u{1CD4}', '\u{1CE0}', I::Extend),
+    ('\u{1CE2}', '\u{1CE8}', I::Extend),
+    ('\u{1CED}', '\u{1CED}', I::Extend),
+    ('\u{1CF4}', '\u{1CF4}', I::Extend),
+    ('\u{1CF8}', '\u{1CF9}', I::Extend),
+    ('\u{1DC0}', '\u{1DFF}', I::Extend),
+    ('\u{200D}', '\u{200D}', I::Extend),
+    ('\u{20D0}', '\u{20F0}', I::Extend),
+    ('\u{2CEF}', '\u{2CF1}', I::Extend),
+    ('\u{2D7F}', '\u{2D7F}', I::Extend),
+    ('\u{2DE0}', '\u{2DFF}', I::Extend),
+    ('\u{302A}', '\u{302F}', I::Extend),
+    ('\u{3099}', '\u{309A}', I::Extend),
+    ('\u{A66F}', '\u{A672}', I::Extend),
+    ('\u{A674}', '\u{A67D}', I::Extend),
+    ('\u{A69E}', '\u{A69F}', I::Extend),
+    ('\u{A6F0}', '\u{A6F1}', I::Extend),
+    ('\u{A802}', '\u{A802}', I::Extend),
+    ('\u{A806}', '\u{A806}', I::Extend),
+    ('\u{A80B}', '\u{A80B}', I::Extend),
+    ('\u{A825}', '\u{A826}', I::Extend),
+    ('\u{A82C}', '\u{A82C}', I::Extend),
+    ('\u{A8C4}', '\u{A8C5}', I::Extend),
+    ('\u{A8E0}', '\u{A8F1}', I::Extend),
+    ('\u{A8FF}', '\u{A8FF}', I::Extend),
+    ('\u{A926}', '\u{A92D}', I::Extend),
+    ('\u{A947}', '\u{A951}', I::Extend),
+    ('\u{A953}', '\u{A953}', I::Extend),
+    ('\u{A980}', '\u{A982}', I::Extend),
+    ('\u{A989}', '\u{A98B}', I::Consonant),
+    ('\u{A98F}', '\u{A9B2}', I::Consonant),
+    ('\u{A9B3}', '\u{A9B3}', I::Extend),
+    ('\u{A9B6}', '\u{A9B9}', I::Extend),
+    ('\u{A9BC}', '\u{A9BD}', I::Extend),
+    ('\u{A9C0}', '\u{A9C0}', I::Linker),
+    ('\u{A9E0}', '\u{A9E4}', I::Consonant),
+    ('\u{A9E5}', '\u{A9E5}', I::Extend),
+    ('\u{A9E7}', '\u{A9EF}', I::Consonant),
+    ('\u{A9FA}', '\u{A9FE}', I::Consonant),
+    ('\u{AA29}', '\u{AA2E}', I::Extend),
+    ('\u{AA31}', '\u{AA32}', I::Extend),
+    ('\u{AA35}', '\u{AA36}', I::Extend),
+    ('\u{AA43}', '\u{AA43}', I::Extend),
+    ('\u{AA4C}', '\u{AA4C}', I::Extend),
+    ('\u{AA60}', '\u{AA6F}', I::Consonant),
+    ('\u{AA71}', '\u{AA73}', I::Consonant),
+    ('\u{AA7A}', '\u{AA7A}', I::Consonant),
+    ('\u{AA7C}', '\u{AA7C}', I::Extend),
+    ('\u{AA7E}', '\u{AA7F}', I::Consonant),
+    ('\u{AAB0}', '\u{AAB0}', I::Extend),
+    ('\u{AAB2}', '\u{AAB4}', I::Extend),
+    ('\u{AAB7}', '\u{AAB8}', I::Extend),
+    ('\u{AABE}', '\u{AABF}', I::Extend),
+    ('\u{AAC1}', '\u{AAC1}', I::Extend),
+    ('\u{AAE0}', '\u{AAEA}', I::Consonant),
+    ('\u{AAEC}', '\u{AAED}', I::Extend),
+    ('\u{AAF6}', '\u{AAF6}', I::Linker),
+    ('\u{ABC0}', '\u{ABDA}', I::Consonant),
+    ('\u{ABE5}', '\u{ABE5}', I::Extend),
+    ('\u{ABE8}', '\u{ABE8}', I::Extend),
+    ('\u{ABED}', '\u{ABED}', I::Extend),
+    ('\u{FB1E}', '\u{FB1E}', I::Extend),
+    ('\u{FE00}', '\u{FE0F}', I::Extend),
+    ('\u{FE20}', '\u{FE2F}', I::Extend),
+    ('\u{FF9E}', '\u{FF9F}', I::Extend),
+    ('\u{101FD}', '\u{101FD}', I::Extend),
+    ('\u{102E0}', '\u{102E0}', I::Extend),
+    ('\u{10376}', '\u{1037A}', I::Extend),
+    ('\u{10A00}', '\u{10A00}', I::Consonant),
+    ('\u{10A01}', '\u{10A03}', I::Extend),
+    ('\u{10A05}', '\u{10A06}', I::Extend),
+    ('\u{10A0C}', '\u{10A0F}', I::Extend),
+    ('\u{10A10}', '\u{10A13}', I::Consonant),
+    ('\u{10A15}', '\u{10A17}', I::Consonant),
+    ('\u{10A19}', '\u{10A35}', I::Consonant),
+    ('\u{10A38}', '\u{10A3A}', I::Extend),
+    ('\u{10A3F}', '\u{10A3F}', I::Linker),
+    ('\u{10AE5}', '\u{10AE6}', I::Extend),
+    ('\u{10D24}', '\u{10D27}', I::Extend),
+    ('\u{10D69}', '\u{10D6D}', I::Extend),
+    ('\u{10EAB}', '\u{10EAC}', I::Extend),
+    ('\u{10EFA}', '\u{10EFF}', I::Extend),
+    ('\u{10F46}', '\u{10F50}', I::Extend),
+    ('\u{10F82}', '\u{10F85}', I::Extend),
+    ('\u{11001}', '\u{11001}', I::Extend),
+    ('\u{11038}', '\u{11046}', I::Extend),
+    ('\u{11070}', '\u{11070}', I::Extend),
+    ('\u{11073}', '\u{11074}', I::Extend),
+    ('\u{1107F}', '\u{11081}', I::Extend),
+    ('\u{110B3}', '\u{110B6}', I::Extend),
+    ('\u{110B9}', '\u{110BA}', I::Extend),
+    ('\u{110C2}', '\u{110C2}', I::Extend),
+    ('\u{11100}', '\u{11102}', I::Extend),
+    ('\u{11103}', '\u{11126}', I::Consonant),
+    ('\u{11127}', '\u{1112B}', I::Extend),
+    ('\u{1112D}', '\u{11132}', I::Extend),
+    ('\u{11133}', '\u{11133}', I::Linker),
+    ('\u{11134}', '\u{11134}', I::Extend),
+    ('\u{11144}', '\u{11144}', I::Consonant),
+    ('\u{11147}', '\u{11147}', I::Consonant),
+    ('\u{11173}', '\u{11173}', I::Extend),
+    ('\u{11180}', '\u{11181}', I::Extend),
+    ('\u{111B6}', '\u{111BE}', I::Extend),
+    ('\u{111C0}', '\u{111C0}', I::Extend),
+    ('\u{111C9}', '\u{111CC}', I::Extend),
+    ('\u{111CF}', '\u{111CF}', I::Extend),
+    ('\u{1122F}', '\u{11231}', I::Extend),
+    ('\u{11234}', '\u{11237}', I::Extend),
+    ('\u{1123E}', '\u{1123E}', I::Extend),
+    ('\u{11241}', '\u{11241}', I::Extend),
+    ('\u{112DF}', '\u{112DF}', I::Extend),
+    ('\u{112E3}', '\u{112EA}', I::Extend),
+    ('\u{11300}', '\u{11301}', I::Extend),
+    ('\u{1133B}', '\u{1133C}', I::Extend),
+    ('\u{1133E}', '\u{1133E}', I::Extend),
+    ('\u{11340}', '\u{11340}', I::Extend),
+    ('\u{1134D}', '\u{1134D}', I::Extend),
+    ('\u{11357}', '\u{11357}', I::Extend),
+    ('\u{11366}', '\u{1136C}', I::Extend),
+    ('\u{11370}', '\u{11374}', I::Extend),
+    ('\u{11380}', '\u{11389}', I::Consonant),
+    ('\u{1138B}', '\u{1138B}', I::Consonant),
+    ('\u{1138E}', '\u{1138E}', I::Consonant),
+    ('\u{11390}', '\u{113B5}', I::Consonant),
+    ('\u{113B8}', '\u{113B8}', I::Extend),
+    ('\u{113BB}', '\u{113C0}', I::Extend),
+    ('\u{113C2}', '\u{113C2}', I::Extend),
+    ('\u{113C5}', '\u{113C5}', I::Extend),
+    ('\u{113C7}', '\u{113C9}', I::Extend),
+    ('\u{113CE}', '\u{113CF}', I::Extend),
+    ('\u{113D0}', '\u{113D0}', I::Linker),
+    ('\u{113D2}', '\u{113D2}', I::Extend),
+    ('\u{113E1}', '\u{113E2}', I::Extend),
+    ('\u{11438}', '\u{1143F}', I::Extend),
+    ('\u{11442}', '\u{11444}', I::Extend),
+    ('\u{11446}', '\u{11446}', I::Extend),
+    ('\u{1145E}', '\u{1145E}', I::Extend),
+    ('\u{114B0}', '\u{114B0}', I::Extend),
+    ('\u{114B3}', '\u{114B8}', I::Extend),
+    ('\u{114BA}', '\u{114BA}', I::Extend),
+    ('\u{114BD}', '\u{114BD}', I::Extend),
+    ('\u{114BF}', '\u{114C0}', I::Extend),
+    ('\u{114C2}', '\u{114C3}', I::Extend),
+    ('\u{115AF}', '\u{115AF}', I::Extend),
+    ('\u{115B2}', '\u{115B5}', I::Extend),
+    ('\u{115BC}', '\u{115BD}', I::Extend),
+    ('\u{115BF}', '\u{115C0}', I::Extend),
+    ('\u{115DC}', '\u{115DD}', I::Extend),
+    ('\u{11633}', '\u{1163A}', I::Extend),
+    ('\u{1163D}', '\u{1163D}', I::Extend),
+    ('\u{1163F}', '\u{11640}', I::Extend),
+    ('\u{116AB}', '\u{116AB}', I::Extend),
+    ('\u{116AD}', '\u{116AD}', I::Extend),
+    ('\u{116B0}', '\u{116B7}', I::Extend),
+    ('\u{1171D}', '\u{1171D}', I::Extend),
+    ('\u{1171F}', '\u{1171F}', I::Extend),
+    ('\u{11722}', '\u{11725}', I::Extend),
+    ('\u{11727}', '\u{1172B}', I::Extend),
+    ('\u{1182F}', '\u{11837}', I::Extend),
+    ('\u{11839}', '\u{1183A}', I::Extend),
+    ('\u{11900}', '\u{11906}', I::Consonant),
+    ('\u{11909}', '\u{11909}', I::Consonant),
+    ('\u{1190C}', '\u{11913}', I::Consonant),
+    ('\u{11915}', '\u{11916}', I::Consonant),
+    ('\u{11918}', '\u{1192F}', I::Consonant),
+    ('\u{11930}', '\u{11930}', I::Extend),
+    ('\u{1193B}', '\u{1193D}', I::Extend),
+    ('\u{1193E}', '\u{1193E}', I::Linker),
+    ('\u{11943}', '\u{11943}', I::Extend),
+    ('\u{119D4}', '\u{119D7}', I::Extend),
+    ('\u{119DA}', '\u{119DB}', I::Extend),
+    ('\u{119E0}', '\u{119E0}', I::Extend),
+    ('\u{11A00}', '\u{11A00}', I::Consonant),
+    ('\u{11A01}', '\u{11A0A}', I::Extend),
+    ('\u{11A0B}', '\u{11A32}', I::Consonant),
+    ('\u{11A33}', '\u{11A38}', I::Extend),
+    ('\u{11A3B}', '\u{11A3E}', I::Extend),
+    ('\u{11A47}', '\u{11A47}', I::Linker),
+    ('\u{11A50}', '\u{11A50}', I::Consonant),
+    ('\u{11A51}', '\u{11A56}', I::Extend),
+    ('\u{11A59}', '\u{11A5B}', I::Extend),
+    ('\u{11A5C}', '\u{11A83}', I::Consonant),
+    ('\u{11A8A}', '\u{11A96}', I::Extend),
+    ('\u{11A98}', '\u{11A98}', I::Extend),
+    ('\u{11A99}', '\u{11A99}', I::Linker),
+    ('\u{11B60}', '\u{11B60}', I::Extend),
+    ('\u{11B62}', '\u{11B64}', I::Extend),
+    ('\u{11B66}', '\u{11B66}', I::Extend),
+    ('\u{11C30}', '\u{11C36}', I::Extend),
+    ('\u{11C38}', '\u{11C3D}', I::Extend),
+    ('\u{11C3F}', '\u{11C3F}', I::Extend),
+    ('\u{11C92}', '\u{11CA7}', I::Extend),
+    ('\u{11CAA}', '\u{11CB0}', I::Extend),
+    ('\u{11CB2}', '\u{11CB3}', I::Extend),
+    ('\u{11CB5}', '\u{11CB6}', I::Extend),
+    ('\u{11D31}', '\u{11D36}', I::Extend),
+    ('\u{11D3A}', '\u{11D3A}', I::Extend),
+    ('\u{11D3C}', '\u{11D3D}', I::Extend),
+    ('\u{11D3F}', '\u{11D45}', I::Extend),
+    ('\u{11D47}', '\u{11D47}', I::Extend),
+    ('\u{11D90}', '\u{11D91}', I::Extend),
+    ('\u{11D95}', '\u{11D95}', I::Extend),
+    ('\u{11D97}', '\u{11D97}', I::Extend),
+    ('\u{11EF3}', '\u{11EF4}', I::Extend),
+    ('\u{11F00}', '\u{11F01}', I::Extend),
+    ('\u{11F04}', '\u{11F10}', I::Consonant),
+    ('\u{11F12}', '\u{11F33}', I::Consonant),
+    ('\u{11F36}', '\u{11F3A}', I::Extend),
+    ('\u{11F40}', '\u{11F41}', I::Extend),
+    ('\u{11F42}', '\u{11F42}', I::Linker),
+    ('\u{11F5A}', '\u{11F5A}', I::Extend),
+    ('\u{13440}', '\u{13440}', I::Extend),
+    ('\u{13447}', '\u{13455}', I::Extend),
+    ('\u{1611E}', '\u{16129}', I::Extend),
+    ('\u{1612D}', '\u{1612F}', I::Extend),
+    ('\u{16AF0}', '\u{16AF4}', I::Extend),
+    ('\u{16B30}', '\u{16B36}', I::Extend),
+    ('\u{16F4F}', '\u{16F4F}', I::Extend),
+    ('\u{16F8F}', '\u{16F92}', I::Extend),
+    ('\u{16FE4}', '\u{16FE4}', I::Extend),
+    ('\u{16FF0}', '\u{16FF1}', I::Extend),
+    ('\u{1BC9D}', '\u{1BC9E}', I::Extend),
+    ('\u{1CF00}', '\u{1CF2D}', I::Extend),
+    ('\u{1CF30}', '\u{1CF46}', I::Extend),
+    ('\u{1D165}', '\u{1D169}', I::Extend),
+    ('\u{1D16D}', '\u{1D172}', I::Extend),
+    ('\u{1D17B}', '\u{1D182}', I::Extend),
+    ('\u{1D185}', '\u{1D18B}', I::Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', I::Extend),
+    ('\u{1D242}', '\u{1D244}', I::Extend),
+    ('\u{1DA00}', '\u{1DA36}', I::Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', I::Extend),
+    ('\u{1DA75}', '\u{1DA75}', I::Extend),
+    ('\u{1DA84}', '\u{1DA84}', I::Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', I::Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', I::Extend),
+    ('\u{1E000}', '\u{1E006}', I::Extend),
+    ('\u{1E008}', '\u{1E018}', I::Extend),
+    ('\u{1E01B}', '\u{1E021}', I::Extend),
+    ('\u{1E023}', '\u{1E024}', I::Extend),
+    ('\u{1E026}', '\u{1E02A}', I::Extend),
+    ('\u{1E08F}', '\u{1E08F}', I::Extend),
+    ('\u{1E130}', '\u{1E136}', I::Extend),
+    ('\u{1E2AE}', '\u{1E2AE}', I::Extend),
+    ('\u{1E2EC}', '\u{1E2EF}', I::Extend),
+    ('\u{1E4EC}', '\u{1E4EF}', I::Extend),
+    ('\u{1E5EE}', '\u{1E5EF}', I::Extend),
+    ('\u{1E6E3}', '\u{1E6E3}', I::Extend),
+    ('\u{1E6E6}', '\u{1E6E6}', I::Extend),
+    ('\u{1E6EE}', '\u{1E6EF}', I::Extend),
+    ('\u{1E6F5}', '\u{1E6F5}', I::Extend),
+    ('\u{1E8D0}', '\u{1E8D6}', I::Extend),
+    ('\u{1E944}', '\u{1E94A}', I::Extend),
+    ('\u{1F3FB}', '\u{1F3FF}', I::Extend),
+    ('\u{E0020}', '\u{E007F}', I::Extend),
+    ('\u{E0100}', '\u{E01EF}', I::Extend),
+];
+
+/// `Word_Break` values other than `Other`.
+pub(super) const WORD_BREAK: &[(char, char, W)] = &[
+    ('\u{A}', '\u{A}', W::Lf),
+    ('\u{B}', '\u{C}', W::Newline),
+    ('\u{D}', '\u{D}', W::Cr),
+    ('\u{20}', '\u{20}', W::WSegSpace),
+    ('\u{22}', '\u{22}', W::DoubleQuote),
+    ('\u{27}', '\u{27}', W::SingleQuote),
+    ('\u{2C}', '\u{2C}', W::MidNum),
+    ('\u{2E}', '\u{2E}', W::MidNumLet),
+    ('\u{30}', '\u{39}', W::Numeric),
+    ('\u{3A}', '\u{3A}', W::MidLetter),
+    ('\u{3B}', '\u{3B}', W::MidNum),
+    ('\u{41}', '\u{5A}', W::ALetter),
+    ('\u{5F}', '\u{5F}', W::ExtendNumLet),
+    ('\u{61}', '\u{7A}', W::ALetter),
+    ('\u{85}', '\u{85}', W::Newline),
+    ('\u{AA}', '\u{AA}', W::ALetter),
+    ('\u{AD}', '\u{AD}', W::Format),
+    ('\u{B5}', '\u{B5}', W::ALetter),
+    ('\u{B7}', '\u{B7}', W::MidLetter),
+    ('\u{B8}', '\u{B8}', W::ALetter),
+    ('\u{BA}', '\u{BA}', W::ALetter),
+    ('\u{C0}', '\u{D6}', W::ALetter),
+    ('\u{D8}', '\u{F6}', W::ALetter),
+    ('\u{F8}', '\u{2D7}', W::ALetter),
+    ('\u{2DE}', '\u{2FF}', W::ALetter),
+    ('\u{300}', '\u{36F}', W::Extend),
+    ('\u{370}', '\u{374}', W::ALetter),
+    ('\u{376}', '\u{377}', W::ALetter),
+    ('\u{37A}', '\u{37D}', W::ALetter),
+    ('\u{37E}', '\u{37E}', W::MidNum),
+    ('\u{37F}', '\u{37F}', W::ALetter),
+    ('\u{386}', '\u{386}', W::ALetter),
+    ('\u{387}', '\u{387}', W::MidLetter),
+    ('\u{388}', '\u{38A}', W::ALetter),
+    ('\u{38C}', '\u{38C}', W::ALetter),
+    ('\u{38E}', '\u{3A1}', W::ALetter),
+    ('\u{3A3}', '\u{3F5}', W::ALetter),
+    ('\u{3F7}', '\u{481}', W::ALetter),
+    ('\u{483}', '\u{489}', W::Extend),
+    ('\u{48A}', '\u{52F}', W::ALetter),
+    ('\u{531}', '\u{556}', W::ALetter),
+    ('\u{559}', '\u{55C}', W::ALetter),
+    ('\u{55E}', '\u{55E}', W::ALetter),
+    ('\u{55F}', '\u{55F}', W::MidLetter),
+    ('\u{560}', '\u{588}', W::ALetter),
+    ('\u{589}', '\u{589}', W::MidNum),
+    ('\u{58A}', '\u{58A}', W::ALetter),
+    ('\u{591}', '\u{5BD}', W::Extend),
+    ('\u{5BF}', '\u{5BF}', W::Extend),
+    ('\u{5C1}', '\u{5C2}', W::Extend),
+    ('\u{5C4}', '\u{5C5}', W::Extend),
+    ('\u{5C7}', '\u{5C7}', W::Extend),
+    ('\u{5D0}', '\u{5EA}', W::HebrewLetter),
+    ('\u{5EF}', '\u{5F2}', W::HebrewLetter),
+    ('\u{5F3}', '\u{5F3}', W::ALetter),
+    ('\u{5F4}', '\u{5F4}', W::MidLetter),
+    ('\u{600}', '\u{605}', W::Numeric),
+    ('\u{60C}', '\u{60D}', W::MidNum),
+    ('\u{610}', '\u{61A}', W::Extend),
+    ('\u{61C}', '\u{61C}', W::Format),
+    ('\u{620}', '\u{64A}', W::ALetter),
+    ('\u{64B}', '\u{65F}', W::Extend),
+    ('\u{660}', '\u{669}', W::Numeric),
+    ('\u{66B}', '\u{66B}', W::Numeric),
+    ('\u{66C}', '\u{66C}', W::MidNum),
+    ('\u{66E}', '\u{66F}', W::ALetter),
+    ('\u{670}', '\u{670}', W::Extend),
+    ('\u{671}', '\u{6D3}', W::ALetter),
+    ('\u{6D5}', '\u{6D5}', W::ALetter),
+    ('\u{6D6}', '\u{6DC}', W::Extend),
+    ('\u{6DD}', '\u{6DD}', W::Numeric),
+    ('\u{6DF}', '\u{6E4}', W::Extend),
+    ('\u{6E5}', '\u{6E6}', W::ALetter),
+    ('\u{6E7}', '\u{6E8}', W::Extend),
+    ('\u{6EA}', '\u{6ED}', W::Extend),
+    ('\u{6EE}', '\u{6EF}', W::ALetter),
+    ('\u{6F0}', '\u{6F9}', W::Numeric),
+    ('\u{6FA}', '\u{6FC}', W::ALetter),
+    ('\u{6FF}', '\u{6FF}', W::ALetter),
+    ('\u{70F}', '\u{710}', W::ALetter),
+    ('\u{711}', '\u{711}', W::Extend),
+    ('\u{712}', '\u{72F}', W::ALetter),
+    ('\u{730}', '\u{74A}', W::Extend),
+    ('\u{74D}', '\u{7A5}', W::ALetter),
+    ('\u{7A6}', '\u{7B0}', W::Extend),
+    ('\u{7B1}', '\u{7B1}', W::ALetter),
+    ('\u{7C0}', '\u{7C9}', W::Numeric),
+    ('\u{7CA}', '\u{7EA}', W::ALetter),
+    ('\u{7EB}', '\u{7F3}', W::Extend),
+    ('\u{7F4}', '\u{7F5}', W::ALetter),
+    ('\u{7F8}', '\u{7F8}', W::MidNum),
+    ('\u{7FA}', '\u{7FA}', W::ALetter),
+    ('\u{7FD}', '\u{7FD}', W::Extend),
+    ('\u{800}', '\u{815}', W::ALetter),
+    ('\u{816}', '\u{819}', W::Extend),
+    ('\u{81A}', '\u{81A}', W::ALetter),
+    ('\u{81B}', '\u{823}', W::Extend),
+    ('\u{824}', '\u{824}', W::ALetter),
+    ('\u{825}', '\u{827}', W::Extend),
+    ('\u{828}', '\u{828}', W::ALetter),
+    ('\u{829}', '\u{82D}', W::Extend),
+    ('\u{840}', '\u{858}', W::ALetter),
+    ('\u{859}', '\u{85B}', W::Extend),
+    ('\u{860}', '\u{86A}', W::ALetter),
+    ('\u{870}', '\u{887}', W::ALetter),
+    ('\u{889}', '\u{88F}', W::ALetter),
+    ('\u{890}', '\u{891}', W::Numeric),
+    ('\u{897}', '\u{89F}', W::Extend),
+    ('\u{8A0}', '\u{8C9}', W::ALetter),
+    ('\u{8CA}', '\u{8E1}', W::Extend),
+    ('\u{8E2}', '\u{8E2}', W::Numeric),
+    ('\u{8E3}', '\u{903}', W::Extend),
+    ('\u{904}', '\u{939}', W::ALetter),
+    ('\u{93A}', '\u{93C}', W::Extend),
+    ('\u{93D}', '\u{93D}', W::ALetter),
+    ('\u{93E}', '\u{94F}', W::Extend),
+    ('\u{950}', '\u{950}', W::ALetter),
+    ('\u{951}', '\u{957}', W::Extend),
+    ('\u{958}', '\u{961}', W::ALetter),
+    ('\u{962}', '\u{963}', W::Extend),
+    ('\u{966}', '\u{96F}', W::Numeric),
+    ('\u{971}', '\u{980}', W::ALetter),
+    ('\u{981}', '\u{983}', W::Extend),
+    ('\u{985}', '\u{98C}', W::ALetter),
+    ('\u{98F}', '\u{990}', W::ALetter),
+    ('\u{993}', '\u{9A8}', W::ALetter),
+    ('\u{9AA}', '\u{9B0}', W::ALetter),
+    ('\u{9B2}', '\u{9B2}', W::ALetter),
+    ('\u{9B6}', '\u{9B9}', W::ALetter),
+    ('\u{9BC}', '\u{9BC}', W::Extend),
+    ('\u{9BD}', '\u{9BD}', W::ALetter),
+    ('\u{9BE}', '\u{9C4}', W::Extend),
+    ('\u{9C7}', '\u{9C8}', W::Extend),
+    ('\u{9CB}', '\u{9CD}', W::Extend),
+    ('\u{9CE}', '\u{9CE}', W::ALetter),
+    ('\u{9D7}', '\u{9D7}', W::Extend),
+    ('\u{9DC}', '\u{9DD}', W::ALetter),
+    ('\u{9DF}', '\u{9E1}', W::ALetter),
+    ('\u{9E2}', '\u{9E3}', W::Extend),
+    ('\u{9E6}', '\u{9EF}', W::Numeric),
+    ('\u{9F0}', '\u{9F1}', W::ALetter),
+    ('\u{9FC}', '\u{9FC}', W::ALetter),
+    ('\u{9FE}', '\u{9FE}', W::Extend),
+    ('\u{A01}', '\u{A03}', W::Extend),
+    ('\u{A05}', '\u{A0A}', W::ALetter),
+    ('\u{A0F}', '\u{A10}', W::ALetter),
+    ('\u{A13}', '\u{A28}', W::ALetter),
+    ('\u{A2A}', '\u{A30}', W::ALetter),
+    ('\u{A32}', '\u{A33}', W::ALetter),
+    ('\u{A35}', '\u{A36}', W::ALetter),
+    ('\u{A38}', '\u{A39}', W::ALetter),
+    ('\u{A3C}', '\u{A3C}', W::Extend),
+    ('\u{A3E}', '\u{A42}', W::Extend),
+    ('\u{A47}', '\u{A48}', W::Extend),
+    ('\u{A4B}', '\u{A4D}', W::Extend),
+    ('\u{A51}', '\u{A51}', W::Extend),
+    ('\u{A59}', '\u{A5C}', W::ALetter),
+    ('\u{A5E}', '\u{A5E}', W::ALetter),
+    ('\u{A66}', '\u{A6F}', W::Numeric),
+    ('\u{A70}', '\u{A71}', W::Extend),
+    ('\u{A72}', '\u{A74}', W::ALetter),
+    ('\u{A75}', '\u{A75}', W::Extend),
+    ('\u{A81}', '\u{A83}', W::Extend),
+    ('\u{A85}', '\u{A8D}', W::ALetter),
+    ('\u{A8F}', '\u{A91}', W::ALetter),
+    ('\u{A93}', '\u{AA8}', W::ALetter),
+    ('\u{AAA}', '\u{AB0}', W::ALetter),
+    ('\u{AB2}', '\u{AB3}', W::ALetter),
+    ('\u{AB5}', '\u{AB9}', W::ALetter),
+    ('\u{ABC}', '\u{ABC}', W::Extend),
+    ('\u{ABD}', '\u{ABD}', W::ALetter),
+    ('\u{ABE}', '\u{AC5}', W::Extend),
+    ('\u{AC7}', '\u{AC9}', W::Extend),
+    ('\u{ACB}', '\u{ACD}', W::Extend),
+    ('\u{AD0}', '\u{AD0}', W::ALetter),
+    ('\u{AE0}', '\u{AE1}', W::ALetter),
+    ('\u{AE2}', '\u{AE3}', W::Extend),
+    ('\u{AE6}', '\u{AEF}', W::Numeric),
+    ('\u{AF9}', '\u{AF9}', W::ALetter),
+    ('\u{AFA}', '\u{AFF}', W::Extend),
+    ('\u{B01}', '\u{B03}', W::Extend),
+    ('\u{B05}', '\u{B0C}', W::ALetter),
+    ('\u{B0F}', '\u{B10}', W::ALetter),
+    ('\u{B13}', '\u{B28}', W::ALetter),
+    ('\u{B2A}', '\u{B30}', W::ALetter),
+    ('\u{B32}', '\u{B33}', W::ALetter),
+    ('\u{B35}', '\u{B39}', W::ALetter),
+    ('\u{B3C}', '\u{B3C}', W::Extend),
+    ('\u{B3D}', '\u{B3D}', W::ALetter),
+    ('\u{B3E}', '\u{B44}', W::Extend),
+    ('\u{B47}', '\u{B48}', W::Extend),
+    ('\u{B4B}', '\u{B4D}', W::Extend),
+    ('\u{B55}', '\u{B57}', W::Extend),
+    ('\u{B5C}', '\u{B5D}', W::ALetter),
+    ('\u{B5F}', '\u{B61}', W::ALetter),
+    ('\u{B62}', '\u{B63}', W::Extend),
+    ('\u{B66}', '\u{B6F}', W::Numeric),
+    ('\u{B71}', '\u{B71}', W::ALetter),
+    ('\u{B82}', '\u{B82}', W::Extend),
+    ('\u{B83}', '\u{B83}', W::ALetter),
+    ('\u{B85}', '\u{B8A}', W::ALetter),
+    ('\u{B8E}', '\u{B90}', W::ALetter),
+    ('\u{B92}', '\u{B95}', W::ALetter),
+    ('\u{B99}', '\u{B9A}', W::ALetter),
+    ('\u{B9C}', '\u{B9C}', W::ALetter),
+    ('\u{B9E}', '\u{B9F}', W::ALetter),
+    ('\u{BA3}', '\u{BA4}', W::ALetter),
+    ('\u{BA8}', '\u{BAA}', W::ALetter),
+    ('\u{BAE}', '\u{BB9}', W::ALetter),
+    ('\u{BBE}', '\u{BC2}', W::Extend),
+    ('\u{BC6}', '\u{BC8}', W::Extend),
+    ('\u{BCA}', '\u{BCD}', W::Extend),
+    ('\u{BD0}', '\u{BD0}', W::ALetter),
+    ('\u{BD7}', '\u{BD7}', W::Extend),
+    ('\u{BE6}', '\u{BEF}', W::Numeric),
+    ('\u{C00}', '\u{C04}', W::Extend),
+    ('\u{C05}', '\u{C0C}', W::ALetter),
+    ('\u{C0E}', '\u{C10}', W::ALetter),
+    ('\u{C12}', '\u{C28}', W::ALetter),
+    ('\u{C2A}', '\u{C39}', W::ALetter),
+    ('\u{C3C}', '\u{C3C}', W::Extend),
+    ('\u{C3D}', '\u{C3D}', W::ALetter),
+    ('\u{C3E}', '\u{C44}', W::Extend),
+    ('\u{C46}', '\u{C48}', W::Extend),
+    ('\u{C4A}', '\u{C4D}', W::Extend),
+    ('\u{C55}', '\u{C56}', W::Extend),
+    ('\u{C58}', '\u{C5A}', W::ALetter),
+    ('\u{C5C}', '\u{C5D}', W::ALetter),
+    ('\u{C60}', '\u{C61}', W::ALetter),
+    ('\u{C62}', '\u{C63}', W::Extend),
+    ('\u{C66}', '\u{C6F}', W::Numeric),
+    ('\u{C80}', '\u{C80}', W::ALetter),
+    ('\u{C81}', '\u{C83}', W::Extend),
+    ('\u{C85}', '\u{C8C}', W::ALetter),
+    ('\u{C8E}', '\u{C90}', W::ALetter),
+    ('\u{C92}', '\u{CA8}', W::ALetter),
+    ('\u{CAA}', '\u{CB3}', W::ALetter),
+    ('\u{CB5}', '\u{CB9}', W::ALetter),
+    ('\u{CBC}', '\u{CBC}', W::Extend),
+    ('\u{CBD}', '\u{CBD}', W::ALetter),
+    ('\u{CBE}', '\u{CC4}', W::Extend),
+    ('\u{CC6}', '\u{CC8}', W::Extend),
+    ('\u{CCA}', '\u{CCD}', W::Extend),
+    ('\u{CD5}', '\u{CD6}', W::Extend),
+    ('\u{CDC}', '\u{CDE}', W::ALetter),
+    ('\u{CE0}', '\u{CE1}', W::ALetter),
+    ('\u{CE2}', '\u{CE3}', W::Extend),
+    ('\u{CE6}', '\u{CEF}', W::Numeric),
+    ('\u{CF1}', '\u{CF2}', W::ALetter),
+    ('\u{CF3}', '\u{CF3}', W::Extend),
+    ('\u{D00}', '\u{D03}', W::Extend),
+    ('\u{D04}', '\u{D0C}', W::ALetter),
+    ('\u{D0E}', '\u{D10}', W::ALetter),
+    ('\u{D12}', '\u{D3A}', W::ALetter),
+    ('\u{D3B}', '\u{D3C}', W::Extend),
+    ('\u{D3D}', '\u{D3D}', W::ALetter),
+    ('\u{D3E}', '\u{D44}', W::Extend),
+    ('\u{D46}', '\u{D48}', W::Extend),
+    ('\u{D4A}', '\u{D4D}', W::Extend),
+    ('\u{D4E}', '\u{D4E}', W::ALetter),
+    ('\u{D54}', '\u{D56}', W::ALetter),
+    ('\u{D57}', '\u{D57}', W::Extend),
+    ('\u{D5F}', '\u{D61}', W::ALetter),
+    ('\u{D62}', '\u{D63}', W::Extend),
+    ('\u{D66}', '\u{D6F}', W::Numeric),
+    ('\u{D7A}', '\u{D7F}', W::ALetter),
+    ('\u{D81}', '\u{D83}', W::Extend),
+    ('\u{D85}', '\u{D96}', W::ALetter),
+    ('\u{D9A}', '\u{DB1}', W::ALetter),
+    ('\u{DB3}', '\u{DBB}', W::ALetter),
+    ('\u{DBD}', '\u{DBD}', W::ALetter),
+    ('\u{DC0}', '\u{DC6}', W::ALetter),
+    ('\u{DCA}', '\u{DCA}', W::Extend),
+    ('\u{DCF}', '\u{DD4}', W::Extend),
+    ('\u{DD6}', '\u{DD6}', W::Extend),
+    ('\u{DD8}', '\u{DDF}', W::Extend),
+    ('\u{DE6}', '\u{DEF}', W::Numeric),
+    ('\u{DF2}', '\u{DF3}', W::Extend),
+    ('\u{E31}', '\u{E31}', W::Extend),
+    ('\u{E34}', '\u{E3A}', W::Extend),
+    ('\u{E47}', '\u{E4E}', W::Extend),
+    ('\u{E50}', '\u{E59}', W::Numeric),
+    ('\u{EB1}', '\u{EB1}', W::Extend),
+    ('\u{EB4}', '\u{EBC}', W::Extend),
+    ('\u{EC8}', '\u{ECE}', W::Extend),
+    ('\u{ED0}', '\u{ED9}', W::Numeric),
+    ('\u{F00}', '\u{F00}', W::ALetter),
+    ('\u{F18}', '\u{F19}', W::Extend),
+    ('\u{F20}', '\u{F29}', W::Numeric),
+    ('\u{F35}', '\u{F35}', W::Extend),
+    ('\u{F37}', '\u{F37}', W::Extend),
+    ('\u{F39}', '\u{F39}', W::Extend),
+    ('\u{F3E}', '\u{F3F}', W::Extend),
+    ('\u{F40}', '\u{F47}', W::ALetter),
+    ('\u{F49}', '\u{F6C}', W::ALetter),
+    ('\u{F71}', '\u{F84}', W::Extend),
+    ('\u{F86}', '\u{F87}', W::Extend),
+    ('\u{F88}', '\u{F8C}', W::ALetter),
+    ('\u{F8D}', '\u{F97}', W::Extend),
+    ('\u{F99}', '\u{FBC}', W::Extend),
+    ('\u{FC6}', '\u{FC6}', W::Extend),
+    ('\u{102B}', '\u{103E}', W::Extend),
+    ('\u{1040}', '\u{1049}', W::Numeric),
+    ('\u{1056}', '\u{1059}', W::Extend),
+    ('\u{105E}', '\u{1060}', W::Extend),
+    ('\u{1062}', '\u{1064}', W::Extend),
+    ('\u{1067}', '\u{106D}', W::Extend),
+    ('\u{1071}', '\u{1074}', W::Extend),
+    ('\u{1082}', '\u{108D}', W::Extend),
+    ('\u{108F}', '\u{108F}', W::Extend),
+    ('\u{1090}', '\u{1099}', W::Numeric),
+    ('\u{109A}', '\u{109D}', W::Extend),
+    ('\u{10A0}', '\u{10C5}', W::ALetter),
+    ('\u{10C7}', '\u{10C7}', W::ALetter),
+    ('\u{10CD}', '\u{10CD}', W::ALetter),
+    ('\u{10D0}', '\u{10FA}', W::ALetter),
+    ('\u{10FC}', '\u{1248}', W::ALetter),
+    ('\u{124A}', '\u{124D}', W::ALetter),
+    ('\u{1250}', '\u{1256}', W::ALetter),
+    ('\u{1258}', '\u{1258}', W::ALetter),
+    ('\u{125A}', '\u{125D}', W::ALetter),
+    ('\u{1260}', '\u{1288}', W::ALetter),
+    ('\u{128A}', '\u{128D}', W::ALetter),
+    ('\u{1290}', '\u{12B0}', W::ALetter),
+    ('\u{12B2}', '\u{12B5}', W::ALetter),
+    ('\u{12B8}', '\u{12BE}', W::ALetter),
+    ('\u{12C0}', '\u{12C0}', W::ALetter),
+    ('\u{12C2}', '\u{12C5}', W::ALetter),
+    ('\u{12C8}', '\u{12D6}', W::ALetter),
+    ('\u{12D8}', '\u{1310}', W::ALetter),
+    ('\u{1312}', '\u{1315}', W::ALetter),
+    ('\u{1318}', '\u{135A}', W::ALetter),
+    ('\u{135D}', '\u{135F}', W::Extend),
+    ('\u{1380}', '\u{138F}', W::ALetter),
+    ('\u{13A0}', '\u{13F5}', W::ALetter),
+    ('\u{13F8}', '\u{13FD}', W::ALetter),
+    ('\u{1401}', '\u{166C}', W::ALetter),
+    ('\u{166F}', '\u{167F}', W::ALetter),
+    ('\u{1680}', '\u{1680}', W::WSegSpace),
+    ('\u{1681}', '\u{169A}', W::ALetter),
+    ('\u{16A0}', '\u{16EA}', W::ALetter),
+    ('\u{16EE}', '\u{16F8}', W::ALetter),
+    ('\u{1700}', '\u{1711}', W::ALetter),
+    ('\u{1712}', '\u{1715}', W::Extend),
+    ('\u{171F}', '\u{1731}', W::ALetter),
+    ('\u{1732}', '\u{1734}', W::Extend),
+    ('\u{1740}', '\u{1751}', W::ALetter),
+    ('\u{1752}', '\u{1753}', W::Extend),
+    ('\u{1760}', '\u{176C}', W::ALetter),
+    ('\u{176E}', '\u{1770}', W::ALetter),
+    ('\u{1772}', '\u{1773}', W::Extend),
+    ('\u{17B4}', '\u{17D3}', W::Extend),
+    ('\u{17DD}', '\u{17DD}', W::Extend),
+    ('\u{17E0}', '\u{17E9}', W::Numeric),
+    ('\u{180B}', '\u{180D}', W::Extend),
+    ('\u{180E}', '\u{180E}', W::Format),
+    ('\u{180F}', '\u{180F}', W::Extend),
+    ('\u{1810}', '\u{1819}', W::Numeric),
+    ('\u{1820}', '\u{1878}', W::ALetter),
+    ('\u{1880}', '\u{1884}', W::ALetter),
+    ('\u{1885}', '\u{1886}', W::Extend),
+    ('\u{1887}', '\u{18A8}', W::ALetter),
+    ('\u{18A9}', '\u{18A9}', W::Extend),
+    ('\u{18AA}', '\u{18AA}', W::ALetter),
+    ('\u{18B0}', '\u{18F5}', W::ALetter),
+    ('\u{1900}', '\u{191E}', W::ALetter),
+    ('\u{1920}', '\u{192B}', W::Extend),
+    ('\u{1930}', '\u{193B}', W::Extend),
+    ('\u{1946}', '\u{194F}', W::Numeric),
+    ('\u{19D0}', '\u{19DA}', W::Numeric),
+    ('\u{1A00}', '\u{1A16}', W::ALetter),
+    ('\u{1A17}', '\u{1A1B}', W::Extend),
+    ('\u{1A55}', '\u{1A5E}', W::Extend),
+    ('\u{1A60}', '\u{1A7C}', W::Extend),
+    ('\u{1A7F}', '\u{1A7F}', W::Extend),
+    ('\u{1A80}', '\u{1A89}', W::Numeric),
+    ('\u{1A90}', '\u{1A99}', W::Numeric),
+    ('\u{1AB0}', '\u{1ADD}', W::Extend),
+    ('\u{1AE0}', '\u{1AEB}', W::Extend),
+    ('\u{1B00}', '\u{1B04}', W::Extend),
+    ('\u{1B05}', '\u{1B33}', W::ALetter),
+    ('\u{1B34}', '\u{1B44}', W::Extend),
+    ('\u{1B45}', '\u{1B4C}', W::ALetter),
+    ('\u{1B50}', '\u{1B59}', W::Numeric),
+    ('\u{1B6B}', '\u{1B73}', W::Extend),
+    ('\u{1B80}', '\u{1B82}', W::Extend),
+    ('\u{1B83}', '\u{1BA0}', W::ALetter),
+    ('\u{1BA1}', '\u{1BAD}', W::Extend),
+    ('\u{1BAE}', '\u{1BAF}', W::ALetter),
+    ('\u{1BB0}', '\u{1BB9}', W::Numeric),
+    ('\u{1BBA}', '\u{1BE5}', W::ALetter),
+    ('\u{1BE6}', '\u{1BF3}', W::Extend),
+    ('\u{1C00}', '\u{1C23}', W::ALetter),
+    ('\u{1C24}', '\u{1C37}', W::Extend),
+    ('\u{1C40}', '\u{1C49}', W::Numeric),
+    ('\u{1C4D}', '\u{1C4F}', W::ALetter),
+    ('\u{1C50}', '\u{1C59}', W::Numeric),
+    ('\u{1C5A}', '\u{1C7D}', W::ALetter),
+    ('\u{1C80}', '\u{1C8A}', W::ALetter),
+    ('\u{1C90}', '\u{1CBA}', W::ALetter),
+    ('\u{1CBD}', '\u{1CBF}', W::ALetter),
+    ('\u{1CD0}', '\u{1CD2}', W::Extend),
+    ('\u{1CD4}', '\u{1CE8}', W::Extend),
+    ('\u{1CE9}', '\u{1CEC}', W::ALetter),
+    ('\u{1CED}', '\u{1CED}', W::Extend),
+    ('\u{1CEE}', '\u{1CF3}', W::ALetter),
+    ('\u{1CF4}', '\u{1CF4}', W::Extend),
+    ('\u{1CF5}', '\u{1CF6}', W::ALetter),
+    ('\u{1CF7}', '\u{1CF9}', W::Extend),
+    ('\u{1CFA}', '\u{1CFA}', W::ALetter),
+    ('\u{1D00}', '\u{1DBF}', W::ALetter),
+    ('\u{1DC0}', '\u{1DFF}', W::Extend),
+    ('\u{1E00}', '\u{1F15}', W::ALetter),
+    ('\u{1F18}', '\u{1F1D}', W::ALetter),
+    ('\u{1F20}', '\u{1F45}', W::ALetter),
+    ('\u{1F48}', '\u{1F4D}', W::ALetter),
+    ('\u{1F50}', '\u{1F57}', W::ALetter),
+    ('\u{1F59}', '\u{1F59}', W::ALetter),
+    ('\u{1F5B}', '\u{1F5B}', W::ALetter),
+    ('\u{1F5D}', '\u{1F5D}', W::ALetter),
+    ('\u{1F5F}', '\u{1F7D}', W::ALetter),
+    ('\u{1F80}', '\u{1FB4}', W::ALetter),
+    ('\u{1FB6}', '\u{1FBC}', W::ALetter),
+    ('\u{1FBE}', '\u{1FBE}', W::ALetter),
+    ('\u{1FC2}', '\u{1FC4}', W::ALetter),
+    ('\u{1FC6}', '\u{1FCC}', W::ALetter),
+    ('\u{1FD0}', '\u{1FD3}', W::ALetter),
+    ('\u{1FD6}', '\u{1FDB}', W::ALetter),
+    ('\u{1FE0}', '\u{1FEC}', W::ALetter),
+    ('\u{1FF2}', '\u{1FF4}', W::ALetter),
+    ('\u{1FF6}', '\u{1FFC}', W::ALetter),
+    ('\u{2000}', '\u{2006}', W::WSegSpace),
+    ('\u{2008}', '\u{200A}', W::WSegSpace),
+    ('\u{200C}', '\u{200C}', W::Extend),
+    ('\u{200D}', '\u{200D}', W::Zwj),
+    ('\u{200E}', '\u{200F}', W::Format),
+    ('\u{2018}', '\u{2019}', W::MidNumLet),
+    ('\u{2024}', '\u{2024}', W::MidNumLet),
+    ('\u{2027}', '\u{2027}', W::MidLetter),
+    ('\u{2028}', '\u{2029}', W::Newline),
+    ('\u{202A}', '\u{202E}', W::Format),
+    ('\u{202F}', '\u{202F}', W::ExtendNumLet),
+    ('\u{203F}', '\u{2040}', W::ExtendNumLet),
+    ('\u{2044}', '\u{2044}', W::MidNum),
+    ('\u{2054}', '\u{2054}', W::ExtendNumLet),
+    ('\u{205F}', '\u{205F}', W::WSegSpace),
+    ('\u{2060}', '\u{2064}', W::Format),
+    ('\u{2066}', '\u{206F}', W::Format),
+    ('\u{2071}', '\u{2071}', W::ALetter),
+    ('\u{207F}', '\u{207F}', W::ALetter),
+    ('\u{2090}', '\u{209C}', W::ALetter),
+    ('\u{20D0}', '\u{20F0}', W::Extend),
+    ('\u{2102}', '\u{2102}', W::ALetter),
+    ('\u{2107}', '\u{2107}', W::ALetter),
+    ('\u{210A}', '\u{2113}', W::ALetter),
+    ('\u{2115}', '\u{2115}', W::ALetter),
+    ('\u{2119}', '\u{211D}', W::ALetter),
+    ('\u{2124}', '\u{2124}', W::ALetter),
+    ('\u{2126}', '\u{2126}', W::ALetter),
+    ('\u{2128}', '\u{2128}', W::ALetter),
+    ('\u{212A}', '\u{212D}', W::ALetter),
+    ('\u{212F}', '\u{2139}', W::ALetter),
+    ('\u{213C}', '\u{213F}', W::ALetter),
+    ('\u{2145}', '\u{2149}', W::ALetter),
+    ('\u{214E}', '\u{214E}', W::ALetter),
+    ('\u{2160}', '\u{2188}', W::ALetter),
+    ('\u{24B6}', '\u{24E9}', W::ALetter),
+    ('\u{2C00}', '\u{2CE4}', W::ALetter),
+    ('\u{2CEB}', '\u{2CEE}', W::ALetter),
+    ('\u{2CEF}', '\u{2CF1}', W::Extend),
+    ('\u{2CF2}', '\u{2CF3}', W::ALetter),
+    ('\u{2D00}', '\u{2D25}', W::ALetter),
+    ('\u{2D27}', '\u{2D27}', W::ALetter),
+    ('\u{2D2D}', '\u{2D2D}', W::ALetter),
+    ('\u{2D30}', '\u{2D67}', W::ALetter),
+    ('\u{2D6F}', '\u{2D6F}', W::ALetter),
+    ('\u{2D7F}', '\u{2D7F}', W::Extend),
+    ('\u{2D80}', '\u{2D96}', W::ALetter),
+    ('\u{2DA0}', '\u{2DA6}', W::ALetter),
+    ('\u{2DA8}', '\u{2DAE}', W::ALetter),
+    ('\u{2DB0}', '\u{2DB6}', W::ALetter),
+    ('\u{2DB8}', '\u{2DBE}', W::ALetter),
+    ('\u{2DC0}', '\u{2DC6}', W::ALetter),
+    ('\u{2DC8}', '\u{2DCE}', W::ALetter),
+    ('\u{2DD0}', '\u{2DD6}', W::ALetter),
+    ('\u{2DD8}', '\u{2DDE}', W::ALetter),
+    ('\u{2DE0}', '\u{2DFF}', W::Extend),
+    ('\u{2E2F}', '\u{2E2F}', W::ALetter),
+    ('\u{3000}', '\u{3000}', W::WSegSpace),
+    ('\u{3005}', '\u{3005}', W::ALetter),
+    ('\u{302A}', '\u{302F}', W::Extend),
+    ('\u{3031}', '\u{3035}', W::Katakana),
+    ('\u{303B}', '\u{303C}', W::ALetter),
+    ('\u{3099}', '\u{309A}', W::Extend),
+    ('\u{309B}', '\u{309C}', W::Katakana),
+    ('\u{30A0}', '\u{30FA}', W::Katakana),
+    ('\u{30FC}', '\u{30FF}', W::Katakana),
+    ('\u{3105}', '\u{312F}', W::ALetter),
+    ('\u{3131}', '\u{318E}', W::ALetter),
+    ('\u{31A0}', '\u{31BF}', W::ALetter),
+    ('\u{31F0}', '\u{31FF}', W::Katakana),
+    ('\u{32D0}', '\u{32FE}', W::Katakana),
+    ('\u{3300}', '\u{3357}', W::Katakana),
+    ('\u{A000}', '\u{A48C}', W::ALetter),
+    ('\u{A4D0}', '\u{A4FD}', W::ALetter),
+    ('\u{A500}', '\u{A60C}', W::ALetter),
+    ('\u{A610}', '\u{A61F}', W::ALetter),
+    ('\u{A620}', '\u{A629}', W::Numeric),
+    ('\u{A62A}', '\u{A62B}', W::ALetter),
+    ('\u{A640}', '\u{A66E}', W::ALetter),
+    ('\u{A66F}', '\u{A672}', W::Extend),
+    ('\u{A674}', '\u{A67D}', W::Extend),
+    ('\u{A67F}', '\u{A69D}', W::ALetter),
+    ('\u{A69E}', '\u{A69F}', W::Extend),
+    ('\u{A6A0}', '\u{A6EF}', W::ALetter),
+    ('\u{A6F0}', '\u{A6F1}', W::Extend),
+    ('\u{A708}', '\u{A7DC}', W::ALetter),
+    ('\u{A7F1}', '\u{A801}', W::ALetter),
+    ('\u{A802}', '\u{A802}', W::Extend),
+    ('\u{A803}', '\u{A805}', W::ALetter),
+    ('\u{A806}', '\u{A806}', W::Extend),
+    ('\u{A807}', '\u{A80A}', W::ALetter),
+    ('\u{A80B}', '\u{A80B}', W::Extend),
+    ('\u{A80C}', '\u{A822}', W::ALetter),
+    ('\u{A823}', '\u{A827}', W::Extend),
+    ('\u{A82C}', '\u{A82C}', W::Extend),
+    ('\u{A840}', '\u{A873}', W::ALetter),
+    ('\u{A880}', '\u{A881}', W::Extend),
+    ('\u{A882}', '\u{A8B3}', W::ALetter),
+    ('\u{A8B4}', '\u{A8C5}', W::Extend),
+    ('\u{A8D0}', '\u{A8D9}', W::Numeric),
+    ('\u{A8E0}', '\u{A8F1}', W::Extend),
+    ('\u{A8F2}', '\u{A8F7}', W::ALetter),
+    ('\u{A8FB}', '\u{A8FB}', W::ALetter),
+    ('\u{A8FD}', '\u{A8FE}', W::ALetter),
+    ('\u{A8FF}', '\u{A8FF}', W::Extend),
+    ('\u{A900}', '\u{A909}', W::Numeric),
+    ('\u{A90A}', '\u{A925}', W::ALetter),
+    ('\u{A926}', '\u{A92D}', W::Extend),
+    ('\u{A930}', '\u{A946}', W::ALetter),
+    ('\u{A947}', '\u{A953}', W::Extend),
+    ('\u{A960}', '\u{A97C}', W::ALetter),
+    ('\u{A980}', '\u{A983}', W::Extend),
+    ('\u{A984}', '\u{A9B2}', W::ALetter),
+    ('\u{A9B3}', '\u{A9C0}', W::Extend),
+    ('\u{A9CF}', '\u{A9CF}', W::ALetter),
+    ('\u{A9D0}', '\u{A9D9}', W::Numeric),
+    ('\u{A9E5}', '\u{A9E5}', W::Extend),
+    ('\u{A9F0}', '\u{A9F9}', W::Numeric),
+    ('\u{AA00}', '\u{AA28}', W::ALetter),
+    ('\u{AA29}', '\u{AA36}', W::Extend),
+    ('\u{AA40}', '\u{AA42}', W::ALetter),
+    ('\u{AA43}', '\u{AA43}', W::Extend),
+    ('\u{AA44}', '\u{AA4B}', W::ALetter),
+    ('\u{AA4C}', '\u{AA4D}', W::Extend),
+    ('\u{AA50}', '\u{AA59}', W::Numeric),
+    ('\u{AA7B}', '\u{AA7D}', W::Extend),
+    ('\u{AAB0}', '\u{AAB0}', W::Extend),
+    ('\u{AAB2}', '\u{AAB4}', W::Extend),
+    ('\u{AAB7}', '\u{AAB8}', W::Extend),
+    ('\u{AABE}', '\u{AABF}', W::Extend),
+    ('\u{AAC1}', '\u{AAC1}', W::Extend),
+    ('\u{AAE0}', '\u{AAEA}', W::ALetter),
+    ('\u{AAEB}', '\u{AAEF}', W::Extend),
+    ('\u{AAF2}', '\u{AAF4}', W::ALetter),
+    ('\u{AAF5}', '\u{AAF6}', W::Extend),
+    ('\u{AB01}', '\u{AB06}', W::ALetter),
+    ('\u{AB09}', '\u{AB0E}', W::ALetter),
+    ('\u{AB11}', '\u{AB16}', W::ALetter),
+    ('\u{AB20}', '\u{AB26}', W::ALetter),
+    ('\u{AB28}', '\u{AB2E}', W::ALetter),
+    ('\u{AB30}', '\u{AB69}', W::ALetter),
+    ('\u{AB70}', '\u{ABE2}', W::ALetter),
+    ('\u{ABE3}', '\u{ABEA}', W::Extend),
+    ('\u{ABEC}', '\u{ABED}', W::Extend),
+    ('\u{ABF0}', '\u{ABF9}', W::Numeric),
+    ('\u{AC00}', '\u{D7A3}', W::ALetter),
+    ('\u{D7B0}', '\u{D7C6}', W::ALetter),
+    ('\u{D7CB}', '\u{D7FB}', W::ALetter),
+    ('\u{FB00}', '\u{FB06}', W::ALetter),
+    ('\u{FB13}', '\u{FB17}', W::ALetter),
+    ('\u{FB1D}', '\u{FB1D}', W::HebrewLetter),
+    ('\u{FB1E}', '\u{FB1E}', W::Extend),
+    ('\u{FB1F}', '\u{FB28}', W::HebrewLetter),
+    ('\u{FB2A}', '\u{FB36}', W::HebrewLetter),
+    ('\u{FB38}', '\u{FB3C}', W::HebrewLetter),
+    ('\u{FB3E}', '\u{FB3E}', W::HebrewLetter),
+    ('\u{FB40}', '\u{FB41}', W::HebrewLetter),
+    ('\u{FB43}', '\u{FB44}', W::HebrewLetter),
+    ('\u{FB46}', '\u{FB4F}', W::HebrewLetter),
+    ('\u{FB50}', '\u{FBB1}', W::ALetter),
+    ('\u{FBD3}', '\u{FD3D}', W::ALetter),
+    ('\u{FD50}', '\u{FD8F}', W::ALetter),
+    ('\u{FD92}', '\u{FDC7}', W::ALetter),
+    ('\u{FDF0}', '\u{FDFB}', W::ALetter),
+    ('\u{FE00}', '\u{FE0F}', W::Extend),
+    ('\u{FE13}', '\u{FE13}', W::MidLetter),
+    ('\u{FE20}', '\u{FE2F}', W::Extend),
+    ('\u{FE33}', '\u{FE34}', W::ExtendNumLet),
+    ('\u{FE4D}', '\u{FE4F}', W::ExtendNumLet),
+    ('\u{FE50}', '\u{FE50}', W::MidNum),
+    ('\u{FE52}', '\u{FE52}', W::MidNumLet),
+    ('\u{FE54}', '\u{FE54}', W::MidNum),
+    ('\u{FE55}', '\u{FE55}', W::MidLetter),
+    ('\u{FE70}', '\u{FE74}', W::ALetter),
+    ('\u{FE76}', '\u{FEFC}', W::ALetter),
+    ('\u{FEFF}', '\u{FEFF}', W::Format),
+    ('\u{FF07}', '\u{FF07}', W::MidNumLet),
+    ('\u{FF0C}', '\u{FF0C}', W::MidNum),
+    ('\u{FF0E}', '\u{FF0E}', W::MidNumLet),
+    ('\u{FF10}', '\u{FF19}', W::Numeric),
+    ('\u{FF1A}', '\u{FF1A}', W::MidLetter),
+    ('\u{FF1B}', '\u{FF1B}', W::MidNum),
+    ('\u{FF21}', '\u{FF3A}', W::ALetter),
+    ('\u{FF3F}', '\u{FF3F}', W::ExtendNumLet),
+    ('\u{FF41}', '\u{FF5A}', W::ALetter),
+    ('\u{FF66}', '\u{FF9D}', W::Katakana),
+    ('\u{FF9E}', '\u{FF9F}', W::Extend),
+    ('\u{FFA0}', '\u{FFBE}', W::ALetter),
+    ('\u{FFC2}', '\u{FFC7}', W::ALetter),
+    ('\u{FFCA}', '\u{FFCF}', W::ALetter),
+    ('\u{FFD2}', '\u{FFD7}', W::ALetter),
+    ('\u{FFDA}', '\u{FFDC}', W::ALetter),
+    ('\u{FFF9}', '\u{FFFB}', W::Format),
+    ('\u{10000}', '\u{1000B}', W::ALetter),
+    ('\u{1000D}', '\u{10026}', W::ALetter),
+    ('\u{10028}', '\u{1003A}', W::ALetter),
+    ('\u{1003C}', '\u{1003D}', W::ALetter),
+    ('\u{1003F}', '\u{1004D}', W::ALetter),
+    ('\u{10050}', '\u{1005D}', W::ALetter),
+    ('\u{10080}', '\u{100FA}', W::ALetter),
+    ('\u{10140}', '\u{10174}', W::ALetter),
+    ('\u{101FD}', '\u{101FD}', W::Extend),
+    ('\u{10280}', '\u{1029C}', W::ALetter),
+    ('\u{102A0}', '\u{102D0}', W::ALetter),
+    ('\u{102E0}', '\u{102E0}', W::Extend),
+    ('\u{10300}', '\u{1031F}', W::ALetter),
+    ('\u{1032D}', '\u{1034A}', W::ALetter),
+    ('\u{10350}', '\u{10375}', W::ALetter),
+    ('\u{10376}', '\u{1037A}', W::Extend),
+    ('\u{10380}', '\u{1039D}', W::ALetter),
+    ('\u{103A0}', '\u{103C3}', W::ALetter),
+    ('\u{103C8}', '\u{103CF}', W::ALetter),
+    ('\u{103D1}', '\u{103D5}', W::ALetter),
+    ('\u{10400}', '\u{1049D}', W::ALetter),
+    ('\u{104A0}', '\u{104A9}', W::Numeric),
+    ('\u{104B0}', '\u{104D3}', W::ALetter),
+    ('\u{104D8}', '\u{104FB}', W::ALetter),
+    ('\u{10500}', '\u{10527}', W::ALetter),
+    ('\u{10530}', '\u{10563}', W::ALetter),
+    ('\u{10570}', '\u{1057A}', W::ALetter),
+    ('\u{1057C}', '\u{1058A}', W::ALetter),
+    ('\u{1058C}', '\u{10592}', W::ALetter),
+    ('\u{10594}', '\u{10595}', W::ALetter),
+    ('\u{10597}', '\u{105A1}', W::ALetter),
+    ('\u{105A3}', '\u{105B1}', W::ALetter),
+    ('\u{105B3}', '\u{105B9}', W::ALetter),
+    ('\u{105BB}', '\u{105BC}', W::ALetter),
+    ('\u{105C0}', '\u{105F3}', W::ALetter),
+    ('\u{10600}', '\u{10736}', W::ALetter),
+    ('\u{10740}', '\u{10755}', W::ALetter),
+    ('\u{10760}', '\u{10767}', W::ALetter),
+    ('\u{10780}', '\u{10785}', W::ALetter),
+    ('\u{10787}', '\u{107B0}', W::ALetter),
+    ('\u{107B2}', '\u{107BA}', W::ALetter),
+    ('\u{10800}', '\u{10805}', W::ALetter),
+    ('\u{10808}', '\u{10808}', W::ALetter),
+    ('\u{1080A}', '\u{10835}', W::ALetter),
+    ('\u{10837}', '\u{10838}', W::ALetter),
+    ('\u{1083C}', '\u{1083C}', W::ALetter),
+    ('\u{1083F}', '\u{10855}', W::ALetter),
+    ('\u{10860}', '\u{10876}', W::ALetter),
+    ('\u{10880}', '\u{1089E}', W::ALetter),
+    ('\u{108E0}', '\u{108F2}', W::ALetter),
+    ('\u{108F4}', '\u{108F5}', W::ALetter),
+    ('\u{10900}', '\u{10915}', W::ALetter),
+    ('\u{10920}', '\u{10939}', W::ALetter),
+    ('\u{10940}', '\u{10959}', W::ALetter),
+    ('\u{10980}', '\u{109B7}', W::ALetter),
+    ('\u{109BE}', '\u{109BF}', W::ALetter),
+    ('\u{10A00}', '\u{10A00}', W::ALetter),
+    ('\u{10A01}', '\u{10A03}', W::Extend),
+    ('\u{10A05}', '\u{10A06}', W::Extend),
+    ('\u{10A0C}', '\u{10A0F}', W::Extend),
+    ('\u{10A10}', '\u{10A13}', W::ALetter),
+    ('\u{10A15}', '\u{10A17}', W::ALetter),
+    ('\u{10A19}', '\u{10A35}', W::ALetter),
+    ('\u{10A38}', '\u{10A3A}', W::Extend),
+    ('\u{10A3F}', '\u{10A3F}', W::Extend),
+    ('\u{10A60}', '\u{10A7C}', W::ALetter),
+    ('\u{10A80}', '\u{10A9C}', W::ALetter),
+    ('\u{10AC0}', '\u{10AC7}', W::ALetter),
+    ('\u{10AC9}', '\u{10AE4}', W::ALetter),
+    ('\u{10AE5}', '\u{10AE6}', W::Extend),
+    ('\u{10B00}', '\u{10B35}', W::ALetter),
+    ('\u{10B40}', '\u{10B55}', W::ALetter),
+    ('\u{10B60}', '\u{10B72}', W::ALetter),
+    ('\u{10B80}', '\u{10B91}', W::ALetter),
+    ('\u{10C00}', '\u{10C48}', W::ALetter),
+    ('\u{10C80}', '\u{10CB2}', W::ALetter),
+    ('\u{10CC0}', '\u{10CF2}', W::ALetter),
+    ('\u{10D00}', '\u{10D23}', W::ALetter),
+    ('\u{10D24}', '\u{10D27}', W::Extend),
+    ('\u{10D30}', '\u{10D39}', W::Numeric),
+    ('\u{10D40}', '\u{10D49}', W::Numeric),
+    ('\u{10D4A}', '\u{10D65}', W::ALetter),
+    ('\u{10D69}', '\u{10D6D}', W::Extend),
+    ('\u{10D6F}', '\u{10D85}', W::ALetter),
+    ('\u{10E80}', '\u{10EA9}', W::ALetter),
+    ('\u{10EAB}', '\u{10EAC}', W::Extend),
+    ('\u{10EB0}', '\u{10EB1}', W::ALetter),
+    ('\u{10EC2}', '\u{10EC7}', W::ALetter),
+    ('\u{10EFA}', '\u{10EFF}', W::Extend),
+    ('\u{10F00}', '\u{10F1C}', W::ALetter),
+    ('\u{10F27}', '\u{10F27}', W::ALetter),
+    ('\u{10F30}', '\u{10F45}', W::ALetter),
+    ('\u{10F46}', '\u{10F50}', W::Extend),
+    ('\u{10F70}', '\u{10F81}', W::ALetter),
+    ('\u{10F82}', '\u{10F85}', W::Extend),
+    ('\u{10FB0}', '\u{10FC4}', W::ALetter),
+    ('\u{10FE0}', '\u{10FF6}', W::ALetter),
+    ('\u{11000}', '\u{11002}', W::Extend),
+    ('\u{11003}', '\u{11037}', W::ALetter),
+    ('\u{11038}', '\u{11046}', W::Extend),
+    ('\u{11066}', '\u{1106F}', W::Numeric),
+    ('\u{11070}', '\u{11070}', W::Extend),
+    ('\u{11071}', '\u{11072}', W::ALetter),
+    ('\u{11073}', '\u{11074}', W::Extend),
+    ('\u{11075}', '\u{11075}', W::ALetter),
+    ('\u{1107F}', '\u{11082}', W::Extend),
+    ('\u{11083}', '\u{110AF}', W::ALetter),
+    ('\u{110B0}', '\u{110BA}', W::Extend),
+    ('\u{110BD}', '\u{110BD}', W::Numeric),
+    ('\u{110C2}', '\u{110C2}', W::Extend),
+    ('\u{110CD}', '\u{110CD}', W::Numeric),
+    ('\u{110D0}', '\u{110E8}', W::ALetter),
+    ('\u{110F0}', '\u{110F9}', W::Numeric),
+    ('\u{11100}', '\u{11102}', W::Extend),
+    ('\u{11103}', '\u{11126}', W::ALetter),
+    ('\u{11127}', '\u{11134}', W::Extend),
+    ('\u{11136}', '\u{1113F}', W::Numeric),
+    ('\u{11144}', '\u{11144}', W::ALetter),
+    ('\u{11145}', '\u{11146}', W::Extend),
+    ('\u{11147}', '\u{11147}', W::ALetter),
+    ('\u{11150}', '\u{11172}', W::ALetter),
+    ('\u{11173}', '\u{11173}', W::Extend),
+    ('\u{11176}', '\u{11176}', W::ALetter),
+    ('\u{11180}', '\u{11182}', W::Extend),
+    ('\u{11183}', '\u{111B2}', W::ALetter),
+    ('\u{111B3}', '\u{111C0}', W::Extend),
+    ('\u{111C1}', '\u{111C4}', W::ALetter),
+    ('\u{111C9}', '\u{111CC}', W::Extend),
+    ('\u{111CE}', '\u{111CF}', W::Extend),
+    ('\u{111D0}', '\u{111D9}', W::Numeric),
+    ('\u{111DA}', '\u{111DA}', W::ALetter),
+    ('\u{111DC}', '\u{111DC}', W::ALetter),
+    ('\u{11200}', '\u{11211}', W::ALetter),
+    ('\u{11213}', '\u{1122B}', W::ALetter),
+    ('\u{1122C}', '\u{11237}', W::Extend),
+    ('\u{1123E}', '\u{1123E}', W::Extend),
+    ('\u{1123F}', '\u{11240}', W::ALetter),
+    ('\u{11241}', '\u{11241}', W::Extend),
+    ('\u{11280}', '\u{11286}', W::ALetter),
+    ('\u{11288}', '\u{11288}', W::ALetter),
+    ('\u{1128A}', '\u{1128D}', W::ALetter),
+    ('\u{1128F}', '\u{1129D}', W::ALetter),
+    ('\u{1129F}', '\u{112A8}', W::ALetter),
+    ('\u{112B0}', '\u{112DE}', W::ALetter),
+    ('\u{112DF}', '\u{112EA}', W::Extend),
+    ('\u{112F0}', '\u{112F9}', W::Numeric),
+    ('\u{11300}', '\u{11303}', W::Extend),
+    ('\u{11305}', '\u{1130C}', W::ALetter),
+    ('\u{1130F}', '\u{11310}', W::ALetter),
+    ('\u{11313}', '\u{11328}', W::ALetter),
+    ('\u{1132A}', '\u{11330}', W::ALetter),
+    ('\u{11332}', '\u{11333}', W::ALetter),
+    ('\u{11335}', '\u{11339}', W::ALetter),
+    ('\u{1133B}', '\u{1133C}', W::Extend),
+    ('\u{1133D}', '\u{1133D}', W::ALetter),
+    ('\u{1133E}', '\u{11344}', W::Extend),
+    ('\u{11347}', '\u{11348}', W::Extend),
+    ('\u{1134B}', '\u{1134D}', W::Extend),
+    ('\u{11350}', '\u{11350}', W::ALetter),
+    ('\u{11357}', '\u{11357}', W::Extend),
+    ('\u{1135D}', '\u{11361}', W::ALetter),
+    ('\u{11362}', '\u{11363}', W::Extend),
+    ('\u{11366}', '\u{1136C}', W::Extend),
+    ('\u{11370}', '\u{11374}', W::Extend),
+    ('\u{11380}', '\u{11389}', W::ALetter),
+    ('\u{1138B}', '\u{1138B}', W::ALetter),
+    ('\u{1138E}', '\u{1138E}', W::ALetter),
+    ('\u{11390}', '\u{113B5}', W::ALetter),
+    ('\u{113B7}', '\u{113B7}', W::ALetter),
+    ('\u{113B8}', '\u{113C0}', W::Extend),
+    ('\u{113C2}', '\u{113C2}', W::Extend),
+    ('\u{113C5}', '\u{113C5}', W::Extend),
+    ('\u{113C7}', '\u{113CA}', W::Extend),
+    ('\u{113CC}', '\u{113D0}', W::Extend),
+    ('\u{113D1}', '\u{113D1}', W::ALetter),
+    ('\u{113D2}', '\u{113D2}', W::Extend),
+    ('\u{113D3}', '\u{113D3}', W::ALetter),
+    ('\u{113E1}', '\u{113E2}', W::Extend),
+    ('\u{11400}', '\u{11434}', W::ALetter),
+    ('\u{11435}', '\u{11446}', W::Extend),
+    ('\u{11447}', '\u{1144A}', W::ALetter),
+    ('\u{11450}', '\u{11459}', W::Numeric),
+    ('\u{1145E}', '\u{1145E}', W::Extend),
+    ('\u{1145F}', '\u{11461}', W::ALetter),
+    ('\u{11480}', '\u{114AF}', W::ALetter),
+    ('\u{114B0}', '\u{114C3}', W::Extend),
+    ('\u{114C4}', '\u{114C5}', W::ALetter),
+    ('\u{114C7}', '\u{114C7}', W::ALetter),
+    ('\u{114D0}', '\u{114D9}', W::Numeric),
+    ('\u{11580}', '\u{115AE}', W::ALetter),
+    ('\u{115AF}', '\u{115B5}', W::Extend),
+    ('\u{115B8}', '\u{115C0}', W::Extend),
+    ('\u{115D8}', '\u{115DB}', W::ALetter),
+    ('\u{115DC}', '\u{115DD}', W::Extend),
+    ('\u{11600}', '\u{1162F}', W::ALetter),
+    ('\u{11630}', '\u{11640}', W::Extend),
+    ('\u{11644}', '\u{11644}', W::ALetter),
+    ('\u{11650}', '\u{11659}', W::Numeric),
+    ('\u{11680}', '\u{116AA}', W::ALetter),
+    ('\u{116AB}', '\u{116B7}', W::Extend),
+    ('\u{116B8}', '\u{116B8}', W::ALetter),
+    ('\u{116C0}', '\u{116C9}', W::Numeric),
+    ('\u{116D0}', '\u{116E3}', W::Numeric),
+    ('\u{1171D}', '\u{1172B}', W::Extend),
+    ('\u{11730}', '\u{11739}', W::Numeric),
+    ('\u{11800}', '\u{1182B}', W::ALetter),
+    ('\u{1182C}', '\u{1183A}', W::Extend),
+    ('\u{118A0}', '\u{118DF}', W::ALetter),
+    ('\u{118E0}', '\u{118E9}', W::Numeric),
+    ('\u{118FF}', '\u{11906}', W::ALetter),
+    ('\u{11909}', '\u{11909}', W::ALetter),
+    ('\u{1190C}', '\u{11913}', W::ALetter),
+    ('\u{11915}', '\u{11916}', W::ALetter),
+    ('\u{11918}', '\u{1192F}', W::ALetter),
+    ('\u{11930}', '\u{11935}', W::Extend),
+    ('\u{11937}', '\u{11938}', W::Extend),
+    ('\u{1193B}', '\u{1193E}', W::Extend),
+    ('\u{1193F}', '\u{1193F}', W::ALetter),
+    ('\u{11940}', '\u{11940}', W::Extend),
+    ('\u{11941}', '\u{11941}', W::ALetter),
+    ('\u{11942}', '\u{11943}', W::Extend),
+    ('\u{11950}', '\u{11959}', W::Numeric),
+    ('\u{119A0}', '\u{119A7}', W::ALetter),
+    ('\u{119AA}', '\u{119D0}', W::ALetter),
+    ('\u{119D1}', '\u{119D7}', W::Extend),
+    ('\u{119DA}', '\u{119E0}', W::Extend),
+    ('\u{119E1}', '\u{119E1}', W::ALetter),
+    ('\u{119E3}', '\u{119E3}', W::ALetter),
+    ('\u{119E4}', '\u{119E4}', W::Extend),
+    ('\u{11A00}', '\u{11A00}', W::ALetter),
+    ('\u{11A01}', '\u{11A0A}', W::Extend),
+    ('\u{11A0B}', '\u{11A32}', W::ALetter),
+    ('\u{11A33}', '\u{11A39}', W::Extend),
+    ('\u{11A3A}', '\u{11A3A}', W::ALetter),
+    ('\u{11A3B}', '\u{11A3E}', W::Extend),
+    ('\u{11A47}', '\u{11A47}', W::Extend),
+    ('\u{11A50}', '\u{11A50}', W::ALetter),
+    ('\u{11A51}', '\u{11A5B}', W::Extend),
+    ('\u{11A5C}', '\u{11A89}', W::ALetter),
+    ('\u{11A8A}', '\u{11A99}', W::Extend),
+    ('\u{11A9D}', '\u{11A9D}', W::ALetter),
+    ('\u{11AB0}', '\u{11AF8}', W::ALetter),
+    ('\u{11B60}', '\u{11B67}', W::Extend),
+    ('\u{11BC0}', '\u{11BE0}', W::ALetter),
+    ('\u{11BF0}', '\u{11BF9}', W::Numeric),
+    ('\u{11C00}', '\u{11C08}', W::ALetter),
+    ('\u{11C0A}', '\u{11C2E}', W::ALetter),
+    ('\u{11C2F}', '\u{11C36}', W::Extend),
+    ('\u{11C38}', '\u{11C3F}', W::Extend),
+    ('\u{11C40}', '\u{11C40}', W::ALetter),
+    ('\u{11C50}', '\u{11C59}', W::Numeric),
+    ('\u{11C72}', '\u{11C8F}', W::ALetter),
+    ('\u{11C92}', '\u{11CA7}', W::Extend),
+    ('\u{11CA9}', '\u{11CB6}', W::Extend),
+    ('\u{11D00}', '\u{11D06}', W::ALetter),
+    ('\u{11D08}', '\u{11D09}', W::ALetter),
+    ('\u{11D0B}', '\u{11D30}', W::ALetter),
+    ('\u{11D31}', '\u{11D36}', W::Extend),
+    ('\u{11D3A}', '\u{11D3A}', W::Extend),
+    ('\u{11D3C}', '\u{11D3D}', W::Extend),
+    ('\u{11D3F}', '\u{11D45}', W::Extend),
+    ('\u{11D46}', '\u{11D46}', W::ALetter),
+    ('\u{11D47}', '\u{11D47}', W::Extend),
+    ('\u{11D50}', '\u{11D59}', W::Numeric),
+    ('\u{11D60}', '\u{11D65}', W::ALetter),
+    ('\u{11D67}', '\u{11D68}', W::ALetter),
+    ('\u{11D6A}', '\u{11D89}', W::ALetter),
+    ('\u{11D8A}', '\u{11D8E}', W::Extend),
+    ('\u{11D90}', '\u{11D91}', W::Extend),
+    ('\u{11D93}', '\u{11D97}', W::Extend),
+    ('\u{11D98}', '\u{11D98}', W::ALetter),
+    ('\u{11DA0}', '\u{11DA9}', W::Numeric),
+    ('\u{11DB0}', '\u{11DDB}', W::ALetter),
+    ('\u{11DE0}', '\u{11DE9}', W::Numeric),
+    ('\u{11EE0}', '\u{11EF2}', W::ALetter),
+    ('\u{11EF3}', '\u{11EF6}', W::Extend),
+    ('\u{11F00}', '\u{11F01}', W::Extend),
+    ('\u{11F02}', '\u{11F02}', W::ALetter),
+    ('\u{11F03}', '\u{11F03}', W::Extend),
+    ('\u{11F04}', '\u{11F10}', W::ALetter),
+    ('\u{11F12}', '\u{11F33}', W::ALetter),
+    ('\u{11F34}', '\u{11F3A}', W::Extend),
+    ('\u{11F3E}', '\u{11F42}', W::Extend),
+    ('\u{11F50}', '\u{11F59}', W::Numeric),
+    ('\u{11F5A}', '\u{11F5A}', W::Extend),
+    ('\u{11FB0}', '\u{11FB0}', W::ALetter),
+    ('\u{12000}', '\u{12399}', W::ALetter),
+    ('\u{12400}', '\u{1246E}', W::ALetter),
+    ('\u{12480}', '\u{12543}', W::ALetter),
+    ('\u{12F90}', '\u{12FF0}', W::ALetter),
+    ('\u{13000}', '\u{1342F}', W::ALetter),
+    ('\u{13430}', '\u{1343F}', W::Format),
+    ('\u{13440}', '\u{13440}', W::Extend),
+    ('\u{13441}', '\u{13446}', W::ALetter),
+    ('\u{13447}', '\u{13455}', W::Extend),
+    ('\u{13460}', '\u{143FA}', W::ALetter),
+    ('\u{14400}', '\u{14646}', W::ALetter),
+    ('\u{16100}', '\u{1611D}', W::ALetter),
+    ('\u{1611E}', '\u{1612F}', W::Extend),
+    ('\u{16130}', '\u{16139}', W::Numeric),
+    ('\u{16800}', '\u{16A38}', W::ALetter),
+    ('\u{16A40}', '\u{16A5E}', W::ALetter),
+    ('\u{16A60}', '\u{16A69}', W::Numeric),
+    ('\u{16A70}', '\u{16ABE}', W::ALetter),
+    ('\u{16AC0}', '\u{16AC9}', W::Numeric),
+    ('\u{16AD0}', '\u{16AED}', W::ALetter),
+    ('\u{16AF0}', '\u{16AF4}', W::Extend),
+    ('\u{16B00}', '\u{16B2F}', W::ALetter),
+    ('\u{16B30}', '\u{16B36}', W::Extend),
+    ('\u{16B40}', '\u{16B43}', W::ALetter),
+    ('\u{16B50}', '\u{16B59}', W::Numeric),
+    ('\u{16B63}', '\u{16B77}', W::ALetter),
+    ('\u{16B7D}', '\u{16B8F}', W::ALetter),
+    ('\u{16D40}', '\u{16D6C}', W::ALetter),
+    ('\u{16D70}', '\u{16D79}', W::Numeric),
+    ('\u{16E40}', '\u{16E7F}', W::ALetter),
+    ('\u{16EA0}', '\u{16EB8}', W::ALetter),
+    ('\u{16EBB}', '\u{16ED3}', W::ALetter),
+    ('\u{16F00}', '\u{16F4A}', W::ALetter),
+    ('\u{16F4F}', '\u{16F4F}', W::Extend),
+    ('\u{16F50}', '\u{16F50}', W::ALetter),
+    ('\u{16F51}', '\u{16F87}', W::Extend),
+    ('\u{16F8F}', '\u{16F92}', W::Extend),
+    ('\u{16F93}', '\u{16F9F}', W::ALetter),
+    ('\u{16FE0}', '\u{16FE1}', W::ALetter),
+    ('\u{16FE3}', '\u{16FE3}', W::ALetter),
+    ('\u{16FE4}', '\u{16FE4}', W::Extend),
+    ('\u{16FF0}', '\u{16FF1}', W::Extend),
+    ('\u{1AFF0}', '\u{1AFF3}', W::Katakana),
+    ('\u{1AFF5}', '\u{1AFFB}', W::Katakana),
+    ('\u{1AFFD}', '\u{1AFFE}', W::Katakana),
+    ('\u{1B000}', '\u{1B000}', W::Katakana),
+    ('\u{1B120}', '\u{1B122}', W::Katakana),
+    ('\u{1B155}', '\u{1B155}', W::Katakana),
+    ('\u{1B164}', '\u{1B167}', W::Katakana),
+    ('\u{1BC00}', '\u{1BC6A}', W::ALetter),
+    ('\u{1BC70}', '\u{1BC7C}', W::ALetter),
+    ('\u{1BC80}', '\u{1BC88}', W::ALetter),
+    ('\u{1BC90}', '\u{1BC99}', W::ALetter),
+    ('\u{1BC9D}', '\u{1BC9E}', W::Extend),
+    ('\u{1BCA0}', '\u{1BCA3}', W::Format),
+    ('\u{1CCF0}', '\u{1CCF9}', W::Numeric),
+    ('\u{1CF00}', '\u{1CF2D}', W::Extend),
+    ('\u{1CF30}', '\u{1CF46}', W::Extend),
+    ('\u{1D165}', '\u{1D169}', W::Extend),
+    ('\u{1D16D}', '\u{1D172}', W::Extend),
+    ('\u{1D173}', '\u{1D17A}', W::Format),
+    ('\u{1D17B}', '\u{1D182}', W::Extend),
+    ('\u{1D185}', '\u{1D18B}', W::Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', W::Extend),
+    ('\u{1D242}', '\u{1D244}', W::Extend),
+    ('\u{1D400}', '\u{1D454}', W::ALetter),
+    ('\u{1D456}', '\u{1D49C}', W::ALetter),
+    ('\u{1D49E}', '\u{1D49F}', W::ALetter),
+    ('\u{1D4A2}', '\u{1D4A2}', W::ALetter),
+    ('\u{1D4A5}', '\u{1D4A6}', W::ALetter),
+    ('\u{1D4A9}', '\u{1D4AC}', W::ALetter),
+    ('\u{1D4AE}', '\u{1D4B9}', W::ALetter),
+    ('\u{1D4BB}', '\u{1D4BB}', W::ALetter),
+    ('\u{1D4BD}', '\u{1D4C3}', W::ALetter),
+    ('\u{1D4C5}', '\u{1D505}', W::ALetter),
+    ('\u{1D507}', '\u{1D50A}', W::ALetter),
+    ('\u{1D50D}', '\u{1D514}', W::ALetter),
+    ('\u{1D516}', '\u{1D51C}', W::ALetter),
+    ('\u{1D51E}', '\u{1D539}', W::ALetter),
+    ('\u{1D53B}', '\u{1D53E}', W::ALetter),
+    ('\u{1D540}', '\u{1D544}', W::ALetter),
+    ('\u{1D546}', '\u{1D546}', W::ALetter),
+    ('\u{1D54A}', '\u{1D550}', W::ALetter),
+    ('\u{1D552}', '\u{1D6A5}', W::ALetter),
+    ('\u{1D6A8}', '\u{1D6C0}', W::ALetter),
+    ('\u{1D6C2}', '\u{1D6DA}', W::ALetter),
+    ('\u{1D6DC}', '\u{1D6FA}', W::ALetter),
+    ('\u{1D6FC}', '\u{1D714}', W::ALetter),
+    ('\u{1D716}', '\u{1D734}', W::ALetter),
+    ('\u{1D736}', '\u{1D74E}', W::ALetter),
+    ('\u{1D750}', '\u{1D76E}', W::ALetter),
+    ('\u{1D770}', '\u{1D788}', W::ALetter),
+    ('\u{1D78A}', '\u{1D7A8}', W::ALetter),
+    ('\u{1D7AA}', '\u{1D7C2}', W::ALetter),
+    ('\u{1D7C4}', '\u{1D7CB}', W::ALetter),
+    ('\u{1D7CE}', '\u{1D7FF}', W::Numeric),
+    ('\u{1DA00}', '\u{1DA36}', W::Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', W::Extend),
+    ('\u{1DA75}', '\u{1DA75}', W::Extend),
+    ('\u{1DA84}', '\u{1DA84}', W::Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', W::Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', W::Extend),
+    ('\u{1DF00}', '\u{1DF1E}', W::ALetter),
+    ('\u{1DF25}', '\u{1DF2A}', W::ALetter),
+    ('\u{1E000}', '\u{1E006}', W::Extend),
+    ('\u{1E008}', '\u{1E018}', W::Extend),
+    ('\u{1E01B}', '\u{1E021}', W::Extend),
+    ('\u{1E023}', '\u{1E024}', W::Extend),
+    ('\u{1E026}', '\u{1E02A}', W::Extend),
+    ('\u{1E030}', '\u{1E06D}', W::ALetter),
+    ('\u{1E08F}', '\u{1E08F}', W::Extend),
+    ('\u{1E100}', '\u{1E12C}', W::ALetter),
+    ('\u{1E130}', '\u{1E136}', W::Extend),
+    ('\u{1E137}', '\u{1E13D}', W::ALetter),
+    ('\u{1E140}', '\u{1E149}', W::Numeric),
+    ('\u{1E14E}', '\u{1E14E}', W::ALetter),
+    ('\u{1E290}', '\u{1E2AD}', W::ALetter),
+    ('\u{1E2AE}', '\u{1E2AE}', W::Extend),
+    ('\u{1E2C0}', '\u{1E2EB}', W::ALetter),
+    ('\u{1E2EC}', '\u{1E2EF}', W::Extend),
+    ('\u{1E2F0}', '\u{1E2F9}', W::Numeric),
+    ('\u{1E4D0}', '\u{1E4EB}', W::ALetter),
+    ('\u{1E4EC}', '\u{1E4EF}', W::Extend),
+    ('\u{1E4F0}', '\u{1E4F9}', W::Numeric),
+    ('\u{1E5D0}', '\u{1E5ED}', W::ALetter),
+    ('\u{1E5EE}', '\u{1E5EF}', W::Extend),
+    ('\u{1E5F0}', '\u{1E5F0}', W::ALetter),
+    ('\u{1E5F1}', '\u{1E5FA}', W::Numeric),
+    ('\u{1E6C0}', '\u{1E6DE}', W::ALetter),
+    ('\u{1E6E0}', '\u{1E6E2}', W::ALetter),
+    ('\u{1E6E3}', '\u{1E6E3}', W::Extend),
+    ('\u{1E6E4}', '\u{1E6E5}', W::ALetter),
+    ('\u{1E6E6}', '\u{1E6E6}', W::Extend),
+    ('\u{1E6E7}', '\u{1E6ED}', W::ALetter),
+    ('\u{1E6EE}', '\u{1E6EF}', W::Extend),
+    ('\u{1E6F0}', '\u{1E6F4}', W::ALetter),
+    ('\u{1E6F5}', '\u{1E6F5}', W::Extend),
+    ('\u{1E6FE}', '\u{1E6FF}', W::ALetter),
+    ('\u{1E7E0}', '\u{1E7E6}', W::ALetter),
+    ('\u{1E7E8}', '\u{1E7EB}', W::ALetter),
+    ('\u{1E7ED}', '\u{1E7EE}', W::ALetter),
+    ('\u{1E7F0}', '\u{1E7FE}', W::ALetter),
+    ('\u{1E800}', '\u{1E8C4}', W::ALetter),
+    ('\u{1E8D0}', '\u{1E8D6}', W::Extend),
+    ('\u{1E900}', '\u{1E943}', W::ALetter),
+    ('\u{1E944}', '\u{1E94A}', W::Extend),
+    ('\u{1E94B}', '\u{1E94B}', W::ALetter),
+    ('\u{1E950}', '\u{1E959}', W::Numeric),
+    ('\u{1EE00}', '\u{1EE03}', W::ALetter),
+    ('\u{1EE05}', '\u{1EE1F}', W::ALetter),
+    ('\u{1EE21}', '\u{1EE22}', W::ALetter),
+    ('\u{1EE24}', '\u{1EE24}', W::ALetter),
+    ('\u{1EE27}', '\u{1EE27}', W::ALetter),
+    ('\u{1EE29}', '\u{1EE32}', W::ALetter),
+    ('\u{1EE34}', '\u{1EE37}', W::ALetter),
+    ('\u{1EE39}', '\u{1EE39}', W::ALetter),
+    ('\u{1EE3B}', '\u{1EE3B}', W::ALetter),
+    ('\u{1EE42}', '\u{1EE42}', W::ALetter),
+    ('\u{1EE47}', '\u{1EE47}', W::ALetter),
+    ('\u{1EE49}', '\u{1EE49}', W::ALetter),
+    ('\u{1EE4B}', '\u{1EE4B}', W::ALetter),
+    ('\u{1EE4D}', '\u{1EE4F}', W::ALetter),
+    ('\u{1EE51}', '\u{1EE52}', W::ALetter),
+    ('\u{1EE54}', '\u{1EE54}', W::ALetter),
+    ('\u{1EE57}', '\u{1EE57}', W::ALetter),
+    ('\u{1EE59}', '\u{1EE59}', W::ALetter),
+    ('\u{1EE5B}', '\u{1EE5B}', W::ALetter),
+    ('\u{1EE5D}', '\u{1EE5D}', W::ALetter),
+    ('\u{1EE5F}', '\u{1EE5F}', W::ALetter),
+    ('\u{1EE61}', '\u{1EE62}', W::ALetter),
+    ('\u{1EE64}', '\u{1EE64}', W::ALetter),
+    ('\u{1EE67}', '\u{1EE6A}', W::ALetter),
+    ('\u{1EE6C}', '\u{1EE72}', W::ALetter),
+    ('\u{1EE74}', '\u{1EE77}', W::ALetter),
+    ('\u{1EE79}', '\u{1EE7C}', W::ALetter),
+    ('\u{1EE7E}', '\u{1EE7E}', W::ALetter),
+    ('\u{1EE80}', '\u{1EE89}', W::ALetter),
+    ('\u{1EE8B}', '\u{1EE9B}', W::ALetter),
+    ('\u{1EEA1}', '\u{1EEA3}', W::ALetter),
+    ('\u{1EEA5}', '\u{1EEA9}', W::ALetter),
+    ('\u{1EEAB}', '\u{1EEBB}', W::ALetter),
+    ('\u{1F130}', '\u{1F149}', W::ALetter),
+    ('\u{1F150}', '\u{1F169}', W::ALetter),
+    ('\u{1F170}', '\u{1F189}', W::ALetter),
+    ('\u{1F1E6}', '\u{1F1FF}', W::RegionalIndicator),
+    ('\u{1F3FB}', '\u{1F3FF}', W::Extend),
+    ('\u{1FBF0}', '\u{1FBF9}', W::Numeric),
+    ('\u{E0001}', '\u{E0001}', W::Format),
+    ('\u{E0020}', '\u{E007F}', W::Extend),
+    ('\u{E0100}', '\u{E01EF}', W::Extend),
+];
@@ -0,0 +1,212 @@
+use alloc::vec::Vec;
+
+use super::properties::{grapheme_break, word_break, GraphemeBreak, WordBreak as W};
+
+/// A character with the `Extend`, `Format` and `ZWJ` characters after it,
+/// which word boundary rules treat as one (WB4).
+#[derive(Debug, Clone, Copy)]
+struct Unit {
+    start: usize,
+    property: W,
+    /// The property of the last character, as rules before WB4 see it.
+    last: W,
+    pictographic: bool,
+}
+
+fn is_ignorable(property: W) -> bool {
+    matches!(property, W::Extend | W::Format | W::Zwj)
+}
+
+fn is_newline(property: W) -> bool {
+    matches!(property, W::Cr | W::Lf | W::Newline)
+}
+
+fn is_letter(property: W) -> bool {
+    matches!(property, W::ALetter | W::HebrewLetter)
+}
+
+fn is_mid_letter(property: W) -> bool {
+    matches!(property, W::MidLetter | W::MidNumLet | W::SingleQuote)
+}
+
+fn is_mid_number(property: W) -> bool {
+    matches!(property, W::MidNum | W::MidNumLet | W::SingleQuote)
+}
+
+/// Groups the characters of a text into units.
+fn units(text: &str) -> Vec<Unit> {
+    let mut units: Vec<Unit> = Vec::new();
+    for (i, c) in text.char_indices() {
+        let property = word_break(c);
+        match units.last_mut() {
+            Some(unit) if is_ignorable(property) && !is_newline(unit.property) => {
+                unit.last = property;
+            }
+            _ => units.push(Unit {
+                start: i,
+                property,
+                last: property,
+                pictographic: grapheme_break(c) == GraphemeBreak::ExtendedPictographic,
+            }),
+        }
+    }
+    units
+}
+
+/// Whether there is a word boundary before unit `k`, which is not the first.
+fn is_boundary(units: &[Unit], k: usize, regional_indicators: usize) -> bool {
+    let property = |k: Option<usize>| {
+        k.and_then(|k| units.get(k))
+            .map_or(W::Other, |unit| unit.property)
+    };
+    let (before, after) = (units[k - 1], units[k]);
+    let (a2, a, b, b2) = (
+        property(k.checked_sub(2)),
+        before.property,
+        after.property,
+        property(Some(k + 1)),
+    );
+    match (a, b) {
+        // WB3, WB3a, WB3b
+        (W::Cr, W::Lf) => false,
+        _ if is_newline(a) || is_newline(b) => true,
+        // WB3c, WB3d
+        _ if before.last == W::Zwj && after.pictographic => false,
+        (W::WSegSpace, W::WSegSpace) if before.last == W::WSegSpace => false,
+        // WB5, WB8, WB9, WB10, WB13
+        (W::ALetter | W::HebrewLetter | W::Numeric, W::ALetter | W::HebrewLetter | W::Numeric)
+        | (W::Katakana, W::Katakana) => false,
+        // WB6, WB7: letters joined by punctuation, as in "can't".
+        _ if is_letter(a) && is_mid_letter(b) && is_letter(b2) => false,
+        _ if is_letter(a2) && is_mid_letter(a) && is_letter(b) => false,
+        // WB7a, WB7b, WB7c: Hebrew letters joined by quotes.
+        (W::HebrewLetter, W::SingleQuote) => false,
+        (W::HebrewLetter, W::DoubleQuote) if b2 == W::HebrewLetter => false,
+        (W::DoubleQuote, W::HebrewLetter) if a2 == W::HebrewLetter => false,
+        // WB11, WB12: numbers joined by punctuation, as in "3.14".
+        _ if a == W::Numeric && is_mid_number(b) && b2 == W::Numeric => false,
+        _ if a2 == W::Numeric && is_mid_number(a) && b == W::Numeric => false,
+        // WB13a, WB13b
+        (
+            W::ALetter | W::HebrewLetter | W::Numeric | W::Katakana | W::ExtendNumLet,
+            W::ExtendNumLet,
+        )
+        | (W::ExtendNumLet, W::ALetter | W::HebrewLetter | W::Numeric | W::Katakana) => false,
+        // WB15, WB16: flags are pairs of regional indicators.
+        (W::RegionalIndicator, W::RegionalIndicator) => regional_indicators.is_multiple_of(2),
+        // WB999
+        _ => true,
+    }
+}
+
+/// Splits a text at its word boundaries, into words, runs of whitespace,
+/// and the other characters between them.
+///
+/// Concatenating the segments gives back the text. Punctuation within a
+/// word or number, as in "can't" or "3.14", does not split it.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::segmentation::word_segments;
+///
+/// assert_eq!(
+///     word_segments("The quick (\"brown\") fox can't jump 32.3 feet, right?"),
+///     [
+///         "The", " ", "quick", " ", "(", "\"", "brown", "\"", ")", " ", "fox", " ", "can't",
+///         " ", "jump", " ", "32.3", " ", "feet", ",", " ", "right", "?"
+///     ]
+/// );
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n log k), for `k` ranges in the property tables.
+///
+/// # References
+///
+/// - [UAX #29: Unicode Text Segmentation, Word Boundaries](https://www.unicode.org/reports/tr29/#Word_Boundaries)
+#[must_use]
+pub fn word_segments(text: &str) -> Vec<&str> {
+    let units = units(text);
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut regional_indicators = 0;
+    for k in 1..units.len() {
+        if units[k - 1].property == W::RegionalIndicator {
+            regional_indicators += 1;
+        } else {
+            regional_indicators = 0;
+        }
+        if is_boundary(&units, k, regional_indicators) {
+            segments.push(&text[start..units[k].start]);
+            start = units[k].start;
+        }
+    }
+    if !text.is_empty() {
+        segments.push(&text[start..]);
+    }
+    segments
+}
+
+/// Finds the words of a text: the [`word_segments`] with a letter or a
+/// digit in them.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::segmentation::unicode_words;
+///
+/// assert_eq!(
+///     unicode_words("Émile's café, 3,5 km — 東京"),
+///     ["Émile's", "café", "3,5", "km", "東", "京"]
+/// );
+/// ```
+#[must_use]
+pub fn unicode_words(text: &str) -> Vec<&str> {
+    word_segments(text)
+        .into_iter()
+        .filter(|segment| segment.chars().any(char::is_alphanumeric))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::break_test_cases;
+    use super::*;
+
+    use test_case::test_case;
+
+    // Cases from WordBreakTest.txt of the Unicode Character Database.
+    #[test_case("", &[]; "empty")]
+    #[test_case("\r\n\r\n", &["\r\n", "\r\n"]; "crlf")]
+    #[test_case("a  \tb", &["a", "  ", "\t", "b"]; "spaces")]
+    #[test_case("a\u{308}b c", &["a\u{308}b", " ", "c"]; "extend")]
+    #[test_case("\u{308}a", &["\u{308}", "a"]; "extend at start")]
+    #[test_case("a\u{AD}b", &["a\u{AD}b"]; "format")]
+    #[test_case("a:b a:", &["a:b", " ", "a", ":"]; "mid letter")]
+    #[test_case("1,000.5 1.", &["1,000.5", " ", "1", "."]; "mid number")]
+    #[test_case("a1_b2", &["a1_b2"]; "extend num let")]
+    #[test_case("\u{5D0}\"\u{5D1}\u{5D0}'", &["\u{5D0}\"\u{5D1}\u{5D0}'"]; "hebrew")]
+    #[test_case("\u{30A2}\u{30A4}\u{3042}", &["\u{30A2}\u{30A4}", "\u{3042}"]; "katakana")]
+    #[test_case("\u{1F1E6}\u{1F1E7}\u{1F1E8}", &["\u{1F1E6}\u{1F1E7}", "\u{1F1E8}"]; "regional indicators")]
+    #[test_case("\u{1F1E6}\u{308}\u{1F1E7}\u{1F1E8}", &["\u{1F1E6}\u{308}\u{1F1E7}", "\u{1F1E8}"]; "regional indicators with extend")]
+    #[test_case("\u{1F6D1}\u{200D}\u{1F6D1}", &["\u{1F6D1}\u{200D}\u{1F6D1}"]; "zwj sequence")]
+    #[test_case("a\u{200D}\u{1F6D1}", &["a\u{200D}\u{1F6D1}"]; "zwj after letter")]
+    #[test_case(" \u{200D}\u{1F6D1}", &[" \u{200D}\u{1F6D1}"]; "zwj after space")]
+    #[test_case("\n\u{308}", &["\n", "\u{308}"]; "extend after newline")]
+    fn splits(text: &str, expected: &[&str]) {
+        assert_eq!(word_segments(text), expected);
+    }
+
+    #[test]
+    #[ignore = "needs the Unicode Character Database in the `UCD` variable"]
+    fn conformance() {
+        for expected in break_test_cases("WordBreakTest.txt") {
+            let text = expected.concat();
+            assert_eq!(word_segments(&text), expected, "{text:?}");
+        }
+    }
+}
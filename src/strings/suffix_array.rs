@@ -0,0 +1,605 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
+/// Marks an empty slot of a suffix array under construction.
+const EMPTY: usize = usize::MAX;
+
+/// Builds the suffix array of a sequence with the SA-IS algorithm.
+///
+/// The suffix array lists the starting positions of the suffixes of `text`
+/// in lexicographic order. Items are ordered by their value as `u64`, so
+/// this works on bytes, `char`s and other integers alike. When the values
+/// are sparse compared with the length of the text they are first replaced
+/// by their ranks.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::suffix_array;
+///
+/// assert_eq!(suffix_array(b"banana"), [5, 3, 1, 0, 4, 2]);
+///
+/// let text: Vec<char> = "\u{e6}b\u{e6}".chars().collect();
+/// assert_eq!(suffix_array(&text), [1, 2, 0]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - O(n + σ) for values below `σ`
+/// - O(n log n) when the values have to be ranked first
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Suffix_array)
+/// - [Nong, Zhang and Chan, Two Efficient Algorithms for Linear Time Suffix Array Construction](https://doi.org/10.1109/TC.2010.188)
+#[must_use]
+pub fn suffix_array<T>(text: &[T]) -> Vec<usize>
+where
+    T: Copy + Into<u64>,
+{
+    let (text, alphabet) = compress(text);
+    suffix_array_with_alphabet(&text, alphabet)
+}
+
+/// Builds the suffix array of a sequence over the integer alphabet `0..alphabet`
+/// with the SA-IS algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::suffix_array_with_alphabet;
+///
+/// assert_eq!(suffix_array_with_alphabet(&[2, 0, 1, 0, 1, 0], 3), [5, 3, 1, 4, 2, 0]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n + alphabet)
+///
+/// # Panics
+///
+/// Panics if a value of `text` is not less than `alphabet`.
+#[must_use]
+pub fn suffix_array_with_alphabet(text: &[usize], alphabet: usize) -> Vec<usize> {
+    assert!(
+        text.iter().all(|&c| c < alphabet),
+        "every value must be less than the size of the alphabet"
+    );
+    sa_is(text, alphabet.saturating_sub(1))
+}
+
+/// Replaces the values of `text` by their ranks if they would need more
+/// buckets than there are items.
+fn compress<T>(text: &[T]) -> (Vec<usize>, usize)
+where
+    T: Copy + Into<u64>,
+{
+    let max = text.iter().map(|&c| c.into()).max().unwrap_or(0);
+    if let Ok(alphabet) = usize::try_from(max) {
+        if alphabet <= text.len().max(256) {
+            let values = text
+                .iter()
+                .map(|&c| usize::try_from(c.into()).unwrap_or(EMPTY));
+            return (values.collect(), alphabet + 1);
+        }
+    }
+
+    let mut values: Vec<u64> = text.iter().map(|&c| c.into()).collect();
+    values.sort_unstable();
+    values.dedup();
+    let ranks = text
+        .iter()
+        .map(|&c| values.binary_search(&c.into()).unwrap_or_default())
+        .collect();
+    (ranks, values.len())
+}
+
+/// SA-IS over values up to `upper`, following the `AtCoder` Library.
+///
+/// Suffixes are classified as S-type if they are smaller than the next
+/// suffix and L-type otherwise. The leftmost S-type suffixes (LMS) are
+/// sorted by induction, named, sorted recursively if their names are not
+/// distinct, and used to induce the order of every other suffix.
+fn sa_is(text: &[usize], upper: usize) -> Vec<usize> {
+    let n = text.len();
+    match n {
+        0 => return vec![],
+        1 => return vec![0],
+        2 => {
+            return if text[0] < text[1] {
+                vec![0, 1]
+            } else {
+                vec![1, 0]
+            }
+        }
+        _ => {}
+    }
+
+    // `ls[i]` is whether the suffix at `i` is S-type.
+    let mut ls = vec![false; n];
+    for i in (0..n - 1).rev() {
+        ls[i] = if text[i] == text[i + 1] {
+            ls[i + 1]
+        } else {
+            text[i] < text[i + 1]
+        };
+    }
+
+    // The start of the L-type and S-type parts of each bucket.
+    let mut buckets = Buckets {
+        l_type: vec![0; upper + 1],
+        s_type: vec![0; upper + 1],
+    };
+    for (&c, &s_type) in text.iter().zip(&ls) {
+        if s_type {
+            buckets.l_type[c + 1] += 1;
+        } else {
+            buckets.s_type[c] += 1;
+        }
+    }
+    for c in 0..=upper {
+        buckets.s_type[c] += buckets.l_type[c];
+        if c < upper {
+            buckets.l_type[c + 1] += buckets.s_type[c];
+        }
+    }
+
+    let lms: Vec<usize> = (1..n).filter(|&i| !ls[i - 1] && ls[i]).collect();
+    let mut lms_index = vec![EMPTY; n + 1];
+    for (k, &i) in lms.iter().enumerate() {
+        lms_index[i] = k;
+    }
+
+    let mut sa = vec![EMPTY; n];
+    induce(&mut sa, text, &ls, &buckets, &lms);
+
+    if !lms.is_empty() {
+        let mut sorted_lms: Vec<usize> = sa
+            .iter()
+            .copied()
+            .filter(|&v| lms_index[v] != EMPTY)
+            .collect();
+
+        // Name the LMS substrings, equal substrings getting equal names.
+        let mut names = vec![0; lms.len()];
+        let mut last_name = 0;
+        for pair in sorted_lms.windows(2) {
+            let (mut left, mut right) = (pair[0], pair[1]);
+            let left_end = lms.get(lms_index[left] + 1).copied().unwrap_or(n);
+            let right_end = lms.get(lms_index[right] + 1).copied().unwrap_or(n);
+
+            let mut same = left_end - left == right_end - right;
+            if same {
+                while left < left_end && text[left] == text[right] {
+                    left += 1;
+                    right += 1;
+                }
+                if left == n || text[left] != text[right] {
+                    same = false;
+                }
+            }
+            if !same {
+                last_name += 1;
+            }
+            names[lms_index[pair[1]]] = last_name;
+        }
+
+        let order = sa_is(&names, last_name);
+        for (sorted, &i) in sorted_lms.iter_mut().zip(&order) {
+            *sorted = lms[i];
+        }
+        induce(&mut sa, text, &ls, &buckets, &sorted_lms);
+    }
+
+    sa
+}
+
+/// Where the L-type and S-type suffixes starting with each value begin in the suffix array.
+struct Buckets {
+    l_type: Vec<usize>,
+    s_type: Vec<usize>,
+}
+
+/// Places the sorted LMS suffixes and induces the order of the others from them.
+fn induce(sa: &mut [usize], text: &[usize], ls: &[bool], buckets: &Buckets, lms: &[usize]) {
+    let n = text.len();
+    sa.fill(EMPTY);
+
+    let mut next = buckets.s_type.clone();
+    for &i in lms {
+        if i != n {
+            sa[next[text[i]]] = i;
+            next[text[i]] += 1;
+        }
+    }
+
+    // L-type suffixes, from left to right.
+    next.copy_from_slice(&buckets.l_type);
+    sa[next[text[n - 1]]] = n - 1;
+    next[text[n - 1]] += 1;
+    for k in 0..n {
+        let v = sa[k];
+        if v != EMPTY && v >= 1 && !ls[v - 1] {
+            sa[next[text[v - 1]]] = v - 1;
+            next[text[v - 1]] += 1;
+        }
+    }
+
+    // S-type suffixes, from right to left.
+    next.copy_from_slice(&buckets.l_type);
+    for k in (0..n).rev() {
+        let v = sa[k];
+        if v != EMPTY && v >= 1 && ls[v - 1] {
+            next[text[v - 1] + 1] -= 1;
+            sa[next[text[v - 1] + 1]] = v - 1;
+        }
+    }
+}
+
+/// Builds the longest common prefix array of a sequence with Kasai's algorithm.
+///
+/// Entry `i` is the length of the longest common prefix of the suffixes at
+/// `suffix_array[i - 1]` and `suffix_array[i]`. The first entry is `0`.
+///
+/// # Arguments
+///
+/// * `text` - The sequence
+/// * `suffix_array` - The suffix array of `text`
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::{lcp_array, suffix_array};
+///
+/// let text = b"banana";
+/// let sa = suffix_array(text);
+/// assert_eq!(lcp_array(text, &sa), [0, 1, 3, 0, 0, 2]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n)
+///
+/// # References
+///
+/// - [Kasai et al., Linear-Time Longest-Common-Prefix Computation in Suffix Arrays and Its Applications](https://doi.org/10.1007/3-540-48194-X_17)
+#[must_use]
+pub fn lcp_array<T>(text: &[T], suffix_array: &[usize]) -> Vec<usize>
+where
+    T: Eq,
+{
+    let n = text.len();
+    let mut rank = vec![0; n];
+    for (i, &suffix) in suffix_array.iter().enumerate() {
+        rank[suffix] = i;
+    }
+
+    // The common prefix shrinks by at most one from each suffix to the next.
+    let mut lcp = vec![0; n];
+    let mut h: usize = 0;
+    for i in 0..n {
+        if rank[i] == 0 {
+            h = 0;
+            continue;
+        }
+        let j = suffix_array[rank[i] - 1];
+        while i + h < n && j + h < n && text[i + h] == text[j + h] {
+            h += 1;
+        }
+        lcp[rank[i]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+/// A suffix array of a sequence with its LCP array, for answering substring queries.
+///
+/// Items are ordered by their value as `u64`, which must agree with their
+/// [`Ord`] implementation, as it does for bytes, `char`s and unsigned integers.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::SuffixArray;
+///
+/// let index = SuffixArray::new(b"mississippi");
+///
+/// assert_eq!(index.count(b"ssi"), 2);
+/// assert_eq!(index.locate(b"si"), [3, 6]);
+/// assert_eq!(index.longest_repeated_substring(), Some(1..5));
+/// assert_eq!(index.distinct_substrings(), 53);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuffixArray<'a, T> {
+    text: &'a [T],
+    suffixes: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl<'a, T> SuffixArray<'a, T>
+where
+    T: Copy + Into<u64> + Ord,
+{
+    /// Builds the suffix array and LCP array of `text`.
+    #[must_use]
+    pub fn new(text: &'a [T]) -> Self {
+        let suffixes = suffix_array(text);
+        let lcp = lcp_array(text, &suffixes);
+        Self {
+            text,
+            suffixes,
+            lcp,
+        }
+    }
+
+    /// The indexed sequence.
+    #[must_use]
+    pub fn text(&self) -> &'a [T] {
+        self.text
+    }
+
+    /// The starting positions of the suffixes in lexicographic order.
+    #[must_use]
+    pub fn suffixes(&self) -> &[usize] {
+        &self.suffixes
+    }
+
+    /// The longest common prefix of each suffix with the one before it, as
+    /// returned by [`lcp_array`].
+    #[must_use]
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// The length of the indexed sequence.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Whether the indexed sequence is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Counts the occurrences of `pattern`, including those that overlap, in
+    /// O(m log n).
+    #[must_use]
+    pub fn count(&self, pattern: &[T]) -> usize {
+        self.matching(pattern).len()
+    }
+
+    /// Finds every occurrence of `pattern`, including those that overlap, in
+    /// O(m log n + k log k) for `k` occurrences.
+    ///
+    /// # Returns
+    ///
+    /// The starting positions of the occurrences in ascending order.
+    #[must_use]
+    pub fn locate(&self, pattern: &[T]) -> Vec<usize> {
+        let mut positions = self.suffixes[self.matching(pattern)].to_vec();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Finds the longest substring that occurs at least twice, possibly overlapping.
+    ///
+    /// # Returns
+    ///
+    /// The range of an occurrence of the lexicographically smallest such
+    /// substring, or `None` if no item repeats.
+    #[must_use]
+    pub fn longest_repeated_substring(&self) -> Option<Range<usize>> {
+        let (i, &len) = self
+            .lcp
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, &len)| len)?;
+        let start = self.suffixes[i].min(self.suffixes[i - usize::from(i > 0)]);
+        (len > 0).then(|| start..start + len)
+    }
+
+    /// Counts the distinct non-empty substrings.
+    #[must_use]
+    pub fn distinct_substrings(&self) -> usize {
+        let n = self.text.len();
+        n * (n + 1) / 2 - self.lcp.iter().sum::<usize>()
+    }
+
+    /// The range of suffixes that start with `pattern`.
+    fn matching(&self, pattern: &[T]) -> Range<usize> {
+        let prefix =
+            |suffix: usize| &self.text[suffix..(suffix + pattern.len()).min(self.text.len())];
+        let start = self.suffixes.partition_point(|&s| prefix(s) < pattern);
+        let end = self.suffixes.partition_point(|&s| prefix(s) <= pattern);
+        start..end
+    }
+}
+
+/// Finds the longest common substring of two sequences with a suffix array.
+///
+/// The two sequences are joined by a separator that occurs in neither, and
+/// the longest common prefix of two adjacent suffixes that start in
+/// different sequences is the answer.
+///
+/// # Returns
+///
+/// The ranges of one occurrence of the substring in `a` and in `b`, which
+/// are empty if the sequences have no item in common.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::longest_common_substring;
+///
+/// let (a, b) = (b"xabxac", b"abcabxabcd");
+/// let (in_a, in_b) = longest_common_substring(a, b);
+/// assert_eq!(&a[in_a], b"abxa");
+/// assert_eq!(&b[in_b], b"abxa");
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(n + m), or O((n + m) log(n + m)) when the values have to be ranked
+#[must_use]
+pub fn longest_common_substring<T>(a: &[T], b: &[T]) -> (Range<usize>, Range<usize>)
+where
+    T: Copy + Into<u64>,
+{
+    let joined: Vec<T> = a.iter().chain(b).copied().collect();
+    let (ranks, alphabet) = compress(&joined);
+
+    // Shift every rank up to make room for the separator `0`.
+    let mut text = Vec::with_capacity(joined.len() + 1);
+    text.extend(ranks[..a.len()].iter().map(|&c| c + 1));
+    text.push(0);
+    text.extend(ranks[a.len()..].iter().map(|&c| c + 1));
+
+    let suffixes = suffix_array_with_alphabet(&text, alphabet + 1);
+    let lcp = lcp_array(&text, &suffixes);
+
+    let mut best = (0, 0, 0);
+    for i in 1..text.len() {
+        let (x, y) = (suffixes[i - 1], suffixes[i]);
+        let (in_a, in_b) = match (x < a.len(), y < a.len()) {
+            (true, false) if y > a.len() => (x, y),
+            (false, true) if x > a.len() => (y, x),
+            _ => continue,
+        };
+        if lcp[i] > best.2 {
+            best = (in_a, in_b - a.len() - 1, lcp[i]);
+        }
+    }
+
+    let (start_a, start_b, len) = best;
+    (start_a..start_a + len, start_b..start_b + len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{collections::BTreeSet, vec::Vec};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use test_case::test_case;
+
+    fn naive_suffix_array(text: &[u8]) -> Vec<usize> {
+        let mut suffixes: Vec<usize> = (0..text.len()).collect();
+        suffixes.sort_by_key(|&i| &text[i..]);
+        suffixes
+    }
+
+    fn naive_lcp(text: &[u8], suffixes: &[usize]) -> Vec<usize> {
+        (0..suffixes.len())
+            .map(|i| {
+                i.checked_sub(1).map_or(0, |j| {
+                    let (x, y) = (&text[suffixes[j]..], &text[suffixes[i]..]);
+                    x.iter().zip(y).take_while(|(a, b)| a == b).count()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn agrees_with_sorting_every_small_text() {
+        for len in 0..=8 {
+            for mut code in 0..3_u32.pow(len) {
+                let text: Vec<u8> = (0..len)
+                    .map(|_| {
+                        let c = b'a' + (code % 3) as u8;
+                        code /= 3;
+                        c
+                    })
+                    .collect();
+                let suffixes = suffix_array(&text);
+                assert_eq!(suffixes, naive_suffix_array(&text), "{text:?}");
+                assert_eq!(lcp_array(&text, &suffixes), naive_lcp(&text, &suffixes));
+            }
+        }
+    }
+
+    #[test]
+    fn agrees_with_sorting_long_texts() {
+        let mut rng = StdRng::seed_from_u64(0x9e37_79b9);
+        for largest in [1, 3, 25, u8::MAX] {
+            let text: Vec<u8> = (0..3000).map(|_| rng.gen_range(0..=largest)).collect();
+            let suffixes = suffix_array(&text);
+            assert_eq!(suffixes, naive_suffix_array(&text));
+            assert_eq!(lcp_array(&text, &suffixes), naive_lcp(&text, &suffixes));
+        }
+
+        let periodic = b"abaabaabaabaabaabaab".repeat(50);
+        assert_eq!(suffix_array(&periodic), naive_suffix_array(&periodic));
+    }
+
+    #[test]
+    fn sparse_and_wide_alphabets() {
+        let words: [u64; 6] = [u64::MAX, 7, 1 << 40, 7, u64::MAX, 0];
+        assert_eq!(suffix_array(&words), [5, 1, 3, 2, 4, 0]);
+
+        let text: Vec<char> = "\u{1f600}a\u{1f600}a".chars().collect();
+        assert_eq!(suffix_array(&text), [3, 1, 2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "less than the size of the alphabet")]
+    fn rejects_values_outside_the_alphabet() {
+        let _ = suffix_array_with_alphabet(&[0, 3, 1], 3);
+    }
+
+    #[test]
+    fn queries_agree_with_brute_force() {
+        let text = b"abracadabra cadabra abracadabra";
+        let index = SuffixArray::new(text);
+        assert_eq!((index.len(), index.is_empty()), (text.len(), false));
+        assert_eq!(index.text(), text);
+
+        let mut substrings = BTreeSet::new();
+        for start in 0..text.len() {
+            for end in start + 1..=text.len() {
+                let _ = substrings.insert(&text[start..end]);
+            }
+        }
+        assert_eq!(index.distinct_substrings(), substrings.len());
+
+        for pattern in substrings.iter().chain(&[&b"abrax"[..], b"", b"zzz"]) {
+            let expected: Vec<usize> = (0..=text.len())
+                .filter(|&i| text[i..].starts_with(pattern) && i < text.len())
+                .collect();
+            assert_eq!(index.locate(pattern), expected);
+            assert_eq!(index.count(pattern), expected.len());
+        }
+
+        let repeated = index.longest_repeated_substring().unwrap();
+        assert_eq!(&text[repeated], b"abracadabra");
+    }
+
+    #[test_case(b"abcd" => None)]
+    #[test_case(b"" => None)]
+    #[test_case(b"aaaa" => Some(0..3))]
+    #[test_case(b"banana" => Some(1..4))]
+    fn test_longest_repeated_substring(text: &[u8]) -> Option<Range<usize>> {
+        SuffixArray::new(text).longest_repeated_substring()
+    }
+
+    #[test_case(b"xabxac", b"abcabxabcd" => 4)]
+    #[test_case(b"abc", b"def" => 0)]
+    #[test_case(b"", b"abc" => 0)]
+    #[test_case(b"GeeksforGeeks", b"GeeksQuiz" => 5)]
+    #[test_case(b"aaaa", b"aa" => 2)]
+    fn test_longest_common_substring(a: &[u8], b: &[u8]) -> usize {
+        let (in_a, in_b) = longest_common_substring(a, b);
+        assert_eq!(a[in_a.clone()], b[in_b]);
+        in_a.len()
+    }
+}
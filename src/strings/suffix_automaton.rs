@@ -0,0 +1,353 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::cell::OnceCell;
+
+/// The initial state, reached by the empty string.
+const ROOT: usize = 0;
+
+/// A state of a [`SuffixAutomaton`], standing for a set of substrings that
+/// end at the same positions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct State {
+    /// The transitions, sorted by byte.
+    next: Vec<(u8, usize)>,
+    /// The state of the longest suffix of this state's strings that ends at more positions.
+    link: Option<usize>,
+    /// The length of the longest string of the state.
+    len: usize,
+    /// The index of the last byte of the first occurrence of the state's strings.
+    first_end: usize,
+    /// Whether the state was split off another, and so ends no new position itself.
+    cloned: bool,
+}
+
+impl State {
+    fn goto(&self, byte: u8) -> Option<usize> {
+        self.next
+            .binary_search_by_key(&byte, |&(b, _)| b)
+            .ok()
+            .map(|i| self.next[i].1)
+    }
+
+    fn set(&mut self, byte: u8, state: usize) {
+        match self.next.binary_search_by_key(&byte, |&(b, _)| b) {
+            Ok(i) => self.next[i].1 = state,
+            Err(i) => self.next.insert(i, (byte, state)),
+        }
+    }
+}
+
+/// The smallest automaton that accepts every substring of a text, built
+/// online as bytes are appended.
+///
+/// Each state stands for the substrings that end at the same set of
+/// positions, so the automaton has fewer than `2n` states and `3n`
+/// transitions. Appending a byte takes amortized constant time for a fixed
+/// alphabet, and a substring is looked up by following one transition per
+/// byte, however long the text is.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::SuffixAutomaton;
+///
+/// let mut automaton = SuffixAutomaton::from_text("abcbc");
+/// assert!(automaton.contains("cbc"));
+/// assert_eq!(automaton.count("bc"), 2);
+/// assert_eq!(automaton.first_occurrence("bc"), Some(1));
+/// assert_eq!(automaton.shortest_absent(b"abc"), Some(b"aa".to_vec()));
+///
+/// automaton.extend(b"aa");
+/// assert_eq!(automaton.shortest_absent(b"abc"), Some(b"ac".to_vec()));
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Append: O(log σ) amortized, for `σ` distinct bytes
+/// - Membership and first occurrence: O(m log σ)
+/// - Occurrence count: O(m log σ), after O(n log n) once per batch of appends
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Suffix_automaton)
+/// - [CP Algorithms](https://cp-algorithms.com/string/suffix-automaton.html)
+#[derive(Debug, Clone)]
+pub struct SuffixAutomaton {
+    states: Vec<State>,
+    last: usize,
+    /// The number of positions each state's strings end at, computed when first needed.
+    counts: OnceCell<Vec<usize>>,
+}
+
+impl SuffixAutomaton {
+    /// An automaton for the empty text.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            states: vec![State::default()],
+            last: ROOT,
+            counts: OnceCell::new(),
+        }
+    }
+
+    /// An automaton for `text`.
+    #[must_use]
+    pub fn from_text<T>(text: &T) -> Self
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let mut automaton = Self::new();
+        automaton.extend(text);
+        automaton
+    }
+
+    /// The length of the text.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.states[self.last].len
+    }
+
+    /// Whether the text is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of states.
+    #[must_use]
+    pub fn states(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Appends `bytes` to the text.
+    pub fn extend<T>(&mut self, bytes: &T)
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        for &byte in bytes.as_ref() {
+            self.push(byte);
+        }
+    }
+
+    /// Appends a byte to the text.
+    pub fn push(&mut self, byte: u8) {
+        self.counts = OnceCell::new();
+
+        let len = self.len() + 1;
+        let current = self.states.len();
+        self.states.push(State {
+            len,
+            first_end: len - 1,
+            ..State::default()
+        });
+
+        // Every suffix of the old text without a transition on `byte` gets one to the new state.
+        let mut p = Some(self.last);
+        while let Some(state) = p.filter(|&s| self.states[s].goto(byte).is_none()) {
+            self.states[state].set(byte, current);
+            p = self.states[state].link;
+        }
+
+        self.states[current].link = Some(match p {
+            None => ROOT,
+            Some(p) => {
+                let q = self.states[p].goto(byte).unwrap_or(ROOT);
+                if self.states[p].len + 1 == self.states[q].len {
+                    q
+                } else {
+                    // `q` also stands for longer strings that do not end
+                    // here, so the shorter ones are split off into a clone.
+                    let clone = self.states.len();
+                    self.states.push(State {
+                        len: self.states[p].len + 1,
+                        cloned: true,
+                        ..self.states[q].clone()
+                    });
+
+                    let mut p = Some(p);
+                    while let Some(state) = p.filter(|&s| self.states[s].goto(byte) == Some(q)) {
+                        self.states[state].set(byte, clone);
+                        p = self.states[state].link;
+                    }
+                    self.states[q].link = Some(clone);
+                    clone
+                }
+            }
+        });
+        self.last = current;
+    }
+
+    /// Checks whether `pattern` is a substring of the text.
+    #[must_use]
+    pub fn contains<P>(&self, pattern: &P) -> bool
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        self.walk(pattern.as_ref()).is_some()
+    }
+
+    /// Counts the occurrences of `pattern` in the text, including those that overlap.
+    ///
+    /// The empty pattern occurs at every position from `0` to the length of the text.
+    #[must_use]
+    pub fn count<P>(&self, pattern: &P) -> usize
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref();
+        if pattern.is_empty() {
+            return self.len() + 1;
+        }
+        self.walk(pattern).map_or(0, |state| {
+            self.counts.get_or_init(|| self.end_counts())[state]
+        })
+    }
+
+    /// Finds the first occurrence of `pattern` in the text.
+    ///
+    /// # Returns
+    ///
+    /// The position where the occurrence starts, or `None` if there is none.
+    #[must_use]
+    pub fn first_occurrence<P>(&self, pattern: &P) -> Option<usize>
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref();
+        if pattern.is_empty() {
+            return Some(0);
+        }
+        let state = self.walk(pattern)?;
+        Some(self.states[state].first_end + 1 - pattern.len())
+    }
+
+    /// Finds a shortest string over `alphabet` that is not a substring of the text.
+    ///
+    /// # Returns
+    ///
+    /// The first such string when strings of the same length are ordered
+    /// by the order of their bytes in `alphabet`, or `None` if `alphabet` is empty.
+    #[must_use]
+    pub fn shortest_absent(&self, alphabet: &[u8]) -> Option<Vec<u8>> {
+        // A breadth-first search reaches each state first by its shortest string.
+        let mut parent: Vec<Option<(usize, u8)>> = vec![None; self.states.len()];
+        let mut visited = vec![false; self.states.len()];
+        visited[ROOT] = true;
+        let mut queue = VecDeque::from([ROOT]);
+
+        while let Some(state) = queue.pop_front() {
+            for &byte in alphabet {
+                let Some(next) = self.states[state].goto(byte) else {
+                    let mut absent = vec![byte];
+                    let mut at = state;
+                    while let Some((previous, byte)) = parent[at] {
+                        absent.push(byte);
+                        at = previous;
+                    }
+                    absent.reverse();
+                    return Some(absent);
+                };
+                if !visited[next] {
+                    visited[next] = true;
+                    parent[next] = Some((state, byte));
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Follows `pattern` from the initial state.
+    fn walk(&self, pattern: &[u8]) -> Option<usize> {
+        pattern
+            .iter()
+            .try_fold(ROOT, |state, &byte| self.states[state].goto(byte))
+    }
+
+    /// Counts the end positions of every state, adding those of each state
+    /// to its suffix link from the longest states down.
+    fn end_counts(&self) -> Vec<usize> {
+        let mut counts: Vec<usize> = self
+            .states
+            .iter()
+            .map(|state| usize::from(!state.cloned))
+            .collect();
+        counts[ROOT] = 0;
+
+        let mut order: Vec<usize> = (1..self.states.len()).collect();
+        order.sort_unstable_by_key(|&state| core::cmp::Reverse(self.states[state].len));
+        for state in order {
+            if let Some(link) = self.states[state].link {
+                counts[link] += counts[state];
+            }
+        }
+        counts
+    }
+}
+
+impl Default for SuffixAutomaton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+
+    #[test]
+    fn agrees_with_brute_force_while_appending() {
+        let text = b"abcbcababbbcaabcbca";
+        let mut automaton = SuffixAutomaton::new();
+        assert!(automaton.is_empty());
+
+        for end in 1..=text.len() {
+            automaton.push(text[end - 1]);
+            let text = &text[..end];
+            assert_eq!(automaton.len(), end);
+            assert!(automaton.states() < 2 * end.max(2));
+
+            for start in 0..end {
+                for stop in start + 1..=end.min(start + 5) {
+                    let pattern = &text[start..stop];
+                    let positions: Vec<usize> = (0..end)
+                        .filter(|&i| text[i..].starts_with(pattern))
+                        .collect();
+                    assert!(automaton.contains(pattern));
+                    assert_eq!(automaton.count(pattern), positions.len());
+                    assert_eq!(
+                        automaton.first_occurrence(pattern),
+                        positions.first().copied()
+                    );
+                }
+            }
+            assert!(!automaton.contains(b"abd"));
+            assert_eq!(automaton.count(b"d"), 0);
+            assert_eq!(automaton.first_occurrence(b"d"), None);
+        }
+    }
+
+    #[test]
+    fn empty_pattern() {
+        let automaton = SuffixAutomaton::from_text("abc");
+        assert!(automaton.contains(""));
+        assert_eq!(automaton.count(""), 4);
+        assert_eq!(automaton.first_occurrence(""), Some(0));
+    }
+
+    #[test]
+    fn shortest_absent_substring() {
+        let automaton = SuffixAutomaton::from_text("aabbabaa");
+        assert_eq!(automaton.shortest_absent(b"ab"), Some(b"aaa".to_vec()));
+        assert_eq!(automaton.shortest_absent(b"ba"), Some(b"bbb".to_vec()));
+        assert_eq!(automaton.shortest_absent(b"abc"), Some(b"c".to_vec()));
+        assert_eq!(automaton.shortest_absent(b""), None);
+        assert_eq!(
+            SuffixAutomaton::new().shortest_absent(b"x"),
+            Some(b"x".to_vec())
+        );
+    }
+}
@@ -0,0 +1,488 @@
+use alloc::{vec, vec::Vec};
+
+/// The root, whose path spells the empty string.
+const ROOT: usize = 0;
+
+/// A symbol of the strings of a [`SuffixTree`], one after another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Symbol {
+    Byte(u8),
+    /// The terminator of the string with this index, which occurs nowhere
+    /// else, so that no suffix of an ended string is a prefix of another.
+    End(usize),
+}
+
+/// A node of a [`SuffixTree`], labelled by the edge from its parent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    /// Where the label of the edge starts in the text.
+    start: usize,
+    /// Where the label of the edge ends in the text, or `None` for a leaf,
+    /// whose edge grows with the text.
+    end: Option<usize>,
+    /// The node of the path without its first symbol, for internal nodes.
+    link: usize,
+    /// The children, sorted by the first symbol of their edge.
+    children: Vec<(Symbol, usize)>,
+    /// Where the suffix a leaf stands for starts in the text.
+    suffix: usize,
+}
+
+impl Node {
+    fn child(&self, symbol: Symbol) -> Option<usize> {
+        self.children
+            .binary_search_by_key(&symbol, |&(s, _)| s)
+            .ok()
+            .map(|i| self.children[i].1)
+    }
+
+    fn set_child(&mut self, symbol: Symbol, node: usize) {
+        match self.children.binary_search_by_key(&symbol, |&(s, _)| s) {
+            Ok(i) => self.children[i].1 = node,
+            Err(i) => self.children.insert(i, (symbol, node)),
+        }
+    }
+}
+
+/// A generalized suffix tree of several strings, built online with
+/// Ukkonen's algorithm as bytes are appended to the last of them.
+///
+/// Every suffix of every string is spelled by a path from the root, so a
+/// pattern is looked up by following it down from the root, and the leaves
+/// below where it ends are its occurrences. The tree holds a sequence of
+/// strings: bytes are appended to the last one, which stays open until
+/// [`end_string`](Self::end_string) starts the next. Each ended string
+/// gets a unique terminator, so the tree keeps the strings apart.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::SuffixTree;
+///
+/// let mut tree = SuffixTree::new();
+/// tree.extend("GET /index.html");
+/// assert_eq!(tree.end_string(), 0);
+/// tree.extend("GET /about.html");
+///
+/// assert!(tree.contains(".html"));
+/// assert!(!tree.contains("html GET"));
+/// assert_eq!(tree.occurrences("GET /"), vec![(0, 0), (1, 0)]);
+/// assert_eq!(tree.strings_containing("about"), vec![1]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// - Append: O(log σ) amortized, for `σ` distinct symbols
+/// - Membership: O(m log σ)
+/// - Occurrences: O(m log σ + k log k) for `k` occurrences, plus O(m) per
+///   suffix of the open string that is not yet a leaf
+///
+/// # References
+///
+/// - [Wikipedia](https://en.wikipedia.org/wiki/Ukkonen%27s_algorithm)
+/// - [Ukkonen, E. (1995). On-line construction of suffix trees](https://doi.org/10.1007/BF01206331)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuffixTree {
+    /// The strings one after another, each ended one followed by its terminator.
+    text: Vec<Symbol>,
+    nodes: Vec<Node>,
+    /// Where each string starts in the text.
+    starts: Vec<usize>,
+    /// The node the active point hangs below.
+    active_node: usize,
+    /// Where the first symbol of the edge of the active point is in the text.
+    active_edge: usize,
+    /// How far along that edge the active point is.
+    active_length: usize,
+    /// How many suffixes of the text are not leaves yet.
+    remainder: usize,
+}
+
+impl SuffixTree {
+    /// A tree of one empty string.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            text: Vec::new(),
+            nodes: vec![Node {
+                start: 0,
+                end: Some(0),
+                link: ROOT,
+                children: Vec::new(),
+                suffix: 0,
+            }],
+            starts: vec![0],
+            active_node: ROOT,
+            active_edge: 0,
+            active_length: 0,
+            remainder: 0,
+        }
+    }
+
+    /// A tree of one open string, `text`.
+    #[must_use]
+    pub fn from_text<T>(text: &T) -> Self
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let mut tree = Self::new();
+        tree.extend(text);
+        tree
+    }
+
+    /// The number of strings, including the open one.
+    #[must_use]
+    pub fn strings(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// The bytes of the string with index `string`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such string.
+    #[must_use]
+    pub fn string(&self, string: usize) -> Vec<u8> {
+        let end = self
+            .starts
+            .get(string + 1)
+            .map_or(self.text.len(), |&start| start - 1);
+        self.text[self.starts[string]..end]
+            .iter()
+            .filter_map(|&symbol| match symbol {
+                Symbol::Byte(byte) => Some(byte),
+                Symbol::End(_) => None,
+            })
+            .collect()
+    }
+
+    /// Appends `bytes` to the open string.
+    pub fn extend<T>(&mut self, bytes: &T)
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        for &byte in bytes.as_ref() {
+            self.push(byte);
+        }
+    }
+
+    /// Appends a byte to the open string.
+    pub fn push(&mut self, byte: u8) {
+        self.insert(Symbol::Byte(byte));
+    }
+
+    /// Ends the open string and starts a new, empty one.
+    ///
+    /// # Returns
+    ///
+    /// The index of the ended string.
+    pub fn end_string(&mut self) -> usize {
+        let string = self.starts.len() - 1;
+        self.insert(Symbol::End(string));
+        self.starts.push(self.text.len());
+        string
+    }
+
+    /// Checks whether `pattern` is a substring of one of the strings.
+    #[must_use]
+    pub fn contains<P>(&self, pattern: &P) -> bool
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        self.locate(pattern.as_ref()).is_some()
+    }
+
+    /// Finds every occurrence of `pattern`, including those that overlap.
+    ///
+    /// # Returns
+    ///
+    /// The index of the string and the position in it where each
+    /// occurrence starts, in order. The empty pattern occurs at every
+    /// position from `0` to the length of each string.
+    #[must_use]
+    pub fn occurrences<P>(&self, pattern: &P) -> Vec<(usize, usize)>
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let pattern = pattern.as_ref();
+        if pattern.is_empty() {
+            return (0..self.strings())
+                .flat_map(|string| {
+                    let len = self.string(string).len();
+                    (0..=len).map(move |offset| (string, offset))
+                })
+                .collect();
+        }
+
+        let mut starts = Vec::new();
+        if let Some(node) = self.locate(pattern) {
+            let mut stack = vec![node];
+            while let Some(node) = stack.pop() {
+                if self.nodes[node].end.is_none() {
+                    starts.push(self.nodes[node].suffix);
+                }
+                stack.extend(self.nodes[node].children.iter().map(|&(_, child)| child));
+            }
+        }
+        // The shortest suffixes of the open string are still implicit and have no leaves.
+        let pending = self.text.len() - self.remainder;
+        starts.extend(
+            (pending..=self.text.len().saturating_sub(pattern.len()))
+                .filter(|&start| self.spells(start, pattern)),
+        );
+
+        starts.sort_unstable();
+        starts
+            .into_iter()
+            .map(|start| {
+                let string = self.starts.partition_point(|&s| s <= start) - 1;
+                (string, start - self.starts[string])
+            })
+            .collect()
+    }
+
+    /// Finds the strings `pattern` is a substring of.
+    ///
+    /// # Returns
+    ///
+    /// The indices of the strings, in order.
+    #[must_use]
+    pub fn strings_containing<P>(&self, pattern: &P) -> Vec<usize>
+    where
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let mut strings: Vec<usize> = self
+            .occurrences(pattern)
+            .into_iter()
+            .map(|(string, _)| string)
+            .collect();
+        strings.dedup();
+        strings
+    }
+
+    /// Adds a symbol to the text, making each suffix that stops being a
+    /// prefix of another suffix a leaf.
+    fn insert(&mut self, symbol: Symbol) {
+        let position = self.text.len();
+        self.text.push(symbol);
+        self.remainder += 1;
+        let mut needs_link: Option<usize> = None;
+
+        while self.remainder > 0 {
+            if self.active_length == 0 {
+                self.active_edge = position;
+            }
+            let first = self.text[self.active_edge];
+
+            match self.nodes[self.active_node].child(first) {
+                None => {
+                    let leaf = self.leaf(position);
+                    self.nodes[self.active_node].set_child(symbol, leaf);
+                    if let Some(node) = needs_link.take() {
+                        self.nodes[node].link = self.active_node;
+                    }
+                }
+                Some(next) => {
+                    let len = self.edge_len(next);
+                    if self.active_length >= len {
+                        // Walk down to the node the active point is below.
+                        self.active_edge += len;
+                        self.active_length -= len;
+                        self.active_node = next;
+                        continue;
+                    }
+
+                    if self.text[self.nodes[next].start + self.active_length] == symbol {
+                        // The suffix is already in the tree, and so are all shorter ones.
+                        if let Some(node) = needs_link.take() {
+                            self.nodes[node].link = self.active_node;
+                        }
+                        self.active_length += 1;
+                        break;
+                    }
+
+                    let split_start = self.nodes[next].start;
+                    let split = self.nodes.len();
+                    self.nodes.push(Node {
+                        start: split_start,
+                        end: Some(split_start + self.active_length),
+                        link: ROOT,
+                        children: Vec::new(),
+                        suffix: 0,
+                    });
+                    self.nodes[self.active_node].set_child(first, split);
+
+                    let leaf = self.leaf(position);
+                    self.nodes[next].start += self.active_length;
+                    let rest = self.text[self.nodes[next].start];
+                    self.nodes[split].set_child(rest, next);
+                    self.nodes[split].set_child(symbol, leaf);
+
+                    if let Some(node) = needs_link.replace(split) {
+                        self.nodes[node].link = split;
+                    }
+                }
+            }
+
+            self.remainder -= 1;
+            if self.active_node == ROOT && self.active_length > 0 {
+                self.active_length -= 1;
+                self.active_edge = position + 1 - self.remainder;
+            } else if self.active_node != ROOT {
+                self.active_node = self.nodes[self.active_node].link;
+            }
+        }
+    }
+
+    /// Adds a leaf for the longest suffix that is not one yet.
+    fn leaf(&mut self, position: usize) -> usize {
+        self.nodes.push(Node {
+            start: position,
+            end: None,
+            link: ROOT,
+            children: Vec::new(),
+            suffix: position + 1 - self.remainder,
+        });
+        self.nodes.len() - 1
+    }
+
+    fn edge_len(&self, node: usize) -> usize {
+        self.nodes[node].end.unwrap_or(self.text.len()) - self.nodes[node].start
+    }
+
+    /// Follows `pattern` down from the root.
+    ///
+    /// # Returns
+    ///
+    /// The highest node whose path starts with `pattern`, or `None` if no
+    /// path does.
+    fn locate(&self, pattern: &[u8]) -> Option<usize> {
+        let (mut node, mut matched) = (ROOT, 0);
+        while matched < pattern.len() {
+            node = self.nodes[node].child(Symbol::Byte(pattern[matched]))?;
+            let len = self.edge_len(node).min(pattern.len() - matched);
+            if !self.spells(self.nodes[node].start, &pattern[matched..matched + len]) {
+                return None;
+            }
+            matched += len;
+        }
+        Some(node)
+    }
+
+    /// Checks whether the text at `start` starts with `bytes`.
+    fn spells(&self, start: usize, bytes: &[u8]) -> bool {
+        self.text[start..].len() >= bytes.len()
+            && bytes
+                .iter()
+                .zip(&self.text[start..])
+                .all(|(&byte, &symbol)| symbol == Symbol::Byte(byte))
+    }
+}
+
+impl Default for SuffixTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Every occurrence of `pattern` in `strings`, found naively.
+    fn naive(strings: &[&[u8]], pattern: &[u8]) -> Vec<(usize, usize)> {
+        strings
+            .iter()
+            .enumerate()
+            .flat_map(|(string, text)| {
+                (0..=text.len())
+                    .filter(move |&i| text[i..].starts_with(pattern))
+                    .map(move |i| (string, i))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn agrees_with_brute_force_while_appending() {
+        let texts: [&[u8]; 4] = [b"abcabxabcd", b"aaaaaa", b"", b"abababcab"];
+        let mut tree = SuffixTree::new();
+        let mut strings: Vec<&[u8]> = Vec::new();
+
+        for (index, text) in texts.iter().enumerate() {
+            for end in 0..=text.len() {
+                if end > 0 {
+                    tree.push(text[end - 1]);
+                }
+                strings.push(&text[..end]);
+                assert_eq!(tree.strings(), strings.len());
+                assert_eq!(tree.string(index), text[..end]);
+
+                for (string, text) in strings.iter().enumerate() {
+                    for start in 0..text.len() {
+                        for stop in start + 1..=text.len().min(start + 4) {
+                            let pattern = &text[start..stop];
+                            assert!(tree.contains(pattern));
+                            assert_eq!(tree.occurrences(pattern), naive(&strings, pattern));
+                            assert!(tree.strings_containing(pattern).contains(&string));
+                        }
+                    }
+                }
+                for pattern in [&b"ca"[..], b"aab", b"xa", b"dd", b"aaaaaaa", b""] {
+                    assert_eq!(tree.contains(pattern), !naive(&strings, pattern).is_empty());
+                    assert_eq!(tree.occurrences(pattern), naive(&strings, pattern));
+                }
+                let _ = strings.pop();
+            }
+            strings.push(text);
+            assert_eq!(tree.end_string(), index);
+        }
+        assert_eq!(tree.strings(), texts.len() + 1);
+    }
+
+    #[test]
+    fn agrees_with_brute_force_on_random_strings() {
+        let mut rng = StdRng::seed_from_u64(0x9e37_79b9);
+
+        for _ in 0..50 {
+            let texts: Vec<Vec<u8>> = (0..3)
+                .map(|_| {
+                    (0..rng.gen_range(0..24))
+                        .map(|_| b"ab"[rng.gen_range(0..2)])
+                        .collect()
+                })
+                .collect();
+            let mut tree = SuffixTree::new();
+            for (index, text) in texts.iter().enumerate() {
+                tree.extend(text);
+                if index + 1 < texts.len() {
+                    let _ = tree.end_string();
+                }
+            }
+
+            let strings: Vec<&[u8]> = texts.iter().map(Vec::as_slice).collect();
+            for len in 1..=5 {
+                for bits in 0..1 << len {
+                    let pattern: Vec<u8> = (0..len).map(|i| b"ab"[bits >> i & 1]).collect();
+                    assert_eq!(tree.occurrences(&pattern), naive(&strings, &pattern));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn patterns_do_not_span_strings() {
+        let mut tree = SuffixTree::from_text("abc");
+        let _ = tree.end_string();
+        tree.extend("def");
+        assert!(tree.contains("bc"));
+        assert!(tree.contains("de"));
+        assert!(!tree.contains("cd"));
+        assert_eq!(tree.strings_containing("c"), [0]);
+        assert_eq!(tree.strings_containing("x"), []);
+    }
+}
@@ -1,34 +1,60 @@
 use alloc::string::String;
 
+use super::Locale;
+
 /// A trait for swapping the case of characters in a string.
 pub trait CaseSwapper {
     /// This function will convert all lowercase letters to uppercase letters and vice versa.
     ///
-    /// # Arguments
-    ///
-    /// * `text` - The text to be processed
+    /// Letters map with the full Unicode case mappings, so `ß` becomes `SS`, and a capital sigma
+    /// becomes `ς` at the end of a word.
     ///
     /// # Returns
     ///
     /// Returns the text with all lowercase letters converted to uppercase letters and vice versa.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::CaseSwapper;
+    ///
+    /// assert_eq!("Straße".swaped_case(), "sTRASSE");
+    /// assert_eq!("ΟΔΟΣ".swaped_case(), "οδος");
+    /// ```
     #[must_use]
-    fn swaped_case(&self) -> String;
+    fn swaped_case(&self) -> String {
+        self.swaped_case_in(Locale::Neutral)
+    }
+
+    /// This function will convert all lowercase letters to uppercase letters and vice versa,
+    /// following the case rules of a language.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - The language whose case rules to follow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::{CaseSwapper, Locale};
+    ///
+    /// assert_eq!("Iğdır".swaped_case_in(Locale::Turkish), "ıĞDIR");
+    /// ```
+    #[must_use]
+    fn swaped_case_in(&self, locale: Locale) -> String;
 }
 
 impl CaseSwapper for str {
-    fn swaped_case(&self) -> String {
-        self.chars()
-            .map(|c| {
-                if c.is_ascii_lowercase() {
-                    c.to_ascii_uppercase()
-                } else {
-                    c.to_ascii_lowercase()
-                }
-            })
-            .fold(String::new(), |mut s, ch| {
-                s.push(ch);
-                s
-            })
+    fn swaped_case_in(&self, locale: Locale) -> String {
+        let mut swapped = String::with_capacity(self.len());
+        for (i, c) in self.char_indices() {
+            if c.is_lowercase() {
+                locale.push_upper_char(c, &mut swapped);
+            } else {
+                locale.push_lower_char(self, i, c, &mut swapped);
+            }
+        }
+        swapped
     }
 }
 
@@ -38,8 +64,19 @@ mod tests {
     use test_case::test_case;
 
     #[test_case("Algorithm", "aLGORITHM")]
+    #[test_case("", "")]
+    #[test_case("123 abc!", "123 ABC!")]
+    #[test_case("Élan", "éLAN"; "accented")]
+    #[test_case("ßẞ", "SSß"; "sharp s")]
+    #[test_case("ΣΑΣ σας", "σας ΣΑΣ"; "final sigma")]
     fn check_case_swap(text: &str, expected: &str) {
         let actual = text.swaped_case();
         assert_eq!(expected, actual);
     }
+
+    #[test_case("Iıİi", "ıIiİ"; "dotted and dotless i")]
+    #[test_case("I\u{307}x", "iX")]
+    fn check_turkish_case_swap(text: &str, expected: &str) {
+        assert_eq!(text.swaped_case_in(Locale::Turkish), expected);
+    }
 }
@@ -0,0 +1,179 @@
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::{cmp::Reverse, hash::Hash};
+
+use hashbrown::{Equivalent, HashMap};
+
+/// Counts how many times each of a set of keys occurs, such as the terms of
+/// a text.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::tokenize::Counter;
+///
+/// let counter: Counter<&str> = "to be or not to be".split(' ').collect();
+/// assert_eq!(counter.get("to"), 2);
+/// assert_eq!(counter.get("maybe"), 0);
+/// assert_eq!((counter.len(), counter.total()), (4, 6));
+/// assert_eq!(counter.most_common(2), [(&"be", 2), (&"to", 2)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<K: Eq + Hash> {
+    counts: HashMap<K, usize>,
+    total: usize,
+}
+
+impl<K: Eq + Hash> Counter<K> {
+    /// Creates a counter with no keys.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+            total: 0,
+        }
+    }
+
+    /// Counts an occurrence of a key.
+    pub fn add(&mut self, key: K) {
+        self.add_count(key, 1);
+    }
+
+    /// Counts `count` occurrences of a key.
+    pub fn add_count(&mut self, key: K, count: usize) {
+        *self.counts.entry(key).or_insert(0) += count;
+        self.total += count;
+    }
+
+    /// The number of occurrences of a key.
+    #[must_use]
+    pub fn get<Q: ?Sized + Hash + Equivalent<K>>(&self, key: &Q) -> usize {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// The number of distinct keys.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Whether no key has been counted.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The number of occurrences of all keys.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The keys with their counts, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, usize)> {
+        self.counts.iter().map(|(key, &count)| (key, count))
+    }
+
+    /// Adds the counts of another counter to this one.
+    pub fn merge(&mut self, other: Self) {
+        for (key, count) in other.counts {
+            self.add_count(key, count);
+        }
+    }
+
+    /// The counts by key.
+    #[must_use]
+    pub fn into_map(self) -> HashMap<K, usize> {
+        self.counts
+    }
+}
+
+impl<K: Eq + Hash + Ord> Counter<K> {
+    /// Finds the `k` most common keys.
+    ///
+    /// # Returns
+    ///
+    /// The keys with their counts, from the most common to the least, and
+    /// in order among keys with the same count.
+    ///
+    /// # Performance
+    ///
+    /// ## Time Complexity
+    ///
+    /// O(n log k), for `n` distinct keys.
+    #[must_use]
+    pub fn most_common(&self, k: usize) -> Vec<(&K, usize)> {
+        // A heap of the `k` most common keys so far, least common on top.
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for (key, &count) in &self.counts {
+            heap.push(Reverse((count, Reverse(key))));
+            if heap.len() > k {
+                let _ = heap.pop();
+            }
+        }
+        let mut top: Vec<(&K, usize)> = heap
+            .into_iter()
+            .map(|Reverse((count, Reverse(key)))| (key, count))
+            .collect();
+        top.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        top
+    }
+}
+
+impl<K: Eq + Hash> Default for Counter<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash> Extend<K> for Counter<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, keys: I) {
+        for key in keys {
+            self.add(key);
+        }
+    }
+}
+
+impl<K: Eq + Hash> FromIterator<K> for Counter<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(keys: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(keys);
+        counter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn most_common_agrees_with_sorting() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_1234);
+
+        for _ in 0..100 {
+            let counter: Counter<usize> = (0..rng.gen_range(0..200))
+                .map(|_| rng.gen_range(0..30))
+                .collect();
+            let mut sorted: Vec<(&usize, usize)> = counter.iter().collect();
+            sorted.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+            for k in [0, 1, 5, 30, 40] {
+                let expected = &sorted[..k.min(sorted.len())];
+                assert_eq!(counter.most_common(k), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn merge() {
+        let mut counter: Counter<char> = "hello".chars().collect();
+        counter.merge("world".chars().collect());
+        assert_eq!(counter.get(&'l'), 3);
+        assert_eq!(counter.get(&'o'), 2);
+        assert_eq!(counter.total(), 10);
+        assert_eq!(counter.len(), 7);
+        assert!(!counter.is_empty());
+        assert!(Counter::<char>::default().is_empty());
+    }
+}
@@ -0,0 +1,17 @@
+//! Tokenizing and counting the terms of texts
+//!
+//! A [`Tokenizer`] splits a text into tokens, normalized as configured by
+//! its [`TokenizerBuilder`]. [`Counter`] counts tokens and finds the most
+//! common ones, [`ngrams`] and [`char_ngrams`] count runs of tokens and
+//! characters, and [`StreamingCounter`] counts the tokens of texts given in
+//! chunks.
+
+mod counter;
+mod ngrams;
+mod stream;
+mod tokenizer;
+
+pub use counter::*;
+pub use ngrams::*;
+pub use stream::*;
+pub use tokenizer::*;
@@ -0,0 +1,81 @@
+use alloc::{string::String, vec::Vec};
+use core::hash::Hash;
+
+use super::Counter;
+
+/// Counts the runs of `n` consecutive items of a sequence, such as the
+/// tokens of a text.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::tokenize::ngrams;
+///
+/// let bigrams = ngrams(&["a", "rose", "is", "a", "rose"], 2);
+/// assert_eq!(bigrams.get(&["a", "rose"][..]), 2);
+/// assert_eq!(bigrams.most_common(1), [(&vec!["a", "rose"], 2)]);
+/// ```
+///
+/// # Performance
+///
+/// ## Time Complexity
+///
+/// O(mn) for a sequence of `m` items.
+#[must_use]
+pub fn ngrams<T: Clone + Eq + Hash>(items: &[T], n: usize) -> Counter<Vec<T>> {
+    assert!(n > 0, "n-grams must have at least one item");
+    items.windows(n).map(<[T]>::to_vec).collect()
+}
+
+/// Counts the runs of `n` consecutive characters of a text.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::tokenize::char_ngrams;
+///
+/// let bigrams = char_ngrams("anana", 2);
+/// assert_eq!(bigrams.get("an"), 2);
+/// assert_eq!(bigrams.get("na"), 2);
+/// assert_eq!(char_ngrams("ab", 3).total(), 0);
+/// ```
+#[must_use]
+pub fn char_ngrams(text: &str, n: usize) -> Counter<String> {
+    assert!(n > 0, "n-grams must have at least one character");
+    let chars: Vec<char> = text.chars().collect();
+    chars
+        .windows(n)
+        .map(|window| window.iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case("", 1, 0, 0)]
+    #[test_case("abc", 1, 3, 3)]
+    #[test_case("abc", 3, 1, 1)]
+    #[test_case("aaaa", 2, 1, 3)]
+    #[test_case("ééé", 2, 1, 2; "multibyte")]
+    fn char_ngram_counts(text: &str, n: usize, distinct: usize, total: usize) {
+        let counter = char_ngrams(text, n);
+        assert_eq!((counter.len(), counter.total()), (distinct, total));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one item")]
+    fn empty_ngrams() {
+        let _ = ngrams(&[1, 2], 0);
+    }
+}
@@ -0,0 +1,212 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::mem;
+
+use super::{Counter, Segmentation, Tokenizer};
+use crate::strings::segmentation::word_segments;
+
+/// Counts the tokens of a text given in chunks, such as the blocks of a
+/// file too large to read at once.
+///
+/// Chunks may split a token, or the bytes of a character, anywhere: only
+/// the text up to a point where the tokens cannot change is tokenized, and
+/// the rest waits for the next chunk. That is the last whitespace seen when
+/// splitting at whitespace, and the start of the last two segments of
+/// [`word_segments`] when splitting into words, since whitespace does not
+/// always end a word and a boundary can depend on the character after it.
+/// Memory is bounded by the longest text held back, not by the length of
+/// the text.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::tokenize::{StreamingCounter, Tokenizer};
+///
+/// let tokenizer = Tokenizer::builder().case_folding(true).build();
+/// let mut counter = StreamingCounter::new(&tokenizer);
+/// for chunk in ["the cat sat on the ", "m", "at. THE E", "ND"] {
+///     counter.push(chunk);
+/// }
+/// counter.push_bytes(&[b' ', 0xC3]);
+/// counter.push_bytes(&[0xA9, b't', 0xC3, 0xA9]);
+///
+/// let counts = counter.finish();
+/// assert_eq!(counts.get("the"), 3);
+/// assert_eq!(counts.get("mat."), 1);
+/// assert_eq!(counts.get("end"), 1);
+/// assert_eq!(counts.get("été"), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamingCounter<'a> {
+    tokenizer: &'a Tokenizer,
+    counts: Counter<String>,
+    /// Text whose tokens may still change with the next chunk.
+    pending: String,
+    /// The bytes of a character split between chunks.
+    partial: Vec<u8>,
+}
+
+impl<'a> StreamingCounter<'a> {
+    /// A counter of the tokens that a tokenizer finds.
+    #[must_use]
+    pub fn new(tokenizer: &'a Tokenizer) -> Self {
+        Self {
+            tokenizer,
+            counts: Counter::new(),
+            pending: String::new(),
+            partial: Vec::new(),
+        }
+    }
+
+    /// Counts the tokens of the next chunk of text.
+    pub fn push(&mut self, chunk: &str) {
+        if !self.partial.is_empty() {
+            self.partial.clear();
+            self.pending.push(char::REPLACEMENT_CHARACTER);
+        }
+        self.push_text(chunk);
+    }
+
+    /// Counts the tokens of the next chunk of UTF-8 encoded text.
+    ///
+    /// Bytes that are not valid UTF-8 count as U+FFFD REPLACEMENT
+    /// CHARACTER, as [`String::from_utf8_lossy`] decodes them.
+    pub fn push_bytes(&mut self, chunk: &[u8]) {
+        let mut bytes = mem::take(&mut self.partial);
+        bytes.extend_from_slice(chunk);
+        let mut rest = &bytes[..];
+        loop {
+            match core::str::from_utf8(rest) {
+                Ok(text) => {
+                    self.push_text(text);
+                    break;
+                }
+                Err(error) => {
+                    let (valid, invalid) = rest.split_at(error.valid_up_to());
+                    // SAFETY: `from_utf8` validated the bytes up to here.
+                    self.push_text(unsafe { core::str::from_utf8_unchecked(valid) });
+                    if let Some(len) = error.error_len() {
+                        self.pending.push(char::REPLACEMENT_CHARACTER);
+                        rest = &invalid[len..];
+                    } else {
+                        // The chunk ends within a character.
+                        self.partial.extend_from_slice(invalid);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// The counts of the tokens so far, apart from those that may continue
+    /// in the next chunk.
+    #[must_use]
+    pub fn counts(&self) -> &Counter<String> {
+        &self.counts
+    }
+
+    /// Counts the tokens at the end of the text.
+    ///
+    /// # Returns
+    ///
+    /// The counts of all the tokens of the text.
+    #[must_use]
+    pub fn finish(mut self) -> Counter<String> {
+        if !self.partial.is_empty() {
+            self.pending.push(char::REPLACEMENT_CHARACTER);
+        }
+        let pending = mem::take(&mut self.pending);
+        self.count(&pending);
+        self.counts
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if self.tokenizer.segmentation() == Segmentation::Words {
+            self.push_words(text);
+            return;
+        }
+        let Some((i, c)) = text.char_indices().rev().find(|(_, c)| c.is_whitespace()) else {
+            self.pending.push_str(text);
+            return;
+        };
+        let (complete, rest) = text.split_at(i + c.len_utf8());
+        let mut pending = mem::take(&mut self.pending);
+        pending.push_str(complete);
+        self.count(&pending);
+        pending.clear();
+        pending.push_str(rest);
+        self.pending = pending;
+    }
+
+    /// Counts the words before the last two segments of the text, whose
+    /// boundaries are final whatever follows.
+    fn push_words(&mut self, text: &str) {
+        let mut pending = mem::take(&mut self.pending);
+        pending.push_str(text);
+        let segments = word_segments(&pending);
+        if let [.., second_last, last] = segments[..] {
+            let complete = pending.len() - second_last.len() - last.len();
+            if complete > 0 {
+                self.count(&pending[..complete]);
+                pending.replace_range(..complete, "");
+            }
+        }
+        self.pending = pending;
+    }
+
+    fn count(&mut self, text: &str) {
+        let tokens = self.tokenizer.tokens(text);
+        self.counts.extend(tokens.into_iter().map(Cow::into_owned));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::strings::tokenize::Segmentation;
+
+    fn chunked(tokenizer: &Tokenizer, text: &str, size: usize) -> Counter<String> {
+        let mut counter = StreamingCounter::new(tokenizer);
+        for chunk in text.as_bytes().chunks(size) {
+            counter.push_bytes(chunk);
+        }
+        counter.finish()
+    }
+
+    #[test]
+    fn agrees_with_counting_at_once() {
+        let text =
+            "Ça va? Ça va bien, merci.\n\tEt  toi — ça va?  Très bien! 👍🏽 10\u{202F}000 \u{301}e";
+        for tokenizer in [
+            Tokenizer::new(),
+            Tokenizer::builder()
+                .segmentation(Segmentation::Words)
+                .case_folding(true)
+                .build(),
+        ] {
+            let expected = tokenizer.count(text);
+            for size in 1..=text.len() {
+                assert_eq!(
+                    chunked(&tokenizer, text, size),
+                    expected,
+                    "chunks of {size}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        let tokenizer = Tokenizer::new();
+        let bytes = b"ok \xFF\xFEx \xE2\x82";
+        let expected = tokenizer.count(&String::from_utf8_lossy(bytes));
+        for size in 1..=bytes.len() {
+            let mut counter = StreamingCounter::new(&tokenizer);
+            for chunk in bytes.chunks(size) {
+                counter.push_bytes(chunk);
+            }
+            assert_eq!(counter.counts().get("ok"), 1);
+            assert_eq!(counter.finish(), expected);
+        }
+    }
+}
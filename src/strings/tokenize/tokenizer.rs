@@ -0,0 +1,370 @@
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use hashbrown::HashSet;
+
+use super::{char_ngrams, ngrams, Counter};
+use crate::strings::{
+    segmentation::{extends_grapheme, graphemes, unicode_words},
+    Locale,
+};
+
+/// The stop words of English that Lucene leaves out of its indexes: words
+/// too common to tell texts apart.
+pub const ENGLISH_STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+/// How a [`Tokenizer`] splits a text into tokens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Segmentation {
+    /// Tokens are separated by whitespace, and keep any punctuation in them.
+    #[default]
+    Whitespace,
+    /// Tokens are the words of Unicode Standard Annex #29, as found by
+    /// [`unicode_words`], which leave out punctuation between words but keep
+    /// it within them, as in "can't" and "3.14".
+    Words,
+}
+
+/// Configures and builds a [`Tokenizer`].
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::tokenize::{Tokenizer, ENGLISH_STOP_WORDS};
+///
+/// let tokenizer = Tokenizer::builder()
+///     .case_folding(true)
+///     .strip_punctuation(true)
+///     .stop_words(ENGLISH_STOP_WORDS)
+///     .min_len(2)
+///     .build();
+///
+/// assert_eq!(
+///     tokenizer.tokens("The World, and the \"world\" of Straße: a STRASSE!"),
+///     ["world", "world", "strasse", "strasse"]
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct TokenizerBuilder {
+    segmentation: Segmentation,
+    case_folding: bool,
+    locale: Locale,
+    strip_punctuation: bool,
+    stop_words: Vec<String>,
+    min_len: usize,
+}
+
+impl TokenizerBuilder {
+    /// A builder for a tokenizer that splits at whitespace and keeps every
+    /// token as it is.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the text is split into tokens.
+    #[must_use]
+    pub fn segmentation(mut self, segmentation: Segmentation) -> Self {
+        self.segmentation = segmentation;
+        self
+    }
+
+    /// Sets whether tokens are case folded, so that tokens that differ only
+    /// in case are the same.
+    #[must_use]
+    pub fn case_folding(mut self, yes: bool) -> Self {
+        self.case_folding = yes;
+        self
+    }
+
+    /// Sets the rules to fold the case of tokens by.
+    #[must_use]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets whether to strip the characters that are neither letters nor
+    /// digits from the start and end of tokens, so that "world," is "world".
+    ///
+    /// Tokens with only such characters are left out.
+    #[must_use]
+    pub fn strip_punctuation(mut self, yes: bool) -> Self {
+        self.strip_punctuation = yes;
+        self
+    }
+
+    /// Adds words to leave out of the tokens.
+    ///
+    /// Stop words are case folded and stripped like tokens are, so they
+    /// match regardless of how they are written if the tokens are.
+    #[must_use]
+    pub fn stop_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.stop_words
+            .extend(words.into_iter().map(|word| word.as_ref().to_string()));
+        self
+    }
+
+    /// Sets the minimum number of characters of a token, which shorter ones
+    /// are left out for.
+    ///
+    /// Characters are extended grapheme clusters, so accents do not count.
+    #[must_use]
+    pub fn min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len;
+        self
+    }
+
+    /// Builds the tokenizer.
+    #[must_use]
+    pub fn build(&self) -> Tokenizer {
+        let mut tokenizer = Tokenizer {
+            segmentation: self.segmentation,
+            case_folding: self.case_folding,
+            locale: self.locale,
+            strip_punctuation: self.strip_punctuation,
+            stop_words: HashSet::new(),
+            min_len: self.min_len,
+        };
+        let stop_words: HashSet<String> = self
+            .stop_words
+            .iter()
+            .filter_map(|word| tokenizer.normalize(word))
+            .map(Cow::into_owned)
+            .collect();
+        tokenizer.stop_words = stop_words;
+        tokenizer
+    }
+}
+
+/// Splits texts into normalized tokens, to count or index the terms in them.
+///
+/// [`Tokenizer::new`] splits at whitespace only, and [`Tokenizer::builder`]
+/// configures case folding, punctuation stripping, stop words and a
+/// minimum token length.
+///
+/// # Examples
+///
+/// ```
+/// use algoritmer::strings::tokenize::{Segmentation, Tokenizer};
+///
+/// let tokenizer = Tokenizer::builder()
+///     .segmentation(Segmentation::Words)
+///     .case_folding(true)
+///     .build();
+///
+/// let counts = tokenizer.count("Hello, world! Hello (again), WORLD.");
+/// assert_eq!(counts.most_common(2), [(&"hello".into(), 2), (&"world".into(), 2)]);
+/// assert_eq!(counts.get("again"), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tokenizer {
+    segmentation: Segmentation,
+    case_folding: bool,
+    locale: Locale,
+    strip_punctuation: bool,
+    stop_words: HashSet<String>,
+    min_len: usize,
+}
+
+impl Tokenizer {
+    /// A tokenizer that splits at whitespace and keeps every token as it is.
+    #[must_use]
+    pub fn new() -> Self {
+        TokenizerBuilder::new().build()
+    }
+
+    /// A builder to configure a tokenizer.
+    #[must_use]
+    pub fn builder() -> TokenizerBuilder {
+        TokenizerBuilder::new()
+    }
+
+    /// Splits a text into tokens.
+    ///
+    /// # Returns
+    ///
+    /// The tokens in the order they appear, borrowed from the text unless
+    /// case folding changed them.
+    ///
+    /// # Performance
+    ///
+    /// ## Time Complexity
+    ///
+    /// O(n) for a text of length `n`, apart from the lookups in the
+    /// Unicode property tables.
+    #[must_use]
+    pub fn tokens<'t>(&self, text: &'t str) -> Vec<Cow<'t, str>> {
+        self.segments(text)
+            .into_iter()
+            .filter_map(|token| self.normalize(token))
+            .filter(|token| self.keeps(token))
+            .collect()
+    }
+
+    /// Splits a text into tokens, with the position of each among the tokens
+    /// before stop words and short tokens are left out.
+    ///
+    /// The positions keep the gaps of the tokens left out, so that "quick
+    /// and the fox" does not have "quick" next to "fox".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::tokenize::{Tokenizer, ENGLISH_STOP_WORDS};
+    ///
+    /// let tokenizer = Tokenizer::builder().stop_words(ENGLISH_STOP_WORDS).build();
+    /// let tokens = tokenizer.positioned_tokens("quick and the fox");
+    /// assert_eq!(tokens, [(0, "quick".into()), (3, "fox".into())]);
+    /// ```
+    #[must_use]
+    pub fn positioned_tokens<'t>(&self, text: &'t str) -> Vec<(usize, Cow<'t, str>)> {
+        self.segments(text)
+            .into_iter()
+            .filter_map(|token| self.normalize(token))
+            .enumerate()
+            .filter(|(_, token)| self.keeps(token))
+            .collect()
+    }
+
+    /// Counts the occurrences of each token of a text.
+    ///
+    /// Large texts can be counted in chunks with a
+    /// [`StreamingCounter`](super::StreamingCounter).
+    #[must_use]
+    pub fn count(&self, text: &str) -> Counter<String> {
+        self.tokens(text).into_iter().map(Cow::into_owned).collect()
+    }
+
+    /// Counts the runs of `n` consecutive tokens of a text.
+    ///
+    /// Tokens left out, such as stop words, do not separate the tokens
+    /// around them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::tokenize::Tokenizer;
+    ///
+    /// let bigrams = Tokenizer::new().word_ngrams("to be or not to be", 2);
+    /// assert_eq!(bigrams.get(&["to".to_string(), "be".to_string()][..]), 2);
+    /// assert_eq!(bigrams.total(), 5);
+    /// ```
+    #[must_use]
+    pub fn word_ngrams(&self, text: &str, n: usize) -> Counter<Vec<String>> {
+        let tokens: Vec<String> = self.tokens(text).into_iter().map(Cow::into_owned).collect();
+        ngrams(&tokens, n)
+    }
+
+    /// Counts the runs of `n` consecutive characters within the tokens of a
+    /// text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algoritmer::strings::tokenize::Tokenizer;
+    ///
+    /// let trigrams = Tokenizer::new().char_ngrams("banana band", 3);
+    /// assert_eq!(trigrams.get("ban"), 2);
+    /// assert_eq!(trigrams.get("ana"), 2);
+    /// assert_eq!(trigrams.get("a b"), 0);
+    /// ```
+    #[must_use]
+    pub fn char_ngrams(&self, text: &str, n: usize) -> Counter<String> {
+        let mut counter = Counter::new();
+        for token in self.tokens(text) {
+            counter.merge(char_ngrams(&token, n));
+        }
+        counter
+    }
+
+    /// Splits a text into tokens before they are normalized.
+    fn segments<'t>(&self, text: &'t str) -> Vec<&'t str> {
+        match self.segmentation {
+            Segmentation::Whitespace => text.split_whitespace().collect(),
+            Segmentation::Words => unicode_words(text),
+        }
+    }
+
+    /// How the tokenizer splits a text into tokens.
+    pub(super) fn segmentation(&self) -> Segmentation {
+        self.segmentation
+    }
+
+    /// Strips and folds a token as configured.
+    fn normalize<'t>(&self, token: &'t str) -> Option<Cow<'t, str>> {
+        let token = if self.strip_punctuation {
+            token
+                .trim_start_matches(|c: char| !c.is_alphanumeric())
+                .trim_end_matches(|c: char| !c.is_alphanumeric() && !extends_grapheme(c))
+        } else {
+            token
+        };
+        if token.is_empty() {
+            None
+        } else if self.case_folding {
+            Some(Cow::Owned(self.locale.fold(token)))
+        } else {
+            Some(Cow::Borrowed(token))
+        }
+    }
+
+    /// Whether a normalized token is neither a stop word nor too short.
+    fn keeps(&self, token: &str) -> bool {
+        !self.stop_words.contains(token)
+            && (self.min_len == 0 || graphemes(token).nth(self.min_len - 1).is_some())
+    }
+}
+
+impl Default for Tokenizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(&Tokenizer::new(), "  Hello, world!\n", &["Hello,", "world!"]; "whitespace")]
+    #[test_case(&Tokenizer::builder().strip_punctuation(true).build(), "(a) -- \"b.\" c's", &["a", "b", "c's"]; "strip punctuation")]
+    #[test_case(&Tokenizer::builder().strip_punctuation(true).build(), "cafe\u{301}. ...", &["cafe\u{301}"]; "combining mark at end")]
+    #[test_case(&Tokenizer::builder().strip_punctuation(true).build(), "\u{928}\u{92E}\u{938}\u{94D}\u{924}\u{947}!", &["\u{928}\u{92E}\u{938}\u{94D}\u{924}\u{947}"]; "devanagari mark at end")]
+    #[test_case(&Tokenizer::builder().segmentation(Segmentation::Words).build(), "(a)--b.c 3.14", &["a", "b.c", "3.14"]; "words")]
+    #[test_case(&Tokenizer::builder().case_folding(true).build(), "Straße STRASSE", &["strasse", "strasse"]; "case folding")]
+    #[test_case(&Tokenizer::builder().case_folding(true).locale(Locale::Turkish).build(), "IŞIK", &["ışık"]; "turkish")]
+    #[test_case(&Tokenizer::builder().min_len(3).build(), "a ab abc ne\u{301}e", &["abc", "ne\u{301}e"]; "min len")]
+    #[test_case(&Tokenizer::builder().stop_words(["The", "of"]).build(), "the The of", &["the"]; "stop words")]
+    #[test_case(&Tokenizer::builder().stop_words(["The!"]).case_folding(true).strip_punctuation(true).build(), "THE, end", &["end"]; "normalized stop words")]
+    fn tokens(tokenizer: &Tokenizer, text: &str, expected: &[&str]) {
+        assert_eq!(tokenizer.tokens(text), expected);
+    }
+
+    #[test]
+    fn borrows_unless_folded() {
+        let tokens = Tokenizer::new().tokens("a b");
+        assert!(tokens.iter().all(|token| matches!(token, Cow::Borrowed(_))));
+    }
+}